@@ -0,0 +1,1059 @@
+use crate::core_arch::arm_shared::{
+    int16x8_t, int32x4_t, int64x2_t, int8x16_t, uint16x8_t, uint32x4_t, uint64x2_t, uint8x16_t,
+};
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.aarch64.crypto.sha512h"]
+    fn vsha512hq_u64_(hash_ed: uint64x2_t, hash_gf: uint64x2_t, kwh_kwh2: uint64x2_t)
+        -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.sha512h2"]
+    fn vsha512h2q_u64_(sum_ab: uint64x2_t, hash_c_: uint64x2_t, hash_ab: uint64x2_t) -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.sha512su0"]
+    fn vsha512su0q_u64_(w0_1: uint64x2_t, w2_: uint64x2_t) -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.sha512su1"]
+    fn vsha512su1q_u64_(s01_s02: uint64x2_t, w14_15: uint64x2_t, w9_10: uint64x2_t) -> uint64x2_t;
+
+    #[link_name = "llvm.aarch64.crypto.eor3s.v16i8"]
+    fn veor3q_s8_(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+    #[link_name = "llvm.aarch64.crypto.eor3s.v8i16"]
+    fn veor3q_s16_(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+    #[link_name = "llvm.aarch64.crypto.eor3s.v4i32"]
+    fn veor3q_s32_(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+    #[link_name = "llvm.aarch64.crypto.eor3s.v2i64"]
+    fn veor3q_s64_(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t;
+    #[link_name = "llvm.aarch64.crypto.eor3u.v16i8"]
+    fn veor3q_u8_(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+    #[link_name = "llvm.aarch64.crypto.eor3u.v8i16"]
+    fn veor3q_u16_(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+    #[link_name = "llvm.aarch64.crypto.eor3u.v4i32"]
+    fn veor3q_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.eor3u.v2i64"]
+    fn veor3q_u64_(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.rax1"]
+    fn vrax1q_u64_(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.xar"]
+    fn vxarq_u64_(a: uint64x2_t, b: uint64x2_t, imm6: i64) -> uint64x2_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxs.v16i8"]
+    fn vbcaxq_s8_(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxs.v8i16"]
+    fn vbcaxq_s16_(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxs.v4i32"]
+    fn vbcaxq_s32_(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxs.v2i64"]
+    fn vbcaxq_s64_(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxu.v16i8"]
+    fn vbcaxq_u8_(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxu.v8i16"]
+    fn vbcaxq_u16_(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxu.v4i32"]
+    fn vbcaxq_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.bcaxu.v2i64"]
+    fn vbcaxq_u64_(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t;
+
+    #[link_name = "llvm.aarch64.crypto.sm3ss1"]
+    fn vsm3ss1q_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3tt1a"]
+    fn vsm3tt1aq_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, imm2: i64) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3tt1b"]
+    fn vsm3tt1bq_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, imm2: i64) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3tt2a"]
+    fn vsm3tt2aq_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, imm2: i64) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3tt2b"]
+    fn vsm3tt2bq_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t, imm2: i64) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3partw1"]
+    fn vsm3partw1q_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm3partw2"]
+    fn vsm3partw2q_u32_(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm4e"]
+    fn vsm4eq_u32_(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+    #[link_name = "llvm.aarch64.crypto.sm4ekey"]
+    fn vsm4ekeyq_u32_(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t;
+}
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// SHA512 hash update part 1.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(sha512h))]
+pub unsafe fn vsha512hq_u64(
+    hash_ed: uint64x2_t,
+    hash_gf: uint64x2_t,
+    kwh_kwh2: uint64x2_t,
+) -> uint64x2_t {
+    vsha512hq_u64_(hash_ed, hash_gf, kwh_kwh2)
+}
+
+/// SHA512 hash update part 2.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(sha512h2))]
+pub unsafe fn vsha512h2q_u64(
+    sum_ab: uint64x2_t,
+    hash_c_: uint64x2_t,
+    hash_ab: uint64x2_t,
+) -> uint64x2_t {
+    vsha512h2q_u64_(sum_ab, hash_c_, hash_ab)
+}
+
+/// SHA512 schedule update 0.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(sha512su0))]
+pub unsafe fn vsha512su0q_u64(w0_1: uint64x2_t, w2_: uint64x2_t) -> uint64x2_t {
+    vsha512su0q_u64_(w0_1, w2_)
+}
+
+/// SHA512 schedule update 1.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(sha512su1))]
+pub unsafe fn vsha512su1q_u64(
+    s01_s02: uint64x2_t,
+    w14_15: uint64x2_t,
+    w9_10: uint64x2_t,
+) -> uint64x2_t {
+    vsha512su1q_u64_(s01_s02, w14_15, w9_10)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t {
+    veor3q_s8_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t {
+    veor3q_s16_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t {
+    veor3q_s32_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_s64(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t {
+    veor3q_s64_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t {
+    veor3q_u8_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t {
+    veor3q_u16_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    veor3q_u32_(a, b, c)
+}
+
+/// Three-way exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(eor3))]
+pub unsafe fn veor3q_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t {
+    veor3q_u64_(a, b, c)
+}
+
+/// Rotate and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(rax1))]
+pub unsafe fn vrax1q_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    vrax1q_u64_(a, b)
+}
+
+/// Exclusive OR and rotate.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(xar, IMM6 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn vxarq_u64<const IMM6: i32>(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    static_assert_imm6!(IMM6);
+    vxarq_u64_(a, b, IMM6 as i64)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t {
+    vbcaxq_s8_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t {
+    vbcaxq_s16_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t {
+    vbcaxq_s32_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_s64(a: int64x2_t, b: int64x2_t, c: int64x2_t) -> int64x2_t {
+    vbcaxq_s64_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t {
+    vbcaxq_u8_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t {
+    vbcaxq_u16_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    vbcaxq_u32_(a, b, c)
+}
+
+/// Bit clear and exclusive OR.
+#[inline]
+#[target_feature(enable = "neon,sha3")]
+#[cfg_attr(test, assert_instr(bcax))]
+pub unsafe fn vbcaxq_u64(a: uint64x2_t, b: uint64x2_t, c: uint64x2_t) -> uint64x2_t {
+    vbcaxq_u64_(a, b, c)
+}
+
+/// SM3 SS1 rotate and add.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3ss1))]
+pub unsafe fn vsm3ss1q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    vsm3ss1q_u32_(a, b, c)
+}
+
+/// SM3 TT1 hash update for rounds 0 to 15.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3tt1a, IMM2 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vsm3tt1aq_u32<const IMM2: i32>(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    c: uint32x4_t,
+) -> uint32x4_t {
+    static_assert_imm2!(IMM2);
+    vsm3tt1aq_u32_(a, b, c, IMM2 as i64)
+}
+
+/// SM3 TT1 hash update for rounds 16 to 63.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3tt1b, IMM2 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vsm3tt1bq_u32<const IMM2: i32>(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    c: uint32x4_t,
+) -> uint32x4_t {
+    static_assert_imm2!(IMM2);
+    vsm3tt1bq_u32_(a, b, c, IMM2 as i64)
+}
+
+/// SM3 TT2 hash update for rounds 0 to 15.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3tt2a, IMM2 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vsm3tt2aq_u32<const IMM2: i32>(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    c: uint32x4_t,
+) -> uint32x4_t {
+    static_assert_imm2!(IMM2);
+    vsm3tt2aq_u32_(a, b, c, IMM2 as i64)
+}
+
+/// SM3 TT2 hash update for rounds 16 to 63.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3tt2b, IMM2 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn vsm3tt2bq_u32<const IMM2: i32>(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    c: uint32x4_t,
+) -> uint32x4_t {
+    static_assert_imm2!(IMM2);
+    vsm3tt2bq_u32_(a, b, c, IMM2 as i64)
+}
+
+/// SM3 message expansion part 1.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3partw1))]
+pub unsafe fn vsm3partw1q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    vsm3partw1q_u32_(a, b, c)
+}
+
+/// SM3 message expansion part 2.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm3partw2))]
+pub unsafe fn vsm3partw2q_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    vsm3partw2q_u32_(a, b, c)
+}
+
+/// SM4 encryption, four rounds.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm4e))]
+pub unsafe fn vsm4eq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    vsm4eq_u32_(a, b)
+}
+
+/// SM4 key expansion, four rounds.
+#[inline]
+#[target_feature(enable = "neon,sm4")]
+#[cfg_attr(test, assert_instr(sm4ekey))]
+pub unsafe fn vsm4ekeyq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    vsm4ekeyq_u32_(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::{aarch64::*, simd::*};
+    use std::mem::transmute;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_s8() {
+        let a = i8x16::new(
+            90, 127, -92, -55, -18, 19, 56, 93, -126, -89, -52, -15, 22, 59, 96, -123,
+        );
+        let b = i8x16::new(
+            51, 102, -103, -52, -1, 50, 101, -104, -53, -2, 49, 100, -105, -54, -3, 48,
+        );
+        let c = i8x16::new(
+            -61, -62, -63, -64, -57, -58, -59, -60, -53, -54, -55, -56, -49, -50, -51, -52,
+        );
+        let e = i8x16::new(
+            -86, -37, -4, -59, -42, -25, -104, 1, -126, -109, 52, 93, 78, 63, 80, 121,
+        );
+        let r: i8x16 = transmute(veor3q_s8(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_s16() {
+        let a = i16x8::new(4660, 9320, 13981, 18641, 23301, 27962, 32622, -28254);
+        let b = i16x8::new(-3856, 30840, 15420, 7710, 3855, 1927, 963, 481);
+        let c = i16x8::new(-256, -256, -256, -256, -256, -256, -256, -256);
+        let e = i16x8::new(7620, -23792, -2655, -22065, -21750, -27203, -31827, 28483);
+        let r: i16x8 = transmute(veor3q_s16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_s32() {
+        let a = i32x4::new(305419896, 610839793, 916259689, 1221679586);
+        let b = i32x4::new(-252645136, 2021161080, 1010580540, 505290270);
+        let c = i32x4::new(-16711936, -16711936, -16711936, -16711936);
+        let e = i32x4::new(499407240, -1559221367, -173948843, -1446004484);
+        let r: i32x4 = transmute(veor3q_s32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_s64() {
+        let a = i64x2::new(245956587649460685, 327942116865947580);
+        let b = i64x2::new(-1085102592571150096, 8680820740569200760);
+        let c = i64x2::new(-71777214294589696, -71777214294589695);
+        let e = i64x2::new(908002843853809213, -8938076366191480635);
+        let r: i64x2 = transmute(veor3q_s64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_u8() {
+        let a = u8x16::new(
+            90, 127, 164, 201, 238, 19, 56, 93, 130, 167, 204, 241, 22, 59, 96, 133,
+        );
+        let b = u8x16::new(
+            51, 102, 153, 204, 255, 50, 101, 152, 203, 254, 49, 100, 151, 202, 253, 48,
+        );
+        let c = u8x16::new(
+            195, 194, 193, 192, 199, 198, 197, 196, 203, 202, 201, 200, 207, 206, 205, 204,
+        );
+        let e = u8x16::new(
+            170, 219, 252, 197, 214, 231, 152, 1, 130, 147, 52, 93, 78, 63, 80, 121,
+        );
+        let r: u8x16 = transmute(veor3q_u8(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_u16() {
+        let a = u16x8::new(4660, 9320, 13981, 18641, 23301, 27962, 32622, 37282);
+        let b = u16x8::new(61680, 30840, 15420, 7710, 3855, 1927, 963, 481);
+        let c = u16x8::new(65280, 65280, 65280, 65280, 65280, 65280, 65280, 65280);
+        let e = u16x8::new(7620, 41744, 62881, 43471, 43786, 38333, 33709, 28483);
+        let r: u16x8 = transmute(veor3q_u16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_u32() {
+        let a = u32x4::new(0x12345678, 0x2468acf1, 0x369d0369, 0x48d159e2);
+        let b = u32x4::new(0xf0f0f0f0, 0x78787878, 0x3c3c3c3c, 0x1e1e1e1e);
+        let c = u32x4::new(0xff00ff00, 0xff00ff00, 0xff00ff00, 0xff00ff00);
+        let e = u32x4::new(0x1dc45988, 0xa3102b89, 0xf5a1c055, 0xa9cfb8fc);
+        let r: u32x4 = transmute(veor3q_u32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_veor3q_u64() {
+        let a = u64x2::new(0x369d0369d0369cd, 0x48d159e26af37bc);
+        let b = u64x2::new(0xf0f0f0f0f0f0f0f0, 0x7878787878787878);
+        let c = u64x2::new(0xff00ff00ff00ff00, 0xff00ff00ff00ff01);
+        let e = u64x2::new(0xc99dfc692f3663d, 0x83f592e6a1d7b0c5);
+        let r: u64x2 = transmute(veor3q_u64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_s8() {
+        let a = i8x16::new(
+            90, 127, -92, -55, -18, 19, 56, 93, -126, -89, -52, -15, 22, 59, 96, -123,
+        );
+        let b = i8x16::new(
+            51, 102, -103, -52, -1, 50, 101, -104, -53, -2, 49, 100, -105, -54, -3, 48,
+        );
+        let c = i8x16::new(
+            -61, -62, -63, -64, -57, -58, -59, -60, -53, -54, -55, -56, -49, -50, -51, -52,
+        );
+        let e = i8x16::new(
+            106, 91, -68, -59, -42, 35, 24, 69, -126, -109, -4, -43, 6, 59, 80, -75,
+        );
+        let r: i8x16 = transmute(vbcaxq_s8(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_s16() {
+        let a = i16x8::new(4660, 9320, 13981, 18641, 23301, 27962, 32622, -28254);
+        let b = i16x8::new(-3856, 30840, 15420, 7710, 3855, 1927, 963, 481);
+        let c = i16x8::new(-256, -256, -256, -256, -256, -256, -256, -256);
+        let e = i16x8::new(4804, 9232, 13985, 18639, 23306, 28093, 32685, -28349);
+        let r: i16x8 = transmute(vbcaxq_s16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_s32() {
+        let a = i32x4::new(305419896, 610839793, 916259689, 1221679586);
+        let b = i32x4::new(-252645136, 2021161080, 1010580540, 505290270);
+        let c = i32x4::new(-16711936, -16711936, -16711936, -16711936);
+        let e = i32x4::new(314857096, 605072521, 916521813, 1221548540);
+        let r: i32x4 = transmute(vbcaxq_s32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_s64() {
+        let a = i64x2::new(245956587649460685, 327942116865947580);
+        let b = i64x2::new(-1085102592571150096, 8680820740569200760);
+        let c = i64x2::new(-71777214294589696, -71777214294589695);
+        let e = i64x2::new(259468005022591293, 357215823684122564);
+        let r: i64x2 = transmute(vbcaxq_s64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_u8() {
+        let a = u8x16::new(
+            90, 127, 164, 201, 238, 19, 56, 93, 130, 167, 204, 241, 22, 59, 96, 133,
+        );
+        let b = u8x16::new(
+            51, 102, 153, 204, 255, 50, 101, 152, 203, 254, 49, 100, 151, 202, 253, 48,
+        );
+        let c = u8x16::new(
+            195, 194, 193, 192, 199, 198, 197, 196, 203, 202, 201, 200, 207, 206, 205, 204,
+        );
+        let e = u8x16::new(
+            106, 91, 188, 197, 214, 35, 24, 69, 130, 147, 252, 213, 6, 59, 80, 181,
+        );
+        let r: u8x16 = transmute(vbcaxq_u8(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_u16() {
+        let a = u16x8::new(4660, 9320, 13981, 18641, 23301, 27962, 32622, 37282);
+        let b = u16x8::new(61680, 30840, 15420, 7710, 3855, 1927, 963, 481);
+        let c = u16x8::new(65280, 65280, 65280, 65280, 65280, 65280, 65280, 65280);
+        let e = u16x8::new(4804, 9232, 13985, 18639, 23306, 28093, 32685, 37187);
+        let r: u16x8 = transmute(vbcaxq_u16(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_u32() {
+        let a = u32x4::new(0x12345678, 0x2468acf1, 0x369d0369, 0x48d159e2);
+        let b = u32x4::new(0xf0f0f0f0, 0x78787878, 0x3c3c3c3c, 0x1e1e1e1e);
+        let c = u32x4::new(0xff00ff00, 0xff00ff00, 0xff00ff00, 0xff00ff00);
+        let e = u32x4::new(0x12c45688, 0x2410ac89, 0x36a10355, 0x48cf59fc);
+        let r: u32x4 = transmute(vbcaxq_u32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vbcaxq_u64() {
+        let a = u64x2::new(0x369d0369d0369cd, 0x48d159e26af37bc);
+        let b = u64x2::new(0xf0f0f0f0f0f0f0f0, 0x7878787878787878);
+        let c = u64x2::new(0xff00ff00ff00ff00, 0xff00ff00ff00ff01);
+        let e = u64x2::new(0x399d0c69df3693d, 0x4f515e626d737c4);
+        let r: u64x2 = transmute(vbcaxq_u64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vrax1q_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0x8000000000000001);
+        let b = u64x2::new(0x8000000000000001, 0xfedcba9876543210);
+        let e = u64x2::new(0x123456789abcdec, 0x7db97530eca86420);
+        let r: u64x2 = transmute(vrax1q_u64(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vxarq_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0x8000000000000001);
+        let b = u64x2::new(0x8000000000000001, 0xfedcba9876543210);
+        let e = u64x2::new(0xee8123456789abcd, 0x117edcba98765432);
+        let r: u64x2 = transmute(vxarq_u64::<8>(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vsha512hq_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0xfedcba9876543210);
+        let b = u64x2::new(0xf1e2d3c4b5a6978, 0x8796a5b4c3d2e1f0);
+        let c = u64x2::new(0x1111222233334444, 0x5555666677778888);
+        let e = u64x2::new(0x8d076d59e10dbc4, 0x79ac5c11be518638);
+        let r: u64x2 = transmute(vsha512hq_u64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vsha512h2q_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0xfedcba9876543210);
+        let b = u64x2::new(0xf1e2d3c4b5a6978, 0x8796a5b4c3d2e1f0);
+        let c = u64x2::new(0x1111222233334444, 0x5555666677778888);
+        let e = u64x2::new(0xd3b15eb575d5ec27, 0xf90d497873bc347);
+        let r: u64x2 = transmute(vsha512h2q_u64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vsha512su0q_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0xfedcba9876543210);
+        let b = u64x2::new(0xf1e2d3c4b5a6978, 0x8796a5b4c3d2e1f0);
+        let e = u64x2::new(0x6f907deb1d5cb34d, 0x7e7aef81d7c50c17);
+        let r: u64x2 = transmute(vsha512su0q_u64(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vsha512su1q_u64() {
+        let a = u64x2::new(0x123456789abcdef, 0xfedcba9876543210);
+        let b = u64x2::new(0xf1e2d3c4b5a6978, 0x8796a5b4c3d2e1f0);
+        let c = u64x2::new(0x1111222233334444, 0x5555666677778888);
+        let e = u64x2::new(0x4816783f2b38bd41, 0xb6c788c106fa9711);
+        let r: u64x2 = transmute(vsha512su1q_u64(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3ss1q_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x0, 0x0, 0x0, 0x35b8eebf);
+        let r: u32x4 = transmute(vsm3ss1q_u32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3tt1aq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x89abcdef, 0xb97531fd, 0x76543210, 0xb66db5a7);
+        let r: u32x4 = transmute(vsm3tt1aq_u32::<1>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3tt1bq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x89abcdef, 0xb97531fd, 0x76543210, 0xd6494cfa);
+        let r: u32x4 = transmute(vsm3tt1bq_u32::<2>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3tt2aq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x89abcdef, 0xd4c7f6e5, 0x76543210, 0xf6f6021c);
+        let r: u32x4 = transmute(vsm3tt2aq_u32::<3>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3tt2bq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x89abcdef, 0xd4c7f6e5, 0x76543210, 0x4a6414da);
+        let r: u32x4 = transmute(vsm3tt2bq_u32::<0>(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3partw1q_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0xf5a82875, 0x2864d79b, 0xe49b7d02, 0x2f76a7fe);
+        let r: u32x4 = transmute(vsm3partw1q_u32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm3partw2q_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let c = u32x4::new(0x11112222, 0x33334444, 0x55556666, 0x77778888);
+        let e = u32x4::new(0x86ac7953, 0x5b53868e, 0xd3f92c06, 0x52741630);
+        let r: u32x4 = transmute(vsm3partw2q_u32(transmute(a), transmute(b), transmute(c)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm4eq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let e = u32x4::new(0x7d05e4ab, 0x851257f1, 0xd8339f3b, 0xcd9cbd0a);
+        let r: u32x4 = transmute(vsm4eq_u32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_vsm4ekeyq_u32() {
+        let a = u32x4::new(0x1234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let b = u32x4::new(0xf1e2d3c, 0x4b5a6978, 0x8796a5b4, 0xc3d2e1f0);
+        let e = u32x4::new(0x6c93422f, 0x5b4d6d28, 0xf5ae4abe, 0x82753175);
+        let r: u32x4 = transmute(vsm4ekeyq_u32(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    // SHA-512 round constants, FIPS 180-4 section 4.2.3.
+    const SHA512_K: [u64; 80] = [
+        0x428a2f98d728ae22,
+        0x7137449123ef65cd,
+        0xb5c0fbcfec4d3b2f,
+        0xe9b5dba58189dbbc,
+        0x3956c25bf348b538,
+        0x59f111f1b605d019,
+        0x923f82a4af194f9b,
+        0xab1c5ed5da6d8118,
+        0xd807aa98a3030242,
+        0x12835b0145706fbe,
+        0x243185be4ee4b28c,
+        0x550c7dc3d5ffb4e2,
+        0x72be5d74f27b896f,
+        0x80deb1fe3b1696b1,
+        0x9bdc06a725c71235,
+        0xc19bf174cf692694,
+        0xe49b69c19ef14ad2,
+        0xefbe4786384f25e3,
+        0x0fc19dc68b8cd5b5,
+        0x240ca1cc77ac9c65,
+        0x2de92c6f592b0275,
+        0x4a7484aa6ea6e483,
+        0x5cb0a9dcbd41fbd4,
+        0x76f988da831153b5,
+        0x983e5152ee66dfab,
+        0xa831c66d2db43210,
+        0xb00327c898fb213f,
+        0xbf597fc7beef0ee4,
+        0xc6e00bf33da88fc2,
+        0xd5a79147930aa725,
+        0x06ca6351e003826f,
+        0x142929670a0e6e70,
+        0x27b70a8546d22ffc,
+        0x2e1b21385c26c926,
+        0x4d2c6dfc5ac42aed,
+        0x53380d139d95b3df,
+        0x650a73548baf63de,
+        0x766a0abb3c77b2a8,
+        0x81c2c92e47edaee6,
+        0x92722c851482353b,
+        0xa2bfe8a14cf10364,
+        0xa81a664bbc423001,
+        0xc24b8b70d0f89791,
+        0xc76c51a30654be30,
+        0xd192e819d6ef5218,
+        0xd69906245565a910,
+        0xf40e35855771202a,
+        0x106aa07032bbd1b8,
+        0x19a4c116b8d2d0c8,
+        0x1e376c085141ab53,
+        0x2748774cdf8eeb99,
+        0x34b0bcb5e19b48a8,
+        0x391c0cb3c5c95a63,
+        0x4ed8aa4ae3418acb,
+        0x5b9cca4f7763e373,
+        0x682e6ff3d6b2b8a3,
+        0x748f82ee5defb2fc,
+        0x78a5636f43172f60,
+        0x84c87814a1f0ab72,
+        0x8cc702081a6439ec,
+        0x90befffa23631e28,
+        0xa4506cebde82bde9,
+        0xbef9a3f7b2c67915,
+        0xc67178f2e372532b,
+        0xca273eceea26619c,
+        0xd186b8c721c0c207,
+        0xeada7dd6cde0eb1e,
+        0xf57d4f7fee6ed178,
+        0x06f067aa72176fba,
+        0x0a637dc5a2c898a6,
+        0x113f9804bef90dae,
+        0x1b710b35131c471b,
+        0x28db77f523047d84,
+        0x32caab7b40c72493,
+        0x3c9ebe0a15c9bebc,
+        0x431d67c49c100d4c,
+        0x4cc5d4becb3e42b6,
+        0x597f299cfc657e2a,
+        0x5fcb6fab3ad6faec,
+        0x6c44198c4a475817,
+    ];
+
+    // SHA-512 compression of a single block, two rounds per
+    // `vsha512hq_u64`/`vsha512h2q_u64` pair.
+    #[target_feature(enable = "neon,sha3")]
+    unsafe fn sha512_block(state: &mut [u64; 8], block: &[u8; 128]) {
+        let mut words = [0u64; 16];
+        for (w, chunk) in words.iter_mut().zip(block.chunks(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *w = u64::from_be_bytes(bytes);
+        }
+        let mut w = [vdupq_n_u64(0); 8];
+        for (i, v) in w.iter_mut().enumerate() {
+            *v = vld1q_u64(words.as_ptr().add(2 * i));
+        }
+        let mut ab = vld1q_u64(state.as_ptr());
+        let mut cd = vld1q_u64(state.as_ptr().add(2));
+        let mut ef = vld1q_u64(state.as_ptr().add(4));
+        let mut gh = vld1q_u64(state.as_ptr().add(6));
+        for i in 0..40 {
+            if i >= 8 {
+                let w9_10 = vextq_u64::<1>(w[(i + 4) % 8], w[(i + 5) % 8]);
+                let s0 = vsha512su0q_u64(w[i % 8], w[(i + 1) % 8]);
+                w[i % 8] = vsha512su1q_u64(s0, w[(i + 7) % 8], w9_10);
+            }
+            let kw = vaddq_u64(vld1q_u64(SHA512_K.as_ptr().add(2 * i)), w[i % 8]);
+            let hash_gf = vextq_u64::<1>(ef, gh);
+            let hash_de = vextq_u64::<1>(cd, ef);
+            let kwh = vaddq_u64(gh, vextq_u64::<1>(kw, kw));
+            let sum = vsha512hq_u64(kwh, hash_gf, hash_de);
+            let sum_ab = vsha512h2q_u64(sum, cd, ab);
+            gh = ef;
+            ef = vaddq_u64(cd, sum);
+            cd = ab;
+            ab = sum_ab;
+        }
+        for (i, v) in [ab, cd, ef, gh].iter().enumerate() {
+            let v: u64x2 = transmute(*v);
+            state[2 * i] = state[2 * i].wrapping_add(v.extract(0));
+            state[2 * i + 1] = state[2 * i + 1].wrapping_add(v.extract(1));
+        }
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_vsha512_fips_180_4() {
+        // FIPS 180-4 example: SHA-512("abc").
+        let mut state = [
+            0x6a09e667f3bcc908,
+            0xbb67ae8584caa73b,
+            0x3c6ef372fe94f82b,
+            0xa54ff53a5f1d36f1,
+            0x510e527fade682d1,
+            0x9b05688c2b3e6c1f,
+            0x1f83d9abfb41bd6b,
+            0x5be0cd19137e2179,
+        ];
+        let mut block = [0u8; 128];
+        block[..3].copy_from_slice(b"abc");
+        block[3] = 0x80;
+        block[127] = 24;
+        sha512_block(&mut state, &block);
+        assert_eq!(
+            state,
+            [
+                0xddaf35a193617aba,
+                0xcc417349ae204131,
+                0x12e6fa4e89a97ea2,
+                0x0a9eeee64b55d39a,
+                0x2192992a274fc1a8,
+                0x36ba3c23a3feebbd,
+                0x454d4423643ce80e,
+                0x2a9ac94fa54ca49f,
+            ]
+        );
+    }
+
+    // Keccak-f[1600] round constants, FIPS 202 section 3.2.5.
+    const KECCAK_RC: [u64; 24] = [
+        0x0000000000000001,
+        0x0000000000008082,
+        0x800000000000808a,
+        0x8000000080008000,
+        0x000000000000808b,
+        0x0000000080000001,
+        0x8000000080008081,
+        0x8000000000008009,
+        0x000000000000008a,
+        0x0000000000000088,
+        0x0000000080008009,
+        0x000000008000000a,
+        0x000000008000808b,
+        0x800000000000008b,
+        0x8000000000008089,
+        0x8000000000008003,
+        0x8000000000008002,
+        0x8000000000000080,
+        0x000000000000800a,
+        0x800000008000000a,
+        0x8000000080008081,
+        0x8000000000008080,
+        0x0000000080000001,
+        0x8000000080008008,
+    ];
+
+    // Keccak-f[1600] on two independent states, one per 64-bit lane.
+    #[target_feature(enable = "neon,sha3")]
+    unsafe fn keccak_f1600(a: &mut [uint64x2_t; 25]) {
+        for &rc in KECCAK_RC.iter() {
+            // theta
+            let mut c = [vdupq_n_u64(0); 5];
+            for (x, c) in c.iter_mut().enumerate() {
+                let t = veor3q_u64(a[x], a[x + 5], a[x + 10]);
+                *c = veor3q_u64(t, a[x + 15], a[x + 20]);
+            }
+            let mut d = [vdupq_n_u64(0); 5];
+            for (x, d) in d.iter_mut().enumerate() {
+                *d = vrax1q_u64(c[(x + 4) % 5], c[(x + 1) % 5]);
+            }
+            // rho and pi
+            let mut b = [vdupq_n_u64(0); 25];
+            b[0] = vxarq_u64::<0>(a[0], d[0]);
+            b[10] = vxarq_u64::<63>(a[1], d[1]);
+            b[20] = vxarq_u64::<2>(a[2], d[2]);
+            b[5] = vxarq_u64::<36>(a[3], d[3]);
+            b[15] = vxarq_u64::<37>(a[4], d[4]);
+            b[16] = vxarq_u64::<28>(a[5], d[0]);
+            b[1] = vxarq_u64::<20>(a[6], d[1]);
+            b[11] = vxarq_u64::<58>(a[7], d[2]);
+            b[21] = vxarq_u64::<9>(a[8], d[3]);
+            b[6] = vxarq_u64::<44>(a[9], d[4]);
+            b[7] = vxarq_u64::<61>(a[10], d[0]);
+            b[17] = vxarq_u64::<54>(a[11], d[1]);
+            b[2] = vxarq_u64::<21>(a[12], d[2]);
+            b[12] = vxarq_u64::<39>(a[13], d[3]);
+            b[22] = vxarq_u64::<25>(a[14], d[4]);
+            b[23] = vxarq_u64::<23>(a[15], d[0]);
+            b[8] = vxarq_u64::<19>(a[16], d[1]);
+            b[18] = vxarq_u64::<49>(a[17], d[2]);
+            b[3] = vxarq_u64::<43>(a[18], d[3]);
+            b[13] = vxarq_u64::<56>(a[19], d[4]);
+            b[14] = vxarq_u64::<46>(a[20], d[0]);
+            b[24] = vxarq_u64::<62>(a[21], d[1]);
+            b[9] = vxarq_u64::<3>(a[22], d[2]);
+            b[19] = vxarq_u64::<8>(a[23], d[3]);
+            b[4] = vxarq_u64::<50>(a[24], d[4]);
+            // chi
+            for y in 0..5 {
+                for x in 0..5 {
+                    let i = x + 5 * y;
+                    a[i] = vbcaxq_u64(b[i], b[(x + 2) % 5 + 5 * y], b[(x + 1) % 5 + 5 * y]);
+                }
+            }
+            // iota
+            a[0] = veorq_u64(a[0], vdupq_n_u64(rc));
+        }
+    }
+
+    #[simd_test(enable = "neon,sha3")]
+    unsafe fn test_sha3_fips_202() {
+        // SHA3-256("") in lane 0 and SHA3-256("abc") in lane 1.
+        let mut a = [vdupq_n_u64(0); 25];
+        a[0] = transmute(u64x2::new(0x06, 0x06636261));
+        a[16] = vdupq_n_u64(0x8000000000000000);
+        keccak_f1600(&mut a);
+        let digest: [u64x2; 4] = [
+            transmute(a[0]),
+            transmute(a[1]),
+            transmute(a[2]),
+            transmute(a[3]),
+        ];
+        let empty: [u64; 4] = [
+            0xa7ffc6f8bf1ed766,
+            0x51c14756a061d662,
+            0xf580ff4de43b49fa,
+            0x82d80a4b80f8434a,
+        ];
+        let abc: [u64; 4] = [
+            0x3a985da74fe225b2,
+            0x045c172d6bd390bd,
+            0x855f086e3e9d525b,
+            0x46bfe24511431532,
+        ];
+        for ((r, e0), e1) in digest.iter().zip(&empty).zip(&abc) {
+            assert_eq!(r.extract(0).to_le_bytes(), e0.to_be_bytes());
+            assert_eq!(r.extract(1).to_le_bytes(), e1.to_be_bytes());
+        }
+    }
+
+    // One SM3 round using message word `LANE` of `w` and `wp`.
+    #[target_feature(enable = "neon,sm4")]
+    unsafe fn sm3_round<const LANE: i32>(
+        j: u32,
+        abcd: &mut uint32x4_t,
+        efgh: &mut uint32x4_t,
+        w: uint32x4_t,
+        wp: uint32x4_t,
+    ) {
+        let t = if j < 16 { 0x79cc4519u32 } else { 0x7a879d8a };
+        let t = transmute(u32x4::new(0, 0, 0, t.rotate_left(j % 32)));
+        let ss1 = vsm3ss1q_u32(*abcd, *efgh, t);
+        if j < 16 {
+            *abcd = vsm3tt1aq_u32::<LANE>(*abcd, ss1, wp);
+            *efgh = vsm3tt2aq_u32::<LANE>(*efgh, ss1, w);
+        } else {
+            *abcd = vsm3tt1bq_u32::<LANE>(*abcd, ss1, wp);
+            *efgh = vsm3tt2bq_u32::<LANE>(*efgh, ss1, w);
+        }
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_sm3_gbt_32905() {
+        // GB/T 32905-2016 example 1: SM3("abc").
+        let iv = u32x4::new(0xda8a0600, 0x172442d7, 0x4914b2b9, 0x7380166f);
+        let iv2 = u32x4::new(0xb0fb0e4e, 0xe38dee4d, 0x163138aa, 0xa96f30bc);
+        let mut abcd: uint32x4_t = transmute(iv);
+        let mut efgh: uint32x4_t = transmute(iv2);
+        let mut w = [vdupq_n_u32(0); 17];
+        w[0] = transmute(u32x4::new(0x61626380, 0, 0, 0));
+        w[3] = transmute(u32x4::new(0, 0, 0, 24));
+        for k in 0..16 {
+            if k < 13 {
+                let t = vextq_u32::<3>(w[k + 1], w[k + 2]);
+                let t = vsm3partw1q_u32(t, w[k], w[k + 3]);
+                let w6 = vextq_u32::<2>(w[k + 2], w[k + 3]);
+                let w13 = vextq_u32::<3>(w[k], w[k + 1]);
+                w[k + 4] = vsm3partw2q_u32(t, w6, w13);
+            }
+            let wp = veorq_u32(w[k], w[k + 1]);
+            let j = 4 * k as u32;
+            sm3_round::<0>(j, &mut abcd, &mut efgh, w[k], wp);
+            sm3_round::<1>(j + 1, &mut abcd, &mut efgh, w[k], wp);
+            sm3_round::<2>(j + 2, &mut abcd, &mut efgh, w[k], wp);
+            sm3_round::<3>(j + 3, &mut abcd, &mut efgh, w[k], wp);
+        }
+        let abcd: u32x4 = transmute(veorq_u32(abcd, transmute(iv)));
+        let efgh: u32x4 = transmute(veorq_u32(efgh, transmute(iv2)));
+        assert_eq!(
+            abcd,
+            u32x4::new(0xdc10e4e2, 0xd1f2d46b, 0x62eeedd9, 0x66c7f0f4)
+        );
+        assert_eq!(
+            efgh,
+            u32x4::new(0x8f4ba8e0, 0x297da02b, 0x5cf2f7a2, 0x4167c487)
+        );
+    }
+
+    #[simd_test(enable = "neon,sm4")]
+    unsafe fn test_sm4_gbt_32907() {
+        // GB/T 32907-2016 example 1: the key and the plaintext are both
+        // 0123456789abcdeffedcba9876543210.
+        let data = u32x4::new(0x01234567, 0x89abcdef, 0xfedcba98, 0x76543210);
+        let fk = u32x4::new(0xa3b1bac6, 0x56aa3350, 0x677d9197, 0xb27022dc);
+        let mut key: uint32x4_t = veorq_u32(transmute(data), transmute(fk));
+        let mut x: uint32x4_t = transmute(data);
+        for i in 0..8 {
+            let mut ck = [0u32; 4];
+            for (j, c) in ck.iter_mut().enumerate() {
+                let n = 4 * (4 * i + j);
+                let b = |k: usize| ((n + k) * 7) as u8;
+                *c = u32::from_be_bytes([b(0), b(1), b(2), b(3)]);
+            }
+            key = vsm4ekeyq_u32(key, vld1q_u32(ck.as_ptr()));
+            x = vsm4eq_u32(x, key);
+        }
+        let r: u32x4 = transmute(x);
+        assert_eq!(
+            r,
+            u32x4::new(0x536e4246, 0x86b3e94f, 0xd206965e, 0x681edf34)
+        );
+    }
+}
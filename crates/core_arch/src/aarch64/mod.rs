@@ -15,9 +15,21 @@ pub use self::neon::*;
 mod tme;
 pub use self::tme::*;
 
+mod mte;
+pub use self::mte::*;
+
+mod pauth;
+pub use self::pauth::*;
+
 mod crc;
 pub use self::crc::*;
 
+mod crypto;
+pub use self::crypto::*;
+
+mod sve;
+pub use self::sve::*;
+
 mod prefetch;
 pub use self::prefetch::*;
 
@@ -0,0 +1,204 @@
+//! ARM's Memory Tagging Extension (MTE).
+//!
+//! MTE associates a 4-bit allocation tag with every 16-byte granule of memory
+//! and a 4-bit logical tag with every pointer, stored in bits 56 to 59 of the
+//! address. These intrinsics only manipulate tags; whether mismatched tags
+//! fault is up to the operating system and the mapping of the memory.
+//!
+//! The reference is the [Arm C Language Extensions][acle], section "Memory
+//! tagging intrinsics".
+//!
+//! [acle]: https://developer.arm.com/documentation/101028/0012/10--Memory-tagging-intrinsics
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.aarch64.irg"]
+    fn aarch64_irg(src: *const u8, exclude: i64) -> *const u8;
+    #[link_name = "llvm.aarch64.addg"]
+    fn aarch64_addg(src: *const u8, offset: i64) -> *const u8;
+    #[link_name = "llvm.aarch64.gmi"]
+    fn aarch64_gmi(src: *const u8, excluded: i64) -> i64;
+    #[link_name = "llvm.aarch64.ldg"]
+    fn aarch64_ldg(ptr: *const u8, tag_address: *const u8) -> *const u8;
+    #[link_name = "llvm.aarch64.stg"]
+    fn aarch64_stg(ptr: *mut u8, tag_address: *mut u8);
+    #[link_name = "llvm.aarch64.subp"]
+    fn aarch64_subp(a: *const u8, b: *const u8) -> i64;
+}
+
+/// Returns a copy of `src` with a randomly generated logical tag.
+///
+/// Tags whose bit is set in `mask` are never generated, in addition to the
+/// tags excluded by the operating system.
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(irg))]
+pub unsafe fn __arm_mte_create_random_tag(src: *const u8, mask: u64) -> *const u8 {
+    aarch64_irg(src, mask as i64)
+}
+
+/// Returns a copy of `src` with `OFFSET` added to its logical tag.
+///
+/// Tags excluded by the operating system are skipped.
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(addg, OFFSET = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn __arm_mte_increment_tag<const OFFSET: i32>(src: *const u8) -> *const u8 {
+    static_assert_imm4!(OFFSET);
+    aarch64_addg(src, OFFSET as i64)
+}
+
+/// Adds the logical tag of `src` to the set of excluded tags `excluded`.
+///
+/// Tag sets are 16-bit masks with bit `n` set for tag `n`, suitable as the
+/// `mask` of [`__arm_mte_create_random_tag`].
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(gmi))]
+pub unsafe fn __arm_mte_exclude_tag(src: *const u8, excluded: u64) -> u64 {
+    aarch64_gmi(src, excluded as i64) as u64
+}
+
+/// Sets the allocation tag of the 16-byte granule at `tag_address` to the
+/// logical tag of `tag_address`.
+///
+/// `tag_address` must be 16-byte aligned.
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(stg))]
+pub unsafe fn __arm_mte_set_tag(tag_address: *mut u8) {
+    aarch64_stg(tag_address, tag_address)
+}
+
+/// Returns a copy of `address` with the logical tag set to the allocation tag
+/// of the 16-byte granule it points into.
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(ldg))]
+pub unsafe fn __arm_mte_get_tag(address: *const u8) -> *const u8 {
+    aarch64_ldg(address, address)
+}
+
+/// Subtracts `b` from `a`, ignoring the logical tags of both pointers.
+#[inline]
+#[target_feature(enable = "mte")]
+#[cfg_attr(test, assert_instr(subp))]
+pub unsafe fn __arm_mte_ptrdiff(a: *const u8, b: *const u8) -> i64 {
+    aarch64_subp(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    const TAG_SHIFT: u32 = 56;
+    const TAG_MASK: usize = 0xf << TAG_SHIFT;
+
+    fn tag(p: *const u8) -> u64 {
+        ((p as usize & TAG_MASK) >> TAG_SHIFT) as u64
+    }
+
+    fn with_tag(p: *const u8, tag: u64) -> *const u8 {
+        ((p as usize & !TAG_MASK) | ((tag as usize) << TAG_SHIFT)) as *const u8
+    }
+
+    // Tags are only checked for memory mapped with `PROT_MTE`, so the tests
+    // below can freely use tagged pointers into ordinary memory. The tags
+    // excluded by the operating system are unknown though, and when all of
+    // them are excluded the tag generating instructions always produce 0.
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_create_random_tag() {
+        let x = [0u8; 16];
+        let p = x.as_ptr();
+        for mask in &[0, 0x5555, 0xfffe] {
+            let q = mte::__arm_mte_create_random_tag(p, *mask);
+            assert_eq!(with_tag(q, 0), with_tag(p, 0));
+            assert!(tag(q) == 0 || *mask & (1 << tag(q)) == 0);
+        }
+    }
+
+    // Lets the tag generating instructions of the calling thread choose from
+    // all 16 tags.
+    #[cfg(target_os = "linux")]
+    unsafe fn include_all_tags() {
+        const PRCTL: u64 = 167;
+        const PR_SET_TAGGED_ADDR_CTRL: u64 = 55;
+        const PR_TAGGED_ADDR_ENABLE: u64 = 1;
+        const PR_MTE_TAG_SHIFT: u64 = 3;
+        let mut r = PR_SET_TAGGED_ADDR_CTRL;
+        asm!(
+            "svc 0",
+            in("x8") PRCTL,
+            inout("x0") r,
+            in("x1") PR_TAGGED_ADDR_ENABLE | 0xffff << PR_MTE_TAG_SHIFT,
+            in("x2") 0,
+            in("x3") 0,
+            in("x4") 0,
+            options(nostack),
+        );
+        assert_eq!(r, 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_increment_tag() {
+        include_all_tags();
+        let x = [0u8; 16];
+        for &t in &[0, 3, 15] {
+            let p = with_tag(x.as_ptr(), t);
+            let q = mte::__arm_mte_increment_tag::<0>(p);
+            assert_eq!(tag(q), t);
+            assert_eq!(with_tag(q, 0), with_tag(p, 0));
+            let q = mte::__arm_mte_increment_tag::<1>(p);
+            assert_eq!(tag(q), (t + 1) % 16);
+            assert_eq!(with_tag(q, 0), with_tag(p, 0));
+            let q = mte::__arm_mte_increment_tag::<15>(p);
+            assert_eq!(tag(q), (t + 15) % 16);
+            assert_eq!(with_tag(q, 0), with_tag(p, 0));
+        }
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_exclude_tag() {
+        let x = [0u8; 16];
+        let p = with_tag(x.as_ptr(), 5);
+        assert_eq!(mte::__arm_mte_exclude_tag(p, 0), 1 << 5);
+        assert_eq!(mte::__arm_mte_exclude_tag(p, 0x8001), 0x8021);
+        let p = with_tag(x.as_ptr(), 15);
+        assert_eq!(mte::__arm_mte_exclude_tag(p, 1 << 15), 1 << 15);
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_set_tag() {
+        #[repr(align(16))]
+        struct Granule([u8; 16]);
+        let mut x = Granule([1; 16]);
+        let p = x.0.as_mut_ptr();
+        mte::__arm_mte_set_tag(p);
+        assert_eq!(x.0, [1; 16]);
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_get_tag() {
+        // Memory without `PROT_MTE` reads back as tag 0, whatever the tag of
+        // the pointer used to access it.
+        let x = [0u8; 16];
+        let p = with_tag(x.as_ptr(), 9);
+        assert_eq!(mte::__arm_mte_get_tag(p), with_tag(p, 0));
+    }
+
+    #[simd_test(enable = "mte")]
+    unsafe fn test_arm_mte_ptrdiff() {
+        let x = [0u8; 64];
+        let a = with_tag(x.as_ptr().add(48), 7);
+        let b = with_tag(x.as_ptr().add(16), 2);
+        assert_eq!(mte::__arm_mte_ptrdiff(a, b), 32);
+        assert_eq!(mte::__arm_mte_ptrdiff(b, a), -32);
+    }
+}
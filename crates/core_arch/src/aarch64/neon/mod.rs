@@ -23,46 +23,42 @@ types! {
     pub struct float64x1_t(f64); // FIXME: check this!
     /// ARM-specific 128-bit wide vector of two packed `f64`.
     pub struct float64x2_t(f64, f64);
+    /// ARM-specific 64-bit wide vector of four packed `bfloat16`, each
+    /// element holds the raw bits of a brain floating-point value.
+    pub struct bfloat16x4_t(u16, u16, u16, u16);
+    /// ARM-specific 128-bit wide vector of eight packed `bfloat16`, each
+    /// element holds the raw bits of a brain floating-point value.
+    pub struct bfloat16x8_t(u16, u16, u16, u16, u16, u16, u16, u16);
 }
 
-/// ARM-specific type containing two `int8x16_t` vectors.
+/// ARM-specific type containing two `float64x1_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x2_t(pub int8x16_t, pub int8x16_t);
-/// ARM-specific type containing three `int8x16_t` vectors.
+pub struct float64x1x2_t(pub float64x1_t, pub float64x1_t);
+/// ARM-specific type containing three `float64x1_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x3_t(pub int8x16_t, pub int8x16_t, pub int8x16_t);
-/// ARM-specific type containing four `int8x16_t` vectors.
+pub struct float64x1x3_t(pub float64x1_t, pub float64x1_t, pub float64x1_t);
+/// ARM-specific type containing four `float64x1_t` vectors.
 #[derive(Copy, Clone)]
-pub struct int8x16x4_t(pub int8x16_t, pub int8x16_t, pub int8x16_t, pub int8x16_t);
-
-/// ARM-specific type containing two `uint8x16_t` vectors.
-#[derive(Copy, Clone)]
-pub struct uint8x16x2_t(pub uint8x16_t, pub uint8x16_t);
-/// ARM-specific type containing three `uint8x16_t` vectors.
-#[derive(Copy, Clone)]
-pub struct uint8x16x3_t(pub uint8x16_t, pub uint8x16_t, pub uint8x16_t);
-/// ARM-specific type containing four `uint8x16_t` vectors.
-#[derive(Copy, Clone)]
-pub struct uint8x16x4_t(
-    pub uint8x16_t,
-    pub uint8x16_t,
-    pub uint8x16_t,
-    pub uint8x16_t,
+pub struct float64x1x4_t(
+    pub float64x1_t,
+    pub float64x1_t,
+    pub float64x1_t,
+    pub float64x1_t,
 );
 
-/// ARM-specific type containing two `poly8x16_t` vectors.
+/// ARM-specific type containing two `float64x2_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x2_t(pub poly8x16_t, pub poly8x16_t);
-/// ARM-specific type containing three `poly8x16_t` vectors.
+pub struct float64x2x2_t(pub float64x2_t, pub float64x2_t);
+/// ARM-specific type containing three `float64x2_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x3_t(pub poly8x16_t, pub poly8x16_t, pub poly8x16_t);
-/// ARM-specific type containing four `poly8x16_t` vectors.
+pub struct float64x2x3_t(pub float64x2_t, pub float64x2_t, pub float64x2_t);
+/// ARM-specific type containing four `float64x2_t` vectors.
 #[derive(Copy, Clone)]
-pub struct poly8x16x4_t(
-    pub poly8x16_t,
-    pub poly8x16_t,
-    pub poly8x16_t,
-    pub poly8x16_t,
+pub struct float64x2x4_t(
+    pub float64x2_t,
+    pub float64x2_t,
+    pub float64x2_t,
+    pub float64x2_t,
 );
 
 #[allow(improper_ctypes)]
@@ -2121,15 +2117,13 @@ pub unsafe fn vgetq_lane_f64<const IMM5: i32>(v: float64x2_t) -> f64 {
     simd_extract(v, IMM5 as u32)
 }
 
-/* FIXME: 16-bit float
 /// Vector combine
 #[inline]
 #[target_feature(enable = "neon")]
 #[cfg_attr(test, assert_instr(mov))]
-pub unsafe fn vcombine_f16 ( low: float16x4_t,  high: float16x4_t) -> float16x8_t {
+pub unsafe fn vcombine_f16(low: float16x4_t, high: float16x4_t) -> float16x8_t {
     simd_shuffle8!(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
 }
-*/
 
 /// Vector combine
 #[inline]
@@ -3363,6 +3357,325 @@ pub unsafe fn vsriq_n_p16<const N: i32>(a: poly16x8_t, b: poly16x8_t) -> poly16x
     transmute(vsriq_n_s16_(transmute(a), transmute(b), N))
 }
 
+// `f16` only stores the bits of a half-precision value, so the LLVM intrinsics, which take
+// `half` vectors, cannot be bound directly. The FP16 and FHM instructions are emitted with `asm!`.
+
+/// Floating-point add (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn vadd_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fadd {r:v}.4h, {a:v}.4h, {b:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point add (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn vaddq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fadd {r:v}.8h, {a:v}.8h, {b:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point subtract (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn vsub_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fsub {r:v}.4h, {a:v}.4h, {b:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point subtract (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn vsubq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fsub {r:v}.8h, {a:v}.8h, {b:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point multiply (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn vmul_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fmul {r:v}.4h, {a:v}.4h, {b:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point multiply (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn vmulq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fmul {r:v}.8h, {a:v}.8h, {b:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point divide (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fdiv))]
+pub unsafe fn vdiv_f16(a: float16x4_t, b: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fdiv {r:v}.4h, {a:v}.4h, {b:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point divide (vector)
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fdiv))]
+pub unsafe fn vdivq_f16(a: float16x8_t, b: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fdiv {r:v}.8h, {a:v}.8h, {b:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point absolute value
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabs))]
+pub unsafe fn vabs_f16(a: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fabs {r:v}.4h, {a:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point absolute value
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fabs))]
+pub unsafe fn vabsq_f16(a: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fabs {r:v}.8h, {a:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point negate
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fneg))]
+pub unsafe fn vneg_f16(a: float16x4_t) -> float16x4_t {
+    let r: float16x4_t;
+    asm!(
+        "fneg {r:v}.4h, {a:v}.4h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point negate
+#[inline]
+#[target_feature(enable = "neon,fp16")]
+#[cfg_attr(test, assert_instr(fneg))]
+pub unsafe fn vnegq_f16(a: float16x8_t) -> float16x8_t {
+    let r: float16x8_t;
+    asm!(
+        "fneg {r:v}.8h, {a:v}.8h",
+        r = lateout(vreg) r,
+        a = in(vreg) a,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-add long to accumulator, using the low half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlal))]
+pub unsafe fn vfmlal_low_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t {
+    let mut r = r;
+    asm!(
+        "fmlal {r:v}.2s, {a:v}.2h, {b:v}.2h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-add long to accumulator, using the low half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlal))]
+pub unsafe fn vfmlalq_low_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t {
+    let mut r = r;
+    asm!(
+        "fmlal {r:v}.4s, {a:v}.4h, {b:v}.4h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-add long to accumulator, using the high half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlal2))]
+pub unsafe fn vfmlal_high_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t {
+    let mut r = r;
+    asm!(
+        "fmlal2 {r:v}.2s, {a:v}.2h, {b:v}.2h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-add long to accumulator, using the high half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlal2))]
+pub unsafe fn vfmlalq_high_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t {
+    let mut r = r;
+    asm!(
+        "fmlal2 {r:v}.4s, {a:v}.4h, {b:v}.4h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-subtract long from accumulator, using the low half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlsl))]
+pub unsafe fn vfmlsl_low_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t {
+    let mut r = r;
+    asm!(
+        "fmlsl {r:v}.2s, {a:v}.2h, {b:v}.2h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-subtract long from accumulator, using the low half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlsl))]
+pub unsafe fn vfmlslq_low_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t {
+    let mut r = r;
+    asm!(
+        "fmlsl {r:v}.4s, {a:v}.4h, {b:v}.4h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-subtract long from accumulator, using the high half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlsl2))]
+pub unsafe fn vfmlsl_high_f16(r: float32x2_t, a: float16x4_t, b: float16x4_t) -> float32x2_t {
+    let mut r = r;
+    asm!(
+        "fmlsl2 {r:v}.2s, {a:v}.2h, {b:v}.2h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Floating-point fused multiply-subtract long from accumulator, using the high half of the inputs
+#[inline]
+#[target_feature(enable = "neon,fp16,fhm")]
+#[cfg_attr(test, assert_instr(fmlsl2))]
+pub unsafe fn vfmlslq_high_f16(r: float32x4_t, a: float16x8_t, b: float16x8_t) -> float32x4_t {
+    let mut r = r;
+    asm!(
+        "fmlsl2 {r:v}.4s, {a:v}.4h, {b:v}.4h",
+        r = inout(vreg) r,
+        a = in(vreg) a,
+        b = in(vreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::aarch64::test_support::*;
@@ -4109,9 +4422,7 @@ mod tests {
     test_vcombine!(test_vcombine_s16 => vcombine_s16([3_i16, -4, 5, -6], [13_i16, -14, 15, -16]));
     test_vcombine!(test_vcombine_u16 => vcombine_u16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
     test_vcombine!(test_vcombine_p16 => vcombine_p16([3_u16, 4, 5, 6], [13_u16, 14, 15, 16]));
-    // FIXME: 16-bit floats
-    // test_vcombine!(test_vcombine_f16 => vcombine_f16([3_f16, 4., 5., 6.],
-    // [13_f16, 14., 15., 16.]));
+    test_vcombine!(test_vcombine_f16 => vcombine_f16([0x4200_u16, 0x4400, 0x4500, 0x4600], [0x4A80_u16, 0x4B00, 0x4B80, 0x4C00]));
 
     test_vcombine!(test_vcombine_s32 => vcombine_s32([3_i32, -4], [13_i32, -14]));
     test_vcombine!(test_vcombine_u32 => vcombine_u32([3_u32, 4], [13_u32, 14]));
@@ -4994,6 +5305,260 @@ mod tests {
         assert_eq!(vals[1], 1.);
         assert_eq!(vals[2], 2.);
     }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vadd_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = u16x4::new(0x3E00, 0x4400, 0xC000, 0x6800);
+        let r: u16x4 = transmute(vadd_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vaddq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = u16x8::new(
+            0x3E00, 0x4400, 0xC000, 0x6800, 0x34CC, 0x4700, 0xC000, 0xC5B3,
+        );
+        let r: u16x8 = transmute(vaddq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsub_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = u16x4::new(0x3800, 0x0000, 0xC400, 0x67FF);
+        let r: u16x4 = transmute(vsub_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vsubq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = u16x8::new(
+            0x3800, 0x0000, 0xC400, 0x67FF, 0xAE66, 0x3C00, 0x4A00, 0xC64D,
+        );
+        let r: u16x8 = transmute(vsubq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmul_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = u16x4::new(0x3800, 0x4400, 0xC200, 0x6800);
+        let r: u16x4 = transmute(vmul_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vmulq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = u16x8::new(
+            0x3800, 0x4400, 0xC200, 0x6800, 0x251E, 0x4A00, 0xD060, 0xBF34,
+        );
+        let r: u16x8 = transmute(vmulq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdiv_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = u16x4::new(0x4000, 0x3C00, 0xC200, 0x6800);
+        let r: u16x4 = transmute(vdiv_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vdivq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = u16x8::new(
+            0x4000, 0x3C00, 0xC200, 0x6800, 0x3800, 0x3D55, 0xB9B7, 0xCD00,
+        );
+        let r: u16x8 = transmute(vdivq_f16(transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabs_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let e = u16x4::new(0x3C00, 0x4000, 0x4200, 0x6800);
+        let r: u16x4 = transmute(vabs_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vabsq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let e = u16x8::new(
+            0x3C00, 0x4000, 0x4200, 0x6800, 0x2E66, 0x4400, 0x4500, 0x4600,
+        );
+        let r: u16x8 = transmute(vabsq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vneg_f16() {
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let e = u16x4::new(0xBC00, 0xC000, 0x4200, 0xE800);
+        let r: u16x4 = transmute(vneg_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vnegq_f16() {
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let e = u16x8::new(
+            0xBC00, 0xC000, 0x4200, 0xE800, 0xAE66, 0xC400, 0xC500, 0x4600,
+        );
+        let r: u16x8 = transmute(vnegq_f16(transmute(a)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16")]
+    unsafe fn test_vfmlal_low_f16() {
+        let r = f32x2::new(1.0, -2.0);
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = f32x2::new(1.5, 2.0);
+        let r: f32x2 = transmute(vfmlal_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_low_f16() {
+        let r = f32x4::new(1.0, -2.0, 3.0, 0.5);
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = f32x4::new(1.5, 2.0, 0.0, 2048.5);
+        let r: f32x4 = transmute(vfmlalq_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_high_f16() {
+        let r = f32x2::new(1.0, -2.0);
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = f32x2::new(-2.0, 2046.0);
+        let r: f32x2 = transmute(vfmlal_high_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlalq_high_f16() {
+        let r = f32x4::new(1.0, -2.0, 3.0, 0.5);
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = f32x4::new(1.0199902, 10.0, -32.0, -1.300293);
+        let r: f32x4 = transmute(vfmlalq_high_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_low_f16() {
+        let r = f32x2::new(1.0, -2.0);
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = f32x2::new(0.5, -6.0);
+        let r: f32x2 = transmute(vfmlsl_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_low_f16() {
+        let r = f32x4::new(1.0, -2.0, 3.0, 0.5);
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = f32x4::new(0.5, -6.0, 6.0, -2047.5);
+        let r: f32x4 = transmute(vfmlslq_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_high_f16() {
+        let r = f32x2::new(1.0, -2.0);
+        let a = u16x4::new(0x3C00, 0x4000, 0xC200, 0x6800);
+        let b = u16x4::new(0x3800, 0x4000, 0x3C00, 0x3C00);
+        let e = f32x2::new(4.0, -2050.0);
+        let r: f32x2 = transmute(vfmlsl_high_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlslq_high_f16() {
+        let r = f32x4::new(1.0, -2.0, 3.0, 0.5);
+        let a = u16x8::new(
+            0x3C00, 0x4000, 0xC200, 0x6800, 0x2E66, 0x4400, 0x4500, 0xC600,
+        );
+        let b = u16x8::new(
+            0x3800, 0x4000, 0x3C00, 0x3C00, 0x3266, 0x4200, 0xC700, 0x34CD,
+        );
+        let e = f32x4::new(0.9800098, -14.0, 38.0, 2.300293);
+        let r: f32x4 = transmute(vfmlslq_high_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlal_low_f16_single_rounding() {
+        // (1 + 2^-10)^2 = 1 + 2^-9 + 2^-20 is not representable in `f16`. Rounding the product
+        // before accumulating would lose the 2^-20 term.
+        let r = f32x2::new(-1.0, 1.0);
+        let a = u16x4::new(0x3C01, 0x3C01, 0, 0);
+        let b = u16x4::new(0x3C01, 0x3C00, 0, 0);
+        let e = f32x2::new(0.001953125 + 0.00000095367431640625, 2.0009765625);
+        let r: f32x2 = transmute(vfmlal_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "neon,fp16,fhm")]
+    unsafe fn test_vfmlsl_low_f16_single_rounding() {
+        let r = f32x2::new(1.0, 1.0);
+        let a = u16x4::new(0x3C01, 0x3C01, 0, 0);
+        let b = u16x4::new(0x3C01, 0x3C00, 0, 0);
+        let e = f32x2::new(-(0.001953125 + 0.00000095367431640625), -0.0009765625);
+        let r: f32x2 = transmute(vfmlsl_low_f16(transmute(r), transmute(a), transmute(b)));
+        assert_eq!(r, e);
+    }
 }
 
 #[cfg(test)]
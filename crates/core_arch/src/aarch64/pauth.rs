@@ -0,0 +1,545 @@
+//! ARM's Pointer Authentication (PAC) intrinsics.
+//!
+//! Pointer authentication stores a cryptographic signature of a pointer, a
+//! 64-bit modifier and a per-process key in the unused upper bits of the
+//! pointer. There are two keys for instruction addresses (A and B), two for
+//! data addresses (A and B) and a generic key. Authenticating a pointer with
+//! the same key and modifier it was signed with gives back the original
+//! pointer. Otherwise the result is an invalid pointer that faults when it is
+//! used or, on processors with `FEAT_FPAC`, the authentication itself faults.
+//!
+//! The keys are chosen by the operating system, so signatures are only
+//! meaningful within the process that created them.
+//!
+//! There are no intrinsics for BTI landing pads: a landing pad only accepts
+//! branches to its own address, so one emitted in the middle of a function
+//! body is never a useful branch target. Functions get landing pads by being
+//! compiled with `-Z branch-protection=bti`, and JIT compilers emit the `bti`
+//! hint into the code they generate.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Signs the instruction address `ptr` with key A and `modifier`.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacia))]
+pub unsafe fn __pacia(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacia {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the instruction address `ptr` with key B and `modifier`.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacib))]
+pub unsafe fn __pacib(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacib {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the data address `ptr` with key A and `modifier`.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacda))]
+pub unsafe fn __pacda(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacda {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the data address `ptr` with key B and `modifier`.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacdb))]
+pub unsafe fn __pacdb(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacdb {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the instruction address `ptr` with key A and `modifier`.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autia))]
+pub unsafe fn __autia(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autia {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the instruction address `ptr` with key B and `modifier`.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autib))]
+pub unsafe fn __autib(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autib {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the data address `ptr` with key A and `modifier`.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autda))]
+pub unsafe fn __autda(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autda {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the data address `ptr` with key B and `modifier`.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autdb))]
+pub unsafe fn __autdb(ptr: u64, modifier: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autdb {ptr}, {modifier}",
+        ptr = inout(reg) r,
+        modifier = in(reg) modifier,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the instruction address `ptr` with key A and a zero modifier.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(paciza))]
+pub unsafe fn __paciza(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "paciza {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the instruction address `ptr` with key B and a zero modifier.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacizb))]
+pub unsafe fn __pacizb(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacizb {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the data address `ptr` with key A and a zero modifier.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacdza))]
+pub unsafe fn __pacdza(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacdza {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Signs the data address `ptr` with key B and a zero modifier.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacdzb))]
+pub unsafe fn __pacdzb(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "pacdzb {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the instruction address `ptr` with key A and a zero modifier.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and a zero modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autiza))]
+pub unsafe fn __autiza(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autiza {ptr}",
+        ptr = inout(reg) r,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the instruction address `ptr` with key B and a zero modifier.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and a zero modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autizb))]
+pub unsafe fn __autizb(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autizb {ptr}",
+        ptr = inout(reg) r,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the data address `ptr` with key A and a zero modifier.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and a zero modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autdza))]
+pub unsafe fn __autdza(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autdza {ptr}",
+        ptr = inout(reg) r,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Authenticates the data address `ptr` with key B and a zero modifier.
+///
+/// Returns `ptr` with the signature removed if it was signed with the same key
+/// and a zero modifier, and an invalid pointer otherwise. On processors with
+/// `FEAT_FPAC` a failed authentication faults instead.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(autdzb))]
+pub unsafe fn __autdzb(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "autdzb {ptr}",
+        ptr = inout(reg) r,
+        options(nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Computes a 32-bit signature of `value` with the generic key and `modifier`.
+///
+/// The signature is returned in the upper 32 bits, the lower 32 bits are zero.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(pacga))]
+pub unsafe fn __pacga(value: u64, modifier: u64) -> u64 {
+    let r: u64;
+    asm!(
+        "pacga {r}, {value}, {modifier}",
+        r = lateout(reg) r,
+        value = in(reg) value,
+        modifier = in(reg) modifier,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Removes the signature from the instruction address `ptr` without authenticating it.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(xpaci))]
+pub unsafe fn __xpaci(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "xpaci {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Removes the signature from the data address `ptr` without authenticating it.
+#[inline]
+#[target_feature(enable = "pauth")]
+#[cfg_attr(test, assert_instr(xpacd))]
+pub unsafe fn __xpacd(ptr: u64) -> u64 {
+    let mut r = ptr;
+    asm!(
+        "xpacd {ptr}",
+        ptr = inout(reg) r,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::aarch64::*;
+
+    // Signatures depend on keys chosen by the operating system, and may even
+    // be all zeroes by chance, so only round trips are checked.
+
+    const MODIFIERS: &[u64] = &[0, 1, 0x0123_4567_89ab_cdef, u64::MAX];
+
+    fn code_address() -> u64 {
+        code_address as fn() -> u64 as usize as u64
+    }
+
+    fn data_address() -> u64 {
+        static DATA: u64 = 0;
+        &DATA as *const u64 as usize as u64
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacia() {
+        let p = code_address();
+        for &m in MODIFIERS {
+            let s = pauth::__pacia(p, m);
+            assert_eq!(pauth::__autia(s, m), p);
+            assert_eq!(pauth::__xpaci(s), p);
+            assert_eq!(pauth::__pacia(p, m), s);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autia() {
+        let p = code_address();
+        for &m in MODIFIERS {
+            assert_eq!(pauth::__autia(pauth::__pacia(p, m), m), p);
+            assert_eq!(pauth::__autia(pauth::__pacia(p + 8, m), m), p + 8);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacib() {
+        let p = code_address();
+        for &m in MODIFIERS {
+            let s = pauth::__pacib(p, m);
+            assert_eq!(pauth::__autib(s, m), p);
+            assert_eq!(pauth::__xpaci(s), p);
+            assert_eq!(pauth::__pacib(p, m), s);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autib() {
+        let p = code_address();
+        for &m in MODIFIERS {
+            assert_eq!(pauth::__autib(pauth::__pacib(p, m), m), p);
+            assert_eq!(pauth::__autib(pauth::__pacib(p + 8, m), m), p + 8);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacda() {
+        let p = data_address();
+        for &m in MODIFIERS {
+            let s = pauth::__pacda(p, m);
+            assert_eq!(pauth::__autda(s, m), p);
+            assert_eq!(pauth::__xpacd(s), p);
+            assert_eq!(pauth::__pacda(p, m), s);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autda() {
+        let p = data_address();
+        for &m in MODIFIERS {
+            assert_eq!(pauth::__autda(pauth::__pacda(p, m), m), p);
+            assert_eq!(pauth::__autda(pauth::__pacda(p + 8, m), m), p + 8);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacdb() {
+        let p = data_address();
+        for &m in MODIFIERS {
+            let s = pauth::__pacdb(p, m);
+            assert_eq!(pauth::__autdb(s, m), p);
+            assert_eq!(pauth::__xpacd(s), p);
+            assert_eq!(pauth::__pacdb(p, m), s);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autdb() {
+        let p = data_address();
+        for &m in MODIFIERS {
+            assert_eq!(pauth::__autdb(pauth::__pacdb(p, m), m), p);
+            assert_eq!(pauth::__autdb(pauth::__pacdb(p + 8, m), m), p + 8);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_paciza() {
+        let p = code_address();
+        let s = pauth::__paciza(p);
+        assert_eq!(s, pauth::__pacia(p, 0));
+        assert_eq!(pauth::__autiza(s), p);
+        assert_eq!(pauth::__xpaci(s), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autiza() {
+        let p = code_address();
+        assert_eq!(pauth::__autiza(pauth::__paciza(p)), p);
+        assert_eq!(pauth::__autiza(pauth::__pacia(p, 0)), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacizb() {
+        let p = code_address();
+        let s = pauth::__pacizb(p);
+        assert_eq!(s, pauth::__pacib(p, 0));
+        assert_eq!(pauth::__autizb(s), p);
+        assert_eq!(pauth::__xpaci(s), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autizb() {
+        let p = code_address();
+        assert_eq!(pauth::__autizb(pauth::__pacizb(p)), p);
+        assert_eq!(pauth::__autizb(pauth::__pacib(p, 0)), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacdza() {
+        let p = data_address();
+        let s = pauth::__pacdza(p);
+        assert_eq!(s, pauth::__pacda(p, 0));
+        assert_eq!(pauth::__autdza(s), p);
+        assert_eq!(pauth::__xpacd(s), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autdza() {
+        let p = data_address();
+        assert_eq!(pauth::__autdza(pauth::__pacdza(p)), p);
+        assert_eq!(pauth::__autdza(pauth::__pacda(p, 0)), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacdzb() {
+        let p = data_address();
+        let s = pauth::__pacdzb(p);
+        assert_eq!(s, pauth::__pacdb(p, 0));
+        assert_eq!(pauth::__autdzb(s), p);
+        assert_eq!(pauth::__xpacd(s), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_autdzb() {
+        let p = data_address();
+        assert_eq!(pauth::__autdzb(pauth::__pacdzb(p)), p);
+        assert_eq!(pauth::__autdzb(pauth::__pacdb(p, 0)), p);
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_pacga() {
+        // There is no instruction that authenticates a generic signature, it
+        // is checked by computing it again.
+        for &(v, m) in &[(0, 0), (1, 2), (u64::MAX, 0x0123_4567_89ab_cdef)] {
+            let s = pauth::__pacga(v, m);
+            assert_eq!(s & 0xffff_ffff, 0);
+            assert_eq!(pauth::__pacga(v, m), s);
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_xpaci() {
+        let p = code_address();
+        assert_eq!(pauth::__xpaci(p), p);
+        for &m in MODIFIERS {
+            for &s in &[pauth::__pacia(p, m), pauth::__pacib(p, m)] {
+                assert_eq!(pauth::__xpaci(s), p);
+            }
+            assert_eq!(
+                pauth::__autia(pauth::__pacia(p, m), m),
+                pauth::__xpaci(pauth::__pacia(p, m))
+            );
+            assert_eq!(
+                pauth::__autib(pauth::__pacib(p, m), m),
+                pauth::__xpaci(pauth::__pacib(p, m))
+            );
+        }
+    }
+
+    #[simd_test(enable = "pauth")]
+    unsafe fn test_xpacd() {
+        let p = data_address();
+        assert_eq!(pauth::__xpacd(p), p);
+        for &m in MODIFIERS {
+            for &s in &[pauth::__pacda(p, m), pauth::__pacdb(p, m)] {
+                assert_eq!(pauth::__xpacd(s), p);
+            }
+            assert_eq!(
+                pauth::__autda(pauth::__pacda(p, m), m),
+                pauth::__xpacd(pauth::__pacda(p, m))
+            );
+            assert_eq!(
+                pauth::__autdb(pauth::__pacdb(p, m), m),
+                pauth::__xpacd(pauth::__pacdb(p, m))
+            );
+        }
+    }
+}
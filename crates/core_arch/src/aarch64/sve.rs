@@ -0,0 +1,4927 @@
+//! Scalable Vector Extension (SVE) intrinsics.
+//!
+//! The reference is the [Arm C Language Extensions for SVE][acle].
+//!
+//! Rust has no sizeless types yet, so the SVE vector and predicate types are
+//! opaque wrappers sized for the largest vector length the architecture
+//! allows (2048 bits). Only the first `svcntb()` bytes of a vector and the
+//! first `svcntb() / 8` bytes of a predicate are meaningful, the rest is left
+//! untouched. Every intrinsic moves its operands between memory and the `z`
+//! and `p` registers around the SVE instruction with `asm!`, so the values
+//! never live in registers across calls.
+//!
+//! [acle]: https://developer.arm.com/documentation/100987/latest
+
+use crate::mem;
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+macro_rules! sve_types {
+    ($(
+        $(#[$doc:meta])*
+        pub struct $name:ident([$elem:ty; $len:literal]);
+    )*) => ($(
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug)]
+        #[repr(C, align(16))]
+        #[allow(non_camel_case_types)]
+        pub struct $name([$elem; $len]);
+    )*)
+}
+
+sve_types! {
+    /// SVE vector of 8-bit signed integer elements.
+    pub struct svint8_t([i8; 256]);
+    /// SVE vector of 16-bit signed integer elements.
+    pub struct svint16_t([i16; 128]);
+    /// SVE vector of 32-bit signed integer elements.
+    pub struct svint32_t([i32; 64]);
+    /// SVE vector of 64-bit signed integer elements.
+    pub struct svint64_t([i64; 32]);
+    /// SVE vector of 8-bit unsigned integer elements.
+    pub struct svuint8_t([u8; 256]);
+    /// SVE vector of 16-bit unsigned integer elements.
+    pub struct svuint16_t([u16; 128]);
+    /// SVE vector of 32-bit unsigned integer elements.
+    pub struct svuint32_t([u32; 64]);
+    /// SVE vector of 64-bit unsigned integer elements.
+    pub struct svuint64_t([u64; 32]);
+    /// SVE vector of 32-bit floating-point elements.
+    pub struct svfloat32_t([f32; 64]);
+    /// SVE vector of 64-bit floating-point elements.
+    pub struct svfloat64_t([f64; 32]);
+    /// SVE predicate, one bit for each byte of a vector.
+    pub struct svbool_t([u8; 32]);
+}
+
+/// Count the number of 8-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntb))]
+pub unsafe fn svcntb() -> u64 {
+    let r: u64;
+    asm!("cntb {}", out(reg) r, options(pure, nomem, nostack, preserves_flags));
+    r
+}
+
+/// Count the number of 16-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cnth))]
+pub unsafe fn svcnth() -> u64 {
+    let r: u64;
+    asm!("cnth {}", out(reg) r, options(pure, nomem, nostack, preserves_flags));
+    r
+}
+
+/// Count the number of 32-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntw))]
+pub unsafe fn svcntw() -> u64 {
+    let r: u64;
+    asm!("cntw {}", out(reg) r, options(pure, nomem, nostack, preserves_flags));
+    r
+}
+
+/// Count the number of 64-bit elements in a vector.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntd))]
+pub unsafe fn svcntd() -> u64 {
+    let r: u64;
+    asm!("cntd {}", out(reg) r, options(pure, nomem, nostack, preserves_flags));
+    r
+}
+
+/// Set all predicate elements of 8-bit elements to true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptrue))]
+pub unsafe fn svptrue_b8() -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "ptrue p0.b",
+        "str p0, [{r}]",
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Set all predicate elements of 16-bit elements to true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptrue))]
+pub unsafe fn svptrue_b16() -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "ptrue p0.h",
+        "str p0, [{r}]",
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Set all predicate elements of 32-bit elements to true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptrue))]
+pub unsafe fn svptrue_b32() -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "ptrue p0.s",
+        "str p0, [{r}]",
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Set all predicate elements of 64-bit elements to true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptrue))]
+pub unsafe fn svptrue_b64() -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "ptrue p0.d",
+        "str p0, [{r}]",
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Set all predicate elements to false.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(pfalse))]
+pub unsafe fn svpfalse_b() -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "pfalse p0.b",
+        "str p0, [{r}]",
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 8-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b8_s32(op1: i32, op2: i32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.b, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 8-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b8_s64(op1: i64, op2: i64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.b, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 8-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b8_u32(op1: u32, op2: u32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.b, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 8-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b8_u64(op1: u64, op2: u64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.b, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 16-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b16_s32(op1: i32, op2: i32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.h, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 16-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b16_s64(op1: i64, op2: i64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.h, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 16-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b16_u32(op1: u32, op2: u32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.h, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 16-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b16_u64(op1: u64, op2: u64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.h, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 32-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b32_s32(op1: i32, op2: i32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.s, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 32-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b32_s64(op1: i64, op2: i64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.s, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 32-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b32_u32(op1: u32, op2: u32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.s, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 32-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b32_u64(op1: u64, op2: u64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.s, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 64-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b64_s32(op1: i32, op2: i32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.d, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 64-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelt))]
+pub unsafe fn svwhilelt_b64_s64(op1: i64, op2: i64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelt p0.d, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 64-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b64_u32(op1: u32, op2: u32) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.d, {op1:w}, {op2:w}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// While incrementing scalar is less than, for 64-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(whilelo))]
+pub unsafe fn svwhilelt_b64_u64(op1: u64, op2: u64) -> svbool_t {
+    let mut r: svbool_t = mem::zeroed();
+    asm!(
+        "whilelo p0.d, {op1}, {op2}",
+        "str p0, [{r}]",
+        op1 = in(reg) op1,
+        op2 = in(reg) op2,
+        r = in(reg) &mut r,
+        out("p0") _,
+        options(nostack)
+    );
+    r
+}
+
+/// Count the active predicate elements of 8-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntp))]
+pub unsafe fn svcntp_b8(pg: svbool_t, op: svbool_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "cntp {r}, p0, p1.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Count the active predicate elements of 16-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntp))]
+pub unsafe fn svcntp_b16(pg: svbool_t, op: svbool_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "cntp {r}, p0, p1.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Count the active predicate elements of 32-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntp))]
+pub unsafe fn svcntp_b32(pg: svbool_t, op: svbool_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "cntp {r}, p0, p1.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Count the active predicate elements of 64-bit elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(cntp))]
+pub unsafe fn svcntp_b64(pg: svbool_t, op: svbool_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "cntp {r}, p0, p1.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Test whether any active element of `op` is true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptest))]
+pub unsafe fn svptest_any(pg: svbool_t, op: svbool_t) -> bool {
+    let r: u32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "ptest p0, p1.b",
+        "cset {r:w}, ne",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack)
+    );
+    r != 0
+}
+
+/// Test whether the first active element of `op` is true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptest))]
+pub unsafe fn svptest_first(pg: svbool_t, op: svbool_t) -> bool {
+    let r: u32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "ptest p0, p1.b",
+        "cset {r:w}, mi",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack)
+    );
+    r != 0
+}
+
+/// Test whether the last active element of `op` is true.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ptest))]
+pub unsafe fn svptest_last(pg: svbool_t, op: svbool_t) -> bool {
+    let r: u32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr p1, [{op}]",
+        "ptest p0, p1.b",
+        "cset {r:w}, lo",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(reg) r,
+        out("p0") _,
+        out("p1") _,
+        options(nostack)
+    );
+    r != 0
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1b))]
+pub unsafe fn svld1_s8(pg: svbool_t, base: *const i8) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1b {{z0.b}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1h))]
+pub unsafe fn svld1_s16(pg: svbool_t, base: *const i16) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1h {{z0.h}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_s32(pg: svbool_t, base: *const i32) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1w {{z0.s}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_s64(pg: svbool_t, base: *const i64) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1d {{z0.d}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1b))]
+pub unsafe fn svld1_u8(pg: svbool_t, base: *const u8) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1b {{z0.b}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1h))]
+pub unsafe fn svld1_u16(pg: svbool_t, base: *const u16) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1h {{z0.h}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_u32(pg: svbool_t, base: *const u32) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1w {{z0.s}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_u64(pg: svbool_t, base: *const u64) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1d {{z0.d}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_f32(pg: svbool_t, base: *const f32) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1w {{z0.s}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Unextended load, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_f64(pg: svbool_t, base: *const f64) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ld1d {{z0.d}}, p0/z, [{base}]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1b))]
+pub unsafe fn svst1_s8(pg: svbool_t, base: *mut i8, data: svint8_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1b {{z0.b}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1h))]
+pub unsafe fn svst1_s16(pg: svbool_t, base: *mut i16, data: svint16_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1h {{z0.h}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_s32(pg: svbool_t, base: *mut i32, data: svint32_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1w {{z0.s}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_s64(pg: svbool_t, base: *mut i64, data: svint64_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1d {{z0.d}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1b))]
+pub unsafe fn svst1_u8(pg: svbool_t, base: *mut u8, data: svuint8_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1b {{z0.b}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1h))]
+pub unsafe fn svst1_u16(pg: svbool_t, base: *mut u16, data: svuint16_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1h {{z0.h}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_u32(pg: svbool_t, base: *mut u32, data: svuint32_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1w {{z0.s}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_u64(pg: svbool_t, base: *mut u64, data: svuint64_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1d {{z0.d}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_f32(pg: svbool_t, base: *mut f32, data: svfloat32_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1w {{z0.s}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Non-truncating store of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_f64(pg: svbool_t, base: *mut f64, data: svfloat64_t) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "st1d {{z0.d}}, p0, [{base}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        data = in(reg) &data,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_s32index_s32(
+    pg: svbool_t,
+    base: *const i32,
+    indices: svint32_t,
+) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, sxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_u32index_s32(
+    pg: svbool_t,
+    base: *const i32,
+    indices: svuint32_t,
+) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, uxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_s64index_s64(
+    pg: svbool_t,
+    base: *const i64,
+    indices: svint64_t,
+) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_u64index_s64(
+    pg: svbool_t,
+    base: *const i64,
+    indices: svuint64_t,
+) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_s32index_u32(
+    pg: svbool_t,
+    base: *const u32,
+    indices: svint32_t,
+) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, sxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_u32index_u32(
+    pg: svbool_t,
+    base: *const u32,
+    indices: svuint32_t,
+) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, uxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_s64index_u64(
+    pg: svbool_t,
+    base: *const u64,
+    indices: svint64_t,
+) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_u64index_u64(
+    pg: svbool_t,
+    base: *const u64,
+    indices: svuint64_t,
+) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_s32index_f32(
+    pg: svbool_t,
+    base: *const f32,
+    indices: svint32_t,
+) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, sxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1w))]
+pub unsafe fn svld1_gather_u32index_f32(
+    pg: svbool_t,
+    base: *const f32,
+    indices: svuint32_t,
+) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1w {{z0.s}}, p0/z, [{base}, z1.s, uxtw #2]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_s64index_f64(
+    pg: svbool_t,
+    base: *const f64,
+    indices: svint64_t,
+) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Gather load of the active elements from `base` plus scaled `indices`, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(ld1d))]
+pub unsafe fn svld1_gather_u64index_f64(
+    pg: svbool_t,
+    base: *const f64,
+    indices: svuint64_t,
+) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z1, [{indices}]",
+        "ld1d {{z0.d}}, p0/z, [{base}, z1.d, lsl #3]",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_s32index_s32(
+    pg: svbool_t,
+    base: *mut i32,
+    indices: svint32_t,
+    data: svint32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, sxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_u32index_s32(
+    pg: svbool_t,
+    base: *mut i32,
+    indices: svuint32_t,
+    data: svint32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, uxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_s64index_s64(
+    pg: svbool_t,
+    base: *mut i64,
+    indices: svint64_t,
+    data: svint64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_u64index_s64(
+    pg: svbool_t,
+    base: *mut i64,
+    indices: svuint64_t,
+    data: svint64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_s32index_u32(
+    pg: svbool_t,
+    base: *mut u32,
+    indices: svint32_t,
+    data: svuint32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, sxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_u32index_u32(
+    pg: svbool_t,
+    base: *mut u32,
+    indices: svuint32_t,
+    data: svuint32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, uxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_s64index_u64(
+    pg: svbool_t,
+    base: *mut u64,
+    indices: svint64_t,
+    data: svuint64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_u64index_u64(
+    pg: svbool_t,
+    base: *mut u64,
+    indices: svuint64_t,
+    data: svuint64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_s32index_f32(
+    pg: svbool_t,
+    base: *mut f32,
+    indices: svint32_t,
+    data: svfloat32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, sxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1w))]
+pub unsafe fn svst1_scatter_u32index_f32(
+    pg: svbool_t,
+    base: *mut f32,
+    indices: svuint32_t,
+    data: svfloat32_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1w {{z0.s}}, p0, [{base}, z1.s, uxtw #2]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_s64index_f64(
+    pg: svbool_t,
+    base: *mut f64,
+    indices: svint64_t,
+    data: svfloat64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Scatter store of the active elements to `base` plus scaled `indices`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(st1d))]
+pub unsafe fn svst1_scatter_u64index_f64(
+    pg: svbool_t,
+    base: *mut f64,
+    indices: svuint64_t,
+    data: svfloat64_t,
+) {
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{data}]",
+        "ldr z1, [{indices}]",
+        "st1d {{z0.d}}, p0, [{base}, z1.d, lsl #3]",
+        pg = in(reg) &pg,
+        base = in(reg) base,
+        indices = in(reg) &indices,
+        data = in(reg) &data,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_s8(op: i8) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "mov z0.b, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_s16(op: i16) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "mov z0.h, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_s32(op: i32) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "mov z0.s, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_s64(op: i64) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "mov z0.d, {op:x}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_u8(op: u8) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "mov z0.b, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_u16(op: u16) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "mov z0.h, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_u32(op: u32) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "mov z0.s, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_u64(op: u64) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "mov z0.d, {op:x}",
+        "str z0, [{r}]",
+        op = in(reg) op,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_f32(op: f32) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "mov z0.s, {op:w}",
+        "str z0, [{r}]",
+        op = in(reg) op.to_bits(),
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Broadcast a scalar value.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn svdup_n_f64(op: f64) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "mov z0.d, {op:x}",
+        "str z0, [{r}]",
+        op = in(reg) op.to_bits(),
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_s8(base: i8, step: i8) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "index z0.b, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_s16(base: i16, step: i16) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "index z0.h, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_s32(base: i32, step: i32) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "index z0.s, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_s64(base: i64, step: i64) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "index z0.d, {base:x}, {step:x}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_u8(base: u8, step: u8) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "index z0.b, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_u16(base: u16, step: u16) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "index z0.h, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_u32(base: u32, step: u32) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "index z0.s, {base:w}, {step:w}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Create linear series, `base`, `base + step`, `base + 2 * step` and so on.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(index))]
+pub unsafe fn svindex_u64(base: u64, step: u64) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "index z0.d, {base:x}, {step:x}",
+        "str z0, [{r}]",
+        base = in(reg) base,
+        step = in(reg) step,
+        r = in(reg) &mut r,
+        out("v0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s8_m(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s8_x(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s8_z(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s16_m(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s16_x(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s16_z(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s32_m(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s32_x(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s32_z(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s64_m(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s64_x(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_s64_z(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u8_m(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u8_x(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u8_z(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "add z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u16_m(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u16_x(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u16_z(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "add z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u32_m(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u32_x(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u32_z(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "add z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u64_m(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u64_x(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(add))]
+pub unsafe fn svadd_u64_z(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "add z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f32_m(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fadd z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f32_x(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fadd z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f32_z(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "fadd z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f64_m(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fadd z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f64_x(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fadd z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fadd))]
+pub unsafe fn svadd_f64_z(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "fadd z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s8_m(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s8_x(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s8_z(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s16_m(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s16_x(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s16_z(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s32_m(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s32_x(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s32_z(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s64_m(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s64_x(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_s64_z(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u8_m(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u8_x(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u8_z(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "sub z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u16_m(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u16_x(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u16_z(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "sub z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u32_m(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u32_x(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u32_z(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "sub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u64_m(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u64_x(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sub))]
+pub unsafe fn svsub_u64_z(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "sub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f32_m(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fsub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f32_x(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fsub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f32_z(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "fsub z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f64_m(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fsub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f64_x(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fsub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Subtract, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fsub))]
+pub unsafe fn svsub_f64_z(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "fsub z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s8_m(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s8_x(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s8_z(pg: svbool_t, op1: svint8_t, op2: svint8_t) -> svint8_t {
+    let mut r: svint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s16_m(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s16_x(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s16_z(pg: svbool_t, op1: svint16_t, op2: svint16_t) -> svint16_t {
+    let mut r: svint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s32_m(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s32_x(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s32_z(pg: svbool_t, op1: svint32_t, op2: svint32_t) -> svint32_t {
+    let mut r: svint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s64_m(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s64_x(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_s64_z(pg: svbool_t, op1: svint64_t, op2: svint64_t) -> svint64_t {
+    let mut r: svint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u8_m(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u8_x(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u8_z(pg: svbool_t, op1: svuint8_t, op2: svuint8_t) -> svuint8_t {
+    let mut r: svuint8_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.b, p0/z, z0.b",
+        "mul z0.b, p0/m, z0.b, z1.b",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u16_m(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u16_x(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u16_z(pg: svbool_t, op1: svuint16_t, op2: svuint16_t) -> svuint16_t {
+    let mut r: svuint16_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.h, p0/z, z0.h",
+        "mul z0.h, p0/m, z0.h, z1.h",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u32_m(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u32_x(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u32_z(pg: svbool_t, op1: svuint32_t, op2: svuint32_t) -> svuint32_t {
+    let mut r: svuint32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "mul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u64_m(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u64_x(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(mul))]
+pub unsafe fn svmul_u64_z(pg: svbool_t, op1: svuint64_t, op2: svuint64_t) -> svuint64_t {
+    let mut r: svuint64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "mul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f32_m(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fmul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f32_x(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fmul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f32_z(pg: svbool_t, op1: svfloat32_t, op2: svfloat32_t) -> svfloat32_t {
+    let mut r: svfloat32_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.s, p0/z, z0.s",
+        "fmul z0.s, p0/m, z0.s, z1.s",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are taken from `op1`.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f64_m(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fmul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are unspecified.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f64_x(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "fmul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Multiply, inactive elements are set to zero.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmul))]
+pub unsafe fn svmul_f64_z(pg: svbool_t, op1: svfloat64_t, op2: svfloat64_t) -> svfloat64_t {
+    let mut r: svfloat64_t = mem::zeroed();
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op1}]",
+        "ldr z1, [{op2}]",
+        "movprfx z0.d, p0/z, z0.d",
+        "fmul z0.d, p0/m, z0.d, z1.d",
+        "str z0, [{r}]",
+        pg = in(reg) &pg,
+        op1 = in(reg) &op1,
+        op2 = in(reg) &op2,
+        r = in(reg) &mut r,
+        out("v0") _,
+        out("v1") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(saddv))]
+pub unsafe fn svaddv_s8(pg: svbool_t, op: svint8_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "saddv {r:d}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(saddv))]
+pub unsafe fn svaddv_s16(pg: svbool_t, op: svint16_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "saddv {r:d}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(saddv))]
+pub unsafe fn svaddv_s32(pg: svbool_t, op: svint32_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "saddv {r:d}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uaddv))]
+pub unsafe fn svaddv_s64(pg: svbool_t, op: svint64_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uaddv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uaddv))]
+pub unsafe fn svaddv_u8(pg: svbool_t, op: svuint8_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uaddv {r:d}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uaddv))]
+pub unsafe fn svaddv_u16(pg: svbool_t, op: svuint16_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uaddv {r:d}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uaddv))]
+pub unsafe fn svaddv_u32(pg: svbool_t, op: svuint32_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uaddv {r:d}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uaddv))]
+pub unsafe fn svaddv_u64(pg: svbool_t, op: svuint64_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uaddv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(faddv))]
+pub unsafe fn svaddv_f32(pg: svbool_t, op: svfloat32_t) -> f32 {
+    let r: f32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "faddv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Add reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(faddv))]
+pub unsafe fn svaddv_f64(pg: svbool_t, op: svfloat64_t) -> f64 {
+    let r: f64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "faddv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(smaxv))]
+pub unsafe fn svmaxv_s8(pg: svbool_t, op: svint8_t) -> i8 {
+    let r: i8;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "smaxv {r:b}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(smaxv))]
+pub unsafe fn svmaxv_s16(pg: svbool_t, op: svint16_t) -> i16 {
+    let r: i16;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "smaxv {r:h}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(smaxv))]
+pub unsafe fn svmaxv_s32(pg: svbool_t, op: svint32_t) -> i32 {
+    let r: i32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "smaxv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(smaxv))]
+pub unsafe fn svmaxv_s64(pg: svbool_t, op: svint64_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "smaxv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(umaxv))]
+pub unsafe fn svmaxv_u8(pg: svbool_t, op: svuint8_t) -> u8 {
+    let r: u8;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "umaxv {r:b}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(umaxv))]
+pub unsafe fn svmaxv_u16(pg: svbool_t, op: svuint16_t) -> u16 {
+    let r: u16;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "umaxv {r:h}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(umaxv))]
+pub unsafe fn svmaxv_u32(pg: svbool_t, op: svuint32_t) -> u32 {
+    let r: u32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "umaxv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(umaxv))]
+pub unsafe fn svmaxv_u64(pg: svbool_t, op: svuint64_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "umaxv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmaxv))]
+pub unsafe fn svmaxv_f32(pg: svbool_t, op: svfloat32_t) -> f32 {
+    let r: f32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "fmaxv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Maximum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fmaxv))]
+pub unsafe fn svmaxv_f64(pg: svbool_t, op: svfloat64_t) -> f64 {
+    let r: f64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "fmaxv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sminv))]
+pub unsafe fn svminv_s8(pg: svbool_t, op: svint8_t) -> i8 {
+    let r: i8;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "sminv {r:b}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sminv))]
+pub unsafe fn svminv_s16(pg: svbool_t, op: svint16_t) -> i16 {
+    let r: i16;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "sminv {r:h}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sminv))]
+pub unsafe fn svminv_s32(pg: svbool_t, op: svint32_t) -> i32 {
+    let r: i32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "sminv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(sminv))]
+pub unsafe fn svminv_s64(pg: svbool_t, op: svint64_t) -> i64 {
+    let r: i64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "sminv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uminv))]
+pub unsafe fn svminv_u8(pg: svbool_t, op: svuint8_t) -> u8 {
+    let r: u8;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uminv {r:b}, p0, z0.b",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uminv))]
+pub unsafe fn svminv_u16(pg: svbool_t, op: svuint16_t) -> u16 {
+    let r: u16;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uminv {r:h}, p0, z0.h",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uminv))]
+pub unsafe fn svminv_u32(pg: svbool_t, op: svuint32_t) -> u32 {
+    let r: u32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uminv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(uminv))]
+pub unsafe fn svminv_u64(pg: svbool_t, op: svuint64_t) -> u64 {
+    let r: u64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "uminv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fminv))]
+pub unsafe fn svminv_f32(pg: svbool_t, op: svfloat32_t) -> f32 {
+    let r: f32;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "fminv {r:s}, p0, z0.s",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+/// Minimum reduction of the active elements.
+#[inline]
+#[target_feature(enable = "sve")]
+#[cfg_attr(test, assert_instr(fminv))]
+pub unsafe fn svminv_f64(pg: svbool_t, op: svfloat64_t) -> f64 {
+    let r: f64;
+    asm!(
+        "ldr p0, [{pg}]",
+        "ldr z0, [{op}]",
+        "fminv {r:d}, p0, z0.d",
+        pg = in(reg) &pg,
+        op = in(reg) &op,
+        r = out(vreg) r,
+        out("v0") _,
+        out("p0") _,
+        options(nostack, preserves_flags)
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::aarch64::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svcnt() {
+        let b = svcntb();
+        assert!(b >= 16 && b <= 256 && b % 16 == 0);
+        assert_eq!(svcnth(), b / 2);
+        assert_eq!(svcntw(), b / 4);
+        assert_eq!(svcntd(), b / 8);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svptrue_svpfalse() {
+        assert_eq!(svcntp_b8(svptrue_b8(), svptrue_b8()), svcntb());
+        assert_eq!(svcntp_b32(svptrue_b32(), svptrue_b32()), svcntw());
+        assert_eq!(svcntp_b64(svptrue_b8(), svptrue_b64()), svcntd());
+        assert_eq!(svcntp_b8(svptrue_b8(), svpfalse_b()), 0);
+        assert!(svptest_any(svptrue_b8(), svptrue_b16()));
+        assert!(!svptest_any(svptrue_b8(), svpfalse_b()));
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svwhilelt() {
+        let pg = svwhilelt_b32_s32(0, 3);
+        assert_eq!(svcntp_b32(svptrue_b32(), pg), 3);
+        assert!(svptest_first(svptrue_b32(), pg));
+        assert!(!svptest_last(svptrue_b32(), pg));
+        assert!(svptest_last(svptrue_b32(), svwhilelt_b32_u64(0, 1000)));
+        assert_eq!(svcntp_b64(svptrue_b64(), svwhilelt_b64_u32(5, 6)), 1);
+        assert_eq!(svcntp_b16(svptrue_b16(), svwhilelt_b16_s64(-2, 1)), 3);
+        assert_eq!(svcntp_b8(svptrue_b8(), svwhilelt_b8_u32(7, 3)), 0);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svld1_svst1_s32() {
+        let n = svcntw() as usize;
+        let mut src = [0i32; 64];
+        for (i, v) in src.iter_mut().enumerate() {
+            *v = i as i32 * 3 - 7;
+        }
+        let pg = svwhilelt_b32_s32(0, 3);
+        let v = svld1_s32(svptrue_b32(), src.as_ptr());
+        let mut dst = [-1i32; 64];
+        svst1_s32(pg, dst.as_mut_ptr(), v);
+        assert_eq!(dst[..3], src[..3]);
+        assert!(dst[3..].iter().all(|&x| x == -1));
+
+        let v = svld1_s32(pg, src.as_ptr());
+        svst1_s32(svptrue_b32(), dst.as_mut_ptr(), v);
+        assert_eq!(dst[..3], src[..3]);
+        assert!(dst[3..n].iter().all(|&x| x == 0));
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svld1_svst1_f64() {
+        let src = [1.5f64; 32];
+        let mut dst = [0f64; 32];
+        let v = svld1_f64(svptrue_b64(), src.as_ptr());
+        svst1_f64(svwhilelt_b64_s32(0, 2), dst.as_mut_ptr(), v);
+        assert_eq!(dst[..3], [1.5, 1.5, 0.0]);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svadd_s32() {
+        let n = svcntw() as usize;
+        let pg = svwhilelt_b32_s32(0, 2);
+        let a = svindex_s32(1, 1);
+        let b = svdup_n_s32(10);
+        let mut r = [0i32; 64];
+        svst1_s32(svptrue_b32(), r.as_mut_ptr(), svadd_s32_m(pg, a, b));
+        assert_eq!(r[..4], [11, 12, 3, 4]);
+        svst1_s32(svptrue_b32(), r.as_mut_ptr(), svadd_s32_z(pg, a, b));
+        assert_eq!(r[..4], [11, 12, 0, 0]);
+        svst1_s32(
+            svptrue_b32(),
+            r.as_mut_ptr(),
+            svadd_s32_x(svptrue_b32(), a, b),
+        );
+        for (i, &x) in r.iter().enumerate().take(n) {
+            assert_eq!(x, i as i32 + 11);
+        }
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svsub_u8() {
+        let a = svindex_u8(0, 1);
+        let b = svdup_n_u8(1);
+        let mut r = [0u8; 256];
+        svst1_u8(svptrue_b8(), r.as_mut_ptr(), svsub_u8_m(svptrue_b8(), a, b));
+        assert_eq!(r[..4], [255, 0, 1, 2]);
+        svst1_u8(
+            svptrue_b8(),
+            r.as_mut_ptr(),
+            svsub_u8_z(svwhilelt_b8_s32(1, 3), a, b),
+        );
+        assert_eq!(r[..4], [0, 0, 1, 0]);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svmul_f64() {
+        let src = [1.0, 2.0];
+        let pg = svwhilelt_b64_s32(0, 2);
+        let a = svld1_f64(pg, src.as_ptr());
+        let b = svdup_n_f64(1.5);
+        let mut r = [0f64; 32];
+        svst1_f64(
+            pg,
+            r.as_mut_ptr(),
+            svmul_f64_m(svwhilelt_b64_s32(0, 1), a, b),
+        );
+        assert_eq!(r[..2], [1.5, 2.0]);
+        svst1_f64(
+            pg,
+            r.as_mut_ptr(),
+            svmul_f64_z(svwhilelt_b64_s32(1, 2), a, b),
+        );
+        assert_eq!(r[..2], [0.0, 3.0]);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svaddv() {
+        let n = svcntw() as i64;
+        assert_eq!(
+            svaddv_s32(svptrue_b32(), svindex_s32(1, 1)),
+            n * (n + 1) / 2
+        );
+        let b = svcntb();
+        assert_eq!(svaddv_u8(svptrue_b8(), svdup_n_u8(255)), 255 * b);
+        assert_eq!(svaddv_s64(svwhilelt_b64_s32(0, 2), svdup_n_s64(-3)), -6);
+        let src = [0.5, 0.25, 8.0, 8.0];
+        let v = svld1_f32(svptrue_b32(), src.as_ptr());
+        assert_eq!(svaddv_f32(svwhilelt_b32_s32(0, 2), v), 0.75);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svmaxv_svminv() {
+        let v = svindex_s16(-10, 3);
+        assert_eq!(svmaxv_s16(svwhilelt_b16_s32(0, 5), v), 2);
+        assert_eq!(svminv_s16(svwhilelt_b16_s32(0, 5), v), -10);
+        let v = svindex_u64(100, 7);
+        assert_eq!(svminv_u64(svptrue_b64(), v), 100);
+        assert_eq!(svmaxv_u64(svwhilelt_b64_s32(0, 2), v), 107);
+        let src = [3.5f32, -1.25, 8.0, 0.5];
+        let v = svld1_f32(svwhilelt_b32_s32(0, 4), src.as_ptr());
+        assert_eq!(svminv_f32(svwhilelt_b32_s32(0, 4), v), -1.25);
+        assert_eq!(svmaxv_f32(svwhilelt_b32_s32(0, 2), v), 3.5);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svld1_gather_s32index_s32() {
+        let n = svcntw() as usize;
+        let mut table = [0i32; 64];
+        for (i, v) in table.iter_mut().enumerate() {
+            *v = (i * i) as i32;
+        }
+        let pg = svptrue_b32();
+        let v = svld1_gather_s32index_s32(pg, table.as_ptr(), svindex_s32(n as i32 - 1, -1));
+        let mut r = [0i32; 64];
+        svst1_s32(pg, r.as_mut_ptr(), v);
+        for (i, &x) in r.iter().enumerate().take(n) {
+            assert_eq!(x, table[n - 1 - i]);
+        }
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svld1_gather_u64index_f64() {
+        let table = [0.5, 1.5, 2.5, 3.5, 4.5, 5.5];
+        let mut r = [-1f64; 32];
+        let pg = svwhilelt_b64_s32(0, 2);
+        let v = svld1_gather_u64index_f64(pg, table.as_ptr(), svindex_u64(1, 2));
+        svst1_f64(pg, r.as_mut_ptr(), v);
+        assert_eq!(r[..3], [1.5, 3.5, -1.0]);
+        let pg = svwhilelt_b64_s32(0, 1);
+        let v = svld1_gather_u64index_f64(pg, table.as_ptr(), svindex_u64(4, 1));
+        svst1_f64(svwhilelt_b64_s32(0, 2), r.as_mut_ptr(), v);
+        assert_eq!(r[..3], [4.5, 0.0, -1.0]);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svst1_scatter_u32index_f32() {
+        let pg = svwhilelt_b32_s32(0, 3);
+        let mut r = [0f32; 8];
+        let src = [1.0, 2.0, 3.0];
+        let data = svld1_f32(pg, src.as_ptr());
+        svst1_scatter_u32index_f32(pg, r.as_mut_ptr(), svindex_u32(1, 2), data);
+        assert_eq!(r[..7], [0.0, 1.0, 0.0, 2.0, 0.0, 3.0, 0.0]);
+    }
+
+    #[simd_test(enable = "sve")]
+    unsafe fn test_svst1_scatter_s64index_s64() {
+        let pg = svwhilelt_b64_s32(0, 2);
+        let mut r = [0i64; 4];
+        svst1_scatter_s64index_s64(pg, r.as_mut_ptr(), svindex_s64(3, -3), svindex_s64(7, 1));
+        assert_eq!(r, [8, 0, 0, 7]);
+    }
+}
@@ -49,9 +49,59 @@ pub unsafe fn _cls_u64(x: u64) -> u64 {
     u64::leading_zeros((((((x as i64) >> 63) as u64) ^ x) << 1) | 1) as u64
 }
 
+/// Reads a 64-bit random number from the `RNDR` register.
+///
+/// The random number is written to `value` and 0 is returned on success. If
+/// no random number could be generated in a reasonable amount of time, 0 is
+/// written to `value` and a non-zero value is returned.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __rndr(value: *mut u64) -> i32 {
+    let r: u64;
+    let failed: u32;
+    // `RNDR` is spelled out as `s3_3_c2_c4_0` so that the assembler does not
+    // need the `rand` feature.
+    asm!(
+        "mrs {r}, s3_3_c2_c4_0",
+        "cset {failed:w}, eq",
+        r = out(reg) r,
+        failed = out(reg) failed,
+        options(nomem, nostack),
+    );
+    *value = r;
+    failed as i32
+}
+
+/// Reads a 64-bit random number from the `RNDRRS` register, reseeding the
+/// random number generator first.
+///
+/// The random number is written to `value` and 0 is returned on success. If
+/// no random number could be generated in a reasonable amount of time, 0 is
+/// written to `value` and a non-zero value is returned.
+#[inline]
+#[target_feature(enable = "rand")]
+#[cfg_attr(test, assert_instr(mrs))]
+pub unsafe fn __rndrrs(value: *mut u64) -> i32 {
+    let r: u64;
+    let failed: u32;
+    // `RNDRRS` is spelled out as `s3_3_c2_c4_1` so that the assembler does not
+    // need the `rand` feature.
+    asm!(
+        "mrs {r}, s3_3_c2_c4_1",
+        "cset {failed:w}, eq",
+        r = out(reg) r,
+        failed = out(reg) failed,
+        options(nomem, nostack),
+    );
+    *value = r;
+    failed as i32
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core_arch::aarch64::v8;
+    use stdarch_test::simd_test;
 
     #[test]
     fn _rev_u64() {
@@ -101,4 +151,33 @@ mod tests {
             );
         }
     }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndr() {
+        // The generator may transiently fail, but never for this long.
+        let mut values = [0u64; 4];
+        for v in values.iter_mut() {
+            let mut tries = 0;
+            while v8::__rndr(v) != 0 {
+                assert_eq!(*v, 0);
+                tries += 1;
+                assert!(tries < 1000);
+            }
+        }
+        assert!(values.iter().any(|&v| v != values[0]));
+    }
+
+    #[simd_test(enable = "rand")]
+    unsafe fn test_rndrrs() {
+        let mut values = [0u64; 4];
+        for v in values.iter_mut() {
+            let mut tries = 0;
+            while v8::__rndrrs(v) != 0 {
+                assert_eq!(*v, 0);
+                tries += 1;
+                assert!(tries < 1000);
+            }
+        }
+        assert!(values.iter().any(|&v| v != values[0]));
+    }
 }
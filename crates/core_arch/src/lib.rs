@@ -34,6 +34,7 @@
     adx_target_feature,
     rtm_target_feature,
     f16c_target_feature,
+    mmx_target_feature,
     allow_internal_unstable,
     decl_macro,
     bench_black_box
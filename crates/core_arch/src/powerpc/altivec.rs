@@ -242,59 +242,102 @@ extern "C" {
 
     #[link_name = "llvm.floor.v4f32"]
     fn vfloor(a: vector_float) -> vector_float;
-}
-
-macro_rules! s_t_l {
-    (i32x4) => {
-        vector_signed_int
-    };
-    (i16x8) => {
-        vector_signed_short
-    };
-    (i8x16) => {
-        vector_signed_char
-    };
-
-    (u32x4) => {
-        vector_unsigned_int
-    };
-    (u16x8) => {
-        vector_unsigned_short
-    };
-    (u8x16) => {
-        vector_unsigned_char
-    };
 
-    (f32x4) => {
-        vector_float
-    };
+    #[link_name = "llvm.ppc.altivec.vrfin"]
+    fn vrfin(a: vector_float) -> vector_float;
+
+    #[link_name = "llvm.ppc.altivec.vrefp"]
+    fn vrefp(a: vector_float) -> vector_float;
+    #[link_name = "llvm.ppc.altivec.vrsqrtefp"]
+    fn vrsqrtefp(a: vector_float) -> vector_float;
+
+    #[link_name = "llvm.ppc.altivec.vrlb"]
+    fn vrlb(a: vector_signed_char, b: vector_unsigned_char) -> vector_signed_char;
+    #[link_name = "llvm.ppc.altivec.vrlh"]
+    fn vrlh(a: vector_signed_short, b: vector_unsigned_short) -> vector_signed_short;
+    #[link_name = "llvm.ppc.altivec.vrlw"]
+    fn vrlw(a: vector_signed_int, b: vector_unsigned_int) -> vector_signed_int;
+
+    #[link_name = "llvm.ppc.altivec.vpkshss"]
+    fn vpkshss(a: vector_signed_short, b: vector_signed_short) -> vector_signed_char;
+    #[link_name = "llvm.ppc.altivec.vpkshus"]
+    fn vpkshus(a: vector_signed_short, b: vector_signed_short) -> vector_unsigned_char;
+    #[link_name = "llvm.ppc.altivec.vpkuhus"]
+    fn vpkuhus(a: vector_unsigned_short, b: vector_unsigned_short) -> vector_unsigned_char;
+    #[link_name = "llvm.ppc.altivec.vpkswss"]
+    fn vpkswss(a: vector_signed_int, b: vector_signed_int) -> vector_signed_short;
+    #[link_name = "llvm.ppc.altivec.vpkswus"]
+    fn vpkswus(a: vector_signed_int, b: vector_signed_int) -> vector_unsigned_short;
+    #[link_name = "llvm.ppc.altivec.vpkuwus"]
+    fn vpkuwus(a: vector_unsigned_int, b: vector_unsigned_int) -> vector_unsigned_short;
+
+    #[link_name = "llvm.ppc.altivec.vcfsx"]
+    fn vcfsx(a: vector_signed_int, b: i32) -> vector_float;
+    #[link_name = "llvm.ppc.altivec.vcfux"]
+    fn vcfux(a: vector_unsigned_int, b: i32) -> vector_float;
+    #[link_name = "llvm.ppc.altivec.vctsxs"]
+    fn vctsxs(a: vector_float, b: i32) -> vector_signed_int;
+    #[link_name = "llvm.ppc.altivec.vctuxs"]
+    fn vctuxs(a: vector_float, b: i32) -> vector_unsigned_int;
+
+    #[link_name = "llvm.ppc.altivec.stvx"]
+    fn stvx(a: vector_signed_int, p: *mut i8);
+    #[link_name = "llvm.ppc.altivec.stvebx"]
+    fn stvebx(a: vector_signed_char, p: *mut i8);
+    #[link_name = "llvm.ppc.altivec.stvehx"]
+    fn stvehx(a: vector_signed_short, p: *mut i8);
+    #[link_name = "llvm.ppc.altivec.stvewx"]
+    fn stvewx(a: vector_signed_int, p: *mut i8);
+    #[link_name = "llvm.ppc.altivec.lvebx"]
+    fn lvebx(p: *const i8) -> vector_signed_char;
+    #[link_name = "llvm.ppc.altivec.lvehx"]
+    fn lvehx(p: *const i8) -> vector_signed_short;
+    #[link_name = "llvm.ppc.altivec.lvewx"]
+    fn lvewx(p: *const i8) -> vector_signed_int;
+
+    #[link_name = "llvm.ppc.altivec.vcmpequb.p"]
+    fn vcmpequb_p(cr: i32, a: vector_unsigned_char, b: vector_unsigned_char) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpequh.p"]
+    fn vcmpequh_p(cr: i32, a: vector_unsigned_short, b: vector_unsigned_short) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpequw.p"]
+    fn vcmpequw_p(cr: i32, a: vector_unsigned_int, b: vector_unsigned_int) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpeqfp.p"]
+    fn vcmpeqfp_p(cr: i32, a: vector_float, b: vector_float) -> i32;
+
+    #[link_name = "llvm.ppc.altivec.vcmpgtub.p"]
+    fn vcmpgtub_p(cr: i32, a: vector_unsigned_char, b: vector_unsigned_char) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtuh.p"]
+    fn vcmpgtuh_p(cr: i32, a: vector_unsigned_short, b: vector_unsigned_short) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtuw.p"]
+    fn vcmpgtuw_p(cr: i32, a: vector_unsigned_int, b: vector_unsigned_int) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtsb.p"]
+    fn vcmpgtsb_p(cr: i32, a: vector_signed_char, b: vector_signed_char) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtsh.p"]
+    fn vcmpgtsh_p(cr: i32, a: vector_signed_short, b: vector_signed_short) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtsw.p"]
+    fn vcmpgtsw_p(cr: i32, a: vector_signed_int, b: vector_signed_int) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgtfp.p"]
+    fn vcmpgtfp_p(cr: i32, a: vector_float, b: vector_float) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpgefp.p"]
+    fn vcmpgefp_p(cr: i32, a: vector_float, b: vector_float) -> i32;
+    #[link_name = "llvm.ppc.altivec.vcmpbfp.p"]
+    fn vcmpbfp_p(cr: i32, a: vector_float, b: vector_float) -> i32;
+
+    #[link_name = "llvm.ppc.altivec.vcmpneb"]
+    fn vcmpneb(a: vector_signed_char, b: vector_signed_char) -> vector_bool_char;
+    #[link_name = "llvm.ppc.altivec.vcmpneh"]
+    fn vcmpneh(a: vector_signed_short, b: vector_signed_short) -> vector_bool_short;
+    #[link_name = "llvm.ppc.altivec.vcmpnew"]
+    fn vcmpnew(a: vector_signed_int, b: vector_signed_int) -> vector_bool_int;
 }
 
-macro_rules! t_t_l {
-    (i32) => {
-        vector_signed_int
-    };
-    (i16) => {
-        vector_signed_short
-    };
-    (i8) => {
-        vector_signed_char
-    };
-
-    (u32) => {
-        vector_unsigned_int
-    };
-    (u16) => {
-        vector_unsigned_short
-    };
-    (u8) => {
-        vector_unsigned_char
-    };
-
-    (f32) => {
-        vector_float
-    };
-}
+// The `cr` operand of the `vcmp*.p` predicates selects which bit of the CR6
+// field is returned: `EQ` is set when the comparison is false for every
+// element and `LT` when it is true for every element.
+const CR6_EQ: i32 = 0;
+const CR6_EQ_REV: i32 = 1;
+const CR6_LT: i32 = 2;
+const CR6_LT_REV: i32 = 3;
 
 macro_rules! impl_from {
     ($s: ident) => {
@@ -332,30 +375,9 @@ impl_neg! { i16x8 : 0 }
 impl_neg! { i32x4 : 0 }
 impl_neg! { f32x4 : 0f32 }
 
-mod sealed {
+pub(crate) mod sealed {
     use super::*;
 
-    macro_rules! test_impl {
-        ($fun:ident ($($v:ident : $ty:ty),*) -> $r:ty [$call:ident, $instr:ident]) => {
-            #[inline]
-            #[target_feature(enable = "altivec")]
-            #[cfg_attr(test, assert_instr($instr))]
-            pub unsafe fn $fun ($($v : $ty),*) -> $r {
-                $call ($($v),*)
-            }
-        };
-        ($fun:ident ($($v:ident : $ty:ty),*) -> $r:ty [$call:ident, $instr_altivec:ident / $instr_vsx:ident]) => {
-            #[inline]
-            #[target_feature(enable = "altivec")]
-            #[cfg_attr(all(test, not(target_feature="vsx")), assert_instr($instr_altivec))]
-            #[cfg_attr(all(test, target_feature="vsx"), assert_instr($instr_vsx))]
-            pub unsafe fn $fun ($($v : $ty),*) -> $r {
-                $call ($($v),*)
-            }
-        }
-
-    }
-
     macro_rules! impl_vec_trait {
         ([$Trait:ident $m:ident] $fun:ident ($a:ty)) => {
             impl $Trait for $a {
@@ -420,8 +442,18 @@ mod sealed {
             impl_vec_trait!{ [$Trait $m] $sw (vector_signed_int, vector_signed_int) -> vector_signed_int }
         };
         ([$Trait:ident $m:ident] 2 ($fn:ident)) => {
-            impl_vec_trait!{ [$Trait $m] ($fn, $fn, $fn, $fn, $fn, $fn) }
-        }
+            impl_vec_trait!{ [$Trait $m] 2 ($fn, $fn, $fn, $fn, $fn, $fn) }
+        };
+        ([$Trait:ident $m:ident]+ $fun:ident ($a:ty, $b:ty) -> $r:ty) => {
+            impl $Trait<$b> for $a {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn $m(self, b: $b) -> Self::Result {
+                    transmute($fun(transmute(self), transmute(b)))
+                }
+            }
+        };
     }
 
     macro_rules! impl_vec_cmp {
@@ -662,16 +694,6 @@ mod sealed {
     impl_abss! { vec_abss_i16, i16x8 }
     impl_abss! { vec_abss_i32, i32x4 }
 
-    macro_rules! splats {
-        ($name:ident, $v:ident, $r:ident) => {
-            #[inline]
-            #[target_feature(enable = "altivec")]
-            unsafe fn $name(v: $v) -> s_t_l!($r) {
-                transmute($r::splat(v))
-            }
-        };
-    }
-
     splats! { splats_u8, u8, u8x16 }
     splats! { splats_u16, u16, u16x8 }
     splats! { splats_u32, u32, u32x4 }
@@ -1446,215 +1468,1477 @@ mod sealed {
     vector_mladd! { vector_unsigned_short, vector_signed_short, vector_signed_short }
     vector_mladd! { vector_signed_short, vector_unsigned_short, vector_signed_short }
     vector_mladd! { vector_signed_short, vector_signed_short, vector_signed_short }
+
+    test_impl! { vec_vor(a: vector_signed_char, b: vector_signed_char) -> vector_signed_char [ simd_or, vor / xxlor ] }
+
+    pub trait VectorOr<Other> {
+        type Result;
+        unsafe fn vec_or(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_trait! { [VectorOr vec_or] ~(simd_or) }
+
+    test_impl! { vec_vxor(a: vector_signed_char, b: vector_signed_char) -> vector_signed_char [ simd_xor, vxor / xxlxor ] }
+
+    pub trait VectorXor<Other> {
+        type Result;
+        unsafe fn vec_xor(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_trait! { [VectorXor vec_xor] ~(simd_xor) }
+
+    #[inline]
+    #[target_feature(enable = "altivec")]
+    #[cfg_attr(all(test, not(target_feature = "vsx")), assert_instr(vnor))]
+    #[cfg_attr(all(test, target_feature = "vsx"), assert_instr(xxlnor))]
+    unsafe fn nor(a: u8x16, b: u8x16) -> u8x16 {
+        simd_xor(simd_or(a, b), u8x16::splat(0xff))
+    }
+
+    pub trait VectorNor {
+        unsafe fn vec_nor(self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_vec_nor {
+        ($($ty:ty),*) => {
+            $(
+                impl VectorNor for $ty {
+                    #[inline]
+                    #[target_feature(enable = "altivec")]
+                    unsafe fn vec_nor(self, b: Self) -> Self {
+                        transmute(nor(transmute(self), transmute(b)))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_nor! {
+        vector_unsigned_char, vector_signed_char, vector_bool_char,
+        vector_unsigned_short, vector_signed_short, vector_bool_short,
+        vector_unsigned_int, vector_signed_int, vector_bool_int
+    }
+
+    #[inline]
+    #[target_feature(enable = "altivec")]
+    #[cfg_attr(all(test, not(target_feature = "vsx")), assert_instr(vsel))]
+    #[cfg_attr(all(test, target_feature = "vsx"), assert_instr(xxsel))]
+    unsafe fn sel(a: u8x16, b: u8x16, c: u8x16) -> u8x16 {
+        let not_c = simd_xor(c, u8x16::splat(0xff));
+
+        simd_or(simd_and(a, not_c), simd_and(b, c))
+    }
+
+    pub trait VectorSel<Mask> {
+        unsafe fn vec_sel(self, b: Self, c: Mask) -> Self;
+    }
+
+    macro_rules! impl_vec_sel {
+        ($ty:ty, $mask:ty) => {
+            impl VectorSel<$mask> for $ty {
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_sel(self, b: Self, c: $mask) -> Self {
+                    transmute(sel(transmute(self), transmute(b), transmute(c)))
+                }
+            }
+        };
+        ($ty:ty, ~$bool:ty, $unsigned:ty) => {
+            impl_vec_sel! { $ty, $bool }
+            impl_vec_sel! { $ty, $unsigned }
+        };
+    }
+
+    impl_vec_sel! { vector_unsigned_char, ~vector_bool_char, vector_unsigned_char }
+    impl_vec_sel! { vector_signed_char, ~vector_bool_char, vector_unsigned_char }
+    impl_vec_sel! { vector_bool_char, ~vector_bool_char, vector_unsigned_char }
+    impl_vec_sel! { vector_unsigned_short, ~vector_bool_short, vector_unsigned_short }
+    impl_vec_sel! { vector_signed_short, ~vector_bool_short, vector_unsigned_short }
+    impl_vec_sel! { vector_bool_short, ~vector_bool_short, vector_unsigned_short }
+    impl_vec_sel! { vector_unsigned_int, ~vector_bool_int, vector_unsigned_int }
+    impl_vec_sel! { vector_signed_int, ~vector_bool_int, vector_unsigned_int }
+    impl_vec_sel! { vector_bool_int, ~vector_bool_int, vector_unsigned_int }
+    impl_vec_sel! { vector_float, ~vector_bool_int, vector_unsigned_int }
+
+    // The shift amounts are taken modulo the element width, as the hardware
+    // does, which also keeps `simd_shl`/`simd_shr` well-defined.
+    macro_rules! impl_vec_shift {
+        ($fun:ident ($a:ident, $b:ident) [$shift:ident, $bits:expr, $instr:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr($instr))]
+            pub unsafe fn $fun(a: $a, b: $b) -> $a {
+                let b = simd_and(b, $b::splat($bits - 1));
+                $shift(a, transmute(b))
+            }
+        };
+        ([$Trait:ident $m:ident] ($ub:ident, $sb:ident, $uh:ident, $sh:ident, $uw:ident, $sw:ident)) => {
+            impl_vec_trait!{ [$Trait $m]+ $ub (vector_unsigned_char, vector_unsigned_char) -> vector_unsigned_char }
+            impl_vec_trait!{ [$Trait $m]+ $sb (vector_signed_char, vector_unsigned_char) -> vector_signed_char }
+            impl_vec_trait!{ [$Trait $m]+ $uh (vector_unsigned_short, vector_unsigned_short) -> vector_unsigned_short }
+            impl_vec_trait!{ [$Trait $m]+ $sh (vector_signed_short, vector_unsigned_short) -> vector_signed_short }
+            impl_vec_trait!{ [$Trait $m]+ $uw (vector_unsigned_int, vector_unsigned_int) -> vector_unsigned_int }
+            impl_vec_trait!{ [$Trait $m]+ $sw (vector_signed_int, vector_unsigned_int) -> vector_signed_int }
+        };
+    }
+
+    impl_vec_shift! { vec_vslb (u8x16, u8x16) [simd_shl, 8, vslb] }
+    impl_vec_shift! { vec_vslh (u16x8, u16x8) [simd_shl, 16, vslh] }
+    impl_vec_shift! { vec_vslw (u32x4, u32x4) [simd_shl, 32, vslw] }
+
+    impl_vec_shift! { vec_vsrb (u8x16, u8x16) [simd_shr, 8, vsrb] }
+    impl_vec_shift! { vec_vsrh (u16x8, u16x8) [simd_shr, 16, vsrh] }
+    impl_vec_shift! { vec_vsrw (u32x4, u32x4) [simd_shr, 32, vsrw] }
+
+    impl_vec_shift! { vec_vsrab (i8x16, u8x16) [simd_shr, 8, vsrab] }
+    impl_vec_shift! { vec_vsrah (i16x8, u16x8) [simd_shr, 16, vsrah] }
+    impl_vec_shift! { vec_vsraw (i32x4, u32x4) [simd_shr, 32, vsraw] }
+
+    pub trait VectorSl<Other> {
+        type Result;
+        unsafe fn vec_sl(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_shift! { [VectorSl vec_sl] (vec_vslb, vec_vslb, vec_vslh, vec_vslh, vec_vslw, vec_vslw) }
+
+    pub trait VectorSr<Other> {
+        type Result;
+        unsafe fn vec_sr(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_shift! { [VectorSr vec_sr] (vec_vsrb, vec_vsrb, vec_vsrh, vec_vsrh, vec_vsrw, vec_vsrw) }
+
+    pub trait VectorSra<Other> {
+        type Result;
+        unsafe fn vec_sra(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_shift! { [VectorSra vec_sra] (vec_vsrab, vec_vsrab, vec_vsrah, vec_vsrah, vec_vsraw, vec_vsraw) }
+
+    test_impl! { vec_vrlb(a: vector_signed_char, b: vector_unsigned_char) -> vector_signed_char [ vrlb, vrlb ] }
+    test_impl! { vec_vrlh(a: vector_signed_short, b: vector_unsigned_short) -> vector_signed_short [ vrlh, vrlh ] }
+    test_impl! { vec_vrlw(a: vector_signed_int, b: vector_unsigned_int) -> vector_signed_int [ vrlw, vrlw ] }
+
+    pub trait VectorRl<Other> {
+        type Result;
+        unsafe fn vec_rl(self, b: Other) -> Self::Result;
+    }
+
+    impl_vec_shift! { [VectorRl vec_rl] (vec_vrlb, vec_vrlb, vec_vrlh, vec_vrlh, vec_vrlw, vec_vrlw) }
+
+    // vec_mergeh and vec_mergel are defined in element order: on little
+    // endian the high/low merge instructions swap roles.
+    macro_rules! merge {
+        ($fun:ident ($ty:ident) [$shuffle:ident, $($idx:expr),+] [$be:ident / $le:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(all(test, target_endian = "big"), assert_instr($be))]
+            #[cfg_attr(all(test, target_endian = "little"), assert_instr($le))]
+            pub unsafe fn $fun(a: $ty, b: $ty) -> $ty {
+                $shuffle!(a, b, [$($idx),+])
+            }
+        };
+    }
+
+    merge! { vec_vmrghb (vector_unsigned_char) [simd_shuffle16, 0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23] [vmrghb / vmrglb] }
+    merge! { vec_vmrghh (vector_unsigned_short) [simd_shuffle8, 0, 8, 1, 9, 2, 10, 3, 11] [vmrghh / vmrglh] }
+    merge! { vec_vmrghw (vector_unsigned_int) [simd_shuffle4, 0, 4, 1, 5] [vmrghw / vmrglw] }
+
+    merge! { vec_vmrglb (vector_unsigned_char) [simd_shuffle16, 8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31] [vmrglb / vmrghb] }
+    merge! { vec_vmrglh (vector_unsigned_short) [simd_shuffle8, 4, 12, 5, 13, 6, 14, 7, 15] [vmrglh / vmrghh] }
+    merge! { vec_vmrglw (vector_unsigned_int) [simd_shuffle4, 2, 6, 3, 7] [vmrglw / vmrghw] }
+
+    pub trait VectorMergeh {
+        unsafe fn vec_mergeh(self, b: Self) -> Self;
+    }
+
+    pub trait VectorMergel {
+        unsafe fn vec_mergel(self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_vec_merge {
+        ($high:ident, $low:ident, $($ty:ty),*) => {
+            $(
+                impl VectorMergeh for $ty {
+                    #[inline]
+                    #[target_feature(enable = "altivec")]
+                    unsafe fn vec_mergeh(self, b: Self) -> Self {
+                        transmute($high(transmute(self), transmute(b)))
+                    }
+                }
+
+                impl VectorMergel for $ty {
+                    #[inline]
+                    #[target_feature(enable = "altivec")]
+                    unsafe fn vec_mergel(self, b: Self) -> Self {
+                        transmute($low(transmute(self), transmute(b)))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_merge! { vec_vmrghb, vec_vmrglb, vector_unsigned_char, vector_signed_char, vector_bool_char }
+    impl_vec_merge! { vec_vmrghh, vec_vmrglh, vector_unsigned_short, vector_signed_short, vector_bool_short }
+    impl_vec_merge! { vec_vmrghw, vec_vmrglw, vector_unsigned_int, vector_signed_int, vector_bool_int, vector_float }
+
+    macro_rules! pack {
+        ($fun:ident ($ty:ident) -> $r:ident [$half:ident, $shuffle:ident, $($idx:expr),+] [$instr:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr($instr))]
+            pub unsafe fn $fun(a: $ty, b: $ty) -> $r {
+                let a: $half = simd_cast(a);
+                let b: $half = simd_cast(b);
+                $shuffle!(a, b, [$($idx),+])
+            }
+        };
+    }
+
+    pack! { vec_vpkuhum (vector_unsigned_short) -> vector_unsigned_char [u8x8, simd_shuffle16, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15] [vpkuhum] }
+    pack! { vec_vpkuwum (vector_unsigned_int) -> vector_unsigned_short [u16x4, simd_shuffle8, 0, 1, 2, 3, 4, 5, 6, 7] [vpkuwum] }
+
+    pub trait VectorPack {
+        type Result;
+        unsafe fn vec_pack(self, b: Self) -> Self::Result;
+    }
+
+    macro_rules! impl_vec_pack {
+        ([$Trait:ident $m:ident] $fun:ident ($a:ty) -> $r:ty) => {
+            impl $Trait for $a {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn $m(self, b: Self) -> Self::Result {
+                    transmute($fun(transmute(self), transmute(b)))
+                }
+            }
+        };
+    }
+
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuhum (vector_unsigned_short) -> vector_unsigned_char }
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuhum (vector_signed_short) -> vector_signed_char }
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuhum (vector_bool_short) -> vector_bool_char }
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuwum (vector_unsigned_int) -> vector_unsigned_short }
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuwum (vector_signed_int) -> vector_signed_short }
+    impl_vec_pack! { [VectorPack vec_pack] vec_vpkuwum (vector_bool_int) -> vector_bool_short }
+
+    // The saturating pack instructions have big-endian bias: on little endian
+    // the operands are swapped so that `a` still ends up in the first half of
+    // the result.
+    macro_rules! packs {
+        ($fun:ident ($ty:ident) -> $r:ident [$call:ident, $instr:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr($instr))]
+            pub unsafe fn $fun(a: $ty, b: $ty) -> $r {
+                if cfg!(target_endian = "little") {
+                    $call(b, a)
+                } else {
+                    $call(a, b)
+                }
+            }
+        };
+    }
+
+    packs! { vec_vpkshss (vector_signed_short) -> vector_signed_char [vpkshss, vpkshss] }
+    packs! { vec_vpkshus (vector_signed_short) -> vector_unsigned_char [vpkshus, vpkshus] }
+    packs! { vec_vpkuhus (vector_unsigned_short) -> vector_unsigned_char [vpkuhus, vpkuhus] }
+    packs! { vec_vpkswss (vector_signed_int) -> vector_signed_short [vpkswss, vpkswss] }
+    packs! { vec_vpkswus (vector_signed_int) -> vector_unsigned_short [vpkswus, vpkswus] }
+    packs! { vec_vpkuwus (vector_unsigned_int) -> vector_unsigned_short [vpkuwus, vpkuwus] }
+
+    pub trait VectorPacks {
+        type Result;
+        unsafe fn vec_packs(self, b: Self) -> Self::Result;
+    }
+
+    impl_vec_pack! { [VectorPacks vec_packs] vec_vpkshss (vector_signed_short) -> vector_signed_char }
+    impl_vec_pack! { [VectorPacks vec_packs] vec_vpkuhus (vector_unsigned_short) -> vector_unsigned_char }
+    impl_vec_pack! { [VectorPacks vec_packs] vec_vpkswss (vector_signed_int) -> vector_signed_short }
+    impl_vec_pack! { [VectorPacks vec_packs] vec_vpkuwus (vector_unsigned_int) -> vector_unsigned_short }
+
+    pub trait VectorPacksu {
+        type Result;
+        unsafe fn vec_packsu(self, b: Self) -> Self::Result;
+    }
+
+    impl_vec_pack! { [VectorPacksu vec_packsu] vec_vpkshus (vector_signed_short) -> vector_unsigned_char }
+    impl_vec_pack! { [VectorPacksu vec_packsu] vec_vpkuhus (vector_unsigned_short) -> vector_unsigned_char }
+    impl_vec_pack! { [VectorPacksu vec_packsu] vec_vpkswus (vector_signed_int) -> vector_unsigned_short }
+    impl_vec_pack! { [VectorPacksu vec_packsu] vec_vpkuwus (vector_unsigned_int) -> vector_unsigned_short }
+
+    // Like the merges, the unpacks are defined in element order, so the
+    // high/low instructions swap roles on little endian.
+    macro_rules! unpack {
+        ($fun:ident ($ty:ident) -> $r:ident [$half:ident, $shuffle:ident, $($idx:expr),+] [$be:ident / $le:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(all(test, target_endian = "big"), assert_instr($be))]
+            #[cfg_attr(all(test, target_endian = "little"), assert_instr($le))]
+            pub unsafe fn $fun(a: $ty) -> $r {
+                let a: $half = $shuffle!(a, a, [$($idx),+]);
+                simd_cast(a)
+            }
+        };
+    }
+
+    unpack! { vec_vupkhsb (vector_signed_char) -> vector_signed_short [i8x8, simd_shuffle8, 0, 1, 2, 3, 4, 5, 6, 7] [vupkhsb / vupklsb] }
+    unpack! { vec_vupklsb (vector_signed_char) -> vector_signed_short [i8x8, simd_shuffle8, 8, 9, 10, 11, 12, 13, 14, 15] [vupklsb / vupkhsb] }
+    unpack! { vec_vupkhsh (vector_signed_short) -> vector_signed_int [i16x4, simd_shuffle4, 0, 1, 2, 3] [vupkhsh / vupklsh] }
+    unpack! { vec_vupklsh (vector_signed_short) -> vector_signed_int [i16x4, simd_shuffle4, 4, 5, 6, 7] [vupklsh / vupkhsh] }
+
+    pub trait VectorUnpackh {
+        type Result;
+        unsafe fn vec_unpackh(self) -> Self::Result;
+    }
+
+    pub trait VectorUnpackl {
+        type Result;
+        unsafe fn vec_unpackl(self) -> Self::Result;
+    }
+
+    macro_rules! impl_vec_unpack {
+        ($high:ident, $low:ident, $ty:ty, $r:ty) => {
+            impl VectorUnpackh for $ty {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_unpackh(self) -> Self::Result {
+                    transmute($high(transmute(self)))
+                }
+            }
+
+            impl VectorUnpackl for $ty {
+                type Result = $r;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_unpackl(self) -> Self::Result {
+                    transmute($low(transmute(self)))
+                }
+            }
+        };
+    }
+
+    impl_vec_unpack! { vec_vupkhsb, vec_vupklsb, vector_signed_char, vector_signed_short }
+    impl_vec_unpack! { vec_vupkhsb, vec_vupklsb, vector_bool_char, vector_bool_short }
+    impl_vec_unpack! { vec_vupkhsh, vec_vupklsh, vector_signed_short, vector_signed_int }
+    impl_vec_unpack! { vec_vupkhsh, vec_vupklsh, vector_bool_short, vector_bool_int }
+
+    pub trait VectorSplat {
+        unsafe fn vec_splat<const IMM: i32>(self) -> Self;
+    }
+
+    macro_rules! impl_vec_splat {
+        ($shuffle:ident, $imm:ident, $lanes:expr, $($ty:ty),*) => {
+            $(
+                impl VectorSplat for $ty {
+                    #[inline]
+                    #[target_feature(enable = "altivec")]
+                    unsafe fn vec_splat<const IMM: i32>(self) -> Self {
+                        $imm!(IMM);
+                        $shuffle!(self, self, <const IMM: i32> [IMM as u32; $lanes])
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_splat! { simd_shuffle16, static_assert_imm4, 16, vector_unsigned_char, vector_signed_char, vector_bool_char }
+    impl_vec_splat! { simd_shuffle8, static_assert_imm3, 8, vector_unsigned_short, vector_signed_short, vector_bool_short }
+    impl_vec_splat! { simd_shuffle4, static_assert_imm2, 4, vector_unsigned_int, vector_signed_int, vector_bool_int, vector_float }
+
+    // vsldoi has big-endian bias: on little endian the operands are swapped
+    // and the shift is counted from the other end.
+    const fn sld_idx(d: i32) -> [u32; 16] {
+        let mut idx = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            idx[i] = if cfg!(target_endian = "little") {
+                (16 - d) as u32 + i as u32
+            } else {
+                d as u32 + i as u32
+            };
+            i += 1;
+        }
+        idx
+    }
+
+    #[inline]
+    #[target_feature(enable = "altivec")]
+    #[cfg_attr(test, assert_instr(vsldoi, UIMM4 = 1))]
+    pub unsafe fn vec_vsldoi<const UIMM4: i32>(
+        a: vector_unsigned_char,
+        b: vector_unsigned_char,
+    ) -> vector_unsigned_char {
+        static_assert_imm4!(UIMM4);
+        if cfg!(target_endian = "little") {
+            simd_shuffle16!(b, a, <const UIMM4: i32> sld_idx(UIMM4))
+        } else {
+            simd_shuffle16!(a, b, <const UIMM4: i32> sld_idx(UIMM4))
+        }
+    }
+
+    pub trait VectorSld {
+        unsafe fn vec_sld<const UIMM4: i32>(self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_vec_sld {
+        ($($ty:ty),*) => {
+            $(
+                impl VectorSld for $ty {
+                    #[inline]
+                    #[target_feature(enable = "altivec")]
+                    unsafe fn vec_sld<const UIMM4: i32>(self, b: Self) -> Self {
+                        transmute(vec_vsldoi::<UIMM4>(transmute(self), transmute(b)))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_vec_sld! {
+        vector_unsigned_char, vector_signed_char, vector_bool_char,
+        vector_unsigned_short, vector_signed_short, vector_bool_short,
+        vector_unsigned_int, vector_signed_int, vector_bool_int,
+        vector_float
+    }
+
+    #[inline]
+    #[target_feature(enable = "altivec")]
+    #[cfg_attr(test, assert_instr(vcfsx, IMM5 = 1))]
+    pub unsafe fn vec_vcfsx<const IMM5: i32>(a: vector_signed_int) -> vector_float {
+        static_assert_imm5!(IMM5);
+        vcfsx(a, IMM5)
+    }
+
+    #[inline]
+    #[target_feature(enable = "altivec")]
+    #[cfg_attr(test, assert_instr(vcfux, IMM5 = 1))]
+    pub unsafe fn vec_vcfux<const IMM5: i32>(a: vector_unsigned_int) -> vector_float {
+        static_assert_imm5!(IMM5);
+        vcfux(a, IMM5)
+    }
+
+    pub trait VectorCtf {
+        unsafe fn vec_ctf<const IMM5: i32>(self) -> vector_float;
+    }
+
+    impl VectorCtf for vector_signed_int {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        unsafe fn vec_ctf<const IMM5: i32>(self) -> vector_float {
+            vec_vcfsx::<IMM5>(self)
+        }
+    }
+
+    impl VectorCtf for vector_unsigned_int {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        unsafe fn vec_ctf<const IMM5: i32>(self) -> vector_float {
+            vec_vcfux::<IMM5>(self)
+        }
+    }
+
+    pub trait VectorSt {
+        type Target;
+        unsafe fn vec_st(self, off: i32, p: Self::Target);
+    }
+
+    pub trait VectorSte {
+        type Target;
+        unsafe fn vec_ste(self, off: i32, p: Self::Target);
+    }
+
+    pub trait VectorLde {
+        type Result;
+        unsafe fn vec_lde(self, off: i32) -> Self::Result;
+    }
+
+    macro_rules! impl_vec_st {
+        ($fun:ident $ty:ident) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr(stvx))]
+            pub unsafe fn $fun(a: t_t_l!($ty), off: i32, p: *mut $ty) {
+                stvx(transmute(a), (p as *mut i8).offset(off as isize))
+            }
+
+            impl VectorSt for t_t_l!($ty) {
+                type Target = *mut $ty;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_st(self, off: i32, p: Self::Target) {
+                    $fun(self, off, p)
+                }
+            }
+        };
+    }
+
+    impl_vec_st! { vec_st_u8 u8 }
+    impl_vec_st! { vec_st_i8 i8 }
+
+    impl_vec_st! { vec_st_u16 u16 }
+    impl_vec_st! { vec_st_i16 i16 }
+
+    impl_vec_st! { vec_st_u32 u32 }
+    impl_vec_st! { vec_st_i32 i32 }
+
+    impl_vec_st! { vec_st_f32 f32 }
+
+    macro_rules! impl_vec_ste {
+        ($fun:ident $ty:ident [$call:ident, $instr:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr($instr))]
+            pub unsafe fn $fun(a: t_t_l!($ty), off: i32, p: *mut $ty) {
+                $call(transmute(a), (p as *mut i8).offset(off as isize))
+            }
+
+            impl VectorSte for t_t_l!($ty) {
+                type Target = *mut $ty;
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_ste(self, off: i32, p: Self::Target) {
+                    $fun(self, off, p)
+                }
+            }
+        };
+    }
+
+    impl_vec_ste! { vec_ste_u8 u8 [stvebx, stvebx] }
+    impl_vec_ste! { vec_ste_i8 i8 [stvebx, stvebx] }
+
+    impl_vec_ste! { vec_ste_u16 u16 [stvehx, stvehx] }
+    impl_vec_ste! { vec_ste_i16 i16 [stvehx, stvehx] }
+
+    impl_vec_ste! { vec_ste_u32 u32 [stvewx, stvewx] }
+    impl_vec_ste! { vec_ste_i32 i32 [stvewx, stvewx] }
+
+    impl_vec_ste! { vec_ste_f32 f32 [stvewx, stvewx] }
+
+    macro_rules! impl_vec_lde {
+        ($fun:ident $ty:ident [$call:ident, $instr:ident]) => {
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            #[cfg_attr(test, assert_instr($instr))]
+            pub unsafe fn $fun(off: i32, p: *const $ty) -> t_t_l!($ty) {
+                transmute($call((p as *const i8).offset(off as isize)))
+            }
+
+            impl VectorLde for *const $ty {
+                type Result = t_t_l!($ty);
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn vec_lde(self, off: i32) -> Self::Result {
+                    $fun(off, self)
+                }
+            }
+        };
+    }
+
+    impl_vec_lde! { vec_lde_u8 u8 [lvebx, lvebx] }
+    impl_vec_lde! { vec_lde_i8 i8 [lvebx, lvebx] }
+
+    impl_vec_lde! { vec_lde_u16 u16 [lvehx, lvehx] }
+    impl_vec_lde! { vec_lde_i16 i16 [lvehx, lvehx] }
+
+    impl_vec_lde! { vec_lde_u32 u32 [lvewx, lvewx] }
+    impl_vec_lde! { vec_lde_i32 i32 [lvewx, lvewx] }
+
+    impl_vec_lde! { vec_lde_f32 f32 [lvewx, lvewx] }
+
+    macro_rules! impl_vec_all_any {
+        ([$Trait:ident $m:ident $cr:ident] $fun:ident ($a:ty, $b:ty)) => {
+            impl $Trait<$b> for $a {
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn $m(self, b: $b) -> bool {
+                    $fun($cr, transmute(self), transmute(b)) != 0
+                }
+            }
+        };
+        ([$Trait:ident $m:ident $cr:ident rev] $fun:ident ($a:ty, $b:ty)) => {
+            impl $Trait<$b> for $a {
+                #[inline]
+                #[target_feature(enable = "altivec")]
+                unsafe fn $m(self, b: $b) -> bool {
+                    $fun($cr, transmute(b), transmute(self)) != 0
+                }
+            }
+        };
+        ([$Trait:ident $m:ident $cr:ident $($rev:ident)?] ($ub:ident, $sb:ident, $uh:ident, $sh:ident, $uw:ident, $sw:ident)) => {
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $ub (vector_unsigned_char, vector_unsigned_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $ub (vector_unsigned_char, vector_bool_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $ub (vector_bool_char, vector_unsigned_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $ub (vector_bool_char, vector_bool_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sb (vector_signed_char, vector_signed_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sb (vector_signed_char, vector_bool_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sb (vector_bool_char, vector_signed_char) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uh (vector_unsigned_short, vector_unsigned_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uh (vector_unsigned_short, vector_bool_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uh (vector_bool_short, vector_unsigned_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uh (vector_bool_short, vector_bool_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sh (vector_signed_short, vector_signed_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sh (vector_signed_short, vector_bool_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sh (vector_bool_short, vector_signed_short) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uw (vector_unsigned_int, vector_unsigned_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uw (vector_unsigned_int, vector_bool_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uw (vector_bool_int, vector_unsigned_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $uw (vector_bool_int, vector_bool_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sw (vector_signed_int, vector_signed_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sw (vector_signed_int, vector_bool_int) }
+            impl_vec_all_any! { [$Trait $m $cr $($rev)?] $sw (vector_bool_int, vector_signed_int) }
+        };
+    }
+
+    macro_rules! vec_all_any_trait {
+        ($($Trait:ident $m:ident),*) => {
+            $(
+                pub trait $Trait<Other> {
+                    unsafe fn $m(self, b: Other) -> bool;
+                }
+            )*
+        };
+    }
+
+    vec_all_any_trait! {
+        VectorAllEq vec_all_eq, VectorAllNe vec_all_ne, VectorAnyEq vec_any_eq, VectorAnyNe vec_any_ne,
+        VectorAllGt vec_all_gt, VectorAllLt vec_all_lt, VectorAllGe vec_all_ge, VectorAllLe vec_all_le,
+        VectorAnyGt vec_any_gt, VectorAnyLt vec_any_lt, VectorAnyGe vec_any_ge, VectorAnyLe vec_any_le
+    }
+
+    impl_vec_all_any! { [VectorAllEq vec_all_eq CR6_LT] (vcmpequb_p, vcmpequb_p, vcmpequh_p, vcmpequh_p, vcmpequw_p, vcmpequw_p) }
+    impl_vec_all_any! { [VectorAllEq vec_all_eq CR6_LT] vcmpeqfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAllNe vec_all_ne CR6_EQ] (vcmpequb_p, vcmpequb_p, vcmpequh_p, vcmpequh_p, vcmpequw_p, vcmpequw_p) }
+    impl_vec_all_any! { [VectorAllNe vec_all_ne CR6_EQ] vcmpeqfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAnyEq vec_any_eq CR6_EQ_REV] (vcmpequb_p, vcmpequb_p, vcmpequh_p, vcmpequh_p, vcmpequw_p, vcmpequw_p) }
+    impl_vec_all_any! { [VectorAnyEq vec_any_eq CR6_EQ_REV] vcmpeqfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAnyNe vec_any_ne CR6_LT_REV] (vcmpequb_p, vcmpequb_p, vcmpequh_p, vcmpequh_p, vcmpequw_p, vcmpequw_p) }
+    impl_vec_all_any! { [VectorAnyNe vec_any_ne CR6_LT_REV] vcmpeqfp_p (vector_float, vector_float) }
+
+    impl_vec_all_any! { [VectorAllGt vec_all_gt CR6_LT] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAllGt vec_all_gt CR6_LT] vcmpgtfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAllLt vec_all_lt CR6_LT rev] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAllLt vec_all_lt CR6_LT rev] vcmpgtfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAllGe vec_all_ge CR6_EQ rev] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAllGe vec_all_ge CR6_LT] vcmpgefp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAllLe vec_all_le CR6_EQ] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAllLe vec_all_le CR6_LT rev] vcmpgefp_p (vector_float, vector_float) }
+
+    impl_vec_all_any! { [VectorAnyGt vec_any_gt CR6_EQ_REV] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAnyGt vec_any_gt CR6_EQ_REV] vcmpgtfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAnyLt vec_any_lt CR6_EQ_REV rev] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAnyLt vec_any_lt CR6_EQ_REV rev] vcmpgtfp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAnyGe vec_any_ge CR6_LT_REV rev] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAnyGe vec_any_ge CR6_EQ_REV] vcmpgefp_p (vector_float, vector_float) }
+    impl_vec_all_any! { [VectorAnyLe vec_any_le CR6_LT_REV] (vcmpgtub_p, vcmpgtsb_p, vcmpgtuh_p, vcmpgtsh_p, vcmpgtuw_p, vcmpgtsw_p) }
+    impl_vec_all_any! { [VectorAnyLe vec_any_le CR6_EQ_REV rev] vcmpgefp_p (vector_float, vector_float) }
+
+    pub trait VectorMul {
+        unsafe fn vec_mul(self, b: Self) -> Self;
+    }
+
+    test_impl! { vec_vmulfp(a: vector_float, b: vector_float) -> vector_float [simd_mul, vmaddfp / xvmulsp] }
+
+    impl VectorMul for vector_float {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        unsafe fn vec_mul(self, b: Self) -> Self {
+            vec_vmulfp(self, b)
+        }
+    }
+    test_impl! { vec_vcmpneb(a: vector_signed_char, b: vector_signed_char) -> vector_bool_char [vcmpneb, vcmpneb, "power9-altivec"] }
+    test_impl! { vec_vcmpneh(a: vector_signed_short, b: vector_signed_short) -> vector_bool_short [vcmpneh, vcmpneh, "power9-altivec"] }
+    test_impl! { vec_vcmpnew(a: vector_signed_int, b: vector_signed_int) -> vector_bool_int [vcmpnew, vcmpnew, "power9-altivec"] }
+
+    pub trait VectorCmpNe<Other> {
+        type Result;
+        unsafe fn vec_cmpne(self, b: Other) -> Self::Result;
+    }
+
+    macro_rules! impl_vec_cmpne {
+        ($fun:ident ($($ty:ty),*) -> $r:ty) => {
+            $(
+                impl_vec_trait_with! { "power9-altivec" [VectorCmpNe vec_cmpne] $fun ($ty, $ty) -> $r }
+            )*
+        };
+    }
+
+    impl_vec_cmpne! { vec_vcmpneb (vector_unsigned_char, vector_signed_char, vector_bool_char) -> vector_bool_char }
+    impl_vec_cmpne! { vec_vcmpneh (vector_unsigned_short, vector_signed_short, vector_bool_short) -> vector_bool_short }
+    impl_vec_cmpne! { vec_vcmpnew (vector_unsigned_int, vector_signed_int, vector_bool_int) -> vector_bool_int }
+
+    pub trait VectorExtract {
+        type Scalar;
+        unsafe fn vec_extract(self, idx: i32) -> Self::Scalar;
+    }
+
+    pub trait VectorInsert {
+        type Scalar;
+        unsafe fn vec_insert(self, a: Self::Scalar, idx: i32) -> Self;
+    }
+
+    impl_vec_extract_insert! { vector_unsigned_char, u8, 16 }
+    impl_vec_extract_insert! { vector_signed_char, i8, 16 }
+    impl_vec_extract_insert! { vector_unsigned_short, u16, 8 }
+    impl_vec_extract_insert! { vector_signed_short, i16, 8 }
+    impl_vec_extract_insert! { vector_unsigned_int, u32, 4 }
+    impl_vec_extract_insert! { vector_signed_int, i32, 4 }
+    impl_vec_extract_insert! { vector_float, f32, 4 }
+}
+
+/// Vector ld.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_ld<T>(off: i32, p: T) -> <T as sealed::VectorLd>::Result
+where
+    T: sealed::VectorLd,
+{
+    p.vec_ld(off)
+}
+
+/// Vector floor.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_floor(a: vector_float) -> vector_float {
+    sealed::vec_floor(a)
+}
+
+/// Vector expte.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_expte(a: vector_float) -> vector_float {
+    sealed::vec_vexptefp(a)
+}
+
+/// Vector cmplt.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmplt<T, U>(a: U, b: T) -> <T as sealed::VectorCmpGt<U>>::Result
+where
+    T: sealed::VectorCmpGt<U>,
+{
+    vec_cmpgt(b, a)
+}
+
+/// Vector cmple.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmple(a: vector_float, b: vector_float) -> vector_bool_int {
+    vec_cmpge(b, a)
+}
+
+/// Vector cmpgt.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmpgt<T, U>(a: T, b: U) -> <T as sealed::VectorCmpGt<U>>::Result
+where
+    T: sealed::VectorCmpGt<U>,
+{
+    a.vec_cmpgt(b)
+}
+
+/// Vector cmpge.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmpge(a: vector_float, b: vector_float) -> vector_bool_int {
+    sealed::vec_vcmpgefp(a, b)
+}
+
+/// Vector cmpeq.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmpeq<T, U>(a: T, b: U) -> <T as sealed::VectorCmpEq<U>>::Result
+where
+    T: sealed::VectorCmpEq<U>,
+{
+    a.vec_cmpeq(b)
+}
+
+/// Vector cmpb.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_cmpb(a: vector_float, b: vector_float) -> vector_signed_int {
+    sealed::vec_vcmpbfp(a, b)
+}
+
+/// Vector cmpb.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_ceil(a: vector_float) -> vector_float {
+    sealed::vec_vceil(a)
+}
+
+/// Vector avg.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_avg<T, U>(a: T, b: U) -> <T as sealed::VectorAvg<U>>::Result
+where
+    T: sealed::VectorAvg<U>,
+{
+    a.vec_avg(b)
+}
+
+/// Vector andc.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_andc<T, U>(a: T, b: U) -> <T as sealed::VectorAndc<U>>::Result
+where
+    T: sealed::VectorAndc<U>,
+{
+    a.vec_andc(b)
+}
+
+/// Vector and.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_and<T, U>(a: T, b: U) -> <T as sealed::VectorAnd<U>>::Result
+where
+    T: sealed::VectorAnd<U>,
+{
+    a.vec_and(b)
+}
+
+/// Vector adds.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_adds<T, U>(a: T, b: U) -> <T as sealed::VectorAdds<U>>::Result
+where
+    T: sealed::VectorAdds<U>,
+{
+    a.vec_adds(b)
+}
+
+/// Vector addc.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_addc(a: vector_unsigned_int, b: vector_unsigned_int) -> vector_unsigned_int {
+    sealed::vec_vaddcuw(a, b)
+}
+
+/// Vector abs.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_abs<T>(a: T) -> T
+where
+    T: sealed::VectorAbs,
+{
+    a.vec_abs()
+}
+
+/// Vector abss.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_abss<T>(a: T) -> T
+where
+    T: sealed::VectorAbss,
+{
+    a.vec_abss()
+}
+
+/// Vector splats.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_splats<T>(a: T) -> <T as sealed::VectorSplats>::Result
+where
+    T: sealed::VectorSplats,
+{
+    a.vec_splats()
+}
+
+/// Vector sub.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sub<T, U>(a: T, b: U) -> <T as sealed::VectorSub<U>>::Result
+where
+    T: sealed::VectorSub<U>,
+{
+    a.vec_sub(b)
+}
+
+/// Vector subs.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_subs<T, U>(a: T, b: U) -> <T as sealed::VectorSubs<U>>::Result
+where
+    T: sealed::VectorSubs<U>,
+{
+    a.vec_subs(b)
+}
+
+/// Vector min.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_min<T, U>(a: T, b: U) -> <T as sealed::VectorMin<U>>::Result
+where
+    T: sealed::VectorMin<U>,
+{
+    a.vec_min(b)
+}
+
+/// Vector max.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_max<T, U>(a: T, b: U) -> <T as sealed::VectorMax<U>>::Result
+where
+    T: sealed::VectorMax<U>,
+{
+    a.vec_max(b)
+}
+
+/// Vector add.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_add<T, U>(a: T, b: U) -> <T as sealed::VectorAdd<U>>::Result
+where
+    T: sealed::VectorAdd<U>,
+{
+    a.vec_add(b)
+}
+
+/// Vector or.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_or<T, U>(a: T, b: U) -> <T as sealed::VectorOr<U>>::Result
+where
+    T: sealed::VectorOr<U>,
+{
+    a.vec_or(b)
+}
+
+/// Vector xor.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_xor<T, U>(a: T, b: U) -> <T as sealed::VectorXor<U>>::Result
+where
+    T: sealed::VectorXor<U>,
+{
+    a.vec_xor(b)
+}
+
+/// Vector nor.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_nor<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorNor,
+{
+    a.vec_nor(b)
+}
+
+/// Vector select.
+///
+/// Each bit of the result is taken from `b` if the corresponding bit of `c`
+/// is set and from `a` otherwise.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sel<T, U>(a: T, b: T, c: U) -> T
+where
+    T: sealed::VectorSel<U>,
+{
+    a.vec_sel(b, c)
+}
+
+/// Vector shift left.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sl<T, U>(a: T, b: U) -> <T as sealed::VectorSl<U>>::Result
+where
+    T: sealed::VectorSl<U>,
+{
+    a.vec_sl(b)
+}
+
+/// Vector shift right.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sr<T, U>(a: T, b: U) -> <T as sealed::VectorSr<U>>::Result
+where
+    T: sealed::VectorSr<U>,
+{
+    a.vec_sr(b)
+}
+
+/// Vector shift right algebraic.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sra<T, U>(a: T, b: U) -> <T as sealed::VectorSra<U>>::Result
+where
+    T: sealed::VectorSra<U>,
+{
+    a.vec_sra(b)
+}
+
+/// Vector rotate left.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_rl<T, U>(a: T, b: U) -> <T as sealed::VectorRl<U>>::Result
+where
+    T: sealed::VectorRl<U>,
+{
+    a.vec_rl(b)
+}
+
+/// Vector merge high.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_mergeh<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorMergeh,
+{
+    a.vec_mergeh(b)
+}
+
+/// Vector merge low.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_mergel<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorMergel,
+{
+    a.vec_mergel(b)
+}
+
+/// Vector pack.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_pack<T>(a: T, b: T) -> <T as sealed::VectorPack>::Result
+where
+    T: sealed::VectorPack,
+{
+    a.vec_pack(b)
+}
+
+/// Vector pack saturated.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_packs<T>(a: T, b: T) -> <T as sealed::VectorPacks>::Result
+where
+    T: sealed::VectorPacks,
+{
+    a.vec_packs(b)
+}
+
+/// Vector pack saturated unsigned.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_packsu<T>(a: T, b: T) -> <T as sealed::VectorPacksu>::Result
+where
+    T: sealed::VectorPacksu,
+{
+    a.vec_packsu(b)
+}
+
+/// Vector unpack high.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_unpackh<T>(a: T) -> <T as sealed::VectorUnpackh>::Result
+where
+    T: sealed::VectorUnpackh,
+{
+    a.vec_unpackh()
+}
+
+/// Vector unpack low.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_unpackl<T>(a: T) -> <T as sealed::VectorUnpackl>::Result
+where
+    T: sealed::VectorUnpackl,
+{
+    a.vec_unpackl()
+}
+
+/// Vector splat.
+///
+/// Replicates the element `IMM` of `a` into every element of the result.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_splat<T, const IMM: i32>(a: T) -> T
+where
+    T: sealed::VectorSplat,
+{
+    a.vec_splat::<IMM>()
+}
+
+/// Vector shift left double by octet immediate.
+///
+/// Returns the 16 bytes starting at byte `UIMM4` of the concatenation of `a`
+/// and `b`.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_sld<T, const UIMM4: i32>(a: T, b: T) -> T
+where
+    T: sealed::VectorSld,
+{
+    a.vec_sld::<UIMM4>(b)
+}
+
+/// Vector st.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_st<T>(a: T, off: i32, p: <T as sealed::VectorSt>::Target)
+where
+    T: sealed::VectorSt,
+{
+    a.vec_st(off, p)
 }
 
-/// Vector ld.
+/// Vector store element.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_ld<T>(off: i32, p: T) -> <T as sealed::VectorLd>::Result
+pub unsafe fn vec_ste<T>(a: T, off: i32, p: <T as sealed::VectorSte>::Target)
 where
-    T: sealed::VectorLd,
+    T: sealed::VectorSte,
 {
-    p.vec_ld(off)
+    a.vec_ste(off, p)
 }
 
-/// Vector floor.
+/// Vector load element.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_floor(a: vector_float) -> vector_float {
-    sealed::vec_floor(a)
+pub unsafe fn vec_lde<T>(off: i32, p: T) -> <T as sealed::VectorLde>::Result
+where
+    T: sealed::VectorLde,
+{
+    p.vec_lde(off)
 }
 
-/// Vector expte.
+/// Vector convert from fixed-point word.
+///
+/// Each element is divided by 2<sup>`IMM5`</sup> after the conversion.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_expte(a: vector_float) -> vector_float {
-    sealed::vec_vexptefp(a)
+pub unsafe fn vec_ctf<T, const IMM5: i32>(a: T) -> vector_float
+where
+    T: sealed::VectorCtf,
+{
+    a.vec_ctf::<IMM5>()
 }
 
-/// Vector cmplt.
+/// Vector convert to signed fixed-point word saturated.
+///
+/// Each element is multiplied by 2<sup>`IMM5`</sup> before the conversion.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmplt<T, U>(a: U, b: T) -> <T as sealed::VectorCmpGt<U>>::Result
-where
-    T: sealed::VectorCmpGt<U>,
-{
-    vec_cmpgt(b, a)
+#[cfg_attr(test, assert_instr(vctsxs, IMM5 = 1))]
+pub unsafe fn vec_cts<const IMM5: i32>(a: vector_float) -> vector_signed_int {
+    static_assert_imm5!(IMM5);
+    vctsxs(a, IMM5)
 }
 
-/// Vector cmple.
+/// Vector convert to unsigned fixed-point word saturated.
+///
+/// Each element is multiplied by 2<sup>`IMM5`</sup> before the conversion.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmple(a: vector_float, b: vector_float) -> vector_bool_int {
-    vec_cmpge(b, a)
+#[cfg_attr(test, assert_instr(vctuxs, IMM5 = 1))]
+pub unsafe fn vec_ctu<const IMM5: i32>(a: vector_float) -> vector_unsigned_int {
+    static_assert_imm5!(IMM5);
+    vctuxs(a, IMM5)
 }
 
-/// Vector cmpgt.
+/// Vector round.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmpgt<T, U>(a: T, b: U) -> <T as sealed::VectorCmpGt<U>>::Result
-where
-    T: sealed::VectorCmpGt<U>,
-{
-    a.vec_cmpgt(b)
+#[cfg_attr(test, assert_instr(vrfin))]
+pub unsafe fn vec_round(a: vector_float) -> vector_float {
+    vrfin(a)
 }
 
-/// Vector cmpge.
+/// Vector reciprocal estimate.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmpge(a: vector_float, b: vector_float) -> vector_bool_int {
-    sealed::vec_vcmpgefp(a, b)
+#[cfg_attr(test, assert_instr(vrefp))]
+pub unsafe fn vec_re(a: vector_float) -> vector_float {
+    vrefp(a)
 }
 
-/// Vector cmpeq.
+/// Vector reciprocal square root estimate.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmpeq<T, U>(a: T, b: U) -> <T as sealed::VectorCmpEq<U>>::Result
+#[cfg_attr(test, assert_instr(vrsqrtefp))]
+pub unsafe fn vec_rsqrte(a: vector_float) -> vector_float {
+    vrsqrtefp(a)
+}
+
+/// All Elements Equal
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_all_eq<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorCmpEq<U>,
+    T: sealed::VectorAllEq<U>,
 {
-    a.vec_cmpeq(b)
+    a.vec_all_eq(b)
 }
 
-/// Vector cmpb.
+/// All Elements Not Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_cmpb(a: vector_float, b: vector_float) -> vector_signed_int {
-    sealed::vec_vcmpbfp(a, b)
+pub unsafe fn vec_all_ne<T, U>(a: T, b: U) -> bool
+where
+    T: sealed::VectorAllNe<U>,
+{
+    a.vec_all_ne(b)
 }
 
-/// Vector cmpb.
+/// Any Element Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_ceil(a: vector_float) -> vector_float {
-    sealed::vec_vceil(a)
+pub unsafe fn vec_any_eq<T, U>(a: T, b: U) -> bool
+where
+    T: sealed::VectorAnyEq<U>,
+{
+    a.vec_any_eq(b)
 }
 
-/// Vector avg.
+/// Any Element Not Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_avg<T, U>(a: T, b: U) -> <T as sealed::VectorAvg<U>>::Result
+pub unsafe fn vec_any_ne<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAvg<U>,
+    T: sealed::VectorAnyNe<U>,
 {
-    a.vec_avg(b)
+    a.vec_any_ne(b)
 }
 
-/// Vector andc.
+/// All Elements Greater Than
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_andc<T, U>(a: T, b: U) -> <T as sealed::VectorAndc<U>>::Result
+pub unsafe fn vec_all_gt<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAndc<U>,
+    T: sealed::VectorAllGt<U>,
 {
-    a.vec_andc(b)
+    a.vec_all_gt(b)
 }
 
-/// Vector and.
+/// All Elements Less Than
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_and<T, U>(a: T, b: U) -> <T as sealed::VectorAnd<U>>::Result
+pub unsafe fn vec_all_lt<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAnd<U>,
+    T: sealed::VectorAllLt<U>,
 {
-    a.vec_and(b)
+    a.vec_all_lt(b)
 }
 
-/// Vector adds.
+/// All Elements Greater Than or Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_adds<T, U>(a: T, b: U) -> <T as sealed::VectorAdds<U>>::Result
+pub unsafe fn vec_all_ge<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAdds<U>,
+    T: sealed::VectorAllGe<U>,
 {
-    a.vec_adds(b)
+    a.vec_all_ge(b)
 }
 
-/// Vector addc.
+/// All Elements Less Than or Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_addc(a: vector_unsigned_int, b: vector_unsigned_int) -> vector_unsigned_int {
-    sealed::vec_vaddcuw(a, b)
+pub unsafe fn vec_all_le<T, U>(a: T, b: U) -> bool
+where
+    T: sealed::VectorAllLe<U>,
+{
+    a.vec_all_le(b)
 }
 
-/// Vector abs.
+/// Any Element Greater Than
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_abs<T>(a: T) -> T
+pub unsafe fn vec_any_gt<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAbs,
+    T: sealed::VectorAnyGt<U>,
 {
-    a.vec_abs()
+    a.vec_any_gt(b)
 }
 
-/// Vector abss.
+/// Any Element Less Than
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_abss<T>(a: T) -> T
+pub unsafe fn vec_any_lt<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorAbss,
+    T: sealed::VectorAnyLt<U>,
 {
-    a.vec_abss()
+    a.vec_any_lt(b)
 }
 
-/// Vector splats.
+/// Any Element Greater Than or Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_splats<T>(a: T) -> <T as sealed::VectorSplats>::Result
+pub unsafe fn vec_any_ge<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorSplats,
+    T: sealed::VectorAnyGe<U>,
 {
-    a.vec_splats()
+    a.vec_any_ge(b)
 }
 
-/// Vector sub.
+/// Any Element Less Than or Equal
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_sub<T, U>(a: T, b: U) -> <T as sealed::VectorSub<U>>::Result
+pub unsafe fn vec_any_le<T, U>(a: T, b: U) -> bool
 where
-    T: sealed::VectorSub<U>,
+    T: sealed::VectorAnyLe<U>,
 {
-    a.vec_sub(b)
+    a.vec_any_le(b)
 }
 
-/// Vector subs.
+/// All Elements Not a Number
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_subs<T, U>(a: T, b: U) -> <T as sealed::VectorSubs<U>>::Result
+#[cfg_attr(test, assert_instr("vcmpeqfp."))]
+pub unsafe fn vec_all_nan(a: vector_float) -> bool {
+    vcmpeqfp_p(CR6_EQ, a, a) != 0
+}
+
+/// Any Element Not a Number
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpeqfp."))]
+pub unsafe fn vec_any_nan(a: vector_float) -> bool {
+    vcmpeqfp_p(CR6_LT_REV, a, a) != 0
+}
+
+/// All Elements Numeric
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpeqfp."))]
+pub unsafe fn vec_all_numeric(a: vector_float) -> bool {
+    vcmpeqfp_p(CR6_LT, a, a) != 0
+}
+
+/// Any Element Numeric
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpeqfp."))]
+pub unsafe fn vec_any_numeric(a: vector_float) -> bool {
+    vcmpeqfp_p(CR6_EQ_REV, a, a) != 0
+}
+
+/// All Elements in Bounds
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpbfp."))]
+pub unsafe fn vec_all_in(a: vector_float, b: vector_float) -> bool {
+    vcmpbfp_p(CR6_EQ, a, b) != 0
+}
+
+/// Any Element Out of Bounds
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpbfp."))]
+pub unsafe fn vec_any_out(a: vector_float, b: vector_float) -> bool {
+    vcmpbfp_p(CR6_EQ_REV, a, b) != 0
+}
+
+/// All Elements Not Greater Than or Equal
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgefp."))]
+pub unsafe fn vec_all_nge(a: vector_float, b: vector_float) -> bool {
+    vcmpgefp_p(CR6_EQ, a, b) != 0
+}
+
+/// All Elements Not Greater Than
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgtfp."))]
+pub unsafe fn vec_all_ngt(a: vector_float, b: vector_float) -> bool {
+    vcmpgtfp_p(CR6_EQ, a, b) != 0
+}
+
+/// All Elements Not Less Than or Equal
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgefp."))]
+pub unsafe fn vec_all_nle(a: vector_float, b: vector_float) -> bool {
+    vcmpgefp_p(CR6_EQ, b, a) != 0
+}
+
+/// All Elements Not Less Than
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgtfp."))]
+pub unsafe fn vec_all_nlt(a: vector_float, b: vector_float) -> bool {
+    vcmpgtfp_p(CR6_EQ, b, a) != 0
+}
+
+/// Any Element Not Greater Than or Equal
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgefp."))]
+pub unsafe fn vec_any_nge(a: vector_float, b: vector_float) -> bool {
+    vcmpgefp_p(CR6_LT_REV, a, b) != 0
+}
+
+/// Any Element Not Greater Than
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgtfp."))]
+pub unsafe fn vec_any_ngt(a: vector_float, b: vector_float) -> bool {
+    vcmpgtfp_p(CR6_LT_REV, a, b) != 0
+}
+
+/// Any Element Not Less Than or Equal
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgefp."))]
+pub unsafe fn vec_any_nle(a: vector_float, b: vector_float) -> bool {
+    vcmpgefp_p(CR6_LT_REV, b, a) != 0
+}
+
+/// Any Element Not Less Than
+#[inline]
+#[target_feature(enable = "altivec")]
+#[cfg_attr(test, assert_instr("vcmpgtfp."))]
+pub unsafe fn vec_any_nlt(a: vector_float, b: vector_float) -> bool {
+    vcmpgtfp_p(CR6_LT_REV, b, a) != 0
+}
+
+/// Vector Multiply.
+#[inline]
+#[target_feature(enable = "altivec")]
+pub unsafe fn vec_mul<T>(a: T, b: T) -> T
 where
-    T: sealed::VectorSubs<U>,
+    T: sealed::VectorMul,
 {
-    a.vec_subs(b)
+    a.vec_mul(b)
 }
 
-/// Vector min.
+/// Vector Compare Not Equal.
 #[inline]
-#[target_feature(enable = "altivec")]
-pub unsafe fn vec_min<T, U>(a: T, b: U) -> <T as sealed::VectorMin<U>>::Result
+#[target_feature(enable = "power9-altivec")]
+pub unsafe fn vec_cmpne<T, U>(a: T, b: U) -> <T as sealed::VectorCmpNe<U>>::Result
 where
-    T: sealed::VectorMin<U>,
+    T: sealed::VectorCmpNe<U>,
 {
-    a.vec_min(b)
+    a.vec_cmpne(b)
 }
 
-/// Vector max.
+/// Vector Extract.
+///
+/// Returns the element `b` of `a`, with `b` taken modulo the number of
+/// elements.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_max<T, U>(a: T, b: U) -> <T as sealed::VectorMax<U>>::Result
+pub unsafe fn vec_extract<T>(a: T, b: i32) -> <T as sealed::VectorExtract>::Scalar
 where
-    T: sealed::VectorMax<U>,
+    T: sealed::VectorExtract,
 {
-    a.vec_max(b)
+    a.vec_extract(b)
 }
 
-/// Vector add.
+/// Vector Insert.
+///
+/// Returns a copy of `b` with its element `c` replaced by `a`, with `c` taken
+/// modulo the number of elements.
 #[inline]
 #[target_feature(enable = "altivec")]
-pub unsafe fn vec_add<T, U>(a: T, b: U) -> <T as sealed::VectorAdd<U>>::Result
+pub unsafe fn vec_insert<T>(a: <T as sealed::VectorInsert>::Scalar, b: T, c: i32) -> T
 where
-    T: sealed::VectorAdd<U>,
+    T: sealed::VectorInsert,
 {
-    a.vec_add(b)
+    b.vec_insert(a, c)
 }
 
 /// Endian-biased intrinsics
@@ -2774,4 +4058,334 @@ mod tests {
         let z = vec_add(x, y);
         assert_eq!(i32x4::splat(5), transmute(z));
     }
+    test_vec_2! { test_vec_or, vec_or, u32x4,
+        [0x0000_00ff, 0xf0f0_f0f0, 0, 1],
+        [0xff00_0000, 0x0f0f_0f0f, 0, 2],
+        [0xff00_00ff, 0xffff_ffff, 0, 3]
+    }
+
+    test_vec_2! { test_vec_xor, vec_xor, i32x4,
+        [0x0000_00ff, 0x7f0f_0f0f, 0, 3],
+        [0x0000_000f, 0x7f0f_0f0f, -1, 1],
+        [0x0000_00f0, 0, -1, 2]
+    }
+
+    test_vec_2! { test_vec_nor, vec_nor, u8x16,
+        [0x0f, 0xf0, 0x00, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0x0f, 0x0f, 0x01, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0xf0, 0x00, 0xfe, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_sel() {
+        let a: vector_signed_int = transmute(i32x4::new(1, 2, 3, 4));
+        let b: vector_signed_int = transmute(i32x4::new(-1, -2, -3, -4));
+        let c: vector_bool_int = transmute(i32x4::new(0, -1, 0, -1));
+        let d = i32x4::new(1, -2, 3, -4);
+        assert_eq!(d, transmute(vec_sel(a, b, c)));
+
+        let a: vector_unsigned_char = transmute(u8x16::splat(0x00));
+        let b: vector_unsigned_char = transmute(u8x16::splat(0xff));
+        let c: vector_unsigned_char = transmute(u8x16::splat(0x3c));
+        assert_eq!(u8x16::splat(0x3c), transmute(vec_sel(a, b, c)));
+    }
+
+    test_vec_2! { test_vec_sl, vec_sl, u32x4,
+        [1, 1, 1, 1],
+        [0, 1, 31, 32],
+        [1, 2, 0x8000_0000, 1]
+    }
+
+    test_vec_2! { test_vec_sr, vec_sr, u32x4,
+        [0x8000_0000, 0x8000_0000, 0x8000_0000, 0x8000_0000],
+        [0, 1, 31, 32],
+        [0x8000_0000, 0x4000_0000, 1, 0x8000_0000]
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_sra() {
+        let a: vector_signed_short = transmute(i16x8::new(-8, -8, -8, -8, 8, 8, 8, 8));
+        let b: vector_unsigned_short = transmute(u16x8::new(0, 1, 3, 16, 0, 1, 3, 16));
+        let d = i16x8::new(-8, -4, -1, -8, 8, 4, 1, 8);
+        assert_eq!(d, transmute(vec_sra(a, b)));
+    }
+
+    test_vec_2! { test_vec_rl, vec_rl, u32x4,
+        [0x8000_0001, 0x8000_0001, 0x8000_0001, 0x8000_0001],
+        [0, 1, 4, 33],
+        [0x8000_0001, 0x0000_0003, 0x0000_0018, 0x0000_0003]
+    }
+
+    test_vec_2! { test_vec_mergeh, vec_mergeh, u32x4,
+        [0, 1, 2, 3],
+        [4, 5, 6, 7],
+        [0, 4, 1, 5]
+    }
+
+    test_vec_2! { test_vec_mergel, vec_mergel, i16x8,
+        [0, 1, 2, 3, 4, 5, 6, 7],
+        [-0, -1, -2, -3, -4, -5, -6, -7],
+        [4, -4, 5, -5, 6, -6, 7, -7]
+    }
+
+    test_vec_2! { test_vec_pack, vec_pack, u16x8 -> u8x16,
+        [0x0100, 0x0201, 0x0302, 0x0403, 0x0504, 0x0605, 0x0706, 0x0807],
+        [0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f],
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    }
+
+    test_vec_2! { test_vec_packs, vec_packs, i16x8 -> i8x16,
+        [-200, 200, 1, -1, 0, 0, 0, 0],
+        [300, -300, 2, -2, 0, 0, 0, 0],
+        [-128, 127, 1, -1, 0, 0, 0, 0, 127, -128, 2, -2, 0, 0, 0, 0]
+    }
+
+    test_vec_2! { test_vec_packsu, vec_packsu, i32x4 -> u16x8,
+        [-1, 70000, 1, 0],
+        [65535, -70000, 2, 0],
+        [0, 65535, 1, 0, 65535, 0, 2, 0]
+    }
+
+    test_vec_1! { test_vec_unpackh, vec_unpackh, i8x16 -> i16x8,
+        [-1, 2, -3, 4, -5, 6, -7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        [-1, 2, -3, 4, -5, 6, -7, 8]
+    }
+
+    test_vec_1! { test_vec_unpackl, vec_unpackl, i16x8 -> i32x4,
+        [1, 2, 3, 4, -5, 6, -7, 8],
+        [-5, 6, -7, 8]
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_splat() {
+        let a: vector_signed_short = transmute(i16x8::new(0, 1, 2, 3, 4, 5, 6, 7));
+        assert_eq!(i16x8::splat(2), transmute(vec_splat::<_, 2>(a)));
+
+        let a: vector_float = transmute(f32x4::new(0.5, 1.5, 2.5, 3.5));
+        assert_eq!(f32x4::splat(3.5), transmute(vec_splat::<_, 3>(a)));
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_sld() {
+        let a: vector_unsigned_char = transmute(u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ));
+        let b: vector_unsigned_char = transmute(u8x16::new(
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+        ));
+        let d = if cfg!(target_endian = "little") {
+            u8x16::new(29, 30, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)
+        } else {
+            u8x16::new(3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18)
+        };
+        assert_eq!(d, transmute(vec_sld::<_, 3>(a, b)));
+        assert_eq!(
+            u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+            transmute(vec_sld::<_, 0>(a, b))
+        );
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_st() {
+        let v: vector_unsigned_char = transmute(u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ));
+
+        for off in 0..16 {
+            let mut buf = [u8x16::splat(0xff)];
+            vec_st(v, off, buf.as_mut_ptr() as *mut u8);
+            assert_eq!(transmute::<_, u8x16>(v), buf[0]);
+        }
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_lde_ste() {
+        let pat = [u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        )];
+        let p = pat.as_ptr() as *const u8;
+
+        for off in 0..16 {
+            let v: u8x16 = transmute(vec_lde(off, p));
+            assert_eq!(off as u8, v.extract(off as usize));
+
+            let mut buf = [u8x16::splat(0xff)];
+            vec_ste(
+                transmute::<_, vector_unsigned_char>(pat[0]),
+                off,
+                buf.as_mut_ptr() as *mut u8,
+            );
+            assert_eq!(off as u8, buf[0].extract(off as usize));
+        }
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_ctf() {
+        let a: vector_signed_int = transmute(i32x4::new(2, -4, 5, 0));
+        assert_eq!(
+            f32x4::new(1.0, -2.0, 2.5, 0.0),
+            transmute(vec_ctf::<_, 1>(a))
+        );
+
+        let a: vector_unsigned_int = transmute(u32x4::new(2, 4, 5, 0));
+        assert_eq!(
+            f32x4::new(0.5, 1.0, 1.25, 0.0),
+            transmute(vec_ctf::<_, 2>(a))
+        );
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_cts() {
+        let a: vector_float = transmute(f32x4::new(1.5, -2.25, 3e10, -3e10));
+        let d = i32x4::new(3, -4, i32::MAX, i32::MIN);
+        assert_eq!(d, transmute(vec_cts::<1>(a)));
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_ctu() {
+        let a: vector_float = transmute(f32x4::new(1.5, -1.0, 3e10, 2.25));
+        let d = u32x4::new(3, 0, u32::MAX, 4);
+        assert_eq!(d, transmute(vec_ctu::<1>(a)));
+    }
+
+    test_vec_1! { test_vec_round, vec_round, f32x4,
+        [1.5, 2.5, -0.5, -1.7],
+        [2.0, 2.0, -0.0, -2.0]
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_re_rsqrte() {
+        // Both are estimates with a relative error of at most 1/4096.
+        let a: vector_float = transmute(f32x4::new(1.0, 2.0, 4.0, 0.25));
+        let e: vector_float = transmute(f32x4::splat(1.0 / 256.0));
+        let t = m32x4::new(true, true, true, true);
+
+        let d: vector_float = transmute(f32x4::new(1.0, 0.5, 0.25, 4.0));
+        let r: m32x4 = transmute(vec_cmple(vec_abs(vec_sub(vec_re(a), d)), e));
+        assert_eq!(t, r);
+
+        let d: vector_float = transmute(f32x4::new(1.0, 0.70710677, 0.5, 2.0));
+        let r: m32x4 = transmute(vec_cmple(vec_abs(vec_sub(vec_rsqrte(a), d)), e));
+        assert_eq!(t, r);
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_all_any_eq() {
+        let a: vector_signed_int = transmute(i32x4::new(1, 2, 3, 4));
+        let b: vector_signed_int = transmute(i32x4::new(1, 2, 3, 5));
+        let c: vector_signed_int = transmute(i32x4::new(5, 6, 7, 8));
+
+        assert!(vec_all_eq(a, a));
+        assert!(!vec_all_eq(a, b));
+        assert!(vec_any_eq(a, b));
+        assert!(!vec_any_eq(a, c));
+        assert!(vec_all_ne(a, c));
+        assert!(!vec_all_ne(a, b));
+        assert!(vec_any_ne(a, b));
+        assert!(!vec_any_ne(a, a));
+
+        let m: vector_bool_char = transmute(m8x16::splat(true));
+        let u: vector_unsigned_char = transmute(u8x16::splat(0xff));
+        assert!(vec_all_eq(m, u));
+        assert!(vec_all_eq(u, m));
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_all_any_cmp() {
+        let a: vector_unsigned_short = transmute(u16x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+        let b: vector_unsigned_short = transmute(u16x8::new(0, 2, 3, 4, 5, 6, 7, 0xffff));
+        let z: vector_unsigned_short = transmute(u16x8::splat(0));
+
+        assert!(vec_all_gt(a, z));
+        assert!(!vec_all_gt(a, b));
+        assert!(vec_any_gt(a, b));
+        assert!(!vec_any_gt(z, a));
+        assert!(vec_all_lt(z, a));
+        assert!(vec_any_lt(a, b));
+        assert!(!vec_any_lt(a, z));
+        assert!(vec_all_ge(a, z));
+        assert!(!vec_all_ge(a, b));
+        assert!(vec_any_ge(a, b));
+        assert!(vec_all_le(z, a));
+        assert!(!vec_all_le(a, b));
+        assert!(vec_any_le(a, b));
+
+        let a: vector_signed_char = transmute(i8x16::splat(-1));
+        let z: vector_signed_char = transmute(i8x16::splat(0));
+        assert!(vec_all_lt(a, z));
+        assert!(!vec_any_ge(a, z));
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_all_any_float() {
+        let a: vector_float = transmute(f32x4::new(1.0, 2.0, 3.0, 4.0));
+        let b: vector_float = transmute(f32x4::new(1.0, 2.0, 3.0, std::f32::NAN));
+        let n: vector_float = transmute(f32x4::splat(std::f32::NAN));
+        let z: vector_float = transmute(f32x4::splat(0.0));
+        let bound: vector_float = transmute(f32x4::splat(4.0));
+
+        assert!(vec_all_eq(a, a));
+        assert!(!vec_all_eq(b, b));
+        assert!(vec_any_ne(b, b));
+        assert!(vec_all_ge(a, a));
+        assert!(vec_all_le(z, a));
+        assert!(vec_any_ge(a, bound));
+        assert!(!vec_any_le(bound, z));
+
+        assert!(vec_all_numeric(a));
+        assert!(!vec_all_numeric(b));
+        assert!(vec_any_numeric(b));
+        assert!(!vec_any_numeric(n));
+        assert!(vec_all_nan(n));
+        assert!(!vec_all_nan(b));
+        assert!(vec_any_nan(b));
+        assert!(!vec_any_nan(a));
+
+        assert!(vec_all_in(a, bound));
+        assert!(!vec_any_out(a, bound));
+        assert!(vec_any_out(b, bound));
+
+        assert!(vec_all_ngt(n, z));
+        assert!(vec_all_nge(z, a));
+        assert!(vec_all_nlt(a, z));
+        assert!(vec_all_nle(bound, z));
+        assert!(!vec_all_nle(z, a));
+        assert!(vec_any_nge(b, z));
+        assert!(!vec_any_nge(a, z));
+        assert!(vec_any_ngt(a, bound));
+        assert!(vec_any_nlt(b, z));
+        assert!(vec_any_nle(a, z));
+        assert!(!vec_any_nle(z, a));
+    }
+
+    #[simd_test(enable = "power9-altivec")]
+    unsafe fn test_vec_cmpne() {
+        let a: vector_signed_char =
+            transmute(i8x16::new(1, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0));
+        let b: vector_signed_char =
+            transmute(i8x16::new(0, -1, -1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1));
+        let d = m8x16::new(
+            true, false, true, true, false, false, false, false, false, false, false, false, false,
+            false, false, true,
+        );
+        assert_eq!(d, transmute(vec_cmpne(a, b)));
+    }
+
+    #[simd_test(enable = "altivec")]
+    unsafe fn test_vec_extract_insert() {
+        let a: vector_signed_short = transmute(i16x8::new(0, -1, 2, -3, 4, -5, 6, -7));
+        assert_eq!(vec_extract(a, 3), -3);
+        assert_eq!(vec_extract(a, 11), -3);
+        assert_eq!(
+            i16x8::new(0, -1, 2, 42, 4, -5, 6, -7),
+            transmute(vec_insert(42, a, 3))
+        );
+
+        let a: vector_float = transmute(f32x4::new(0.5, 1.5, 2.5, 3.5));
+        assert_eq!(vec_extract(a, 2), 2.5);
+        assert_eq!(
+            f32x4::new(0.5, 1.5, 2.5, -1.0),
+            transmute(vec_insert(-1.0, a, -1))
+        );
+    }
 }
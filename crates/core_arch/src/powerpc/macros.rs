@@ -0,0 +1,169 @@
+//! Utility macros shared by the AltiVec and VSX intrinsics.
+
+#[allow(unused)]
+macro_rules! s_t_l {
+    (i32x4) => {
+        vector_signed_int
+    };
+    (i16x8) => {
+        vector_signed_short
+    };
+    (i8x16) => {
+        vector_signed_char
+    };
+
+    (u32x4) => {
+        vector_unsigned_int
+    };
+    (u16x8) => {
+        vector_unsigned_short
+    };
+    (u8x16) => {
+        vector_unsigned_char
+    };
+
+    (f32x4) => {
+        vector_float
+    };
+
+    (i64x2) => {
+        vector_signed_long
+    };
+    (u64x2) => {
+        vector_unsigned_long
+    };
+    (f64x2) => {
+        vector_double
+    };
+}
+
+#[allow(unused)]
+macro_rules! t_t_l {
+    (i32) => {
+        vector_signed_int
+    };
+    (i16) => {
+        vector_signed_short
+    };
+    (i8) => {
+        vector_signed_char
+    };
+
+    (u32) => {
+        vector_unsigned_int
+    };
+    (u16) => {
+        vector_unsigned_short
+    };
+    (u8) => {
+        vector_unsigned_char
+    };
+
+    (f32) => {
+        vector_float
+    };
+
+    (i64) => {
+        vector_signed_long
+    };
+    (u64) => {
+        vector_unsigned_long
+    };
+    (f64) => {
+        vector_double
+    };
+}
+
+#[allow(unused)]
+macro_rules! test_impl {
+    ($fun:ident ($($v:ident : $ty:ty),*) -> $r:ty [$call:ident, $instr:ident]) => {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        #[cfg_attr(test, assert_instr($instr))]
+        pub unsafe fn $fun ($($v : $ty),*) -> $r {
+            $call ($($v),*)
+        }
+    };
+    ($fun:ident ($($v:ident : $ty:ty),*) -> $r:ty [$call:ident, $instr_altivec:ident / $instr_vsx:ident]) => {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        #[cfg_attr(all(test, not(target_feature="vsx")), assert_instr($instr_altivec))]
+        #[cfg_attr(all(test, target_feature="vsx"), assert_instr($instr_vsx))]
+        pub unsafe fn $fun ($($v : $ty),*) -> $r {
+            $call ($($v),*)
+        }
+    };
+    ($fun:ident ($($v:ident : $ty:ty),*) -> $r:ty [$call:ident, $instr:ident, $feature:literal]) => {
+        #[inline]
+        #[target_feature(enable = $feature)]
+        #[cfg_attr(test, assert_instr($instr))]
+        pub unsafe fn $fun ($($v : $ty),*) -> $r {
+            $call ($($v),*)
+        }
+    };
+
+}
+
+#[allow(unused)]
+macro_rules! splats {
+    ($name:ident, $v:ident, $r:ident) => {
+        #[inline]
+        #[target_feature(enable = "altivec")]
+        unsafe fn $name(v: $v) -> s_t_l!($r) {
+            transmute($r::splat(v))
+        }
+    };
+}
+
+// Implements a generic operation with a newer target feature than the
+// AltiVec trait methods, e.g. for doubleword elements.
+#[allow(unused)]
+macro_rules! impl_vec_trait_with {
+    ($feature:literal [$Trait:ident $m:ident] $fun:ident ($a:ty) -> $r:ty) => {
+        impl $Trait for $a {
+            type Result = $r;
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn $m(self) -> Self::Result {
+                transmute($fun(transmute(self)))
+            }
+        }
+    };
+    ($feature:literal [$Trait:ident $m:ident] $fun:ident ($a:ty, $b:ty) -> $r:ty) => {
+        impl $Trait<$b> for $a {
+            type Result = $r;
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn $m(self, b: $b) -> Self::Result {
+                transmute($fun(transmute(self), transmute(b)))
+            }
+        }
+    };
+}
+
+// The element index is taken modulo the number of elements.
+#[allow(unused)]
+macro_rules! impl_vec_extract_insert {
+    ($ty:ty, $scalar:ty, $lanes:expr) => {
+        impl VectorExtract for $ty {
+            type Scalar = $scalar;
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            unsafe fn vec_extract(self, idx: i32) -> Self::Scalar {
+                let v: [$scalar; $lanes] = transmute(self);
+                v[idx as usize % $lanes]
+            }
+        }
+
+        impl VectorInsert for $ty {
+            type Scalar = $scalar;
+            #[inline]
+            #[target_feature(enable = "altivec")]
+            unsafe fn vec_insert(self, a: Self::Scalar, idx: i32) -> Self {
+                let mut v: [$scalar; $lanes] = transmute(self);
+                v[idx as usize % $lanes] = a;
+                transmute(v)
+            }
+        }
+    };
+}
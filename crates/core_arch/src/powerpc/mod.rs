@@ -1,5 +1,8 @@
 //! PowerPC intrinsics
 
+#[macro_use]
+mod macros;
+
 #[cfg(target_feature = "altivec")]
 mod altivec;
 #[cfg(target_feature = "altivec")]
@@ -1,5 +1,9 @@
 //! PowerPC Vector Scalar eXtensions (VSX) intrinsics.
 //!
+//! This module also contains the POWER8 (`power8-vector`, `power8-crypto`)
+//! and POWER9 (`power9-vector`) vector intrinsics. Those that take the
+//! AltiVec vector types are only available with `altivec`.
+//!
 //! The references are: [POWER ISA v2.07B (for POWER8 & POWER8 with NVIDIA
 //! NVlink)] and [POWER ISA v3.0B (for POWER9)].
 //!
@@ -10,6 +14,15 @@
 
 use crate::core_arch::simd_llvm::*;
 
+#[cfg(target_feature = "altivec")]
+use crate::{core_arch::simd::*, mem::transmute, ptr};
+
+#[cfg(target_feature = "altivec")]
+use super::altivec::{
+    vector_float, vector_signed_char, vector_signed_int, vector_signed_short, vector_unsigned_char,
+    vector_unsigned_int, vector_unsigned_short,
+};
+
 #[cfg(test)]
 use stdarch_test::assert_instr;
 
@@ -32,6 +45,66 @@ types! {
     // pub struct vector_unsigned___int128 = i128x1;
 }
 
+#[cfg(target_feature = "altivec")]
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.ppc.vsx.xvmaxdp"]
+    fn xvmaxdp(a: vector_double, b: vector_double) -> vector_double;
+    #[link_name = "llvm.ppc.vsx.xvmindp"]
+    fn xvmindp(a: vector_double, b: vector_double) -> vector_double;
+
+    #[link_name = "llvm.ppc.altivec.vmaxsd"]
+    fn vmaxsd(a: vector_signed_long, b: vector_signed_long) -> vector_signed_long;
+    #[link_name = "llvm.ppc.altivec.vmaxud"]
+    fn vmaxud(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.vminsd"]
+    fn vminsd(a: vector_signed_long, b: vector_signed_long) -> vector_signed_long;
+    #[link_name = "llvm.ppc.altivec.vminud"]
+    fn vminud(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+
+    #[link_name = "llvm.ctpop.v16i8"]
+    fn vpopcntb(a: vector_unsigned_char) -> vector_unsigned_char;
+    #[link_name = "llvm.ctpop.v8i16"]
+    fn vpopcnth(a: vector_unsigned_short) -> vector_unsigned_short;
+    #[link_name = "llvm.ctpop.v4i32"]
+    fn vpopcntw(a: vector_unsigned_int) -> vector_unsigned_int;
+    #[link_name = "llvm.ctpop.v2i64"]
+    fn vpopcntd(a: vector_unsigned_long) -> vector_unsigned_long;
+
+    #[link_name = "llvm.ctlz.v16i8"]
+    fn vclzb(a: vector_unsigned_char, nonzero: bool) -> vector_unsigned_char;
+    #[link_name = "llvm.ctlz.v8i16"]
+    fn vclzh(a: vector_unsigned_short, nonzero: bool) -> vector_unsigned_short;
+    #[link_name = "llvm.ctlz.v4i32"]
+    fn vclzw(a: vector_unsigned_int, nonzero: bool) -> vector_unsigned_int;
+    #[link_name = "llvm.ctlz.v2i64"]
+    fn vclzd(a: vector_unsigned_long, nonzero: bool) -> vector_unsigned_long;
+
+    #[link_name = "llvm.ppc.altivec.crypto.vcipher"]
+    fn vcipher(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vcipherlast"]
+    fn vcipherlast(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipher"]
+    fn vncipher(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vncipherlast"]
+    fn vncipherlast(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vsbox"]
+    fn vsbox(a: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vpmsumd"]
+    fn vpmsumd(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long;
+    #[link_name = "llvm.ppc.altivec.crypto.vshasigmaw"]
+    fn vshasigmaw(a: vector_unsigned_int, st: i32, six: i32) -> vector_unsigned_int;
+    #[link_name = "llvm.ppc.altivec.crypto.vshasigmad"]
+    fn vshasigmad(a: vector_unsigned_long, st: i32, six: i32) -> vector_unsigned_long;
+
+    #[cfg(target_arch = "powerpc64")]
+    #[link_name = "llvm.ppc.vsx.lxvl"]
+    fn lxvl(p: *const i8, l: usize) -> vector_signed_int;
+    #[cfg(target_arch = "powerpc64")]
+    #[link_name = "llvm.ppc.vsx.stxvl"]
+    fn stxvl(v: vector_signed_int, p: *mut i8, l: usize);
+}
+
 mod sealed {
     use super::*;
     use crate::core_arch::simd::*;
@@ -70,6 +143,369 @@ mod sealed {
     vec_xxpermdi! { vector_signed_long }
     vec_xxpermdi! { vector_bool_long }
     vec_xxpermdi! { vector_double }
+
+    // The VSX, POWER8 and POWER9 operations below take or implement the
+    // AltiVec vector types and traits, which are only available with
+    // `altivec`.
+    #[cfg(target_feature = "altivec")]
+    pub use self::ext::*;
+
+    #[cfg(target_feature = "altivec")]
+    mod ext {
+        use super::*;
+        use crate::core_arch::powerpc::altivec::sealed::{
+            VectorAbs, VectorAdd, VectorCmpEq, VectorCmpGt, VectorExtract, VectorInsert, VectorMax,
+            VectorMin, VectorMul, VectorSplats, VectorSub,
+        };
+
+        // Unaligned loads and stores. POWER9 has indexed forms that handle
+        // any element type; before that, lxvd2x/stxvd2x leave the doublewords
+        // swapped on little endian and are followed by an xxswapd.
+        pub trait VectorXl {
+            type Result;
+            unsafe fn vec_xl(self, off: isize) -> Self::Result;
+        }
+
+        pub trait VectorXst {
+            type Target;
+            unsafe fn vec_xst(self, off: isize, p: Self::Target);
+        }
+
+        macro_rules! impl_vec_xl_xst {
+            ($xl:ident, $xst:ident, $ty:ident [$be_l:ident, $be_st:ident]) => {
+                #[inline]
+                #[target_feature(enable = "vsx")]
+                #[cfg_attr(all(test, target_feature = "power9-vector"), assert_instr(lxvx))]
+                #[cfg_attr(
+                    all(test, not(target_feature = "power9-vector"), target_endian = "little"),
+                    assert_instr(lxvd2x)
+                )]
+                #[cfg_attr(
+                    all(test, not(target_feature = "power9-vector"), target_endian = "big"),
+                    assert_instr($be_l)
+                )]
+                pub unsafe fn $xl(off: isize, p: *const $ty) -> t_t_l!($ty) {
+                    let addr = (p as *const u8).offset(off);
+                    ptr::read_unaligned(addr as *const t_t_l!($ty))
+                }
+
+                #[inline]
+                #[target_feature(enable = "vsx")]
+                #[cfg_attr(all(test, target_feature = "power9-vector"), assert_instr(stxvx))]
+                #[cfg_attr(
+                    all(test, not(target_feature = "power9-vector"), target_endian = "little"),
+                    assert_instr(stxvd2x)
+                )]
+                #[cfg_attr(
+                    all(test, not(target_feature = "power9-vector"), target_endian = "big"),
+                    assert_instr($be_st)
+                )]
+                pub unsafe fn $xst(a: t_t_l!($ty), off: isize, p: *mut $ty) {
+                    let addr = (p as *mut u8).offset(off);
+                    ptr::write_unaligned(addr as *mut t_t_l!($ty), a)
+                }
+
+                impl VectorXl for *const $ty {
+                    type Result = t_t_l!($ty);
+                    #[inline]
+                    #[target_feature(enable = "vsx")]
+                    unsafe fn vec_xl(self, off: isize) -> Self::Result {
+                        $xl(off, self)
+                    }
+                }
+
+                impl VectorXst for t_t_l!($ty) {
+                    type Target = *mut $ty;
+                    #[inline]
+                    #[target_feature(enable = "vsx")]
+                    unsafe fn vec_xst(self, off: isize, p: Self::Target) {
+                        $xst(self, off, p)
+                    }
+                }
+            };
+        }
+
+        impl_vec_xl_xst! { vec_xl_u8, vec_xst_u8, u8 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_i8, vec_xst_i8, i8 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_u16, vec_xst_u16, u16 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_i16, vec_xst_i16, i16 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_u32, vec_xst_u32, u32 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_i32, vec_xst_i32, i32 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_f32, vec_xst_f32, f32 [lxvw4x, stxvw4x] }
+        impl_vec_xl_xst! { vec_xl_u64, vec_xst_u64, u64 [lxvd2x, stxvd2x] }
+        impl_vec_xl_xst! { vec_xl_i64, vec_xst_i64, i64 [lxvd2x, stxvd2x] }
+        impl_vec_xl_xst! { vec_xl_f64, vec_xst_f64, f64 [lxvd2x, stxvd2x] }
+
+        test_impl! { vec_xvadddp(a: vector_double, b: vector_double) -> vector_double [simd_add, xvadddp, "vsx"] }
+        test_impl! { vec_xvsubdp(a: vector_double, b: vector_double) -> vector_double [simd_sub, xvsubdp, "vsx"] }
+        test_impl! { vec_xvmaxdp(a: vector_double, b: vector_double) -> vector_double [xvmaxdp, xvmaxdp, "vsx"] }
+        test_impl! { vec_xvmindp(a: vector_double, b: vector_double) -> vector_double [xvmindp, xvmindp, "vsx"] }
+        test_impl! { vec_xvcmpeqdp(a: vector_double, b: vector_double) -> vector_bool_long [simd_eq, xvcmpeqdp, "vsx"] }
+        test_impl! { vec_xvcmpgtdp(a: vector_double, b: vector_double) -> vector_bool_long [simd_gt, xvcmpgtdp, "vsx"] }
+
+        impl_vec_trait_with! { "vsx" [VectorAdd vec_add] vec_xvadddp (vector_double, vector_double) -> vector_double }
+        impl_vec_trait_with! { "vsx" [VectorSub vec_sub] vec_xvsubdp (vector_double, vector_double) -> vector_double }
+        impl_vec_trait_with! { "vsx" [VectorMax vec_max] vec_xvmaxdp (vector_double, vector_double) -> vector_double }
+        impl_vec_trait_with! { "vsx" [VectorMin vec_min] vec_xvmindp (vector_double, vector_double) -> vector_double }
+        impl_vec_trait_with! { "vsx" [VectorCmpEq vec_cmpeq] vec_xvcmpeqdp (vector_double, vector_double) -> vector_bool_long }
+        impl_vec_trait_with! { "vsx" [VectorCmpGt vec_cmpgt] vec_xvcmpgtdp (vector_double, vector_double) -> vector_bool_long }
+
+        test_impl! { vec_xvabsdp(a: vector_double) -> vector_double [simd_fabs, xvabsdp, "vsx"] }
+
+        impl VectorAbs for vector_double {
+            #[inline]
+            #[target_feature(enable = "vsx")]
+            unsafe fn vec_abs(self) -> Self {
+                vec_xvabsdp(self)
+            }
+        }
+
+        test_impl! { vec_vaddudm(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long [simd_add, vaddudm, "power8-vector"] }
+        test_impl! { vec_vsubudm(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long [simd_sub, vsubudm, "power8-vector"] }
+        test_impl! { vec_vmaxsd(a: vector_signed_long, b: vector_signed_long) -> vector_signed_long [vmaxsd, vmaxsd, "power8-vector"] }
+        test_impl! { vec_vmaxud(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long [vmaxud, vmaxud, "power8-vector"] }
+        test_impl! { vec_vminsd(a: vector_signed_long, b: vector_signed_long) -> vector_signed_long [vminsd, vminsd, "power8-vector"] }
+        test_impl! { vec_vminud(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_unsigned_long [vminud, vminud, "power8-vector"] }
+        test_impl! { vec_vcmpequd(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_bool_long [simd_eq, vcmpequd, "power8-vector"] }
+        test_impl! { vec_vcmpgtsd(a: vector_signed_long, b: vector_signed_long) -> vector_bool_long [simd_gt, vcmpgtsd, "power8-vector"] }
+        test_impl! { vec_vcmpgtud(a: vector_unsigned_long, b: vector_unsigned_long) -> vector_bool_long [simd_gt, vcmpgtud, "power8-vector"] }
+
+        macro_rules! impl_vec_trait_long {
+            ([$Trait:ident $m:ident] ($u:ident, $s:ident) -> ($ur:ty, $sr:ty)) => {
+                impl_vec_trait_with! { "power8-vector" [$Trait $m] $u (vector_unsigned_long, vector_unsigned_long) -> $ur }
+                impl_vec_trait_with! { "power8-vector" [$Trait $m] $s (vector_signed_long, vector_signed_long) -> $sr }
+            };
+        }
+
+        impl_vec_trait_long! { [VectorAdd vec_add] (vec_vaddudm, vec_vaddudm) -> (vector_unsigned_long, vector_signed_long) }
+        impl_vec_trait_long! { [VectorSub vec_sub] (vec_vsubudm, vec_vsubudm) -> (vector_unsigned_long, vector_signed_long) }
+        impl_vec_trait_long! { [VectorMax vec_max] (vec_vmaxud, vec_vmaxsd) -> (vector_unsigned_long, vector_signed_long) }
+        impl_vec_trait_long! { [VectorMin vec_min] (vec_vminud, vec_vminsd) -> (vector_unsigned_long, vector_signed_long) }
+        impl_vec_trait_long! { [VectorCmpEq vec_cmpeq] (vec_vcmpequd, vec_vcmpequd) -> (vector_bool_long, vector_bool_long) }
+        impl_vec_trait_long! { [VectorCmpGt vec_cmpgt] (vec_vcmpgtud, vec_vcmpgtsd) -> (vector_bool_long, vector_bool_long) }
+
+        splats! { splats_u64, u64, u64x2 }
+        splats! { splats_i64, i64, i64x2 }
+        splats! { splats_f64, f64, f64x2 }
+
+        impl_vec_trait_with! { "altivec" [VectorSplats vec_splats] splats_u64 (u64) -> vector_unsigned_long }
+        impl_vec_trait_with! { "altivec" [VectorSplats vec_splats] splats_i64 (i64) -> vector_signed_long }
+        impl_vec_trait_with! { "altivec" [VectorSplats vec_splats] splats_f64 (f64) -> vector_double }
+
+        pub trait VectorDiv {
+            unsafe fn vec_div(self, b: Self) -> Self;
+        }
+
+        pub trait VectorSqrt {
+            unsafe fn vec_sqrt(self) -> Self;
+        }
+
+        test_impl! { vec_xvmuldp(a: vector_double, b: vector_double) -> vector_double [simd_mul, xvmuldp, "vsx"] }
+        test_impl! { vec_xvdivsp(a: vector_float, b: vector_float) -> vector_float [simd_div, xvdivsp, "vsx"] }
+        test_impl! { vec_xvdivdp(a: vector_double, b: vector_double) -> vector_double [simd_div, xvdivdp, "vsx"] }
+        test_impl! { vec_xvsqrtsp(a: vector_float) -> vector_float [simd_fsqrt, xvsqrtsp, "vsx"] }
+        test_impl! { vec_xvsqrtdp(a: vector_double) -> vector_double [simd_fsqrt, xvsqrtdp, "vsx"] }
+
+        macro_rules! impl_vec_float_op {
+            ($feature:literal [$Trait:ident $m:ident] $fun:ident ($ty:ty)) => {
+                impl $Trait for $ty {
+                    #[inline]
+                    #[target_feature(enable = $feature)]
+                    unsafe fn $m(self) -> Self {
+                        $fun(self)
+                    }
+                }
+            };
+            ($feature:literal [$Trait:ident $m:ident] $fun:ident ($ty:ty, $ty2:ty)) => {
+                impl $Trait for $ty {
+                    #[inline]
+                    #[target_feature(enable = $feature)]
+                    unsafe fn $m(self, b: $ty2) -> Self {
+                        $fun(self, b)
+                    }
+                }
+            };
+        }
+
+        impl_vec_float_op! { "vsx" [VectorMul vec_mul] vec_xvmuldp (vector_double, vector_double) }
+        impl_vec_float_op! { "vsx" [VectorDiv vec_div] vec_xvdivsp (vector_float, vector_float) }
+        impl_vec_float_op! { "vsx" [VectorDiv vec_div] vec_xvdivdp (vector_double, vector_double) }
+        impl_vec_float_op! { "vsx" [VectorSqrt vec_sqrt] vec_xvsqrtsp (vector_float) }
+        impl_vec_float_op! { "vsx" [VectorSqrt vec_sqrt] vec_xvsqrtdp (vector_double) }
+
+        test_impl! { vec_vpopcntb(a: vector_unsigned_char) -> vector_unsigned_char [vpopcntb, vpopcntb, "power8-vector"] }
+        test_impl! { vec_vpopcnth(a: vector_unsigned_short) -> vector_unsigned_short [vpopcnth, vpopcnth, "power8-vector"] }
+        test_impl! { vec_vpopcntw(a: vector_unsigned_int) -> vector_unsigned_int [vpopcntw, vpopcntw, "power8-vector"] }
+        test_impl! { vec_vpopcntd(a: vector_unsigned_long) -> vector_unsigned_long [vpopcntd, vpopcntd, "power8-vector"] }
+
+        pub trait VectorPopcnt {
+            type Result;
+            unsafe fn vec_popcnt(self) -> Self::Result;
+        }
+
+        macro_rules! impl_vec_popcnt {
+            ($fun:ident ($($ty:ty),*) -> $r:ty) => {
+                $(
+                    impl_vec_trait_with! { "power8-vector" [VectorPopcnt vec_popcnt] $fun ($ty) -> $r }
+                )*
+            };
+        }
+
+        impl_vec_popcnt! { vec_vpopcntb (vector_unsigned_char, vector_signed_char) -> vector_unsigned_char }
+        impl_vec_popcnt! { vec_vpopcnth (vector_unsigned_short, vector_signed_short) -> vector_unsigned_short }
+        impl_vec_popcnt! { vec_vpopcntw (vector_unsigned_int, vector_signed_int) -> vector_unsigned_int }
+        impl_vec_popcnt! { vec_vpopcntd (vector_unsigned_long, vector_signed_long) -> vector_unsigned_long }
+
+        macro_rules! cntlz {
+            ($fun:ident ($ty:ident) [$call:ident, $instr:ident]) => {
+                #[inline]
+                #[target_feature(enable = "power8-vector")]
+                #[cfg_attr(test, assert_instr($instr))]
+                pub unsafe fn $fun(a: $ty) -> $ty {
+                    $call(a, false)
+                }
+            };
+        }
+
+        cntlz! { vec_vclzb (vector_unsigned_char) [vclzb, vclzb] }
+        cntlz! { vec_vclzh (vector_unsigned_short) [vclzh, vclzh] }
+        cntlz! { vec_vclzw (vector_unsigned_int) [vclzw, vclzw] }
+        cntlz! { vec_vclzd (vector_unsigned_long) [vclzd, vclzd] }
+
+        pub trait VectorCntlz {
+            unsafe fn vec_cntlz(self) -> Self;
+        }
+
+        macro_rules! impl_vec_cntlz {
+            ($fun:ident ($($ty:ty),*)) => {
+                $(
+                    impl VectorCntlz for $ty {
+                        #[inline]
+                        #[target_feature(enable = "power8-vector")]
+                        unsafe fn vec_cntlz(self) -> Self {
+                            transmute($fun(transmute(self)))
+                        }
+                    }
+                )*
+            };
+        }
+
+        impl_vec_cntlz! { vec_vclzb (vector_unsigned_char, vector_signed_char) }
+        impl_vec_cntlz! { vec_vclzh (vector_unsigned_short, vector_signed_short) }
+        impl_vec_cntlz! { vec_vclzw (vector_unsigned_int, vector_signed_int) }
+        impl_vec_cntlz! { vec_vclzd (vector_unsigned_long, vector_signed_long) }
+
+        pub trait VectorShasigma {
+            unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self;
+        }
+
+        impl VectorShasigma for vector_unsigned_int {
+            #[inline]
+            #[target_feature(enable = "power8-crypto")]
+            unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self {
+                vec_vshasigmaw::<ST, SIX>(self)
+            }
+        }
+
+        impl VectorShasigma for vector_unsigned_long {
+            #[inline]
+            #[target_feature(enable = "power8-crypto")]
+            unsafe fn vec_shasigma_be<const ST: i32, const SIX: i32>(self) -> Self {
+                vec_vshasigmad::<ST, SIX>(self)
+            }
+        }
+
+        #[inline]
+        #[target_feature(enable = "power8-crypto")]
+        #[cfg_attr(test, assert_instr(vshasigmaw, ST = 1, SIX = 0xf))]
+        pub unsafe fn vec_vshasigmaw<const ST: i32, const SIX: i32>(
+            a: vector_unsigned_int,
+        ) -> vector_unsigned_int {
+            static_assert_imm1!(ST);
+            static_assert_imm4!(SIX);
+            vshasigmaw(a, ST, SIX)
+        }
+
+        #[inline]
+        #[target_feature(enable = "power8-crypto")]
+        #[cfg_attr(test, assert_instr(vshasigmad, ST = 1, SIX = 0xf))]
+        pub unsafe fn vec_vshasigmad<const ST: i32, const SIX: i32>(
+            a: vector_unsigned_long,
+        ) -> vector_unsigned_long {
+            static_assert_imm1!(ST);
+            static_assert_imm4!(SIX);
+            vshasigmad(a, ST, SIX)
+        }
+
+        impl_vec_extract_insert! { vector_unsigned_long, u64, 2 }
+        impl_vec_extract_insert! { vector_signed_long, i64, 2 }
+        impl_vec_extract_insert! { vector_double, f64, 2 }
+
+        // lxvl and stxvl take the number of bytes in the most significant
+        // byte of the length operand.
+        #[cfg(target_arch = "powerpc64")]
+        pub trait VectorXlLen {
+            type Result;
+            unsafe fn vec_xl_len(self, len: usize) -> Self::Result;
+        }
+
+        #[cfg(target_arch = "powerpc64")]
+        pub trait VectorXstLen {
+            type Target;
+            unsafe fn vec_xst_len(self, p: Self::Target, len: usize);
+        }
+
+        macro_rules! impl_vec_xl_len {
+            ($xl:ident, $xst:ident, $ty:ident) => {
+                #[cfg(target_arch = "powerpc64")]
+                #[inline]
+                #[target_feature(enable = "power9-vector")]
+                #[cfg_attr(test, assert_instr(lxvl))]
+                pub unsafe fn $xl(p: *const $ty, len: usize) -> t_t_l!($ty) {
+                    transmute(lxvl(p as *const i8, len << 56))
+                }
+
+                #[cfg(target_arch = "powerpc64")]
+                #[inline]
+                #[target_feature(enable = "power9-vector")]
+                #[cfg_attr(test, assert_instr(stxvl))]
+                pub unsafe fn $xst(a: t_t_l!($ty), p: *mut $ty, len: usize) {
+                    stxvl(transmute(a), p as *mut i8, len << 56)
+                }
+
+                #[cfg(target_arch = "powerpc64")]
+                impl VectorXlLen for *const $ty {
+                    type Result = t_t_l!($ty);
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_xl_len(self, len: usize) -> Self::Result {
+                        $xl(self, len)
+                    }
+                }
+
+                #[cfg(target_arch = "powerpc64")]
+                impl VectorXstLen for t_t_l!($ty) {
+                    type Target = *mut $ty;
+                    #[inline]
+                    #[target_feature(enable = "power9-vector")]
+                    unsafe fn vec_xst_len(self, p: Self::Target, len: usize) {
+                        $xst(self, p, len)
+                    }
+                }
+            };
+        }
+
+        impl_vec_xl_len! { vec_xl_len_u8, vec_xst_len_u8, u8 }
+        impl_vec_xl_len! { vec_xl_len_i8, vec_xst_len_i8, i8 }
+        impl_vec_xl_len! { vec_xl_len_u16, vec_xst_len_u16, u16 }
+        impl_vec_xl_len! { vec_xl_len_i16, vec_xst_len_i16, i16 }
+        impl_vec_xl_len! { vec_xl_len_u32, vec_xst_len_u32, u32 }
+        impl_vec_xl_len! { vec_xl_len_i32, vec_xst_len_i32, i32 }
+        impl_vec_xl_len! { vec_xl_len_f32, vec_xst_len_f32, f32 }
+        impl_vec_xl_len! { vec_xl_len_u64, vec_xst_len_u64, u64 }
+        impl_vec_xl_len! { vec_xl_len_i64, vec_xst_len_i64, i64 }
+        impl_vec_xl_len! { vec_xl_len_f64, vec_xst_len_f64, f64 }
+    }
 }
 
 /// Vector permute.
@@ -84,6 +520,194 @@ where
     a.vec_xxpermdi(b, DM as u8)
 }
 
+/// Vector Load Unaligned.
+///
+/// Loads 16 bytes from `p + off`, where `off` is in bytes and the address
+/// does not need to be 16-byte aligned.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "vsx")]
+pub unsafe fn vec_xl<T>(off: isize, p: T) -> <T as sealed::VectorXl>::Result
+where
+    T: sealed::VectorXl,
+{
+    p.vec_xl(off)
+}
+
+/// Vector Store Unaligned.
+///
+/// Stores 16 bytes to `p + off`, where `off` is in bytes and the address does
+/// not need to be 16-byte aligned.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "vsx")]
+pub unsafe fn vec_xst<T>(a: T, off: isize, p: <T as sealed::VectorXst>::Target)
+where
+    T: sealed::VectorXst,
+{
+    a.vec_xst(off, p)
+}
+
+/// Vector Divide.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "vsx")]
+pub unsafe fn vec_div<T>(a: T, b: T) -> T
+where
+    T: sealed::VectorDiv,
+{
+    a.vec_div(b)
+}
+
+/// Vector Square Root.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "vsx")]
+pub unsafe fn vec_sqrt<T>(a: T) -> T
+where
+    T: sealed::VectorSqrt,
+{
+    a.vec_sqrt()
+}
+
+/// Vector Population Count.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-vector")]
+pub unsafe fn vec_popcnt<T>(a: T) -> <T as sealed::VectorPopcnt>::Result
+where
+    T: sealed::VectorPopcnt,
+{
+    a.vec_popcnt()
+}
+
+/// Vector Count Leading Zeros.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-vector")]
+pub unsafe fn vec_cntlz<T>(a: T) -> T
+where
+    T: sealed::VectorCntlz,
+{
+    a.vec_cntlz()
+}
+
+/// Vector AES Cipher (big-endian state).
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vcipher))]
+pub unsafe fn vec_cipher_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vcipher(transmute(a), transmute(b)))
+}
+
+/// Vector AES Cipher Last (big-endian state).
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vcipherlast))]
+pub unsafe fn vec_cipherlast_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vcipherlast(transmute(a), transmute(b)))
+}
+
+/// Vector AES Inverse Cipher (big-endian state).
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vncipher))]
+pub unsafe fn vec_ncipher_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vncipher(transmute(a), transmute(b)))
+}
+
+/// Vector AES Inverse Cipher Last (big-endian state).
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vncipherlast))]
+pub unsafe fn vec_ncipherlast_be(
+    a: vector_unsigned_char,
+    b: vector_unsigned_char,
+) -> vector_unsigned_char {
+    transmute(vncipherlast(transmute(a), transmute(b)))
+}
+
+/// Vector AES S-Box (big-endian state).
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vsbox))]
+pub unsafe fn vec_sbox_be(a: vector_unsigned_char) -> vector_unsigned_char {
+    transmute(vsbox(transmute(a)))
+}
+
+/// Vector Polynomial Multiply-Sum Doubleword.
+///
+/// Returns the carry-less products of the corresponding doublewords of `a`
+/// and `b`, xor-ed together. The 128-bit result spans the whole vector.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+#[cfg_attr(test, assert_instr(vpmsumd))]
+pub unsafe fn vec_vpmsumd(
+    a: vector_unsigned_long,
+    b: vector_unsigned_long,
+) -> vector_unsigned_long {
+    vpmsumd(a, b)
+}
+
+/// Vector SHA-2 Sigma (big-endian state).
+///
+/// Computes the SHA-256 (for word elements) or SHA-512 (for doubleword
+/// elements) sigma function of each element: the lower-case sigma functions
+/// if `ST` is 0 and the upper-case ones otherwise. The bits of `SIX` select
+/// between sigma0 and sigma1 for each element.
+#[cfg(target_feature = "altivec")]
+#[inline]
+#[target_feature(enable = "power8-crypto")]
+pub unsafe fn vec_shasigma_be<T, const ST: i32, const SIX: i32>(a: T) -> T
+where
+    T: sealed::VectorShasigma,
+{
+    a.vec_shasigma_be::<ST, SIX>()
+}
+
+/// Vector Load with Length.
+///
+/// Loads the first `len` bytes at `p` and sets the remaining bytes of the
+/// result to zero. Lengths greater than 16 load the whole vector.
+#[cfg(all(target_feature = "altivec", target_arch = "powerpc64"))]
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_xl_len<T>(p: T, len: usize) -> <T as sealed::VectorXlLen>::Result
+where
+    T: sealed::VectorXlLen,
+{
+    p.vec_xl_len(len)
+}
+
+/// Vector Store with Length.
+///
+/// Stores the first `len` bytes of `a` to `p`. Lengths greater than 16 store
+/// the whole vector.
+#[cfg(all(target_feature = "altivec", target_arch = "powerpc64"))]
+#[inline]
+#[target_feature(enable = "power9-vector")]
+pub unsafe fn vec_xst_len<T>(a: T, p: <T as sealed::VectorXstLen>::Target, len: usize)
+where
+    T: sealed::VectorXstLen,
+{
+    a.vec_xst_len(p, len)
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_arch = "powerpc")]
@@ -115,4 +739,194 @@ mod tests {
     test_vec_xxpermdi! {test_vec_xxpermdi_i64x2, i64x2, vector_signed_long, [0], [-1], [2], [-3]}
     test_vec_xxpermdi! {test_vec_xxpermdi_m64x2, m64x2, vector_bool_long, [false], [true], [false], [true]}
     test_vec_xxpermdi! {test_vec_xxpermdi_f64x2, f64x2, vector_double, [0.0], [1.0], [2.0], [3.0]}
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "vsx")]
+    unsafe fn test_vec_xl_xst() {
+        let mut buf = [0u8; 48];
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        let v: u8x16 = mem::transmute(vec_xl(3, buf.as_ptr()));
+        assert_eq!(
+            v,
+            u8x16::new(3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18)
+        );
+
+        let v: vector_unsigned_char = mem::transmute(u8x16::splat(0xff));
+        vec_xst(v, 21, buf.as_mut_ptr());
+        assert_eq!(buf[20], 20);
+        assert!(buf[21..37].iter().all(|&b| b == 0xff));
+        assert_eq!(buf[37], 37);
+
+        let d = [1.5f64, -2.5, 3.5];
+        let v: f64x2 = mem::transmute(vec_xl(8, d.as_ptr()));
+        assert_eq!(v, f64x2::new(-2.5, 3.5));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "vsx")]
+    unsafe fn test_vec_double_arith() {
+        let a: vector_double = mem::transmute(f64x2::new(1.0, -4.0));
+        let b: vector_double = mem::transmute(f64x2::new(4.0, 2.0));
+
+        assert_eq!(f64x2::new(5.0, -2.0), mem::transmute(vec_add(a, b)));
+        assert_eq!(f64x2::new(-3.0, -6.0), mem::transmute(vec_sub(a, b)));
+        assert_eq!(f64x2::new(4.0, -8.0), mem::transmute(vec_mul(a, b)));
+        assert_eq!(f64x2::new(0.25, -2.0), mem::transmute(vec_div(a, b)));
+        assert_eq!(
+            f64x2::new(2.0, 1.4142135623730951),
+            mem::transmute(vec_sqrt(b))
+        );
+        assert_eq!(f64x2::new(1.0, 4.0), mem::transmute(vec_abs(a)));
+        assert_eq!(f64x2::new(4.0, 2.0), mem::transmute(vec_max(a, b)));
+        assert_eq!(f64x2::new(1.0, -4.0), mem::transmute(vec_min(a, b)));
+        assert_eq!(m64x2::new(false, false), mem::transmute(vec_cmpeq(a, b)));
+        assert_eq!(m64x2::new(true, true), mem::transmute(vec_cmpeq(a, a)));
+        assert_eq!(m64x2::new(true, false), mem::transmute(vec_cmpgt(b, a)));
+        assert_eq!(f64x2::splat(0.5), mem::transmute(vec_splats(0.5f64)));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "vsx")]
+    unsafe fn test_vec_float_div_sqrt() {
+        let a: vector_float = mem::transmute(f32x4::new(1.0, -4.0, 9.0, 0.5));
+        let b: vector_float = mem::transmute(f32x4::new(4.0, 2.0, 3.0, 0.25));
+
+        assert_eq!(
+            f32x4::new(4.0, -8.0, 27.0, 0.125),
+            mem::transmute(vec_mul(a, b))
+        );
+        assert_eq!(
+            f32x4::new(0.25, -2.0, 3.0, 2.0),
+            mem::transmute(vec_div(a, b))
+        );
+        assert_eq!(
+            f32x4::new(2.0, 1.4142135, 1.7320508, 0.5),
+            mem::transmute(vec_sqrt(b))
+        );
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "power8-vector")]
+    unsafe fn test_vec_long_arith() {
+        let a: vector_signed_long = mem::transmute(i64x2::new(-1, i64::MAX));
+        let b: vector_signed_long = mem::transmute(i64x2::new(1, 1));
+
+        assert_eq!(i64x2::new(0, i64::MIN), mem::transmute(vec_add(a, b)));
+        assert_eq!(i64x2::new(-2, i64::MAX - 1), mem::transmute(vec_sub(a, b)));
+        assert_eq!(i64x2::new(1, i64::MAX), mem::transmute(vec_max(a, b)));
+        assert_eq!(i64x2::new(-1, 1), mem::transmute(vec_min(a, b)));
+        assert_eq!(m64x2::new(false, true), mem::transmute(vec_cmpgt(a, b)));
+        assert_eq!(m64x2::new(true, true), mem::transmute(vec_cmpeq(a, a)));
+
+        let a: vector_unsigned_long = mem::transmute(u64x2::new(u64::MAX, 1));
+        let b: vector_unsigned_long = mem::transmute(u64x2::new(1, 1));
+        assert_eq!(u64x2::new(1, 1), mem::transmute(vec_min(a, b)));
+        assert_eq!(m64x2::new(true, false), mem::transmute(vec_cmpgt(a, b)));
+        assert_eq!(m64x2::new(false, true), mem::transmute(vec_cmpeq(a, b)));
+        assert_eq!(u64x2::splat(7), mem::transmute(vec_splats(7u64)));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "power8-vector")]
+    unsafe fn test_vec_popcnt_cntlz() {
+        let a: vector_signed_char = mem::transmute(i8x16::new(
+            0, 1, 3, 7, 15, 31, 63, 127, -1, -128, 2, 4, 8, 16, 32, 64,
+        ));
+        assert_eq!(
+            u8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 1, 1, 1, 1, 1, 1, 1),
+            mem::transmute(vec_popcnt(a))
+        );
+        assert_eq!(
+            i8x16::new(8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 6, 5, 4, 3, 2, 1),
+            mem::transmute(vec_cntlz(a))
+        );
+
+        let a: vector_unsigned_long = mem::transmute(u64x2::new(0, 0x0000_ffff_0000_0001));
+        assert_eq!(u64x2::new(0, 17), mem::transmute(vec_popcnt(a)));
+        assert_eq!(u64x2::new(64, 16), mem::transmute(vec_cntlz(a)));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_cipher_be() {
+        // With an all-zero state and round key, every byte of the state goes
+        // through the S-box (0 -> 0x63) and MixColumns leaves it unchanged.
+        let zero: vector_unsigned_char = mem::transmute(u8x16::splat(0));
+        let s: vector_unsigned_char = mem::transmute(u8x16::splat(0x63));
+
+        assert_eq!(
+            u8x16::splat(0x63),
+            mem::transmute(vec_cipher_be(zero, zero))
+        );
+        assert_eq!(
+            u8x16::splat(0x63),
+            mem::transmute(vec_cipherlast_be(zero, zero))
+        );
+        assert_eq!(u8x16::splat(0x63), mem::transmute(vec_sbox_be(zero)));
+        assert_eq!(u8x16::splat(0), mem::transmute(vec_ncipher_be(s, zero)));
+        assert_eq!(u8x16::splat(0), mem::transmute(vec_ncipherlast_be(s, zero)));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_vpmsumd() {
+        // (x + 1) * (x + 1) = x^2 + 1 in GF(2)[x].
+        let a: vector_unsigned_long = mem::transmute(u64x2::new(3, 0));
+        let d = if cfg!(target_endian = "little") {
+            u64x2::new(5, 0)
+        } else {
+            u64x2::new(0, 5)
+        };
+        assert_eq!(d, mem::transmute(vec_vpmsumd(a, a)));
+    }
+
+    #[cfg(target_feature = "altivec")]
+    #[simd_test(enable = "power8-crypto")]
+    unsafe fn test_vec_shasigma_be() {
+        let a: vector_unsigned_int = mem::transmute(u32x4::splat(1));
+        assert_eq!(
+            u32x4::splat(0x0200_4000),
+            mem::transmute(vec_shasigma_be::<_, 0, 0>(a))
+        );
+        assert_eq!(
+            u32x4::splat(0x0000_a000),
+            mem::transmute(vec_shasigma_be::<_, 0, 0xf>(a))
+        );
+        assert_eq!(
+            u32x4::splat(0x4008_0400),
+            mem::transmute(vec_shasigma_be::<_, 1, 0>(a))
+        );
+        assert_eq!(
+            u32x4::splat(0x0420_0080),
+            mem::transmute(vec_shasigma_be::<_, 1, 0xf>(a))
+        );
+
+        let a: vector_unsigned_long = mem::transmute(u64x2::splat(1));
+        assert_eq!(
+            u64x2::splat(0x8100_0000_0000_0000),
+            mem::transmute(vec_shasigma_be::<_, 0, 0>(a))
+        );
+    }
+
+    #[cfg(all(target_feature = "altivec", target_arch = "powerpc64"))]
+    #[simd_test(enable = "power9-vector")]
+    unsafe fn test_vec_xl_xst_len() {
+        let buf: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+
+        let v: u8x16 = mem::transmute(vec_xl_len(buf.as_ptr(), 5));
+        assert_eq!(
+            v,
+            u8x16::new(1, 2, 3, 4, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+        );
+        let v: u8x16 = mem::transmute(vec_xl_len(buf.as_ptr(), 0));
+        assert_eq!(v, u8x16::splat(0));
+
+        let mut out = [0u8; 16];
+        let v: vector_unsigned_char = mem::transmute(u8x16::splat(0xff));
+        vec_xst_len(v, out.as_mut_ptr(), 3);
+        assert_eq!(&out[..4], &[0xff, 0xff, 0xff, 0]);
+    }
 }
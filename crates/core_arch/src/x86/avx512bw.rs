@@ -8216,10 +8216,17 @@ pub unsafe fn _mm_movm_epi8(k: __mmask16) -> __m128i {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask32&expand=3207)
 #[inline]
 #[target_feature(enable = "avx512bw")]
-#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kaddd
-                                     //llvm.x86.avx512.kadd.d
+#[cfg_attr(test, assert_instr(kaddd))]
 pub unsafe fn _kadd_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
-    transmute(a + b)
+    let r: __mmask32;
+    asm!(
+        "kaddd {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
 }
 
 /// Add 64-bit masks in a and b, and store the result in k.
@@ -8227,9 +8234,17 @@ pub unsafe fn _kadd_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask64&expand=3208)
 #[inline]
 #[target_feature(enable = "avx512bw")]
-#[cfg_attr(test, assert_instr(mov))] // generate normal and code instead of kaddq
+#[cfg_attr(test, assert_instr(kaddq))]
 pub unsafe fn _kadd_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
-    transmute(a + b)
+    let r: __mmask64;
+    asm!(
+        "kaddq {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
 }
 
 /// Compute the bitwise AND of 32-bit masks a and b, and store the result in k.
@@ -8350,6 +8365,402 @@ pub unsafe fn _kxnor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
     transmute(_knot_mask64(a ^ b))
 }
 
+/// Shift the bits of 32-bit mask a left by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftld, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask32<const IMM8: u32>(a: __mmask32) -> __mmask32 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask32;
+    asm!(
+        "kshiftld {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Shift the bits of 32-bit mask a right by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftrd, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask32<const IMM8: u32>(a: __mmask32) -> __mmask32 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask32;
+    asm!(
+        "kshiftrd {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortest_mask32_u8(a: __mmask32, b: __mmask32, all_ones: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "kortestd {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *all_ones = cf;
+    zf
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let cf: u8;
+    asm!(
+        "kortestd {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise OR of 32-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestd))]
+pub unsafe fn _kortestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let zf: u8;
+    asm!(
+        "kortestd {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Compute the bitwise AND of 32-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktest_mask32_u8(a: __mmask32, b: __mmask32, and_not: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "ktestd {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *and_not = cf;
+    zf
+}
+
+/// Compute the bitwise NOT of 32-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let cf: u8;
+    asm!(
+        "ktestd {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise AND of 32-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestd))]
+pub unsafe fn _ktestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    let zf: u8;
+    asm!(
+        "ktestd {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Convert 32-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask32_u32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+pub unsafe fn _cvtmask32_u32(a: __mmask32) -> u32 {
+    a as u32
+}
+
+/// Convert integer value a into an 32-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+pub unsafe fn _cvtu32_mask32(a: u32) -> __mmask32 {
+    a as __mmask32
+}
+
+/// Shift the bits of 64-bit mask a left by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftlq, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask64<const IMM8: u32>(a: __mmask64) -> __mmask64 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask64;
+    asm!(
+        "kshiftlq {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Shift the bits of 64-bit mask a right by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kshiftrq, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask64<const IMM8: u32>(a: __mmask64) -> __mmask64 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask64;
+    asm!(
+        "kshiftrq {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortest_mask64_u8(a: __mmask64, b: __mmask64, all_ones: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "kortestq {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *all_ones = cf;
+    zf
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let cf: u8;
+    asm!(
+        "kortestq {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise OR of 64-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kortestq))]
+pub unsafe fn _kortestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let zf: u8;
+    asm!(
+        "kortestq {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Compute the bitwise AND of 64-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktest_mask64_u8(a: __mmask64, b: __mmask64, and_not: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "ktestq {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *and_not = cf;
+    zf
+}
+
+/// Compute the bitwise NOT of 64-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let cf: u8;
+    asm!(
+        "ktestq {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise AND of 64-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(ktestq))]
+pub unsafe fn _ktestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    let zf: u8;
+    asm!(
+        "ktestq {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Convert 64-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask64_u64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+pub unsafe fn _cvtmask64_u64(a: __mmask64) -> u64 {
+    a as u64
+}
+
+/// Convert integer value a into an 64-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu64_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+pub unsafe fn _cvtu64_mask64(a: u64) -> __mmask64 {
+    a as __mmask64
+}
+
+/// Unpack and interleave 16 bits from masks a and b, and store the 32-bit result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_kunpackw)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kunpckwd))]
+pub unsafe fn _mm512_kunpackw(a: __mmask32, b: __mmask32) -> __mmask32 {
+    let r: __mmask32;
+    asm!(
+        "kunpckwd {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Unpack and interleave 32 bits from masks a and b, and store the 64-bit result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_kunpackd)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(kunpckdq))]
+pub unsafe fn _mm512_kunpackd(a: __mmask64, b: __mmask64) -> __mmask64 {
+    let r: __mmask64;
+    asm!(
+        "kunpckdq {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
 /// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the results in dst.
 ///
 /// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_epi8&expand=1407)
@@ -17488,6 +17899,377 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask32() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let r = _kshiftli_mask32::<3>(a);
+        let e: __mmask32 = 0b11111000_00000101_01010001_10011000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask32::<31>(a);
+        let e: __mmask32 = 0b10000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask32::<32>(a);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask32() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let r = _kshiftri_mask32::<3>(a);
+        let e: __mmask32 = 0b00011111_11100000_00010101_01000110;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask32::<31>(a);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000001;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask32::<32>(a);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask32_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask32_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let a: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let b: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask32_u8(a, b, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let r = _kortestc_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let r = _kortestc_mask32_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let b: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let r = _kortestc_mask32_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let r = _kortestz_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let r = _kortestz_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let b: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        let r = _kortestz_mask32_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask32_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask32_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask32_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let r = _ktestc_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let r = _ktestc_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let r = _ktestc_mask32_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask32_u8() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let r = _ktestz_mask32_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00000000_11111111_01010101_11001100;
+        let r = _ktestz_mask32_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let r = _ktestz_mask32_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask32_u32() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let r = _cvtmask32_u32(a);
+        let e: u32 = 0b11111111_00000000_10101010_00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtu32_mask32() {
+        let a: u32 = 0b11111111_00000000_10101010_00110011;
+        let r = _cvtu32_mask32(a);
+        let e: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask64() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _kshiftli_mask64::<3>(a);
+        let e: __mmask64 =
+            0b00000111_11111000_00000111_11111111_10000111_10000000_10010001_10100000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask64::<63>(a);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask64::<64>(a);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask64() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _kshiftri_mask64::<3>(a);
+        let e: __mmask64 =
+            0b00000000_00011111_11100000_00011111_11111110_00011110_00000010_01000110;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask64::<63>(a);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask64::<64>(a);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask64_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask64_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let a: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let b: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask64_u8(a, b, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let r = _kortestc_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let r = _kortestc_mask64_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let b: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let r = _kortestc_mask64_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let r = _kortestz_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let r = _kortestz_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let b: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        let r = _kortestz_mask64_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask64_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask64_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask64_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let r = _ktestc_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let r = _ktestc_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _ktestc_mask64_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask64_u8() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let r = _ktestz_mask64_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b11111111_00000000_11111111_00000000_00001111_00001111_11101101_11001011;
+        let r = _ktestz_mask64_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _ktestz_mask64_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask64_u64() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _cvtmask64_u64(a);
+        let e: u64 = 0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtu64_mask64() {
+        let a: u64 = 0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let r = _cvtu64_mask64(a);
+        let e: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackw() {
+        let a: __mmask32 = 0b11111111_00000000_10101010_00110011;
+        let b: __mmask32 = 0b00001111_11110000_01010101_11001100;
+        let r = _mm512_kunpackw(a, b);
+        let e: __mmask32 = 0b10101010_00110011_01010101_11001100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackd() {
+        let a: __mmask64 =
+            0b00000000_11111111_00000000_11111111_11110000_11110000_00010010_00110100;
+        let b: __mmask64 =
+            0b00001111_00001111_11110000_00000000_00001111_00001111_01000011_00100001;
+        let r = _mm512_kunpackd(a, b);
+        let e: __mmask64 =
+            0b11110000_11110000_00010010_00110100_00001111_00001111_01000011_00100001;
+        assert_eq!(r, e);
+    }
+
     #[simd_test(enable = "avx512bw")]
     unsafe fn test_mm512_cvtepi16_epi8() {
         let a = _mm512_set1_epi16(2);
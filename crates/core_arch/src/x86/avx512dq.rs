@@ -0,0 +1,10691 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+    ptr,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_and_pd(a: __m512d, b: __m512d) -> __m512d {
+    transmute(simd_and(transmute::<_, u64x8>(a), transmute::<_, u64x8>(b)))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm512_mask_and_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, and, src.as_f64x8()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm512_maskz_and_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm256_mask_and_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let and = _mm256_and_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, and, src.as_f64x4()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm256_maskz_and_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let and = _mm256_and_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm_mask_and_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let and = _mm_and_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, and, src.as_f64x2()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm_maskz_and_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let and = _mm_and_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_and_ps(a: __m512, b: __m512) -> __m512 {
+    transmute(simd_and(
+        transmute::<_, u32x16>(a),
+        transmute::<_, u32x16>(b),
+    ))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_mask_and_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, and, src.as_f32x16()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_maskz_and_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm256_mask_and_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let and = _mm256_and_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, and, src.as_f32x8()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm256_maskz_and_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let and = _mm256_and_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm_mask_and_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let and = _mm_and_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, and, src.as_f32x4()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm_maskz_and_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let and = _mm_and_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_andnot_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a = transmute::<_, u64x8>(a);
+    let b = transmute::<_, u64x8>(b);
+    transmute(simd_and(simd_xor(u64x8::splat(u64::MAX), a), b))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm512_mask_andnot_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x8()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm512_maskz_andnot_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm256_mask_andnot_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let andnot = _mm256_andnot_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x4()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm256_maskz_andnot_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let andnot = _mm256_andnot_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm_mask_andnot_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let andnot = _mm_andnot_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x2()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm_maskz_andnot_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let andnot = _mm_andnot_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512 {
+    let a = transmute::<_, u32x16>(a);
+    let b = transmute::<_, u32x16>(b);
+    transmute(simd_and(simd_xor(u32x16::splat(u32::MAX), a), b))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_mask_andnot_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x16()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_maskz_andnot_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm256_mask_andnot_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let andnot = _mm256_andnot_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x8()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm256_maskz_andnot_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let andnot = _mm256_andnot_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm_mask_andnot_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let andnot = _mm_andnot_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x4()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm_maskz_andnot_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let andnot = _mm_andnot_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_or_pd(a: __m512d, b: __m512d) -> __m512d {
+    transmute(simd_or(transmute::<_, u64x8>(a), transmute::<_, u64x8>(b)))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm512_mask_or_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, or, src.as_f64x8()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm512_maskz_or_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm256_mask_or_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let or = _mm256_or_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, or, src.as_f64x4()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm256_maskz_or_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let or = _mm256_or_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm_mask_or_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let or = _mm_or_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, or, src.as_f64x2()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm_maskz_or_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let or = _mm_or_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_or_ps(a: __m512, b: __m512) -> __m512 {
+    transmute(simd_or(
+        transmute::<_, u32x16>(a),
+        transmute::<_, u32x16>(b),
+    ))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_mask_or_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, or, src.as_f32x16()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_maskz_or_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm256_mask_or_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let or = _mm256_or_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, or, src.as_f32x8()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm256_maskz_or_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let or = _mm256_or_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm_mask_or_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let or = _mm_or_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, or, src.as_f32x4()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm_maskz_or_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let or = _mm_or_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_xor_pd(a: __m512d, b: __m512d) -> __m512d {
+    transmute(simd_xor(transmute::<_, u64x8>(a), transmute::<_, u64x8>(b)))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm512_mask_xor_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x8()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm512_maskz_xor_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm256_mask_xor_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let xor = _mm256_xor_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x4()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm256_maskz_xor_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let xor = _mm256_xor_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm_mask_xor_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let xor = _mm_xor_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x2()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm_maskz_xor_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let xor = _mm_xor_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512 {
+    transmute(simd_xor(
+        transmute::<_, u32x16>(a),
+        transmute::<_, u32x16>(b),
+    ))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_mask_xor_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x16()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_maskz_xor_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_mask_xor_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let xor = _mm256_xor_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x8()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_maskz_xor_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let xor = _mm256_xor_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_mask_xor_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let xor = _mm_xor_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x4()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_maskz_xor_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let xor = _mm_xor_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f32x2(a: __m128) -> __m512 {
+    simd_shuffle16!(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_f32x2(src: __m512, k: __mmask16, a: __m128) -> __m512 {
+    let b = _mm512_broadcast_f32x2(a).as_f32x16();
+    transmute(simd_select_bitmask(k, b, src.as_f32x16()))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_f32x2(k: __mmask16, a: __m128) -> __m512 {
+    let b = _mm512_broadcast_f32x2(a).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_f32x2(a: __m128) -> __m256 {
+    simd_shuffle8!(a, a, [0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_mask_broadcast_f32x2(src: __m256, k: __mmask8, a: __m128) -> __m256 {
+    let b = _mm256_broadcast_f32x2(a).as_f32x8();
+    transmute(simd_select_bitmask(k, b, src.as_f32x8()))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_maskz_broadcast_f32x2(k: __mmask8, a: __m128) -> __m256 {
+    let b = _mm256_broadcast_f32x2(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f32x8(a: __m256) -> __m512 {
+    simd_shuffle16!(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_f32x8(src: __m512, k: __mmask16, a: __m256) -> __m512 {
+    let b = _mm512_broadcast_f32x8(a).as_f32x16();
+    transmute(simd_select_bitmask(k, b, src.as_f32x16()))
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_f32x8(k: __mmask16, a: __m256) -> __m512 {
+    let b = _mm512_broadcast_f32x8(a).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f64x2(a: __m128d) -> __m512d {
+    simd_shuffle8!(a, a, [0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_f64x2(src: __m512d, k: __mmask8, a: __m128d) -> __m512d {
+    let b = _mm512_broadcast_f64x2(a).as_f64x8();
+    transmute(simd_select_bitmask(k, b, src.as_f64x8()))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m512d {
+    let b = _mm512_broadcast_f64x2(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_f64x2(a: __m128d) -> __m256d {
+    simd_shuffle4!(a, a, [0, 1, 0, 1])
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_mask_broadcast_f64x2(src: __m256d, k: __mmask8, a: __m128d) -> __m256d {
+    let b = _mm256_broadcast_f64x2(a).as_f64x4();
+    transmute(simd_select_bitmask(k, b, src.as_f64x4()))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m256d {
+    let b = _mm256_broadcast_f64x2(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i32x2(a: __m128i) -> __m512i {
+    let a = a.as_i32x4();
+    let ret: i32x16 = simd_shuffle16!(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_i32x2(src: __m512i, k: __mmask16, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i32x2(a).as_i32x16();
+    transmute(simd_select_bitmask(k, b, src.as_i32x16()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_i32x2(k: __mmask16, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i32x2(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_i32x2(a: __m128i) -> __m256i {
+    let a = a.as_i32x4();
+    let ret: i32x8 = simd_shuffle8!(a, a, [0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_mask_broadcast_i32x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i32x2(a).as_i32x8();
+    transmute(simd_select_bitmask(k, b, src.as_i32x8()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i32x2(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm_broadcast_i32x2(a: __m128i) -> __m128i {
+    let a = a.as_i32x4();
+    let ret: i32x4 = simd_shuffle4!(a, a, [0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm_mask_broadcast_i32x2(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let b = _mm_broadcast_i32x2(a).as_i32x4();
+    transmute(simd_select_bitmask(k, b, src.as_i32x4()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m128i {
+    let b = _mm_broadcast_i32x2(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i32x8(a: __m256i) -> __m512i {
+    let a = a.as_i32x8();
+    let ret: i32x16 = simd_shuffle16!(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    transmute(ret)
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_i32x8(src: __m512i, k: __mmask16, a: __m256i) -> __m512i {
+    let b = _mm512_broadcast_i32x8(a).as_i32x16();
+    transmute(simd_select_bitmask(k, b, src.as_i32x16()))
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_i32x8(k: __mmask16, a: __m256i) -> __m512i {
+    let b = _mm512_broadcast_i32x8(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i64x2(a: __m128i) -> __m512i {
+    let a = a.as_i64x2();
+    let ret: i64x8 = simd_shuffle8!(a, a, [0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_mask_broadcast_i64x2(src: __m512i, k: __mmask8, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i64x2(a).as_i64x8();
+    transmute(simd_select_bitmask(k, b, src.as_i64x8()))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i64x2(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_i64x2(a: __m128i) -> __m256i {
+    let a = a.as_i64x2();
+    let ret: i64x4 = simd_shuffle4!(a, a, [0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_mask_broadcast_i64x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i64x2(a).as_i64x4();
+    transmute(simd_select_bitmask(k, b, src.as_i64x4()))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i64x2(a).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_cvtepi64_pd(a: __m512i) -> __m512d {
+    transmute::<f64x8, _>(simd_cast(a.as_i64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_mask_cvtepi64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let convert = _mm512_cvtepi64_pd(a).as_f64x8();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepi64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let convert = _mm512_cvtepi64_pd(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepi64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2pd_round(a, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepi64_pd<const ROUNDING: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2pd_round(a, ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepi64_pd<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2pd_round(a, ROUNDING);
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_cvtepi64_pd(a: __m256i) -> __m256d {
+    transmute::<f64x4, _>(simd_cast(a.as_i64x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_mask_cvtepi64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepi64_pd(a).as_f64x4();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_maskz_cvtepi64_pd(k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepi64_pd(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_cvtepi64_pd(a: __m128i) -> __m128d {
+    transmute::<f64x2, _>(simd_cast(a.as_i64x2()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_mask_cvtepi64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepi64_pd(a).as_f64x2();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x2()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_maskz_cvtepi64_pd(k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepi64_pd(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_cvtepi64_ps(a: __m512i) -> __m256 {
+    transmute::<f32x8, _>(simd_cast(a.as_i64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_mask_cvtepi64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let convert = _mm512_cvtepi64_ps(a).as_f32x8();
+    transmute(simd_select_bitmask(k, convert, src.as_f32x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepi64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let convert = _mm512_cvtepi64_ps(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepi64_ps<const ROUNDING: i32>(a: __m512i) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2ps_round(a, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepi64_ps<const ROUNDING: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2ps_round(a, ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepi64_ps<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_i64x8();
+    let r = vcvtqq2ps_round(a, ROUNDING);
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_cvtepi64_ps(a: __m256i) -> __m128 {
+    transmute::<f32x4, _>(simd_cast(a.as_i64x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128 {
+    let convert = _mm256_cvtepi64_ps(a).as_f32x4();
+    transmute(simd_select_bitmask(k, convert, src.as_f32x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_maskz_cvtepi64_ps(k: __mmask8, a: __m256i) -> __m128 {
+    let convert = _mm256_cvtepi64_ps(a).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_cvtepi64_ps(a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps128(
+        a.as_i64x2(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps128(a.as_i64x2(), src.as_f32x4(), k))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_maskz_cvtepi64_ps(k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps128(a.as_i64x2(), _mm_setzero_ps().as_f32x4(), k))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_cvtepu64_pd(a: __m512i) -> __m512d {
+    transmute::<f64x8, _>(simd_cast(a.as_u64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_mask_cvtepu64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let convert = _mm512_cvtepu64_pd(a).as_f64x8();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepu64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let convert = _mm512_cvtepu64_pd(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepu64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2pd_round(a, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepu64_pd<const ROUNDING: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2pd_round(a, ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepu64_pd<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2pd_round(a, ROUNDING);
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_cvtepu64_pd(a: __m256i) -> __m256d {
+    transmute::<f64x4, _>(simd_cast(a.as_u64x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_mask_cvtepu64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepu64_pd(a).as_f64x4();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_maskz_cvtepu64_pd(k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepu64_pd(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_cvtepu64_pd(a: __m128i) -> __m128d {
+    transmute::<f64x2, _>(simd_cast(a.as_u64x2()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_mask_cvtepu64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepu64_pd(a).as_f64x2();
+    transmute(simd_select_bitmask(k, convert, src.as_f64x2()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_maskz_cvtepu64_pd(k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepu64_pd(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_cvtepu64_ps(a: __m512i) -> __m256 {
+    transmute::<f32x8, _>(simd_cast(a.as_u64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_mask_cvtepu64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let convert = _mm512_cvtepu64_ps(a).as_f32x8();
+    transmute(simd_select_bitmask(k, convert, src.as_f32x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepu64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let convert = _mm512_cvtepu64_ps(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepu64_ps<const ROUNDING: i32>(a: __m512i) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2ps_round(a, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepu64_ps<const ROUNDING: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2ps_round(a, ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepu64_ps<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_u64x8();
+    let r = vcvtuqq2ps_round(a, ROUNDING);
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_cvtepu64_ps(a: __m256i) -> __m128 {
+    transmute::<f32x4, _>(simd_cast(a.as_u64x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128 {
+    let convert = _mm256_cvtepu64_ps(a).as_f32x4();
+    transmute(simd_select_bitmask(k, convert, src.as_f32x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_maskz_cvtepu64_ps(k: __mmask8, a: __m256i) -> __m128 {
+    let convert = _mm256_cvtepu64_ps(a).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_cvtepu64_ps(a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps128(
+        a.as_u64x2(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps128(a.as_u64x2(), src.as_f32x4(), k))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_maskz_cvtepu64_ps(k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps128(a.as_u64x2(), _mm_setzero_ps().as_f32x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_cvtpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_mask_cvtpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_maskz_cvtpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundpd_epi64<const ROUNDING: i32>(a: __m512d) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvtpd2qq(a, zero, 0b11111111, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundpd_epi64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let src = src.as_i64x8();
+    let r = vcvtpd2qq(a, src, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundpd_epi64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvtpd2qq(a, zero, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_cvtpd_epi64(a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_mask_cvtpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq256(a.as_f64x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_maskz_cvtpd_epi64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_cvtpd_epi64(a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_mask_cvtpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq128(a.as_f64x2(), src.as_i64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_maskz_cvtpd_epi64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_cvtpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_mask_cvtpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_maskz_cvtpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundpd_epu64<const ROUNDING: i32>(a: __m512d) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvtpd2uqq(a, zero, 0b11111111, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundpd_epu64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let src = src.as_u64x8();
+    let r = vcvtpd2uqq(a, src, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundpd_epu64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvtpd2uqq(a, zero, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_cvtpd_epu64(a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_mask_cvtpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq256(a.as_f64x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_maskz_cvtpd_epu64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_cvtpd_epu64(a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_mask_cvtpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq128(a.as_f64x2(), src.as_u64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_maskz_cvtpd_epu64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_cvtps_epi64(a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_mask_cvtps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_maskz_cvtps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundps_epi64<const ROUNDING: i32>(a: __m256) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvtps2qq(a, zero, 0b11111111, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundps_epi64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let src = src.as_i64x8();
+    let r = vcvtps2qq(a, src, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundps_epi64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvtps2qq(a, zero, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_cvtps_epi64(a: __m128) -> __m256i {
+    transmute(vcvtps2qq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_mask_cvtps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2qq256(a.as_f32x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2qq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_cvtps_epi64(a: __m128) -> __m128i {
+    transmute(vcvtps2qq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_mask_cvtps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2qq128(a.as_f32x4(), src.as_i64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2qq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_cvtps_epu64(a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_mask_cvtps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_maskz_cvtps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundps_epu64<const ROUNDING: i32>(a: __m256) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvtps2uqq(a, zero, 0b11111111, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundps_epu64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let src = src.as_u64x8();
+    let r = vcvtps2uqq(a, src, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundps_epu64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvtps2uqq(a, zero, k, ROUNDING);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_cvtps_epu64(a: __m128) -> __m256i {
+    transmute(vcvtps2uqq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_mask_cvtps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2uqq256(a.as_f32x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2uqq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_cvtps_epu64(a: __m128) -> __m128i {
+    transmute(vcvtps2uqq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_mask_cvtps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2uqq128(a.as_f32x4(), src.as_u64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2uqq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_cvttpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_mask_cvttpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_maskz_cvttpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundpd_epi64<const SAE: i32>(a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvttpd2qq(a, zero, 0b11111111, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundpd_epi64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let src = src.as_i64x8();
+    let r = vcvttpd2qq(a, src, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundpd_epi64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvttpd2qq(a, zero, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_cvttpd_epi64(a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_mask_cvttpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq256(a.as_f64x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_maskz_cvttpd_epi64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_cvttpd_epi64(a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_mask_cvttpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq128(a.as_f64x2(), src.as_i64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_maskz_cvttpd_epi64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_cvttpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_mask_cvttpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_maskz_cvttpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundpd_epu64<const SAE: i32>(a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvttpd2uqq(a, zero, 0b11111111, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundpd_epu64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let src = src.as_u64x8();
+    let r = vcvttpd2uqq(a, src, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundpd_epu64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f64x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvttpd2uqq(a, zero, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_cvttpd_epu64(a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_mask_cvttpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq256(a.as_f64x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_maskz_cvttpd_epu64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_cvttpd_epu64(a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_mask_cvttpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq128(a.as_f64x2(), src.as_u64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_maskz_cvttpd_epu64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_cvttps_epi64(a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_mask_cvttps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_maskz_cvttps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundps_epi64<const SAE: i32>(a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvttps2qq(a, zero, 0b11111111, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundps_epi64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let src = src.as_i64x8();
+    let r = vcvttps2qq(a, src, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundps_epi64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    let r = vcvttps2qq(a, zero, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_cvttps_epi64(a: __m128) -> __m256i {
+    transmute(vcvttps2qq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_mask_cvttps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2qq256(a.as_f32x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2qq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_cvttps_epi64(a: __m128) -> __m128i {
+    transmute(vcvttps2qq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_mask_cvttps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2qq128(a.as_f32x4(), src.as_i64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed signed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2qq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_cvttps_epu64(a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_mask_cvttps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_maskz_cvttps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundps_epu64<const SAE: i32>(a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvttps2uqq(a, zero, 0b11111111, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundps_epu64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let src = src.as_u64x8();
+    let r = vcvttps2uqq(a, src, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundps_epu64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    let a = a.as_f32x8();
+    let zero = _mm512_setzero_si512().as_u64x8();
+    let r = vcvttps2uqq(a, zero, k, SAE);
+    transmute(r)
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_cvttps_epu64(a: __m128) -> __m256i {
+    transmute(vcvttps2uqq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_mask_cvttps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2uqq256(a.as_f32x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2uqq256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_cvttps_epu64(a: __m128) -> __m128i {
+    transmute(vcvttps2uqq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11111111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_mask_cvttps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2uqq128(a.as_f32x4(), src.as_u64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2uqq128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mullo_epi64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_mul(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mask_mullo_epi64(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x8()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_maskz_mullo_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mullo_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_mul(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mask_mullo_epi64(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x4()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_maskz_mullo_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_mul(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mask_mullo_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x2()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_maskz_mullo_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extractf32x8_ps<const IMM8: i32>(a: __m512) -> __m256 {
+    static_assert_imm1!(IMM8);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle8!(a, a, [0, 1, 2, 3, 4, 5, 6, 7]),
+        _ => simd_shuffle8!(a, a, [8, 9, 10, 11, 12, 13, 14, 15]),
+    }
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extractf32x8_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512,
+) -> __m256 {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_extractf32x8_ps::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_f32x8(), src.as_f32x8()))
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extractf32x8_ps<const IMM8: i32>(k: __mmask8, a: __m512) -> __m256 {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_extractf32x8_ps::<IMM8>(a);
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r.as_f32x8(), zero))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extractf64x2_pd<const IMM8: i32>(a: __m512d) -> __m128d {
+    static_assert_imm2!(IMM8);
+    match IMM8 & 0b11 {
+        0 => simd_shuffle2!(a, a, [0, 1]),
+        1 => simd_shuffle2!(a, a, [2, 3]),
+        2 => simd_shuffle2!(a, a, [4, 5]),
+        _ => simd_shuffle2!(a, a, [6, 7]),
+    }
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extractf64x2_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m128d {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_extractf64x2_pd::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_f64x2(), src.as_f64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m128d {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_extractf64x2_pd::<IMM8>(a);
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r.as_f64x2(), zero))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_extractf64x2_pd<const IMM8: i32>(a: __m256d) -> __m128d {
+    static_assert_imm1!(IMM8);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle2!(a, a, [0, 1]),
+        _ => simd_shuffle2!(a, a, [2, 3]),
+    }
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextractf64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_extractf64x2_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m256d,
+) -> __m128d {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_extractf64x2_pd::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_f64x2(), src.as_f64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextractf64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m128d {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_extractf64x2_pd::<IMM8>(a);
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r.as_f64x2(), zero))
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extracti32x8_epi32<const IMM8: i32>(a: __m512i) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let a = a.as_i32x16();
+    let r: i32x8 = match IMM8 & 0b1 {
+        0 => simd_shuffle8!(a, a, [0, 1, 2, 3, 4, 5, 6, 7]),
+        _ => simd_shuffle8!(a, a, [8, 9, 10, 11, 12, 13, 14, 15]),
+    };
+    transmute(r)
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extracti32x8_epi32<const IMM8: i32>(
+    src: __m256i,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_extracti32x8_epi32::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_i32x8(), src.as_i32x8()))
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extracti32x8_epi32<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_extracti32x8_epi32::<IMM8>(a);
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r.as_i32x8(), zero))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extracti64x2_epi64<const IMM8: i32>(a: __m512i) -> __m128i {
+    static_assert_imm2!(IMM8);
+    let a = a.as_i64x8();
+    let r: i64x2 = match IMM8 & 0b11 {
+        0 => simd_shuffle2!(a, a, [0, 1]),
+        1 => simd_shuffle2!(a, a, [2, 3]),
+        2 => simd_shuffle2!(a, a, [4, 5]),
+        _ => simd_shuffle2!(a, a, [6, 7]),
+    };
+    transmute(r)
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extracti64x2_epi64<const IMM8: i32>(
+    src: __m128i,
+    k: __mmask8,
+    a: __m512i,
+) -> __m128i {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_extracti64x2_epi64::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_i64x2(), src.as_i64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m128i {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_extracti64x2_epi64::<IMM8>(a);
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r.as_i64x2(), zero))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextract, IMM8 = 1))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_extracti64x2_epi64<const IMM8: i32>(a: __m256i) -> __m128i {
+    static_assert_imm1!(IMM8);
+    let a = a.as_i64x4();
+    let r: i64x2 = match IMM8 & 0b1 {
+        0 => simd_shuffle2!(a, a, [0, 1]),
+        _ => simd_shuffle2!(a, a, [2, 3]),
+    };
+    transmute(r)
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextracti64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_extracti64x2_epi64<const IMM8: i32>(
+    src: __m128i,
+    k: __mmask8,
+    a: __m256i,
+) -> __m128i {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_extracti64x2_epi64::<IMM8>(a);
+    transmute(simd_select_bitmask(k, r.as_i64x2(), src.as_i64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vextracti64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m128i {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_extracti64x2_epi64::<IMM8>(a);
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r.as_i64x2(), zero))
+}
+
+/// Copy a to dst, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_insertf32x8<const IMM8: i32>(a: __m512, b: __m256) -> __m512 {
+    static_assert_imm1!(IMM8);
+    let a = a.as_f32x16();
+    let b = _mm512_castps256_ps512(b).as_f32x16();
+    let r: f32x16 = match IMM8 & 0b1 {
+        0 => simd_shuffle16!(
+            a,
+            b,
+            [16, 17, 18, 19, 20, 21, 22, 23, 8, 9, 10, 11, 12, 13, 14, 15]
+        ),
+        _ => simd_shuffle16!(
+            a,
+            b,
+            [0, 1, 2, 3, 4, 5, 6, 7, 16, 17, 18, 19, 20, 21, 22, 23]
+        ),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_insertf32x8<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m256,
+) -> __m512 {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_insertf32x8::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_f32x16(), src.as_f32x16()))
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_insertf32x8<const IMM8: i32>(
+    k: __mmask16,
+    a: __m512,
+    b: __m256,
+) -> __m512 {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_insertf32x8::<IMM8>(a, b);
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, r.as_f32x16(), zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_insertf64x2<const IMM8: i32>(a: __m512d, b: __m128d) -> __m512d {
+    static_assert_imm2!(IMM8);
+    let a = a.as_f64x8();
+    let b = _mm512_castpd128_pd512(b).as_f64x8();
+    let r: f64x8 = match IMM8 & 0b11 {
+        0 => simd_shuffle8!(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle8!(a, b, [0, 1, 8, 9, 4, 5, 6, 7]),
+        2 => simd_shuffle8!(a, b, [0, 1, 2, 3, 8, 9, 6, 7]),
+        _ => simd_shuffle8!(a, b, [0, 1, 2, 3, 4, 5, 8, 9]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_insertf64x2<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m128d,
+) -> __m512d {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_insertf64x2::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_f64x8(), src.as_f64x8()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_insertf64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m128d,
+) -> __m512d {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_insertf64x2::<IMM8>(a, b);
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r.as_f64x8(), zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_insertf64x2<const IMM8: i32>(a: __m256d, b: __m128d) -> __m256d {
+    static_assert_imm1!(IMM8);
+    let a = a.as_f64x4();
+    let b = _mm256_castpd128_pd256(b).as_f64x4();
+    let r: f64x4 = match IMM8 & 0b1 {
+        0 => simd_shuffle4!(a, b, [4, 5, 2, 3]),
+        _ => simd_shuffle4!(a, b, [0, 1, 4, 5]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_insertf64x2<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+    b: __m128d,
+) -> __m256d {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_insertf64x2::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_f64x4(), src.as_f64x4()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_insertf64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256d,
+    b: __m128d,
+) -> __m256d {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_insertf64x2::<IMM8>(a, b);
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, r.as_f64x4(), zero))
+}
+
+/// Copy a to dst, then insert 256 bits (composed of 8 packed 32-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_inserti32x8<const IMM8: i32>(a: __m512i, b: __m256i) -> __m512i {
+    static_assert_imm1!(IMM8);
+    let a = a.as_i32x16();
+    let b = _mm512_castsi256_si512(b).as_i32x16();
+    let r: i32x16 = match IMM8 & 0b1 {
+        0 => simd_shuffle16!(
+            a,
+            b,
+            [16, 17, 18, 19, 20, 21, 22, 23, 8, 9, 10, 11, 12, 13, 14, 15]
+        ),
+        _ => simd_shuffle16!(
+            a,
+            b,
+            [0, 1, 2, 3, 4, 5, 6, 7, 16, 17, 18, 19, 20, 21, 22, 23]
+        ),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed 32-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_inserti32x8<const IMM8: i32>(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m256i,
+) -> __m512i {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_inserti32x8::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_i32x16(), src.as_i32x16()))
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed 32-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti32x8, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_inserti32x8<const IMM8: i32>(
+    k: __mmask16,
+    a: __m512i,
+    b: __m256i,
+) -> __m512i {
+    static_assert_imm1!(IMM8);
+    let r = _mm512_inserti32x8::<IMM8>(a, b);
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r.as_i32x16(), zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed 64-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_inserti64x2<const IMM8: i32>(a: __m512i, b: __m128i) -> __m512i {
+    static_assert_imm2!(IMM8);
+    let a = a.as_i64x8();
+    let b = _mm512_castsi128_si512(b).as_i64x8();
+    let r: i64x8 = match IMM8 & 0b11 {
+        0 => simd_shuffle8!(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle8!(a, b, [0, 1, 8, 9, 4, 5, 6, 7]),
+        2 => simd_shuffle8!(a, b, [0, 1, 2, 3, 8, 9, 6, 7]),
+        _ => simd_shuffle8!(a, b, [0, 1, 2, 3, 4, 5, 8, 9]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_inserti64x2<const IMM8: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m128i,
+) -> __m512i {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_inserti64x2::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_i64x8(), src.as_i64x8()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti64x2, IMM8 = 3))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_inserti64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512i,
+    b: __m128i,
+) -> __m512i {
+    static_assert_imm2!(IMM8);
+    let r = _mm512_inserti64x2::<IMM8>(a, b);
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r.as_i64x8(), zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed 64-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_inserti64x2<const IMM8: i32>(a: __m256i, b: __m128i) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let a = a.as_i64x4();
+    let b = _mm256_castsi128_si256(b).as_i64x4();
+    let r: i64x4 = match IMM8 & 0b1 {
+        0 => simd_shuffle4!(a, b, [4, 5, 2, 3]),
+        _ => simd_shuffle4!(a, b, [0, 1, 4, 5]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinserti64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_inserti64x2<const IMM8: i32>(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m128i,
+) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_inserti64x2::<IMM8>(a, b);
+    transmute(simd_select_bitmask(k, r.as_i64x4(), src.as_i64x4()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8. Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vinserti64x2, IMM8 = 1))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_inserti64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256i,
+    b: __m128i,
+) -> __m256i {
+    static_assert_imm1!(IMM8);
+    let r = _mm256_inserti64x2::<IMM8>(a, b);
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r.as_i64x4(), zero))
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_fpclass_pd_mask<const IMM8: i32>(a: __m512d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd(a.as_f64x8(), IMM8, 0b11111111)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m512d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd(a.as_f64x8(), IMM8, k1)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_fpclass_pd_mask<const IMM8: i32>(a: __m256d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd256(a.as_f64x4(), IMM8, 0b1111)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m256d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd256(a.as_f64x4(), IMM8, k1)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_pd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd128(a.as_f64x2(), IMM8, 0b11)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasspd128(a.as_f64x2(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_fpclass_ps_mask<const IMM8: i32>(a: __m512) -> __mmask16 {
+    static_assert_imm8!(IMM8);
+    vfpclassps(a.as_f32x16(), IMM8, 0b11111111_11111111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask16, a: __m512) -> __mmask16 {
+    static_assert_imm8!(IMM8);
+    vfpclassps(a.as_f32x16(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_fpclass_ps_mask<const IMM8: i32>(a: __m256) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassps256(a.as_f32x8(), IMM8, 0b11111111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m256) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassps256(a.as_f32x8(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_ps_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassps128(a.as_f32x4(), IMM8, 0b1111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassps128(a.as_f32x4(), IMM8, k1)
+}
+
+/// Test the lower double-precision (64-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_sd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasssd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_sd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasssd(a.as_f64x2(), IMM8, 0b1)
+}
+
+/// Test the lower double-precision (64-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k using zeromask k1 (the element is zeroed out when mask bit 0 is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_sd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasssd, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_sd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclasssd(a.as_f64x2(), IMM8, k1)
+}
+
+/// Test the lower single-precision (32-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k.\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fpclass_ss_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassss, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_ss_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassss(a.as_f32x4(), IMM8, 0b1)
+}
+
+/// Test the lower single-precision (32-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k using zeromask k1 (the element is zeroed out when mask bit 0 is not set).\
+/// imm can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fpclass_ss_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassss, IMM8 = 0x19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_ss_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8 {
+    static_assert_imm8!(IMM8);
+    vfpclassss(a.as_f32x4(), IMM8, k1)
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovd2m but msvc does not generate it
+pub unsafe fn _mm512_movepi32_mask(a: __m512i) -> __mmask16 {
+    let filter = _mm512_set1_epi32(1 << 31);
+    let a = _mm512_and_si512(a, filter);
+    _mm512_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm512_movm_epi32(k: __mmask16) -> __m512i {
+    let one = _mm512_set1_epi32(-1).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovd2m but msvc does not generate it
+pub unsafe fn _mm256_movepi32_mask(a: __m256i) -> __mmask8 {
+    let filter = _mm256_set1_epi32(1 << 31);
+    let a = _mm256_and_si256(a, filter);
+    _mm256_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm256_movm_epi32(k: __mmask8) -> __m256i {
+    let one = _mm256_set1_epi32(-1).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovd2m but msvc does not generate it
+pub unsafe fn _mm_movepi32_mask(a: __m128i) -> __mmask8 {
+    let filter = _mm_set1_epi32(1 << 31);
+    let a = _mm_and_si128(a, filter);
+    _mm_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm_movm_epi32(k: __mmask8) -> __m128i {
+    let one = _mm_set1_epi32(-1).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovq2m but msvc does not generate it
+pub unsafe fn _mm512_movepi64_mask(a: __m512i) -> __mmask8 {
+    let filter = _mm512_set1_epi64(1 << 63);
+    let a = _mm512_and_si512(a, filter);
+    _mm512_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm512_movm_epi64(k: __mmask8) -> __m512i {
+    let one = _mm512_set1_epi64(-1).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovq2m but msvc does not generate it
+pub unsafe fn _mm256_movepi64_mask(a: __m256i) -> __mmask8 {
+    let filter = _mm256_set1_epi64x(1 << 63);
+    let a = _mm256_and_si256(a, filter);
+    _mm256_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm256_movm_epi64(k: __mmask8) -> __m256i {
+    let one = _mm256_set1_epi64x(-1).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(mov))] // should be vpmovq2m but msvc does not generate it
+pub unsafe fn _mm_movepi64_mask(a: __m128i) -> __mmask8 {
+    let filter = _mm_set1_epi64x(1 << 63);
+    let a = _mm_and_si128(a, filter);
+    _mm_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm_movm_epi64(k: __mmask8) -> __m128i {
+    let one = _mm_set1_epi64x(-1).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_range_pd<const IMM8: i32>(a: __m512d, b: __m512d) -> __m512d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_range_pd<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_range_pd<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_range_round_pd<const IMM8: i32, const SAE: i32>(
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm512_mask_range_round_pd<const IMM8: i32, const SAE: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_maskz_range_round_pd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_range_pd<const IMM8: i32>(a: __m256d, b: __m256d) -> __m256d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd256(
+        a.as_f64x4(),
+        b.as_f64x4(),
+        IMM8,
+        _mm256_setzero_pd().as_f64x4(),
+        0b11111111,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_range_pd<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+    b: __m256d,
+) -> __m256d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd256(
+        a.as_f64x4(),
+        b.as_f64x4(),
+        IMM8,
+        src.as_f64x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_range_pd<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256d,
+    b: __m256d,
+) -> __m256d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd256(
+        a.as_f64x4(),
+        b.as_f64x4(),
+        IMM8,
+        _mm256_setzero_pd().as_f64x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_range_pd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd128(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        IMM8,
+        _mm_setzero_pd().as_f64x2(),
+        0b11111111,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd128(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        IMM8,
+        src.as_f64x2(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_pd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangepd128(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        IMM8,
+        _mm_setzero_pd().as_f64x2(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_range_ps<const IMM8: i32>(a: __m512, b: __m512) -> __m512 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        0b11111111_11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_range_ps<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_range_ps<const IMM8: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_range_round_ps<const IMM8: i32, const SAE: i32>(
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        0b11111111_11111111,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm512_mask_range_round_ps<const IMM8: i32, const SAE: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_maskz_range_round_ps<const IMM8: i32, const SAE: i32>(
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_range_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps256(
+        a.as_f32x8(),
+        b.as_f32x8(),
+        IMM8,
+        _mm256_setzero_ps().as_f32x8(),
+        0b11111111,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_range_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m256,
+    b: __m256,
+) -> __m256 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps256(
+        a.as_f32x8(),
+        b.as_f32x8(),
+        IMM8,
+        src.as_f32x8(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps256(
+        a.as_f32x8(),
+        b.as_f32x8(),
+        IMM8,
+        _mm256_setzero_ps().as_f32x8(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_range_ps<const IMM8: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps128(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        IMM8,
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_ps<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps128(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        IMM8,
+        src.as_f32x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangeps128(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        IMM8,
+        _mm_setzero_ps().as_f32x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_sd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_imm4!(IMM8);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst, and copy the upper element from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_range_round_sd<const IMM8: i32, const SAE: i32>(
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        0b11111111,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_range_round_sd<const IMM8: i32, const SAE: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_range_round_sd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_ss<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_imm4!(IMM8);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_range_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_range_round_ss<const IMM8: i32, const SAE: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute min, 11 = absolute max.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_range_round_ss<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm4!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_reduce_pd<const IMM8: i32>(a: __m512d) -> __m512d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_reduce_pd<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m512d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+pub unsafe fn _mm512_reduce_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d) -> __m512d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_mask_reduce_round_pd<const IMM8: i32, const SAE: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducepd(a.as_f64x8(), IMM8, src.as_f64x8(), k, SAE))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_maskz_reduce_round_pd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        _mm512_setzero_pd().as_f64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_reduce_pd<const IMM8: i32>(a: __m256d) -> __m256d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd256(
+        a.as_f64x4(),
+        IMM8,
+        _mm256_setzero_pd().as_f64x4(),
+        0b11111111,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_reduce_pd<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+) -> __m256d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd256(a.as_f64x4(), IMM8, src.as_f64x4(), k))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m256d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd256(
+        a.as_f64x4(),
+        IMM8,
+        _mm256_setzero_pd().as_f64x4(),
+        k,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_reduce_pd<const IMM8: i32>(a: __m128d) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd128(
+        a.as_f64x2(),
+        IMM8,
+        _mm_setzero_pd().as_f64x2(),
+        0b11111111,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_mask_reduce_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd128(a.as_f64x2(), IMM8, src.as_f64x2(), k))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m128d) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducepd128(
+        a.as_f64x2(),
+        IMM8,
+        _mm_setzero_pd().as_f64x2(),
+        k,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_reduce_ps<const IMM8: i32>(a: __m512) -> __m512 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        0b11111111_11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_reduce_ps<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_reduce_ps<const IMM8: i32>(k: __mmask16, a: __m512) -> __m512 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+pub unsafe fn _mm512_reduce_round_ps<const IMM8: i32, const SAE: i32>(a: __m512) -> __m512 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        0b11111111_11111111,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_mask_reduce_round_ps<const IMM8: i32, const SAE: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreduceps(a.as_f32x16(), IMM8, src.as_f32x16(), k, SAE))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_maskz_reduce_round_ps<const IMM8: i32, const SAE: i32>(
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        _mm512_setzero_ps().as_f32x16(),
+        k,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_reduce_ps<const IMM8: i32>(a: __m256) -> __m256 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps256(
+        a.as_f32x8(),
+        IMM8,
+        _mm256_setzero_ps().as_f32x8(),
+        0b11111111,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_reduce_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m256,
+) -> __m256 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps256(a.as_f32x8(), IMM8, src.as_f32x8(), k))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m256) -> __m256 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps256(
+        a.as_f32x8(),
+        IMM8,
+        _mm256_setzero_ps().as_f32x8(),
+        k,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_reduce_ps<const IMM8: i32>(a: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps128(
+        a.as_f32x4(),
+        IMM8,
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_mask_reduce_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps128(a.as_f32x4(), IMM8, src.as_f32x4(), k))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreduceps128(
+        a.as_f32x4(),
+        IMM8,
+        _mm_setzero_ps().as_f32x4(),
+        k,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper element from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_reduce_sd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        0b11111111,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_reduce_sd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_reduce_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_imm8!(IMM8);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper element from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        0b11111111,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        _mm_setzero_pd().as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_reduce_ss<const IMM8: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_reduce_ss<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_reduce_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_reduce_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11111111,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_reduce_round_ss<const IMM8: i32, const SAE: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 19, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_reduce_round_ss<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_imm8!(IMM8);
+    static_assert_sae!(SAE);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        _mm_setzero_ps().as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Shift the bits of 8-bit mask a left by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kshiftlb, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask8<const IMM8: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask8;
+    asm!(
+        "kshiftlb {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Shift the bits of 8-bit mask a right by imm8 while shifting in zeros, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kshiftrb, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask8<const IMM8: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_imm_u8!(IMM8);
+    let r: __mmask8;
+    asm!(
+        "kshiftrb {k}, {a}, {imm8}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        imm8 = const IMM8,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Add 8-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kaddb))]
+pub unsafe fn _kadd_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    let r: __mmask8;
+    asm!(
+        "kaddb {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortest_mask8_u8(a: __mmask8, b: __mmask8, all_ones: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "kortestb {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *all_ones = cf;
+    zf
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let cf: u8;
+    asm!(
+        "kortestb {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kortestb))]
+pub unsafe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let zf: u8;
+    asm!(
+        "kortestb {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktest_mask8_u8(a: __mmask8, b: __mmask8, and_not: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "ktestb {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *and_not = cf;
+    zf
+}
+
+/// Compute the bitwise NOT of 8-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let cf: u8;
+    asm!(
+        "ktestb {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestb))]
+pub unsafe fn _ktestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    let zf: u8;
+    asm!(
+        "ktestb {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Convert 8-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask8_u32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _cvtmask8_u32(a: __mmask8) -> u32 {
+    a as u32
+}
+
+/// Convert integer value a into an 8-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _cvtu32_mask8(a: u32) -> __mmask8 {
+    a as __mmask8
+}
+
+/// Add 16-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kadd_mask16)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(kaddw))]
+pub unsafe fn _kadd_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    let r: __mmask16;
+    asm!(
+        "kaddw {k}, {a}, {b}",
+        k = lateout(kreg) r,
+        a = in(kreg) a,
+        b = in(kreg) b,
+        options(pure, nomem, nostack, preserves_flags),
+    );
+    r
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktest_mask16_u8(a: __mmask16, b: __mmask16, and_not: *mut u8) -> u8 {
+    let zf: u8;
+    let cf: u8;
+    asm!(
+        "ktestw {a}, {b}",
+        "setz {zf}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    *and_not = cf;
+    zf
+}
+
+/// Compute the bitwise NOT of 16-bit mask a and then AND with b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let cf: u8;
+    asm!(
+        "ktestw {a}, {b}",
+        "setc {cf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        cf = lateout(reg_byte) cf,
+        options(pure, nomem, nostack),
+    );
+    cf
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(ktestw))]
+pub unsafe fn _ktestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    let zf: u8;
+    asm!(
+        "ktestw {a}, {b}",
+        "setz {zf}",
+        a = in(kreg) a,
+        b = in(kreg) b,
+        zf = lateout(reg_byte) zf,
+        options(pure, nomem, nostack),
+    );
+    zf
+}
+
+/// Store 8-bit mask from a into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn _store_mask8(mem_addr: *mut u8, a: __mmask8) {
+    ptr::write(mem_addr as *mut __mmask8, a);
+}
+
+/// Load 8-bit mask from memory into k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn _load_mask8(mem_addr: *const u8) -> __mmask8 {
+    ptr::read(mem_addr as *const __mmask8)
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kand_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // generate normal and code instead of kandb
+pub unsafe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a & b
+}
+
+/// Compute the bitwise NOT of 8-bit mask a, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_knot_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _knot_mask8(a: __mmask8) -> __mmask8 {
+    a ^ 0b11111111
+}
+
+/// Compute the bitwise NOT of 8-bit masks a and then AND with b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kandn_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(not))] // generate normal not code instead of kandnb
+pub unsafe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    _knot_mask8(a) & b
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))] // generate normal or code instead of korb
+pub unsafe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a | b
+}
+
+/// Compute the bitwise XOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))] // generate normal xor code instead of kxorb
+pub unsafe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a ^ b
+}
+
+/// Compute the bitwise XNOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxnor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))] // generate normal xor code instead of kxnorb
+pub unsafe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    _knot_mask8(a ^ b)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.sitofp.round.v8f64.v8i64"]
+    fn vcvtqq2pd_round(a: i64x8, rounding: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.sitofp.round.v8f32.v8i64"]
+    fn vcvtqq2ps_round(a: i64x8, rounding: i32) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtqq2ps.128"]
+    fn vcvtqq2ps128(a: i64x2, src: f32x4, mask: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.uitofp.round.v8f64.v8i64"]
+    fn vcvtuqq2pd_round(a: u64x8, rounding: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.uitofp.round.v8f32.v8i64"]
+    fn vcvtuqq2ps_round(a: u64x8, rounding: i32) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtuqq2ps.128"]
+    fn vcvtuqq2ps128(a: u64x2, src: f32x4, mask: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.512"]
+    fn vcvtpd2qq(a: f64x8, src: i64x8, mask: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.256"]
+    fn vcvtpd2qq256(a: f64x4, src: i64x4, mask: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.128"]
+    fn vcvtpd2qq128(a: f64x2, src: i64x2, mask: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.512"]
+    fn vcvtpd2uqq(a: f64x8, src: u64x8, mask: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.256"]
+    fn vcvtpd2uqq256(a: f64x4, src: u64x4, mask: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.128"]
+    fn vcvtpd2uqq128(a: f64x2, src: u64x2, mask: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.512"]
+    fn vcvtps2qq(a: f32x8, src: i64x8, mask: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.256"]
+    fn vcvtps2qq256(a: f32x4, src: i64x4, mask: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.128"]
+    fn vcvtps2qq128(a: f32x4, src: i64x2, mask: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.512"]
+    fn vcvtps2uqq(a: f32x8, src: u64x8, mask: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.256"]
+    fn vcvtps2uqq256(a: f32x4, src: u64x4, mask: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.128"]
+    fn vcvtps2uqq128(a: f32x4, src: u64x2, mask: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.512"]
+    fn vcvttpd2qq(a: f64x8, src: i64x8, mask: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.256"]
+    fn vcvttpd2qq256(a: f64x4, src: i64x4, mask: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.128"]
+    fn vcvttpd2qq128(a: f64x2, src: i64x2, mask: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.512"]
+    fn vcvttpd2uqq(a: f64x8, src: u64x8, mask: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.256"]
+    fn vcvttpd2uqq256(a: f64x4, src: u64x4, mask: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.128"]
+    fn vcvttpd2uqq128(a: f64x2, src: u64x2, mask: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.512"]
+    fn vcvttps2qq(a: f32x8, src: i64x8, mask: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.256"]
+    fn vcvttps2qq256(a: f32x4, src: i64x4, mask: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.128"]
+    fn vcvttps2qq128(a: f32x4, src: i64x2, mask: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.512"]
+    fn vcvttps2uqq(a: f32x8, src: u64x8, mask: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.256"]
+    fn vcvttps2uqq256(a: f32x4, src: u64x4, mask: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.128"]
+    fn vcvttps2uqq128(a: f32x4, src: u64x2, mask: u8) -> u64x2;
+    // LLVM upgrades the masked `fpclass` forms to `llvm.x86.avx512.fpclass.*`,
+    // whose `<N x i1>` result is then combined with the mask.
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.512"]
+    fn vfpclasspd(a: f64x8, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.256"]
+    fn vfpclasspd256(a: f64x4, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.128"]
+    fn vfpclasspd128(a: f64x2, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.512"]
+    fn vfpclassps(a: f32x16, imm8: i32, mask: u16) -> u16;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.256"]
+    fn vfpclassps256(a: f32x8, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.128"]
+    fn vfpclassps128(a: f32x4, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.sd"]
+    fn vfpclasssd(a: f64x2, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ss"]
+    fn vfpclassss(a: f32x4, imm8: i32, mask: u8) -> u8;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.512"]
+    fn vrangepd(a: f64x8, b: f64x8, imm8: i32, src: f64x8, mask: u8, sae: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.256"]
+    fn vrangepd256(a: f64x4, b: f64x4, imm8: i32, src: f64x4, mask: u8) -> f64x4;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.128"]
+    fn vrangepd128(a: f64x2, b: f64x2, imm8: i32, src: f64x2, mask: u8) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.512"]
+    fn vrangeps(a: f32x16, b: f32x16, imm8: i32, src: f32x16, mask: u16, sae: i32) -> f32x16;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.256"]
+    fn vrangeps256(a: f32x8, b: f32x8, imm8: i32, src: f32x8, mask: u8) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.128"]
+    fn vrangeps128(a: f32x4, b: f32x4, imm8: i32, src: f32x4, mask: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.range.sd"]
+    fn vrangesd(a: f64x2, b: f64x2, src: f64x2, mask: u8, imm8: i32, sae: i32) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.range.ss"]
+    fn vrangess(a: f32x4, b: f32x4, src: f32x4, mask: u8, imm8: i32, sae: i32) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.512"]
+    fn vreducepd(a: f64x8, imm8: i32, src: f64x8, mask: u8, sae: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.256"]
+    fn vreducepd256(a: f64x4, imm8: i32, src: f64x4, mask: u8) -> f64x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.128"]
+    fn vreducepd128(a: f64x2, imm8: i32, src: f64x2, mask: u8) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.512"]
+    fn vreduceps(a: f32x16, imm8: i32, src: f32x16, mask: u16, sae: i32) -> f32x16;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.256"]
+    fn vreduceps256(a: f32x8, imm8: i32, src: f32x8, mask: u8) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.128"]
+    fn vreduceps128(a: f32x4, imm8: i32, src: f32x4, mask: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.sd"]
+    fn vreducesd(a: f64x2, b: f64x2, src: f64x2, mask: u8, imm8: i32, sae: i32) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ss"]
+    fn vreducess(a: f32x4, b: f32x4, src: f32x4, mask: u8, imm8: i32, sae: i32) -> f32x4;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_pd() {
+        let a = _mm512_set1_pd(-1.);
+        let b = _mm512_setr_pd(1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5);
+        let r = _mm512_and_pd(a, b);
+        let e = _mm512_setr_pd(1., -1., 1., -1., 1., -1., 1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_pd() {
+        let a = _mm512_set1_pd(-1.);
+        let b = _mm512_setr_pd(1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5);
+        let r = _mm512_mask_and_pd(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_and_pd(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(1., -1., 1., -1., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_pd() {
+        let a = _mm512_set1_pd(-1.);
+        let b = _mm512_setr_pd(1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5);
+        let r = _mm512_maskz_and_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_and_pd(0b00001111, a, b);
+        let e = _mm512_setr_pd(1., -1., 1., -1., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_and_pd() {
+        let a = _mm256_set1_pd(-1.);
+        let b = _mm256_setr_pd(1.5, -1.5, 1.5, -1.5);
+        let r = _mm256_mask_and_pd(a, 0, a, b);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_and_pd(a, 0b00000011, a, b);
+        let e = _mm256_setr_pd(1., -1., -1., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_and_pd() {
+        let a = _mm256_set1_pd(-1.);
+        let b = _mm256_setr_pd(1.5, -1.5, 1.5, -1.5);
+        let r = _mm256_maskz_and_pd(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_and_pd(0b00000011, a, b);
+        let e = _mm256_setr_pd(1., -1., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_and_pd() {
+        let a = _mm_set1_pd(-1.);
+        let b = _mm_setr_pd(1.5, -1.5);
+        let r = _mm_mask_and_pd(a, 0, a, b);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_and_pd(a, 0b00000001, a, b);
+        let e = _mm_setr_pd(1., -1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_and_pd() {
+        let a = _mm_set1_pd(-1.);
+        let b = _mm_setr_pd(1.5, -1.5);
+        let r = _mm_maskz_and_pd(0, a, b);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_and_pd(0b00000001, a, b);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_ps() {
+        let a = _mm512_set1_ps(-1.);
+        let b = _mm512_setr_ps(
+            1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5,
+        );
+        let r = _mm512_and_ps(a, b);
+        let e = _mm512_setr_ps(
+            1., -1., 1., -1., 1., -1., 1., -1., 1., -1., 1., -1., 1., -1., 1., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_ps() {
+        let a = _mm512_set1_ps(-1.);
+        let b = _mm512_setr_ps(
+            1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5,
+        );
+        let r = _mm512_mask_and_ps(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_and_ps(a, 0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            1., -1., 1., -1., 1., -1., 1., -1., -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_ps() {
+        let a = _mm512_set1_ps(-1.);
+        let b = _mm512_setr_ps(
+            1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5,
+        );
+        let r = _mm512_maskz_and_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_and_ps(0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            1., -1., 1., -1., 1., -1., 1., -1., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_and_ps() {
+        let a = _mm256_set1_ps(-1.);
+        let b = _mm256_setr_ps(1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5);
+        let r = _mm256_mask_and_ps(a, 0, a, b);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_and_ps(a, 0b00001111, a, b);
+        let e = _mm256_setr_ps(1., -1., 1., -1., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_and_ps() {
+        let a = _mm256_set1_ps(-1.);
+        let b = _mm256_setr_ps(1.5, -1.5, 1.5, -1.5, 1.5, -1.5, 1.5, -1.5);
+        let r = _mm256_maskz_and_ps(0, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_and_ps(0b00001111, a, b);
+        let e = _mm256_setr_ps(1., -1., 1., -1., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_and_ps() {
+        let a = _mm_set1_ps(-1.);
+        let b = _mm_setr_ps(1.5, -1.5, 1.5, -1.5);
+        let r = _mm_mask_and_ps(a, 0, a, b);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_and_ps(a, 0b00000011, a, b);
+        let e = _mm_setr_ps(1., -1., -1., -1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_and_ps() {
+        let a = _mm_set1_ps(-1.);
+        let b = _mm_setr_ps(1.5, -1.5, 1.5, -1.5);
+        let r = _mm_maskz_and_ps(0, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_and_ps(0b00000011, a, b);
+        let e = _mm_setr_ps(1., -1., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_andnot_pd(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_mask_andnot_pd(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_andnot_pd(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., -0., -0., -0., -0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_maskz_andnot_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_andnot_pd(0b00001111, a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_andnot_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let r = _mm256_mask_andnot_pd(a, 0, a, b);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_andnot_pd(a, 0b00000011, a, b);
+        let e = _mm256_setr_pd(1., 2., -0., -0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_andnot_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let r = _mm256_maskz_andnot_pd(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_andnot_pd(0b00000011, a, b);
+        let e = _mm256_setr_pd(1., 2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_andnot_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let r = _mm_mask_andnot_pd(a, 0, a, b);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_andnot_pd(a, 0b00000001, a, b);
+        let e = _mm_setr_pd(1., -0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_andnot_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let r = _mm_maskz_andnot_pd(0, a, b);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_andnot_pd(0b00000001, a, b);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_andnot_ps(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_mask_andnot_ps(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_andnot_ps(a, 0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., -0., -0., -0., -0., -0., -0., -0., -0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_maskz_andnot_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_andnot_ps(0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_andnot_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm256_mask_andnot_ps(a, 0, a, b);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_andnot_ps(a, 0b00001111, a, b);
+        let e = _mm256_setr_ps(1., 2., 3., 4., -0., -0., -0., -0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_andnot_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm256_maskz_andnot_ps(0, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_andnot_ps(0b00001111, a, b);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_andnot_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let r = _mm_mask_andnot_ps(a, 0, a, b);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_andnot_ps(a, 0b00000011, a, b);
+        let e = _mm_setr_ps(1., 2., -0., -0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_andnot_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let r = _mm_maskz_andnot_ps(0, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_andnot_ps(0b00000011, a, b);
+        let e = _mm_setr_ps(1., 2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_or_pd(a, b);
+        let e = _mm512_setr_pd(-1., -2., -3., -4., -5., -6., -7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_mask_or_pd(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_or_pd(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(-1., -2., -3., -4., -0., -0., -0., -0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_maskz_or_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_or_pd(0b00001111, a, b);
+        let e = _mm512_setr_pd(-1., -2., -3., -4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_or_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_mask_or_pd(a, 0, a, b);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_or_pd(a, 0b00000011, a, b);
+        let e = _mm256_setr_pd(-1., -2., -0., -0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_or_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_maskz_or_pd(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_or_pd(0b00000011, a, b);
+        let e = _mm256_setr_pd(-1., -2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_or_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., 2.);
+        let r = _mm_mask_or_pd(a, 0, a, b);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_or_pd(a, 0b00000001, a, b);
+        let e = _mm_setr_pd(-1., -0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_or_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., 2.);
+        let r = _mm_maskz_or_pd(0, a, b);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_or_pd(0b00000001, a, b);
+        let e = _mm_setr_pd(-1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_or_ps(a, b);
+        let e = _mm512_setr_ps(
+            -1., -2., -3., -4., -5., -6., -7., -8., -9., -10., -11., -12., -13., -14., -15., -16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_mask_or_ps(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_or_ps(a, 0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            -1., -2., -3., -4., -5., -6., -7., -8., -0., -0., -0., -0., -0., -0., -0., -0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_maskz_or_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_or_ps(0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            -1., -2., -3., -4., -5., -6., -7., -8., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_or_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm256_mask_or_ps(a, 0, a, b);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_or_ps(a, 0b00001111, a, b);
+        let e = _mm256_setr_ps(-1., -2., -3., -4., -0., -0., -0., -0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_or_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm256_maskz_or_ps(0, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_or_ps(0b00001111, a, b);
+        let e = _mm256_setr_ps(-1., -2., -3., -4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_or_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm_mask_or_ps(a, 0, a, b);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_or_ps(a, 0b00000011, a, b);
+        let e = _mm_setr_ps(-1., -2., -0., -0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_or_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm_maskz_or_ps(0, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_or_ps(0b00000011, a, b);
+        let e = _mm_setr_ps(-1., -2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_xor_pd(a, b);
+        let e = _mm512_setr_pd(-1., 2., -3., 4., -5., 6., -7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_mask_xor_pd(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_xor_pd(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(-1., 2., -3., 4., -0., -0., -0., -0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_maskz_xor_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_xor_pd(0b00001111, a, b);
+        let e = _mm512_setr_pd(-1., 2., -3., 4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_xor_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let r = _mm256_mask_xor_pd(a, 0, a, b);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_xor_pd(a, 0b00000011, a, b);
+        let e = _mm256_setr_pd(-1., 2., -0., -0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_xor_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let r = _mm256_maskz_xor_pd(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_xor_pd(0b00000011, a, b);
+        let e = _mm256_setr_pd(-1., 2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_xor_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let r = _mm_mask_xor_pd(a, 0, a, b);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_xor_pd(a, 0b00000001, a, b);
+        let e = _mm_setr_pd(-1., -0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_xor_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let r = _mm_maskz_xor_pd(0, a, b);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_xor_pd(0b00000001, a, b);
+        let e = _mm_setr_pd(-1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_xor_ps(a, b);
+        let e = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -9., 10., -11., 12., -13., 14., -15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_mask_xor_ps(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_xor_ps(a, 0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -0., -0., -0., -0., -0., -0., -0., -0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_maskz_xor_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_xor_ps(0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_xor_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm256_mask_xor_ps(a, 0, a, b);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_xor_ps(a, 0b00001111, a, b);
+        let e = _mm256_setr_ps(-1., 2., -3., 4., -0., -0., -0., -0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_xor_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm256_maskz_xor_ps(0, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_xor_ps(0b00001111, a, b);
+        let e = _mm256_setr_ps(-1., 2., -3., 4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_xor_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let r = _mm_mask_xor_ps(a, 0, a, b);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_xor_ps(a, 0b00000011, a, b);
+        let e = _mm_setr_ps(-1., 2., -0., -0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_xor_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let r = _mm_maskz_xor_ps(0, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_xor_ps(0b00000011, a, b);
+        let e = _mm_setr_ps(-1., 2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_broadcast_f32x2(a);
+        let e = _mm512_setr_ps(
+            1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let src = _mm512_set1_ps(-1.);
+        let r = _mm512_mask_broadcast_f32x2(src, 0, a);
+        assert_eq_m512(r, src);
+        let r = _mm512_mask_broadcast_f32x2(src, 0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            1., 2., 1., 2., 1., 2., 1., 2., -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_maskz_broadcast_f32x2(0, a);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_broadcast_f32x2(0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            1., 2., 1., 2., 1., 2., 1., 2., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm256_broadcast_f32x2(a);
+        let e = _mm256_setr_ps(1., 2., 1., 2., 1., 2., 1., 2.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let src = _mm256_set1_ps(-1.);
+        let r = _mm256_mask_broadcast_f32x2(src, 0, a);
+        assert_eq_m256(r, src);
+        let r = _mm256_mask_broadcast_f32x2(src, 0b00001111, a);
+        let e = _mm256_setr_ps(1., 2., 1., 2., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm256_maskz_broadcast_f32x2(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_broadcast_f32x2(0b00001111, a);
+        let e = _mm256_setr_ps(1., 2., 1., 2., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_broadcast_f32x8(a);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 1., 2., 3., 4., 5., 6., 7., 8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let src = _mm512_set1_ps(-1.);
+        let r = _mm512_mask_broadcast_f32x8(src, 0, a);
+        assert_eq_m512(r, src);
+        let r = _mm512_mask_broadcast_f32x8(src, 0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_maskz_broadcast_f32x8(0, a);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_broadcast_f32x8(0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_broadcast_f64x2(a);
+        let e = _mm512_setr_pd(1., 2., 1., 2., 1., 2., 1., 2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let src = _mm512_set1_pd(-1.);
+        let r = _mm512_mask_broadcast_f64x2(src, 0, a);
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_broadcast_f64x2(src, 0b00001111, a);
+        let e = _mm512_setr_pd(1., 2., 1., 2., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_maskz_broadcast_f64x2(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_broadcast_f64x2(0b00001111, a);
+        let e = _mm512_setr_pd(1., 2., 1., 2., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm256_broadcast_f64x2(a);
+        let e = _mm256_setr_pd(1., 2., 1., 2.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let src = _mm256_set1_pd(-1.);
+        let r = _mm256_mask_broadcast_f64x2(src, 0, a);
+        assert_eq_m256d(r, src);
+        let r = _mm256_mask_broadcast_f64x2(src, 0b00000011, a);
+        let e = _mm256_setr_pd(1., 2., -1., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm256_maskz_broadcast_f64x2(0, a);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_broadcast_f64x2(0b00000011, a);
+        let e = _mm256_setr_pd(1., 2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_broadcast_i32x2(a);
+        let e = _mm512_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_broadcast_i32x2(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_broadcast_i32x2(src, 0b00000000_11111111, a);
+        let e = _mm512_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2, -1, -1, -1, -1, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_maskz_broadcast_i32x2(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_broadcast_i32x2(0b00000000_11111111, a);
+        let e = _mm512_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm256_broadcast_i32x2(a);
+        let e = _mm256_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_mask_broadcast_i32x2(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_broadcast_i32x2(src, 0b00001111, a);
+        let e = _mm256_setr_epi32(1, 2, 1, 2, -1, -1, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm256_maskz_broadcast_i32x2(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_broadcast_i32x2(0b00001111, a);
+        let e = _mm256_setr_epi32(1, 2, 1, 2, 0, 0, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm_broadcast_i32x2(a);
+        let e = _mm_setr_epi32(1, 2, 1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_mask_broadcast_i32x2(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_broadcast_i32x2(src, 0b00000011, a);
+        let e = _mm_setr_epi32(1, 2, -1, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm_maskz_broadcast_i32x2(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_broadcast_i32x2(0b00000011, a);
+        let e = _mm_setr_epi32(1, 2, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_broadcast_i32x8(a);
+        let e = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_broadcast_i32x8(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_broadcast_i32x8(src, 0b00000000_11111111, a);
+        let e = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, -1, -1, -1, -1, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_broadcast_i32x8(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_broadcast_i32x8(0b00000000_11111111, a);
+        let e = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm512_broadcast_i64x2(a);
+        let e = _mm512_setr_epi64(1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_broadcast_i64x2(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_broadcast_i64x2(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 1, 2, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm512_maskz_broadcast_i64x2(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_broadcast_i64x2(0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 1, 2, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm256_broadcast_i64x2(a);
+        let e = _mm256_setr_epi64x(1, 2, 1, 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_broadcast_i64x2(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_broadcast_i64x2(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm256_maskz_broadcast_i64x2(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_broadcast_i64x2(0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvtepi64_pd(a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm512_set1_pd(-1.);
+        let r = _mm512_mask_cvtepi64_pd(src, 0, a);
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_cvtepi64_pd(src, 0b00001111, a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvtepi64_pd(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvtepi64_pd(0b00001111, a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm512_set1_pd(-1.);
+        let r = _mm512_mask_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_pd(1., -2., 3., -4., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_pd(1., -2., 3., -4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_cvtepi64_pd(a);
+        let e = _mm256_setr_pd(1., -2., 3., -4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let src = _mm256_set1_pd(-1.);
+        let r = _mm256_mask_cvtepi64_pd(src, 0, a);
+        assert_eq_m256d(r, src);
+        let r = _mm256_mask_cvtepi64_pd(src, 0b00000011, a);
+        let e = _mm256_setr_pd(1., -2., -1., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_maskz_cvtepi64_pd(0, a);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_cvtepi64_pd(0b00000011, a);
+        let e = _mm256_setr_pd(1., -2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_cvtepi64_pd(a);
+        let e = _mm_setr_pd(1., -2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_cvtepi64_pd(src, 0, a);
+        assert_eq_m128d(r, src);
+        let r = _mm_mask_cvtepi64_pd(src, 0b00000001, a);
+        let e = _mm_setr_pd(1., -1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_maskz_cvtepi64_pd(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_cvtepi64_pd(0b00000001, a);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvtepi64_ps(a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm256_set1_ps(-1.);
+        let r = _mm512_mask_cvtepi64_ps(src, 0, a);
+        assert_eq_m256(r, src);
+        let r = _mm512_mask_cvtepi64_ps(src, 0b00001111, a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvtepi64_ps(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvtepi64_ps(0b00001111, a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm256_set1_ps(-1.);
+        let r = _mm512_mask_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m256(r, src);
+        let r = _mm512_mask_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm256_setr_ps(1., -2., 3., -4., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm256_setr_ps(1., -2., 3., -4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_cvtepi64_ps(a);
+        let e = _mm_setr_ps(1., -2., 3., -4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm256_mask_cvtepi64_ps(src, 0, a);
+        assert_eq_m128(r, src);
+        let r = _mm256_mask_cvtepi64_ps(src, 0b00000011, a);
+        let e = _mm_setr_ps(1., -2., -1., -1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_maskz_cvtepi64_ps(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm256_maskz_cvtepi64_ps(0b00000011, a);
+        let e = _mm_setr_ps(1., -2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_cvtepi64_ps(a);
+        let e = _mm_setr_ps(1., -2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_cvtepi64_ps(src, 0, a);
+        assert_eq_m128(r, _mm_setr_ps(-1., -1., 0., 0.));
+        let r = _mm_mask_cvtepi64_ps(src, 0b00000001, a);
+        let e = _mm_setr_ps(1., -1., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_maskz_cvtepi64_ps(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_cvtepi64_ps(0b00000001, a);
+        let e = _mm_setr_ps(1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvtepu64_pd(a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_set1_pd(-1.);
+        let r = _mm512_mask_cvtepu64_pd(src, 0, a);
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_cvtepu64_pd(src, 0b00001111, a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvtepu64_pd(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvtepu64_pd(0b00001111, a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_set1_pd(-1.);
+        let r = _mm512_mask_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_pd(1., 2., 3., 4., -1., -1., -1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_pd(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_cvtepu64_pd(a);
+        let e = _mm256_setr_pd(1., 2., 3., 4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm256_set1_pd(-1.);
+        let r = _mm256_mask_cvtepu64_pd(src, 0, a);
+        assert_eq_m256d(r, src);
+        let r = _mm256_mask_cvtepu64_pd(src, 0b00000011, a);
+        let e = _mm256_setr_pd(1., 2., -1., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_cvtepu64_pd(0, a);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_cvtepu64_pd(0b00000011, a);
+        let e = _mm256_setr_pd(1., 2., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_cvtepu64_pd(a);
+        let e = _mm_setr_pd(1., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_cvtepu64_pd(src, 0, a);
+        assert_eq_m128d(r, src);
+        let r = _mm_mask_cvtepu64_pd(src, 0b00000001, a);
+        let e = _mm_setr_pd(1., -1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_maskz_cvtepu64_pd(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_cvtepu64_pd(0b00000001, a);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvtepu64_ps(a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm256_set1_ps(-1.);
+        let r = _mm512_mask_cvtepu64_ps(src, 0, a);
+        assert_eq_m256(r, src);
+        let r = _mm512_mask_cvtepu64_ps(src, 0b00001111, a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvtepu64_ps(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvtepu64_ps(0b00001111, a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm256_set1_ps(-1.);
+        let r = _mm512_mask_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m256(r, src);
+        let r = _mm512_mask_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm256_setr_ps(1., 2., 3., 4., -1., -1., -1., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm256_setr_ps(1., 2., 3., 4., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_cvtepu64_ps(a);
+        let e = _mm_setr_ps(1., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm256_mask_cvtepu64_ps(src, 0, a);
+        assert_eq_m128(r, src);
+        let r = _mm256_mask_cvtepu64_ps(src, 0b00000011, a);
+        let e = _mm_setr_ps(1., 2., -1., -1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_cvtepu64_ps(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm256_maskz_cvtepu64_ps(0b00000011, a);
+        let e = _mm_setr_ps(1., 2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_cvtepu64_ps(a);
+        let e = _mm_setr_ps(1., 2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_cvtepu64_ps(src, 0, a);
+        assert_eq_m128(r, _mm_setr_ps(-1., -1., 0., 0.));
+        let r = _mm_mask_cvtepu64_ps(src, 0b00000001, a);
+        let e = _mm_setr_ps(1., -1., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_maskz_cvtepu64_ps(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_cvtepu64_ps(0b00000001, a);
+        let e = _mm_setr_ps(1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtpd_epi64(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtpd_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtpd_epi64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtpd_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtpd_epi64(0b00001111, a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, -2, 4, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvtpd_epi64(a);
+        let e = _mm256_setr_epi64x(2, -2, 4, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvtpd_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtpd_epi64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(2, -2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvtpd_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtpd_epi64(0b00000011, a);
+        let e = _mm256_setr_epi64x(2, -2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_cvtpd_epi64(a);
+        let e = _mm_setr_epi64x(2, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvtpd_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtpd_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(2, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_maskz_cvtpd_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtpd_epi64(0b00000001, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtpd_epu64(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtpd_epu64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtpd_epu64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtpd_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtpd_epu64(0b00001111, a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, 2, 4, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvtpd_epu64(a);
+        let e = _mm256_setr_epi64x(2, 2, 4, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvtpd_epu64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtpd_epu64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(2, 2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvtpd_epu64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtpd_epu64(0b00000011, a);
+        let e = _mm256_setr_epi64x(2, 2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_cvtpd_epu64(a);
+        let e = _mm_set1_epi64x(2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvtpd_epu64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtpd_epu64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(2, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_maskz_cvtpd_epu64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtpd_epu64(0b00000001, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtps_epi64(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtps_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtps_epi64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtps_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtps_epi64(0b00001111, a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, -2, 4, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvtps_epi64(a);
+        let e = _mm256_setr_epi64x(2, -2, 4, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvtps_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtps_epi64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(2, -2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvtps_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtps_epi64(0b00000011, a);
+        let e = _mm256_setr_epi64x(2, -2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_cvtps_epi64(a);
+        let e = _mm_setr_epi64x(2, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvtps_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtps_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(2, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_maskz_cvtps_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtps_epi64(0b00000001, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtps_epu64(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtps_epu64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtps_epu64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtps_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtps_epu64(0b00001111, a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0, a,
+        );
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, 2, 4, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0, a,
+        );
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvtps_epu64(a);
+        let e = _mm256_setr_epi64x(2, 2, 4, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvtps_epu64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtps_epu64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(2, 2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvtps_epu64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtps_epu64(0b00000011, a);
+        let e = _mm256_setr_epi64x(2, 2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_cvtps_epu64(a);
+        let e = _mm_set1_epi64x(2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvtps_epu64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtps_epu64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(2, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_maskz_cvtps_epu64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtps_epu64(0b00000001, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvttpd_epi64(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvttpd_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvttpd_epi64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvttpd_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttpd_epi64(0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtt_roundpd_epi64::<_MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtt_roundpd_epi64::<_MM_FROUND_NO_EXC>(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtt_roundpd_epi64::<_MM_FROUND_NO_EXC>(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtt_roundpd_epi64::<_MM_FROUND_NO_EXC>(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtt_roundpd_epi64::<_MM_FROUND_NO_EXC>(0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvttpd_epi64(a);
+        let e = _mm256_setr_epi64x(1, -2, 3, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvttpd_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvttpd_epi64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(1, -2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvttpd_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvttpd_epi64(0b00000011, a);
+        let e = _mm256_setr_epi64x(1, -2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_cvttpd_epi64(a);
+        let e = _mm_setr_epi64x(1, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvttpd_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvttpd_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(1, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_maskz_cvttpd_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvttpd_epi64(0b00000001, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvttpd_epu64(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvttpd_epu64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvttpd_epu64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvttpd_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttpd_epu64(0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtt_roundpd_epu64::<_MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtt_roundpd_epu64::<_MM_FROUND_NO_EXC>(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtt_roundpd_epu64::<_MM_FROUND_NO_EXC>(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtt_roundpd_epu64::<_MM_FROUND_NO_EXC>(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtt_roundpd_epu64::<_MM_FROUND_NO_EXC>(0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvttpd_epu64(a);
+        let e = _mm256_setr_epi64x(1, 2, 3, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvttpd_epu64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvttpd_epu64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvttpd_epu64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvttpd_epu64(0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_cvttpd_epu64(a);
+        let e = _mm_setr_epi64x(1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvttpd_epu64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvttpd_epu64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(1, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_maskz_cvttpd_epu64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvttpd_epu64(0b00000001, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvttps_epi64(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvttps_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvttps_epi64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvttps_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttps_epi64(0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtt_roundps_epi64::<_MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtt_roundps_epi64::<_MM_FROUND_NO_EXC>(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtt_roundps_epi64::<_MM_FROUND_NO_EXC>(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtt_roundps_epi64::<_MM_FROUND_NO_EXC>(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtt_roundps_epi64::<_MM_FROUND_NO_EXC>(0b00001111, a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvttps_epi64(a);
+        let e = _mm256_setr_epi64x(1, -2, 3, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvttps_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvttps_epi64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(1, -2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvttps_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvttps_epi64(0b00000011, a);
+        let e = _mm256_setr_epi64x(1, -2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_cvttps_epi64(a);
+        let e = _mm_setr_epi64x(1, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvttps_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvttps_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(1, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_maskz_cvttps_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvttps_epi64(0b00000001, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvttps_epu64(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvttps_epu64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvttps_epu64(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvttps_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttps_epu64(0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtt_roundps_epu64::<_MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_cvtt_roundps_epu64::<_MM_FROUND_NO_EXC>(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtt_roundps_epu64::<_MM_FROUND_NO_EXC>(src, 0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, -1, -1, -1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtt_roundps_epu64::<_MM_FROUND_NO_EXC>(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtt_roundps_epu64::<_MM_FROUND_NO_EXC>(0b00001111, a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvttps_epu64(a);
+        let e = _mm256_setr_epi64x(1, 2, 3, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_cvttps_epu64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvttps_epu64(src, 0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvttps_epu64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvttps_epu64(0b00000011, a);
+        let e = _mm256_setr_epi64x(1, 2, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_cvttps_epu64(a);
+        let e = _mm_setr_epi64x(1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_cvttps_epu64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvttps_epu64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(1, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_maskz_cvttps_epu64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvttps_epu64(0b00000001, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mullo_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm512_setr_epi64(2, -3, 4, -5, 6, -7, 8, -9);
+        let r = _mm512_mullo_epi64(a, b);
+        let e = _mm512_setr_epi64(2, -6, 12, -20, 30, -42, 56, -72);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_mullo_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm512_setr_epi64(2, -3, 4, -5, 6, -7, 8, -9);
+        let r = _mm512_mask_mullo_epi64(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_mullo_epi64(a, 0b00001111, a, b);
+        let e = _mm512_setr_epi64(2, -6, 12, -20, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_mullo_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm512_setr_epi64(2, -3, 4, -5, 6, -7, 8, -9);
+        let r = _mm512_maskz_mullo_epi64(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mullo_epi64(0b00001111, a, b);
+        let e = _mm512_setr_epi64(2, -6, 12, -20, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mullo_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm256_setr_epi64x(2, -3, 4, -5);
+        let r = _mm256_mullo_epi64(a, b);
+        let e = _mm256_setr_epi64x(2, -6, 12, -20);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_mullo_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm256_setr_epi64x(2, -3, 4, -5);
+        let r = _mm256_mask_mullo_epi64(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_mullo_epi64(a, 0b00000011, a, b);
+        let e = _mm256_setr_epi64x(2, -6, 3, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_mullo_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm256_setr_epi64x(2, -3, 4, -5);
+        let r = _mm256_maskz_mullo_epi64(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_mullo_epi64(0b00000011, a, b);
+        let e = _mm256_setr_epi64x(2, -6, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mullo_epi64() {
+        let a = _mm_setr_epi64x(1, 2);
+        let b = _mm_setr_epi64x(2, -3);
+        let r = _mm_mullo_epi64(a, b);
+        let e = _mm_setr_epi64x(2, -6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_mullo_epi64() {
+        let a = _mm_setr_epi64x(1, 2);
+        let b = _mm_setr_epi64x(2, -3);
+        let r = _mm_mask_mullo_epi64(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_mullo_epi64(a, 0b00000001, a, b);
+        let e = _mm_set1_epi64x(2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_mullo_epi64() {
+        let a = _mm_setr_epi64x(1, 2);
+        let b = _mm_setr_epi64x(2, -3);
+        let r = _mm_maskz_mullo_epi64(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_mullo_epi64(0b00000001, a, b);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_extractf32x8_ps::<1>(a);
+        let e = _mm256_setr_ps(9., 10., 11., 12., 13., 14., 15., 16.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let src = _mm256_set1_ps(100.);
+        let r = _mm512_mask_extractf32x8_ps::<1>(src, 0, a);
+        assert_eq_m256(r, src);
+        let r = _mm512_mask_extractf32x8_ps::<1>(src, 0b00001111, a);
+        let e = _mm256_setr_ps(9., 10., 11., 12., 100., 100., 100., 100.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_maskz_extractf32x8_ps::<1>(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_extractf32x8_ps::<1>(0b00001111, a);
+        let e = _mm256_setr_ps(9., 10., 11., 12., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_extractf64x2_pd::<3>(a);
+        let e = _mm_setr_pd(7., 8.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let src = _mm_set1_pd(100.);
+        let r = _mm512_mask_extractf64x2_pd::<3>(src, 0, a);
+        assert_eq_m128d(r, src);
+        let r = _mm512_mask_extractf64x2_pd::<3>(src, 0b00000001, a);
+        let e = _mm_setr_pd(7., 100.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_maskz_extractf64x2_pd::<3>(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm512_maskz_extractf64x2_pd::<3>(0b00000001, a);
+        let e = _mm_setr_pd(7., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_extractf64x2_pd::<1>(a);
+        let e = _mm_setr_pd(3., 4.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let src = _mm_set1_pd(100.);
+        let r = _mm256_mask_extractf64x2_pd::<1>(src, 0, a);
+        assert_eq_m128d(r, src);
+        let r = _mm256_mask_extractf64x2_pd::<1>(src, 0b00000001, a);
+        let e = _mm_setr_pd(3., 100.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_maskz_extractf64x2_pd::<1>(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm256_maskz_extractf64x2_pd::<1>(0b00000001, a);
+        let e = _mm_setr_pd(3., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm512_extracti32x8_epi32::<1>(a);
+        let e = _mm256_setr_epi32(9, 10, 11, 12, 13, 14, 15, 16);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let src = _mm256_set1_epi32(100);
+        let r = _mm512_mask_extracti32x8_epi32::<1>(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm512_mask_extracti32x8_epi32::<1>(src, 0b00001111, a);
+        let e = _mm256_setr_epi32(9, 10, 11, 12, 100, 100, 100, 100);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm512_maskz_extracti32x8_epi32::<1>(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm512_maskz_extracti32x8_epi32::<1>(0b00001111, a);
+        let e = _mm256_setr_epi32(9, 10, 11, 12, 0, 0, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_extracti64x2_epi64::<3>(a);
+        let e = _mm_setr_epi64x(7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm_set1_epi64x(100);
+        let r = _mm512_mask_extracti64x2_epi64::<3>(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm512_mask_extracti64x2_epi64::<3>(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(7, 100);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_extracti64x2_epi64::<3>(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm512_maskz_extracti64x2_epi64::<3>(0b00000001, a);
+        let e = _mm_setr_epi64x(7, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_extracti64x2_epi64::<1>(a);
+        let e = _mm_setr_epi64x(3, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm_set1_epi64x(100);
+        let r = _mm256_mask_extracti64x2_epi64::<1>(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm256_mask_extracti64x2_epi64::<1>(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(3, 100);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_extracti64x2_epi64::<1>(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm256_maskz_extracti64x2_epi64::<1>(0b00000001, a);
+        let e = _mm_setr_epi64x(3, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(-1., -2., -3., -4., -5., -6., -7., -8.);
+        let r = _mm512_insertf32x8::<1>(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., -1., -2., -3., -4., -5., -6., -7., -8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(-1., -2., -3., -4., -5., -6., -7., -8.);
+        let src = _mm512_set1_ps(100.);
+        let r = _mm512_mask_insertf32x8::<1>(src, 0, a, b);
+        assert_eq_m512(r, src);
+        let r = _mm512_mask_insertf32x8::<1>(src, 0b11111111_11110000, a, b);
+        let e = _mm512_setr_ps(
+            100., 100., 100., 100., 5., 6., 7., 8., -1., -2., -3., -4., -5., -6., -7., -8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(-1., -2., -3., -4., -5., -6., -7., -8.);
+        let r = _mm512_maskz_insertf32x8::<1>(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_insertf32x8::<1>(0b11111111_11110000, a, b);
+        let e = _mm512_setr_ps(
+            0., 0., 0., 0., 5., 6., 7., 8., -1., -2., -3., -4., -5., -6., -7., -8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm512_insertf64x2::<3>(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., -1., -2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(-1., -2.);
+        let src = _mm512_set1_pd(100.);
+        let r = _mm512_mask_insertf64x2::<3>(src, 0, a, b);
+        assert_eq_m512d(r, src);
+        let r = _mm512_mask_insertf64x2::<3>(src, 0b11111100, a, b);
+        let e = _mm512_setr_pd(100., 100., 3., 4., 5., 6., -1., -2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm512_maskz_insertf64x2::<3>(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_insertf64x2::<3>(0b11111100, a, b);
+        let e = _mm512_setr_pd(0., 0., 3., 4., 5., 6., -1., -2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm256_insertf64x2::<1>(a, b);
+        let e = _mm256_setr_pd(1., 2., -1., -2.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(-1., -2.);
+        let src = _mm256_set1_pd(100.);
+        let r = _mm256_mask_insertf64x2::<1>(src, 0, a, b);
+        assert_eq_m256d(r, src);
+        let r = _mm256_mask_insertf64x2::<1>(src, 0b00001110, a, b);
+        let e = _mm256_setr_pd(100., 2., -1., -2.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm256_maskz_insertf64x2::<1>(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_insertf64x2::<1>(0b00001110, a, b);
+        let e = _mm256_setr_pd(0., 2., -1., -2.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm512_inserti32x8::<1>(a, b);
+        let e = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, -1, -2, -3, -4, -5, -6, -7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(-1, -2, -3, -4, -5, -6, -7, -8);
+        let src = _mm512_set1_epi32(100);
+        let r = _mm512_mask_inserti32x8::<1>(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_inserti32x8::<1>(src, 0b11111111_11110000, a, b);
+        let e = _mm512_setr_epi32(
+            100, 100, 100, 100, 5, 6, 7, 8, -1, -2, -3, -4, -5, -6, -7, -8,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm512_maskz_inserti32x8::<1>(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_inserti32x8::<1>(0b11111111_11110000, a, b);
+        let e = _mm512_setr_epi32(0, 0, 0, 0, 5, 6, 7, 8, -1, -2, -3, -4, -5, -6, -7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(-1, -2);
+        let r = _mm512_inserti64x2::<3>(a, b);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, -1, -2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(-1, -2);
+        let src = _mm512_set1_epi64(100);
+        let r = _mm512_mask_inserti64x2::<3>(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_inserti64x2::<3>(src, 0b11111100, a, b);
+        let e = _mm512_setr_epi64(100, 100, 3, 4, 5, 6, -1, -2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(-1, -2);
+        let r = _mm512_maskz_inserti64x2::<3>(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_inserti64x2::<3>(0b11111100, a, b);
+        let e = _mm512_setr_epi64(0, 0, 3, 4, 5, 6, -1, -2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(-1, -2);
+        let r = _mm256_inserti64x2::<1>(a, b);
+        let e = _mm256_setr_epi64x(1, 2, -1, -2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(-1, -2);
+        let src = _mm256_set1_epi64x(100);
+        let r = _mm256_mask_inserti64x2::<1>(src, 0, a, b);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_inserti64x2::<1>(src, 0b00001110, a, b);
+        let e = _mm256_setr_epi64x(100, 2, -1, -2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(-1, -2);
+        let r = _mm256_maskz_inserti64x2::<1>(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_inserti64x2::<1>(0b00001110, a, b);
+        let e = _mm256_setr_epi64x(0, 2, -1, -2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_pd_mask() {
+        let a = _mm512_setr_pd(
+            1.,
+            f64::INFINITY,
+            -0.,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm512_fpclass_pd_mask::<0x19>(a);
+        let e: __mmask8 = 0b00011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_pd_mask() {
+        let a = _mm512_setr_pd(
+            1.,
+            f64::INFINITY,
+            -0.,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm512_mask_fpclass_pd_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm512_mask_fpclass_pd_mask::<0x19>(0b00001111, a);
+        let e: __mmask8 = 0b00001010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_fpclass_pd_mask() {
+        let a = _mm256_setr_pd(f64::NEG_INFINITY, 1., f64::NAN, -0.);
+        let r = _mm256_fpclass_pd_mask::<0x19>(a);
+        let e: __mmask8 = 0b00000101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_fpclass_pd_mask() {
+        let a = _mm256_setr_pd(f64::NEG_INFINITY, 1., f64::NAN, -0.);
+        let r = _mm256_mask_fpclass_pd_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm256_mask_fpclass_pd_mask::<0x19>(0b00000011, a);
+        let e: __mmask8 = 0b00000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_fpclass_pd_mask() {
+        let a = _mm_setr_pd(f64::INFINITY, 1.);
+        let r = _mm_fpclass_pd_mask::<0x19>(a);
+        let e: __mmask8 = 0b00000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_fpclass_pd_mask() {
+        let a = _mm_setr_pd(f64::INFINITY, 1.);
+        let r = _mm_mask_fpclass_pd_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm_mask_fpclass_pd_mask::<0x19>(0b00000001, a);
+        let e: __mmask8 = 0b00000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_ps_mask() {
+        let a = _mm512_setr_ps(
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm512_fpclass_ps_mask::<0x19>(a);
+        let e: __mmask16 = 0b00011010_00011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_ps_mask() {
+        let a = _mm512_setr_ps(
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm512_mask_fpclass_ps_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm512_mask_fpclass_ps_mask::<0x19>(0b00000000_11111111, a);
+        let e: __mmask16 = 0b00000000_00011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_fpclass_ps_mask() {
+        let a = _mm256_setr_ps(
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm256_fpclass_ps_mask::<0x19>(a);
+        let e: __mmask8 = 0b00011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_fpclass_ps_mask() {
+        let a = _mm256_setr_ps(
+            1.,
+            f32::INFINITY,
+            -0.,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -1.,
+            0.,
+            2.,
+        );
+        let r = _mm256_mask_fpclass_ps_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm256_mask_fpclass_ps_mask::<0x19>(0b00001111, a);
+        let e: __mmask8 = 0b00001010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_fpclass_ps_mask() {
+        let a = _mm_setr_ps(f32::NEG_INFINITY, 1., f32::NAN, -0.);
+        let r = _mm_fpclass_ps_mask::<0x19>(a);
+        let e: __mmask8 = 0b00000101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_fpclass_ps_mask() {
+        let a = _mm_setr_ps(f32::NEG_INFINITY, 1., f32::NAN, -0.);
+        let r = _mm_mask_fpclass_ps_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm_mask_fpclass_ps_mask::<0x19>(0b00000011, a);
+        let e: __mmask8 = 0b00000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_fpclass_sd_mask() {
+        let a = _mm_setr_pd(f64::NEG_INFINITY, 1.);
+        let r = _mm_fpclass_sd_mask::<0x19>(a);
+        assert_eq!(r, 0b1);
+        let r = _mm_fpclass_sd_mask::<0x06>(a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_fpclass_sd_mask() {
+        let a = _mm_setr_pd(f64::NEG_INFINITY, 1.);
+        let r = _mm_mask_fpclass_sd_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm_mask_fpclass_sd_mask::<0x19>(0b1, a);
+        assert_eq!(r, 0b1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_fpclass_ss_mask() {
+        let a = _mm_setr_ps(f32::NEG_INFINITY, 1., 1., 1.);
+        let r = _mm_fpclass_ss_mask::<0x19>(a);
+        assert_eq!(r, 0b1);
+        let r = _mm_fpclass_ss_mask::<0x06>(a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_fpclass_ss_mask() {
+        let a = _mm_setr_ps(f32::NEG_INFINITY, 1., 1., 1.);
+        let r = _mm_mask_fpclass_ss_mask::<0x19>(0, a);
+        assert_eq!(r, 0);
+        let r = _mm_mask_fpclass_ss_mask::<0x19>(0b1, a);
+        assert_eq!(r, 0b1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi32_mask() {
+        let a = _mm512_setr_epi32(
+            -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16,
+        );
+        let r = _mm512_movepi32_mask(a);
+        let e: __mmask16 = 0b01010101_01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi32() {
+        let a: __mmask16 = 0b00000000_11111111;
+        let r = _mm512_movm_epi32(a);
+        let e = _mm512_setr_epi32(-1, -1, -1, -1, -1, -1, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movepi32_mask() {
+        let a = _mm256_setr_epi32(-1, 2, -3, 4, -5, 6, -7, 8);
+        let r = _mm256_movepi32_mask(a);
+        let e: __mmask8 = 0b01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movm_epi32() {
+        let a: __mmask8 = 0b00001111;
+        let r = _mm256_movm_epi32(a);
+        let e = _mm256_setr_epi32(-1, -1, -1, -1, 0, 0, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movepi32_mask() {
+        let a = _mm_setr_epi32(-1, 2, -3, 4);
+        let r = _mm_movepi32_mask(a);
+        let e: __mmask8 = 0b00000101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movm_epi32() {
+        let a: __mmask8 = 0b00000011;
+        let r = _mm_movm_epi32(a);
+        let e = _mm_setr_epi32(-1, -1, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi64_mask() {
+        let a = _mm512_setr_epi64(-1, 2, -3, 4, -5, 6, -7, 8);
+        let r = _mm512_movepi64_mask(a);
+        let e: __mmask8 = 0b01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi64() {
+        let a: __mmask8 = 0b00001111;
+        let r = _mm512_movm_epi64(a);
+        let e = _mm512_setr_epi64(-1, -1, -1, -1, 0, 0, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movepi64_mask() {
+        let a = _mm256_setr_epi64x(-1, 2, -3, 4);
+        let r = _mm256_movepi64_mask(a);
+        let e: __mmask8 = 0b00000101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movm_epi64() {
+        let a: __mmask8 = 0b00000011;
+        let r = _mm256_movm_epi64(a);
+        let e = _mm256_setr_epi64x(-1, -1, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movepi64_mask() {
+        let a = _mm_setr_epi64x(-1, 2);
+        let r = _mm_movepi64_mask(a);
+        let e: __mmask8 = 0b00000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movm_epi64() {
+        let a: __mmask8 = 0b00000001;
+        let r = _mm_movm_epi64(a);
+        let e = _mm_setr_epi64x(-1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_range_pd::<0b0101>(a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 5., 3., 7., 1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_mask_range_pd::<0b0101>(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_range_pd::<0b0101>(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_maskz_range_pd::<0b0101>(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_range_pd::<0b0101>(0b00001111, a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_round_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_range_round_pd::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 5., 3., 7., 1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_round_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_mask_range_round_pd::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, 0, a, b);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_range_round_pd::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, 0b00001111, a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_round_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm512_maskz_range_round_pd::<0b0101, _MM_FROUND_CUR_DIRECTION>(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_range_round_pd::<0b0101, _MM_FROUND_CUR_DIRECTION>(0b00001111, a, b);
+        let e = _mm512_setr_pd(8., 7., 6., 5., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_range_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_setr_pd(4., 3., 2., 1.);
+        let r = _mm256_range_pd::<0b0101>(a, b);
+        let e = _mm256_setr_pd(4., 3., 3., 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_range_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_setr_pd(4., 3., 2., 1.);
+        let r = _mm256_mask_range_pd::<0b0101>(a, 0, a, b);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_range_pd::<0b0101>(a, 0b00000011, a, b);
+        let e = _mm256_setr_pd(4., 3., 3., -4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_range_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_setr_pd(4., 3., 2., 1.);
+        let r = _mm256_maskz_range_pd::<0b0101>(0, a, b);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_range_pd::<0b0101>(0b00000011, a, b);
+        let e = _mm256_setr_pd(4., 3., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_range_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_setr_pd(2., 1.);
+        let r = _mm_range_pd::<0b0101>(a, b);
+        let e = _mm_setr_pd(2., 1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_range_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_setr_pd(2., 1.);
+        let r = _mm_mask_range_pd::<0b0101>(a, 0, a, b);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_range_pd::<0b0101>(a, 0b00000001, a, b);
+        let e = _mm_setr_pd(2., -2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_range_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_setr_pd(2., 1.);
+        let r = _mm_maskz_range_pd::<0b0101>(0, a, b);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_range_pd::<0b0101>(0b00000001, a, b);
+        let e = _mm_setr_pd(2., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_range_ps::<0b0101>(a, b);
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 9., 7., 11., 5., 13., 3., 15., 1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_mask_range_ps::<0b0101>(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_range_ps::<0b0101>(a, 0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_maskz_range_ps::<0b0101>(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_range_ps::<0b0101>(0b00000000_11111111, a, b);
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_range_round_ps::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 9., 7., 11., 5., 13., 3., 15., 1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_mask_range_round_ps::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, 0, a, b);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_range_round_ps::<0b0101, _MM_FROUND_CUR_DIRECTION>(
+            a,
+            0b00000000_11111111,
+            a,
+            b,
+        );
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 8., 7., 6., 5., 4., 3., 2., 1.,
+        );
+        let r = _mm512_maskz_range_round_ps::<0b0101, _MM_FROUND_CUR_DIRECTION>(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_range_round_ps::<0b0101, _MM_FROUND_CUR_DIRECTION>(
+            0b00000000_11111111,
+            a,
+            b,
+        );
+        let e = _mm512_setr_ps(
+            16., 15., 14., 13., 12., 11., 10., 9., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_range_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_setr_ps(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm256_range_ps::<0b0101>(a, b);
+        let e = _mm256_setr_ps(8., 7., 6., 5., 5., 3., 7., 1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_range_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_setr_ps(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm256_mask_range_ps::<0b0101>(a, 0, a, b);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_range_ps::<0b0101>(a, 0b00001111, a, b);
+        let e = _mm256_setr_ps(8., 7., 6., 5., 5., -6., 7., -8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_range_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_setr_ps(8., 7., 6., 5., 4., 3., 2., 1.);
+        let r = _mm256_maskz_range_ps::<0b0101>(0, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_range_ps::<0b0101>(0b00001111, a, b);
+        let e = _mm256_setr_ps(8., 7., 6., 5., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_range_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_setr_ps(4., 3., 2., 1.);
+        let r = _mm_range_ps::<0b0101>(a, b);
+        let e = _mm_setr_ps(4., 3., 3., 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_range_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_setr_ps(4., 3., 2., 1.);
+        let r = _mm_mask_range_ps::<0b0101>(a, 0, a, b);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_range_ps::<0b0101>(a, 0b00000011, a, b);
+        let e = _mm_setr_ps(4., 3., 3., -4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_range_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_setr_ps(4., 3., 2., 1.);
+        let r = _mm_maskz_range_ps::<0b0101>(0, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_range_ps::<0b0101>(0b00000011, a, b);
+        let e = _mm_setr_ps(4., 3., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_range_sd::<0b0101>(src, 0, a, b);
+        let e = _mm_setr_pd(-1., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_mask_range_sd::<0b0101>(src, 0b11111111, a, b);
+        let e = _mm_setr_pd(10.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_maskz_range_sd::<0b0101>(0, a, b);
+        let e = _mm_setr_pd(0., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_maskz_range_sd::<0b0101>(0b11111111, a, b);
+        let e = _mm_setr_pd(10.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_range_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_range_round_sd::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm_setr_pd(10.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_range_round_sd::<0b0101, _MM_FROUND_CUR_DIRECTION>(src, 0, a, b);
+        let e = _mm_setr_pd(-1., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_mask_range_round_sd::<0b0101, _MM_FROUND_CUR_DIRECTION>(src, 0b11111111, a, b);
+        let e = _mm_setr_pd(10.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_maskz_range_round_sd::<0b0101, _MM_FROUND_CUR_DIRECTION>(0, a, b);
+        let e = _mm_setr_pd(0., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_maskz_range_round_sd::<0b0101, _MM_FROUND_CUR_DIRECTION>(0b11111111, a, b);
+        let e = _mm_setr_pd(10.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_range_ss::<0b0101>(src, 0, a, b);
+        let e = _mm_setr_ps(-1., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_mask_range_ss::<0b0101>(src, 0b11111111, a, b);
+        let e = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_maskz_range_ss::<0b0101>(0, a, b);
+        let e = _mm_setr_ps(0., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_maskz_range_ss::<0b0101>(0b11111111, a, b);
+        let e = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_range_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_range_round_ss::<0b0101, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_range_round_ss::<0b0101, _MM_FROUND_CUR_DIRECTION>(src, 0, a, b);
+        let e = _mm_setr_ps(-1., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_mask_range_round_ss::<0b0101, _MM_FROUND_CUR_DIRECTION>(src, 0b11111111, a, b);
+        let e = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_maskz_range_round_ss::<0b0101, _MM_FROUND_CUR_DIRECTION>(0, a, b);
+        let e = _mm_setr_ps(0., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_maskz_range_round_ss::<0b0101, _MM_FROUND_CUR_DIRECTION>(0b11111111, a, b);
+        let e = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00001111, a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 1.25, 1.5, 1.75, 2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00001111, a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_mask_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            a, 0, a,
+        );
+        assert_eq_m512d(r, a);
+        let r = _mm512_mask_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            a, 0b00001111, a,
+        );
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 1.25, 1.5, 1.75, 2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_maskz_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0, a,
+        );
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0b00001111, a,
+        );
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0., 0., 0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let r = _mm256_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm256_setr_pd(0.25, 0., 0.25, 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let r = _mm256_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m256d(r, a);
+        let r = _mm256_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00000011, a);
+        let e = _mm256_setr_pd(0.25, 0., 0.75, 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let r = _mm256_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00000011, a);
+        let e = _mm256_setr_pd(0.25, 0., 0., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let r = _mm_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm_setr_pd(0.25, 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let r = _mm_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m128d(r, a);
+        let r = _mm_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00000001, a);
+        let e = _mm_setr_pd(0.25, 0.5);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let r = _mm_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00000001, a);
+        let e = _mm_setr_pd(0.25, 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00000000_11111111, a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_mask_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            a, 0, a,
+        );
+        assert_eq_m512(r, a);
+        let r = _mm512_mask_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            a,
+            0b00000000_11111111,
+            a,
+        );
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_maskz_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0, a,
+        );
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0b00000000_11111111,
+            a,
+        );
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0., 0., 0., 0., 0., 0., 0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm256_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm256_setr_ps(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm256_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m256(r, a);
+        let r = _mm256_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00001111, a);
+        let e = _mm256_setr_ps(0.25, 0., 0.25, 0., 1.25, 1.5, 1.75, 2.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm256_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00001111, a);
+        let e = _mm256_setr_ps(0.25, 0., 0.25, 0., 0., 0., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let r = _mm_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm_setr_ps(0.25, 0., 0.25, 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let r = _mm_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0, a);
+        assert_eq_m128(r, a);
+        let r = _mm_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a, 0b00000011, a);
+        let e = _mm_setr_ps(0.25, 0., 0.75, 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let r = _mm_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b00000011, a);
+        let e = _mm_setr_ps(0.25, 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, b);
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0, a, b);
+        let e = _mm_setr_pd(-1., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_mask_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b11111111, a, b);
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_maskz_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a, b);
+        let e = _mm_setr_pd(0., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_maskz_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b11111111, a, b);
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let src = _mm_set1_pd(-1.);
+        let r = _mm_mask_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            src, 0, a, b,
+        );
+        let e = _mm_setr_pd(-1., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_mask_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            src, 0b11111111, a, b,
+        );
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_round_sd() {
+        let a = _mm_setr_pd(10.25, 20.25);
+        let b = _mm_setr_pd(0.75, 1.75);
+        let r = _mm_maskz_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0, a, b,
+        );
+        let e = _mm_setr_pd(0., 20.25);
+        assert_eq_m128d(r, e);
+        let r = _mm_maskz_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0b11111111, a, b,
+        );
+        let e = _mm_setr_pd(0.25, 20.25);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(a, b);
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0, a, b);
+        let e = _mm_setr_ps(-1., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_mask_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b11111111, a, b);
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_maskz_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(0, a, b);
+        let e = _mm_setr_ps(0., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_maskz_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(0b11111111, a, b);
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(a, b);
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let src = _mm_set1_ps(-1.);
+        let r = _mm_mask_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            src, 0, a, b,
+        );
+        let e = _mm_setr_ps(-1., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_mask_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            src, 0b11111111, a, b,
+        );
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_round_ss() {
+        let a = _mm_setr_ps(10.25, 20.25, 30.25, 40.25);
+        let b = _mm_setr_ps(0.75, 1.75, 2.75, 3.75);
+        let r = _mm_maskz_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0, a, b,
+        );
+        let e = _mm_setr_ps(0., 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+        let r = _mm_maskz_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_CUR_DIRECTION>(
+            0b11111111, a, b,
+        );
+        let e = _mm_setr_ps(0.25, 20.25, 30.25, 40.25);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftli_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let r = _kshiftli_mask8::<3>(a);
+        let e: __mmask8 = 0b01001000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask8::<7>(a);
+        let e: __mmask8 = 0b10000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask8::<8>(a);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftri_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let r = _kshiftri_mask8::<3>(a);
+        let e: __mmask8 = 0b00001101;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask8::<7>(a);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask8::<8>(a);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kadd_mask8(a, b);
+        let e: __mmask8 = 0b00011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortest_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask8_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask8_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let a: __mmask8 = 0b00000000;
+        let b: __mmask8 = 0b00000000;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask8_u8(a, b, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestc_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kortestc_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let r = _kortestc_mask8_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask8 = 0b00000000;
+        let b: __mmask8 = 0b00000000;
+        let r = _kortestc_mask8_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestz_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kortestz_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let r = _kortestz_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b00000000;
+        let b: __mmask8 = 0b00000000;
+        let r = _kortestz_mask8_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask8_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask8_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b01101001;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask8_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _ktestc_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let r = _ktestc_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b01101001;
+        let r = _ktestc_mask8_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask8_u8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _ktestz_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10010110;
+        let r = _ktestz_mask8_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b01101001;
+        let r = _ktestz_mask8_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtmask8_u32() {
+        let a: __mmask8 = 0b01101001;
+        let r = _cvtmask8_u32(a);
+        let e: u32 = 0b01101001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtu32_mask8() {
+        let a: u32 = 0b11111111_11111111_00000000_01101001;
+        let r = _cvtu32_mask8(a);
+        let e: __mmask8 = 0b01101001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask16() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00101110_00001011;
+        let r = _kadd_mask16(a, b);
+        let e: __mmask16 = 0b11111010_00111110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask16_u8() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00101110_00001011;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask16_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 0);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00110011_11001100;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask16_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b11001100_00110011;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask16_u8(a, b, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask16_u8() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00101110_00001011;
+        let r = _ktestc_mask16_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00110011_11001100;
+        let r = _ktestc_mask16_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _ktestc_mask16_u8(a, b);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask16_u8() {
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00101110_00001011;
+        let r = _ktestz_mask16_u8(a, b);
+        assert_eq!(r, 0);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b00110011_11001100;
+        let r = _ktestz_mask16_u8(a, b);
+        assert_eq!(r, 1);
+        let a: __mmask16 = 0b11001100_00110011;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _ktestz_mask16_u8(a, b);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_store_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let mut r = 0;
+        _store_mask8(&mut r as *mut _ as *mut u8, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_load_mask8() {
+        let p: __mmask8 = 0b01101001;
+        let r = _load_mask8(&p);
+        let e: __mmask8 = 0b01101001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kand_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kand_mask8(a, b);
+        let e: __mmask8 = 0b00100001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_knot_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let r = _knot_mask8(a);
+        let e: __mmask8 = 0b10010110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kandn_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kandn_mask8(a, b);
+        let e: __mmask8 = 0b10010000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kor_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kor_mask8(a, b);
+        let e: __mmask8 = 0b11111001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxor_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kxor_mask8(a, b);
+        let e: __mmask8 = 0b11011000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxnor_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let b: __mmask8 = 0b10110001;
+        let r = _kxnor_mask8(a, b);
+        let e: __mmask8 = 0b00100111;
+        assert_eq!(r, e);
+    }
+}
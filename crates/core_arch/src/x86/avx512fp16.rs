@@ -0,0 +1,8918 @@
+//! [AVX512-FP16 intrinsics].
+//!
+//! Rust has no `f16` type, so the half-precision vector types store each
+//! lane as the `u16` bit pattern of its IEEE 754 binary16 value, and scalar
+//! `_Float16` results are returned as that bit pattern too. The instructions
+//! operating on these types have no LLVM intrinsics that can be expressed
+//! without `half`, so they are all written with inline assembly.
+//!
+//! [AVX512-FP16 intrinsics]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#avx512techs=AVX512_FP16
+
+use crate::{
+    core_arch::{simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Emits an instruction with the embedded rounding control selected by
+/// `ROUNDING`, or without one for `_MM_FROUND_CUR_DIRECTION`.
+macro_rules! asm_rounding {
+    ($rounding:expr, $insn:expr, $($operands:tt)*) => {
+        match $rounding {
+            _MM_FROUND_CUR_DIRECTION => asm!($insn, $($operands)*),
+            8 => asm!(concat!($insn, ", {{rn-sae}}"), $($operands)*),
+            9 => asm!(concat!($insn, ", {{rd-sae}}"), $($operands)*),
+            10 => asm!(concat!($insn, ", {{ru-sae}}"), $($operands)*),
+            _ => asm!(concat!($insn, ", {{rz-sae}}"), $($operands)*),
+        }
+    };
+}
+
+/// Return vector of type __m128h with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_setzero_ph() -> __m128h {
+    transmute(_mm_set1_epi16(0))
+}
+
+/// Cast vector of type __m128h to type __m128. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castph_ps(a: __m128h) -> __m128 {
+    transmute(a)
+}
+
+/// Cast vector of type __m128 to type __m128h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castps_ph(a: __m128) -> __m128h {
+    transmute(a)
+}
+
+/// Cast vector of type __m128h to type __m128d. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castph_pd(a: __m128h) -> __m128d {
+    transmute(a)
+}
+
+/// Cast vector of type __m128d to type __m128h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castpd_ph(a: __m128d) -> __m128h {
+    transmute(a)
+}
+
+/// Cast vector of type __m128h to type __m128i. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castph_si128)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castph_si128(a: __m128h) -> __m128i {
+    transmute(a)
+}
+
+/// Cast vector of type __m128i to type __m128h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_castsi128_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm_castsi128_ph(a: __m128i) -> __m128h {
+    transmute(a)
+}
+
+/// Return vector of type __m256h with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_setzero_ph() -> __m256h {
+    transmute(_mm256_set1_epi16(0))
+}
+
+/// Cast vector of type __m256h to type __m256. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castph_ps(a: __m256h) -> __m256 {
+    transmute(a)
+}
+
+/// Cast vector of type __m256 to type __m256h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castps_ph(a: __m256) -> __m256h {
+    transmute(a)
+}
+
+/// Cast vector of type __m256h to type __m256d. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castph_pd(a: __m256h) -> __m256d {
+    transmute(a)
+}
+
+/// Cast vector of type __m256d to type __m256h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castpd_ph(a: __m256d) -> __m256h {
+    transmute(a)
+}
+
+/// Cast vector of type __m256h to type __m256i. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castph_si256)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castph_si256(a: __m256h) -> __m256i {
+    transmute(a)
+}
+
+/// Cast vector of type __m256i to type __m256h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castsi256_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castsi256_ph(a: __m256i) -> __m256h {
+    transmute(a)
+}
+
+/// Return vector of type __m512h with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_ph() -> __m512h {
+    transmute(_mm512_set1_epi16(0))
+}
+
+/// Cast vector of type __m512h to type __m512. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castph_ps(a: __m512h) -> __m512 {
+    transmute(a)
+}
+
+/// Cast vector of type __m512 to type __m512h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castps_ph(a: __m512) -> __m512h {
+    transmute(a)
+}
+
+/// Cast vector of type __m512h to type __m512d. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castph_pd(a: __m512h) -> __m512d {
+    transmute(a)
+}
+
+/// Cast vector of type __m512d to type __m512h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castpd_ph(a: __m512d) -> __m512h {
+    transmute(a)
+}
+
+/// Cast vector of type __m512h to type __m512i. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castph_si512)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castph_si512(a: __m512h) -> __m512i {
+    transmute(a)
+}
+
+/// Cast vector of type __m512i to type __m512h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castsi512_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castsi512_ph(a: __m512i) -> __m512h {
+    transmute(a)
+}
+
+/// Cast vector of type __m512h to type __m256h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castph512_ph256)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castph512_ph256(a: __m512h) -> __m256h {
+    simd_shuffle16!(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+}
+
+/// Cast vector of type __m512h to type __m128h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_castph512_ph128)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_castph512_ph128(a: __m512h) -> __m128h {
+    simd_shuffle8!(a, a, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Cast vector of type __m256h to type __m128h. This intrinsic is only used for compilation and does not generate any instructions, thus it has zero latency.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_castph256_ph128)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm256_castph256_ph128(a: __m256h) -> __m128h {
+    simd_shuffle8!(a, a, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_add_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vaddph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_mask_add_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vaddph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_maskz_add_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vaddph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_add_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vaddph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_mask_add_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vaddph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_maskz_add_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vaddph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_add_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vaddph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_mask_add_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vaddph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_maskz_add_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vaddph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_sub_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsubph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_mask_sub_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vsubph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_maskz_sub_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsubph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_sub_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vsubph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_mask_sub_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vsubph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_maskz_sub_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vsubph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_sub_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vsubph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_mask_sub_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vsubph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_maskz_sub_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vsubph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mul_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmulph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mask_mul_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vmulph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_maskz_mul_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmulph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mul_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vmulph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mask_mul_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vmulph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_maskz_mul_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vmulph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mul_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vmulph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mask_mul_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vmulph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_maskz_mul_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vmulph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_div_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vdivph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_mask_div_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vdivph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_maskz_div_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vdivph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_div_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vdivph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_mask_div_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vdivph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_maskz_div_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vdivph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_div_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vdivph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_mask_div_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vdivph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_maskz_div_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vdivph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_min_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vminph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_mask_min_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vminph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_maskz_min_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vminph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_min_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vminph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_mask_min_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vminph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_maskz_min_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vminph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_min_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vminph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_mask_min_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vminph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed minimum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_maskz_min_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vminph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_max_ph(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmaxph {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_mask_max_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vmaxph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_maskz_max_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmaxph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_max_ph(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vmaxph {dst}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_mask_max_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vmaxph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_maskz_max_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vmaxph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_max_ph(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vmaxph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_mask_max_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vmaxph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare packed half-precision (16-bit) floating-point elements in a and b, and store packed maximum values, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_maskz_max_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vmaxph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_add_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vaddph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_add_round_ph<const ROUNDING: i32>(
+    src: __m512h,
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = src;
+    asm_rounding!(
+        ROUNDING,
+        "vaddph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Add packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_add_round_ph<const ROUNDING: i32>(
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vaddph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_sub_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vsubph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_sub_round_ph<const ROUNDING: i32>(
+    src: __m512h,
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = src;
+    asm_rounding!(
+        ROUNDING,
+        "vsubph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Subtract packed half-precision (16-bit) floating-point elements in b from packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_sub_round_ph<const ROUNDING: i32>(
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vsubph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mul_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_mul_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vmulph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mul_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_mul_round_ph<const ROUNDING: i32>(
+    src: __m512h,
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = src;
+    asm_rounding!(
+        ROUNDING,
+        "vmulph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mul_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_mul_round_ph<const ROUNDING: i32>(
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vmulph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_div_round_ph<const ROUNDING: i32>(a: __m512h, b: __m512h) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vdivph {dst}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_div_round_ph<const ROUNDING: i32>(
+    src: __m512h,
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = src;
+    asm_rounding!(
+        ROUNDING,
+        "vdivph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Divide packed half-precision (16-bit) floating-point elements in a by packed elements in b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_div_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_div_round_ph<const ROUNDING: i32>(
+    k: __mmask32,
+    a: __m512h,
+    b: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vdivph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_sqrt_ph(a: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsqrtph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_mask_sqrt_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vsqrtph {dst}{{{k}}}, {a}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_maskz_sqrt_ph(k: __mmask8, a: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsqrtph {dst}{{{k}}}{{z}}, {a}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_sqrt_ph(a: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vsqrtph {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_mask_sqrt_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vsqrtph {dst}{{{k}}}, {a}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_maskz_sqrt_ph(k: __mmask16, a: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vsqrtph {dst}{{{k}}}{{z}}, {a}",
+        dst = lateout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_sqrt_ph(a: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vsqrtph {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_mask_sqrt_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vsqrtph {dst}{{{k}}}, {a}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_maskz_sqrt_ph(k: __mmask32, a: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vsqrtph {dst}{{{k}}}{{z}}, {a}",
+        dst = lateout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of packed half-precision (16-bit) floating-point elements in a, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sqrt_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_sqrt_round_ph<const ROUNDING: i32>(a: __m512h) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m512h;
+    asm_rounding!(
+        ROUNDING,
+        "vsqrtph {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Add the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddsh))]
+pub unsafe fn _mm_add_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vaddsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddsh))]
+pub unsafe fn _mm_mask_add_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vaddsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddsh))]
+pub unsafe fn _mm_maskz_add_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vaddsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Add the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_round_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddsh, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_add_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m128h;
+    asm_rounding!(
+        ROUNDING,
+        "vaddsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Subtract the lower half-precision (16-bit) floating-point elements in b from the lower half-precision (16-bit) floating-point elements in a, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubsh))]
+pub unsafe fn _mm_sub_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsubsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract the lower half-precision (16-bit) floating-point elements in b from the lower half-precision (16-bit) floating-point elements in a, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubsh))]
+pub unsafe fn _mm_mask_sub_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vsubsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract the lower half-precision (16-bit) floating-point elements in b from the lower half-precision (16-bit) floating-point elements in a, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubsh))]
+pub unsafe fn _mm_maskz_sub_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsubsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Subtract the lower half-precision (16-bit) floating-point elements in b from the lower half-precision (16-bit) floating-point elements in a, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_round_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubsh, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_sub_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m128h;
+    asm_rounding!(
+        ROUNDING,
+        "vsubsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulsh))]
+pub unsafe fn _mm_mul_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmulsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mul_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulsh))]
+pub unsafe fn _mm_mask_mul_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vmulsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mul_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulsh))]
+pub unsafe fn _mm_maskz_mul_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmulsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_round_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulsh, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mul_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m128h;
+    asm_rounding!(
+        ROUNDING,
+        "vmulsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Divide the lower half-precision (16-bit) floating-point elements in a by the lower element in b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivsh))]
+pub unsafe fn _mm_div_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vdivsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide the lower half-precision (16-bit) floating-point elements in a by the lower element in b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_div_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivsh))]
+pub unsafe fn _mm_mask_div_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vdivsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide the lower half-precision (16-bit) floating-point elements in a by the lower element in b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_div_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivsh))]
+pub unsafe fn _mm_maskz_div_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vdivsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Divide the lower half-precision (16-bit) floating-point elements in a by the lower element in b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_round_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivsh, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_div_round_sh<const ROUNDING: i32>(a: __m128h, b: __m128h) -> __m128h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m128h;
+    asm_rounding!(
+        ROUNDING,
+        "vdivsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the minimum value, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_min_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminsh))]
+pub unsafe fn _mm_min_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vminsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the minimum value, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_min_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminsh))]
+pub unsafe fn _mm_mask_min_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vminsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the minimum value, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_min_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminsh))]
+pub unsafe fn _mm_maskz_min_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vminsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the maximum value, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_max_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxsh))]
+pub unsafe fn _mm_max_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmaxsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the maximum value, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_max_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxsh))]
+pub unsafe fn _mm_mask_max_sh(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vmaxsh {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compare the lower half-precision (16-bit) floating-point elements in a and b, and store the maximum value, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_max_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxsh))]
+pub unsafe fn _mm_maskz_max_sh(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vmaxsh {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Compute the square root of the lower half-precision (16-bit) floating-point element in b, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sqrt_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtsh))]
+pub unsafe fn _mm_sqrt_sh(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vsqrtsh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm_fmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm_mask_fmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm_maskz_fmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd231ph))]
+pub unsafe fn _mm_mask3_fmadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm256_fmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm256_mask_fmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm256_maskz_fmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd231ph))]
+pub unsafe fn _mm256_mask3_fmadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm512_fmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm512_mask_fmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd213ph))]
+pub unsafe fn _mm512_maskz_fmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd231ph))]
+pub unsafe fn _mm512_mask3_fmadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the intermediate result to packed elements in c, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmadd_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd213ph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_fmadd_round_ph<const ROUNDING: i32>(
+    a: __m512h,
+    b: __m512h,
+    c: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = a;
+    asm_rounding!(
+        ROUNDING,
+        "vfmadd213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, add the intermediate result to the lower element in c, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmadd_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd213sh))]
+pub unsafe fn _mm_fmadd_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmadd213sh {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm_fmsub_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm_mask_fmsub_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm_maskz_fmsub_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub231ph))]
+pub unsafe fn _mm_mask3_fmsub_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm256_fmsub_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm256_mask_fmsub_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm256_maskz_fmsub_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmsub231ph))]
+pub unsafe fn _mm256_mask3_fmsub_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm512_fmsub_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm512_mask_fmsub_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub213ph))]
+pub unsafe fn _mm512_maskz_fmsub_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub231ph))]
+pub unsafe fn _mm512_mask3_fmsub_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the intermediate result, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmsub_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub213ph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_fmsub_round_ph<const ROUNDING: i32>(
+    a: __m512h,
+    b: __m512h,
+    c: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = a;
+    asm_rounding!(
+        ROUNDING,
+        "vfmsub213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, subtract the lower element in c from the intermediate result, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmsub_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmsub213sh))]
+pub unsafe fn _mm_fmsub_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmsub213sh {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm_fnmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm_mask_fnmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm_maskz_fnmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd231ph))]
+pub unsafe fn _mm_mask3_fnmadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfnmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm256_fnmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm256_mask_fnmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm256_maskz_fnmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmadd231ph))]
+pub unsafe fn _mm256_mask3_fnmadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfnmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm512_fnmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm512_mask_fnmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph))]
+pub unsafe fn _mm512_maskz_fnmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fnmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd231ph))]
+pub unsafe fn _mm512_mask3_fnmadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfnmadd231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to packed elements in c, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fnmadd_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd213ph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_fnmadd_round_ph<const ROUNDING: i32>(
+    a: __m512h,
+    b: __m512h,
+    c: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = a;
+    asm_rounding!(
+        ROUNDING,
+        "vfnmadd213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, add the negated intermediate result to the lower element in c, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fnmadd_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmadd213sh))]
+pub unsafe fn _mm_fnmadd_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmadd213sh {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm_fnmsub_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm_mask_fnmsub_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm_maskz_fnmsub_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub231ph))]
+pub unsafe fn _mm_mask3_fnmsub_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfnmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm256_fnmsub_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm256_mask_fnmsub_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm256_maskz_fnmsub_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        b = in(ymm_reg) b,
+        c = in(ymm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfnmsub231ph))]
+pub unsafe fn _mm256_mask3_fnmsub_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfnmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm512_fnmsub_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from a when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm512_mask_fnmsub_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph))]
+pub unsafe fn _mm512_maskz_fnmsub_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213ph {dst}{{{k}}}{{z}}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst using writemask k (elements are copied from c when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fnmsub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub231ph))]
+pub unsafe fn _mm512_mask3_fnmsub_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfnmsub231ph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed half-precision (16-bit) floating-point elements in a and b, subtract packed elements in c from the negated intermediate result, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fnmsub_round_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub213ph, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_fnmsub_round_ph<const ROUNDING: i32>(
+    a: __m512h,
+    b: __m512h,
+    c: __m512h,
+) -> __m512h {
+    static_assert_rounding!(ROUNDING);
+    let mut dst = a;
+    asm_rounding!(
+        ROUNDING,
+        "vfnmsub213ph {dst}, {b}, {c}",
+        dst = inout(zmm_reg) dst,
+        b = in(zmm_reg) b,
+        c = in(zmm_reg) c,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Multiply the lower half-precision (16-bit) floating-point elements in a and b, subtract the lower element in c from the negated intermediate result, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fnmsub_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfnmsub213sh))]
+pub unsafe fn _mm_fnmsub_sh(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfnmsub213sh {dst}, {b}, {c}",
+        dst = inout(xmm_reg) dst,
+        b = in(xmm_reg) b,
+        c = in(xmm_reg) c,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm_cvtxph_ps(a: __m128h) -> __m128 {
+    let dst: __m128;
+    asm!(
+        "vcvtph2psx {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm256_cvtxph_ps(a: __m128h) -> __m256 {
+    let dst: __m256;
+    asm!(
+        "vcvtph2psx {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm512_cvtxph_ps(a: __m256h) -> __m512 {
+    let dst: __m512;
+    asm!(
+        "vcvtph2psx {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm_cvtxps_ph(a: __m128) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtps2phx {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm256_cvtxps_ph(a: __m256) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtps2phx {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm512_cvtxps_ph(a: __m512) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vcvtps2phx {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// Rounding is done according to the rounding parameter, which can be one of:
+///
+/// * [`_MM_FROUND_TO_NEAREST_INT`] | [`_MM_FROUND_NO_EXC`] : round to nearest and suppress exceptions
+/// * [`_MM_FROUND_TO_NEG_INF`] | [`_MM_FROUND_NO_EXC`] : round down and suppress exceptions
+/// * [`_MM_FROUND_TO_POS_INF`] | [`_MM_FROUND_NO_EXC`] : round up and suppress exceptions
+/// * [`_MM_FROUND_TO_ZERO`] | [`_MM_FROUND_NO_EXC`] : truncate and suppress exceptions
+/// * [`_MM_FROUND_CUR_DIRECTION`] : use `MXCSR.RC` - see [`_MM_SET_ROUNDING_MODE`]
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtx_roundps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtx_roundps_ph<const ROUNDING: i32>(a: __m512) -> __m256h {
+    static_assert_rounding!(ROUNDING);
+    let dst: __m256h;
+    asm_rounding!(
+        ROUNDING,
+        "vcvtps2phx {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack)
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm_cvtph_pd(a: __m128h) -> __m128d {
+    let dst: __m128d;
+    asm!(
+        "vcvtph2pd {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm256_cvtph_pd(a: __m128h) -> __m256d {
+    let dst: __m256d;
+    asm!(
+        "vcvtph2pd {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_pd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2pd))]
+pub unsafe fn _mm512_cvtph_pd(a: __m128h) -> __m512d {
+    let dst: __m512d;
+    asm!(
+        "vcvtph2pd {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm_cvtpd_ph(a: __m128d) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtpd2ph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm256_cvtpd_ph(a: __m256d) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtpd2ph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtpd2ph))]
+pub unsafe fn _mm512_cvtpd_ph(a: __m512d) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtpd2ph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm_cvtph_epi16(a: __m128h) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vcvtph2w {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm256_cvtph_epi16(a: __m256h) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vcvtph2w {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm512_cvtph_epi16(a: __m512h) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vcvtph2w {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm_cvttph_epi16(a: __m128h) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vcvttph2w {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm256_cvttph_epi16(a: __m256h) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vcvttph2w {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm512_cvttph_epi16(a: __m512h) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vcvttph2w {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2uw))]
+pub unsafe fn _mm_cvtph_epu16(a: __m128h) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vcvtph2uw {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2uw))]
+pub unsafe fn _mm256_cvtph_epu16(a: __m256h) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vcvtph2uw {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2uw))]
+pub unsafe fn _mm512_cvtph_epu16(a: __m512h) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vcvtph2uw {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2uw))]
+pub unsafe fn _mm_cvttph_epu16(a: __m128h) -> __m128i {
+    let dst: __m128i;
+    asm!(
+        "vcvttph2uw {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2uw))]
+pub unsafe fn _mm256_cvttph_epu16(a: __m256h) -> __m256i {
+    let dst: __m256i;
+    asm!(
+        "vcvttph2uw {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed half-precision (16-bit) floating-point elements in a to packed unsigned 16-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttph_epu16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttph2uw))]
+pub unsafe fn _mm512_cvttph_epu16(a: __m512h) -> __m512i {
+    let dst: __m512i;
+    asm!(
+        "vcvttph2uw {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed signed 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm_cvtepi16_ph(a: __m128i) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtw2ph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed signed 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm256_cvtepi16_ph(a: __m256i) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vcvtw2ph {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed signed 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm512_cvtepi16_ph(a: __m512i) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vcvtw2ph {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed unsigned 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepu16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuw2ph))]
+pub unsafe fn _mm_cvtepu16_ph(a: __m128i) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtuw2ph {dst}, {a}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed unsigned 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepu16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuw2ph))]
+pub unsafe fn _mm256_cvtepu16_ph(a: __m256i) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vcvtuw2ph {dst}, {a}",
+        dst = lateout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert packed unsigned 16-bit integers in a to packed half-precision (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepu16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtuw2ph))]
+pub unsafe fn _mm512_cvtepu16_ph(a: __m512i) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vcvtuw2ph {dst}, {a}",
+        dst = lateout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower single-precision (32-bit) floating-point element in b to a half-precision (16-bit) floating-point element, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtss_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtss2sh))]
+pub unsafe fn _mm_cvtss_sh(a: __m128h, b: __m128) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtss2sh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower double-precision (64-bit) floating-point element in b to a half-precision (16-bit) floating-point element, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsd_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtsd2sh))]
+pub unsafe fn _mm_cvtsd_sh(a: __m128h, b: __m128d) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtsd2sh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower half-precision (16-bit) floating-point element in b to a single-precision (32-bit) floating-point element, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsh_ss)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtsh2ss))]
+pub unsafe fn _mm_cvtsh_ss(a: __m128, b: __m128h) -> __m128 {
+    let dst: __m128;
+    asm!(
+        "vcvtsh2ss {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower half-precision (16-bit) floating-point element in b to a double-precision (64-bit) floating-point element, store the result in the lower element of dst, and copy the upper element from a to the upper element of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsh_sd)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtsh2sd))]
+pub unsafe fn _mm_cvtsh_sd(a: __m128d, b: __m128h) -> __m128d {
+    let dst: __m128d;
+    asm!(
+        "vcvtsh2sd {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the signed 32-bit integer b to a half-precision (16-bit) floating-point element, store the result in the lower element of dst, and copy the upper 7 packed elements from a to the upper elements of dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvti32_sh)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtsi2sh))]
+pub unsafe fn _mm_cvti32_sh(a: __m128h, b: i32) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vcvtsi2sh {dst}, {a}, {b}",
+        dst = lateout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower half-precision (16-bit) floating-point element in a to a 32-bit integer, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsh_i32)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtsh2si))]
+pub unsafe fn _mm_cvtsh_i32(a: __m128h) -> i32 {
+    let dst: i32;
+    asm!(
+        "vcvtsh2si {dst:e}, {a}",
+        dst = lateout(reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Convert the lower half-precision (16-bit) floating-point element in a to a 32-bit integer with truncation, and store the result in dst.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttsh_i32)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttsh2si))]
+pub unsafe fn _mm_cvttsh_i32(a: __m128h) -> i32 {
+    let dst: i32;
+    asm!(
+        "vcvttsh2si {dst:e}, {a}",
+        dst = lateout(reg) dst,
+        a = in(xmm_reg) a,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_fmul_pch(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_mask_fmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_maskz_fmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_fmul_pch(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_mask_fmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_maskz_fmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_fmul_pch(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_mask_fmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_maskz_fmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_mul_pch(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_mask_mul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_maskz_mul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_mul_pch(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_mask_mul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_maskz_mul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_mul_pch(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfmulcph {dst}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_mask_mul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vfmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_maskz_mul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_fcmul_pch(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_mask_fcmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_maskz_fcmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_fcmul_pch(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_mask_fcmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_maskz_fcmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_fcmul_pch(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_mask_fcmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_maskz_fcmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_cmul_pch(a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_mask_cmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_maskz_cmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let dst: __m128h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_cmul_pch(a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_mask_cmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_maskz_cmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let dst: __m256h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_cmul_pch(a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfcmulcph {dst}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using writemask k (the element is copied from src when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_mask_cmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let mut dst = src;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, and store the results in dst using zeromask k (the element is zeroed out when corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_maskz_cmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let dst: __m512h;
+    asm!(
+        "vfcmulcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = out(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm_fmadd_pch(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm_mask_fmadd_pch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        c = inout(xmm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm_mask3_fmadd_pch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm_maskz_fmadd_pch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm256_fmadd_pch(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm256_mask_fmadd_pch(a: __m256h, k: __mmask8, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        c = inout(ymm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm256_mask3_fmadd_pch(a: __m256h, b: __m256h, c: __m256h, k: __mmask8) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm256_maskz_fmadd_pch(k: __mmask8, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm512_fmadd_pch(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm512_mask_fmadd_pch(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        c = inout(zmm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm512_mask3_fmadd_pch(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a and b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmaddcph))]
+pub unsafe fn _mm512_maskz_fmadd_pch(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm_fcmadd_pch(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm_mask_fcmadd_pch(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = a;
+    asm!(
+        "vfcmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        c = inout(xmm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm_mask3_fcmadd_pch(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm_maskz_fcmadd_pch(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(xmm_reg) dst,
+        k = in(kreg) k,
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm256_fcmadd_pch(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm256_mask_fcmadd_pch(a: __m256h, k: __mmask8, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = a;
+    asm!(
+        "vfcmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        c = inout(ymm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm256_mask3_fcmadd_pch(a: __m256h, b: __m256h, c: __m256h, k: __mmask8) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm256_maskz_fcmadd_pch(k: __mmask8, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(ymm_reg) dst,
+        k = in(kreg) k,
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst.
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm512_fcmadd_pch(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from a when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm512_mask_fcmadd_pch(a: __m512h, k: __mmask16, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = a;
+    asm!(
+        "vfcmaddcph {c}, {a}, {b}", "vmovaps {dst}{{{k}}}, {c}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        c = inout(zmm_reg) c => _,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using writemask k (the element is copied from c when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm512_mask3_fcmadd_pch(a: __m512h, b: __m512h, c: __m512h, k: __mmask16) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Multiply packed complex numbers in a by the complex conjugates of packed complex numbers in b, accumulate to the corresponding complex numbers in c, and store the results in dst using zeromask k (the element is zeroed out when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit) floating-point elements, which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fcmadd_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmaddcph))]
+pub unsafe fn _mm512_maskz_fcmadd_pch(k: __mmask16, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let mut dst = c;
+    asm!(
+        "vfcmaddcph {dst}{{{k}}}{{z}}, {a}, {b}",
+        dst = inout(zmm_reg) dst,
+        k = in(kreg) k,
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        options(pure, nomem, nostack),
+    );
+    dst
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by addition. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_reduce_add_ph(a: __m128h) -> u16 {
+    let a = _mm_add_ph(a, transmute(_mm_srli_si128::<8>(transmute(a))));
+    let a = _mm_add_ph(a, transmute(_mm_srli_si128::<4>(transmute(a))));
+    let a = _mm_add_ph(a, transmute(_mm_srli_si128::<2>(transmute(a))));
+    simd_extract(a, 0)
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by addition. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_reduce_add_ph(a: __m256h) -> u16 {
+    let hi: __m128h = transmute(_mm256_extractf128_si256::<1>(transmute(a)));
+    _mm_reduce_add_ph(_mm_add_ph(_mm256_castph256_ph128(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by addition. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_reduce_add_ph(a: __m512h) -> u16 {
+    let hi: __m256h = transmute(_mm512_extracti64x4_epi64::<1>(transmute(a)));
+    _mm256_reduce_add_ph(_mm256_add_ph(_mm512_castph512_ph256(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by multiplication. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_reduce_mul_ph(a: __m128h) -> u16 {
+    let a = _mm_mul_ph(a, transmute(_mm_srli_si128::<8>(transmute(a))));
+    let a = _mm_mul_ph(a, transmute(_mm_srli_si128::<4>(transmute(a))));
+    let a = _mm_mul_ph(a, transmute(_mm_srli_si128::<2>(transmute(a))));
+    simd_extract(a, 0)
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by multiplication. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_reduce_mul_ph(a: __m256h) -> u16 {
+    let hi: __m128h = transmute(_mm256_extractf128_si256::<1>(transmute(a)));
+    _mm_reduce_mul_ph(_mm_mul_ph(_mm256_castph256_ph128(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by multiplication. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_reduce_mul_ph(a: __m512h) -> u16 {
+    let hi: __m256h = transmute(_mm512_extracti64x4_epi64::<1>(transmute(a)));
+    _mm256_reduce_mul_ph(_mm256_mul_ph(_mm512_castph512_ph256(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by minimum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_reduce_min_ph(a: __m128h) -> u16 {
+    let a = _mm_min_ph(a, transmute(_mm_srli_si128::<8>(transmute(a))));
+    let a = _mm_min_ph(a, transmute(_mm_srli_si128::<4>(transmute(a))));
+    let a = _mm_min_ph(a, transmute(_mm_srli_si128::<2>(transmute(a))));
+    simd_extract(a, 0)
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by minimum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_reduce_min_ph(a: __m256h) -> u16 {
+    let hi: __m128h = transmute(_mm256_extractf128_si256::<1>(transmute(a)));
+    _mm_reduce_min_ph(_mm_min_ph(_mm256_castph256_ph128(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by minimum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_reduce_min_ph(a: __m512h) -> u16 {
+    let hi: __m256h = transmute(_mm512_extracti64x4_epi64::<1>(transmute(a)));
+    _mm256_reduce_min_ph(_mm256_min_ph(_mm512_castph512_ph256(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by maximum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_reduce_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_reduce_max_ph(a: __m128h) -> u16 {
+    let a = _mm_max_ph(a, transmute(_mm_srli_si128::<8>(transmute(a))));
+    let a = _mm_max_ph(a, transmute(_mm_srli_si128::<4>(transmute(a))));
+    let a = _mm_max_ph(a, transmute(_mm_srli_si128::<2>(transmute(a))));
+    simd_extract(a, 0)
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by maximum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_reduce_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_reduce_max_ph(a: __m256h) -> u16 {
+    let hi: __m128h = transmute(_mm256_extractf128_si256::<1>(transmute(a)));
+    _mm_reduce_max_ph(_mm_max_ph(_mm256_castph256_ph128(a), hi))
+}
+
+/// Reduce the packed half-precision (16-bit) floating-point elements in a by maximum. Returns the binary16 bit pattern of the result, since Rust has no `f16` type.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_reduce_max_ph(a: __m512h) -> u16 {
+    let hi: __m256h = transmute(_mm512_extracti64x4_epi64::<1>(transmute(a)));
+    _mm256_reduce_max_ph(_mm256_max_ph(_mm512_castph512_ph256(a), hi))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_setzero_ph() {
+        let r = _mm_setzero_ph();
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x0000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castph_ps() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_castph_ps(a);
+        assert_eq_m128i(transmute(r), _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castps_ph() {
+        let a: __m128 = transmute(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_castps_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castph_pd() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_castph_pd(a);
+        assert_eq_m128i(transmute(r), _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castpd_ph() {
+        let a: __m128d = transmute(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_castpd_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castph_si128() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_castph_si128(a);
+        assert_eq_m128i(r, _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_castsi128_ph() {
+        let a = _mm_set1_epi16(0x3c00 as i16);
+        let r = _mm_castsi128_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), a);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_setzero_ph() {
+        let r = _mm256_setzero_ph();
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x0000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castph_ps() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castph_ps(a);
+        assert_eq_m256i(transmute(r), _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castps_ph() {
+        let a: __m256 = transmute(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castps_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castph_pd() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castph_pd(a);
+        assert_eq_m256i(transmute(r), _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castpd_ph() {
+        let a: __m256d = transmute(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castpd_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castph_si256() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castph_si256(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castsi256_ph() {
+        let a = _mm256_set1_epi16(0x3c00 as i16);
+        let r = _mm256_castsi256_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), a);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_setzero_ph() {
+        let r = _mm512_setzero_ph();
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x0000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castph_ps() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castph_ps(a);
+        assert_eq_m512i(transmute(r), _mm512_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castps_ph() {
+        let a: __m512 = transmute(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castps_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castph_pd() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castph_pd(a);
+        assert_eq_m512i(transmute(r), _mm512_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castpd_ph() {
+        let a: __m512d = transmute(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castpd_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), transmute(a));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castph_si512() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castph_si512(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castsi512_ph() {
+        let a = _mm512_set1_epi16(0x3c00 as i16);
+        let r = _mm512_castsi512_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), a);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castph512_ph256() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castph512_ph256(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_castph512_ph128() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_castph512_ph128(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm256_castph256_ph128() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_castph256_ph128(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_add_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_add_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_add_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_add_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_add_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_add_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_add_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_add_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_add_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mask_add_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_add_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_maskz_add_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_add_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_add_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4200 as i16));
+        // Subnormals are neither flushed to zero nor treated as zero.
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x0001 as i16));
+        let r = _mm512_add_ph(a, a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x0002 as i16));
+        // A NaN in a is returned quieted with its payload, before a NaN in b.
+        let qnan = _mm512_castsi512_ph(_mm512_set1_epi16(0x7e55 as i16));
+        let snan = _mm512_castsi512_ph(_mm512_set1_epi16(0x7d2a as i16));
+        let one = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_add_ph(qnan, snan);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x7e55 as i16));
+        let r = _mm512_add_ph(one, snan);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x7f2a as i16));
+        // Overflow rounds to infinity.
+        let max = _mm512_castsi512_ph(_mm512_set1_epi16(0x7bff as i16));
+        let r = _mm512_add_ph(max, max);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x7c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_add_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mask_add_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_add_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_maskz_add_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_sub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_sub_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_sub_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_sub_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_sub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_sub_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_sub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_sub_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_sub_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask_sub_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_sub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_maskz_sub_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_sub_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sub_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask_sub_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_maskz_sub_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mul_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mul_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_mul_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_mul_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_mul_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_mul_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mul_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mul_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_mul_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mask_mul_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_mul_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_maskz_mul_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mul_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mul_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_mul_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mask_mul_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_mul_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_maskz_mul_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_div_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_div_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x3e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_div_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_div_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_div_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_div_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_div_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_div_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_div_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mask_div_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_div_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4200 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_maskz_div_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_div_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_div_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_div_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mask_div_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_div_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_maskz_div_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_min_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_min_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_min_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_min_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_min_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_min_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_min_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_min_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_min_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mask_min_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_min_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_maskz_min_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_min_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_min_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        // If either element is a NaN, the element of b is returned.
+        let nan = _mm512_castsi512_ph(_mm512_set1_epi16(0x7e00 as i16));
+        let r = _mm512_min_ph(nan, a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_min_ph(a, nan);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x7e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_min_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mask_min_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_min_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_maskz_min_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_max_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_max_ph(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_max_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_max_ph(src, 0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_max_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_max_ph(0b01010101, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_max_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_max_ph(a, b);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_max_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_mask_max_ph(src, 0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_max_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let r = _mm256_maskz_max_ph(0b01010101_01010101, a, b);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_max_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_max_ph(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4000 as i16));
+        // If either element is a NaN, the element of b is returned.
+        let nan = _mm512_castsi512_ph(_mm512_set1_epi16(0x7e00 as i16));
+        let r = _mm512_max_ph(nan, a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_max_ph(a, nan);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x7e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_max_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_mask_max_ph(src, 0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_max_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_maskz_max_ph(0b01010101_01010101_01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_add_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x1000 as i16));
+        let r = _mm512_add_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_add_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_add_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c01 as i16));
+        let r = _mm512_add_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_add_round_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x1000 as i16));
+        let r = _mm512_mask_add_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+            src,
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3c01 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_add_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x1000 as i16));
+        let r = _mm512_maskz_add_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sub_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x9000 as i16));
+        let r = _mm512_sub_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_sub_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_sub_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c01 as i16));
+        let r = _mm512_sub_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sub_round_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x9000 as i16));
+        let r = _mm512_mask_sub_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+            src,
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3c01 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sub_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x9000 as i16));
+        let r = _mm512_maskz_sub_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mul_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let r = _mm512_mul_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c02 as i16));
+        let r = _mm512_mul_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c02 as i16));
+        let r = _mm512_mul_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c03 as i16));
+        let r = _mm512_mul_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3c02 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_mul_round_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let r = _mm512_mask_mul_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+            src,
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3c03 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_mul_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let r = _mm512_maskz_mul_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3c02 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_div_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let r = _mm512_div_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3555 as i16));
+        let r = _mm512_div_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3555 as i16));
+        let r = _mm512_div_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3556 as i16));
+        let r = _mm512_div_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3555 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_div_round_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let r = _mm512_mask_div_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(
+            src,
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x3556 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_div_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4200 as i16));
+        let r = _mm512_maskz_div_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(
+            0b01010101_01010101_01010101_01010101,
+            a,
+            b,
+        );
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x3555 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_sqrt_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4400 as i16));
+        let r = _mm_sqrt_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_sqrt_ph() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4400 as i16));
+        let r = _mm_mask_sqrt_ph(src, 0b01010101, a);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x5000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_sqrt_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4400 as i16));
+        let r = _mm_maskz_sqrt_ph(0b01010101, a);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_sqrt_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4400 as i16));
+        let r = _mm256_sqrt_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_sqrt_ph() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi16(0x5000 as i16));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4400 as i16));
+        let r = _mm256_mask_sqrt_ph(src, 0b01010101_01010101, a);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x5000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_sqrt_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4400 as i16));
+        let r = _mm256_maskz_sqrt_ph(0b01010101_01010101, a);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sqrt_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4400 as i16));
+        let r = _mm512_sqrt_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4000 as i16));
+        // The square root of a negative number is the default NaN, and the
+        // square root of -0.0 is -0.0.
+        let r = _mm512_sqrt_ph(_mm512_castsi512_ph(_mm512_set1_epi16(0xbc00 as i16)));
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xfe00 as i16));
+        let r = _mm512_sqrt_ph(_mm512_castsi512_ph(_mm512_set1_epi16(0x8000 as i16)));
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x8000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sqrt_ph() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi16(0x5000 as i16));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4400 as i16));
+        let r = _mm512_mask_sqrt_ph(src, 0b01010101_01010101_01010101_01010101, a);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x5000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sqrt_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4400 as i16));
+        let r = _mm512_maskz_sqrt_ph(0b01010101_01010101_01010101_01010101, a);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sqrt_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let r = _mm512_sqrt_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3da8 as i16));
+        let r = _mm512_sqrt_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3da8 as i16));
+        let r = _mm512_sqrt_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3da9 as i16));
+        let r = _mm512_sqrt_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x3da8 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_add_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_add_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_add_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_add_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_add_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_add_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_add_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_add_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_add_round_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x1000 as i16));
+        let r = _mm_add_round_sh::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_add_round_sh::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_add_round_sh::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c01 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_add_round_sh::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_sub_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_sub_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_sub_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_sub_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_sub_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_sub_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_sub_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_sub_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_sub_round_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x9000 as i16));
+        let r = _mm_sub_round_sh::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_sub_round_sh::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_sub_round_sh::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c01 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_sub_round_sh::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mul_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mul_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_mul_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_mul_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_mul_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_mul_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_mul_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_mul_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mul_round_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c01 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x3c01 as i16));
+        let r = _mm_mul_round_sh::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c01 as i16),
+            _mm_set1_epi16(0x3c02 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mul_round_sh::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c01 as i16),
+            _mm_set1_epi16(0x3c02 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mul_round_sh::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c01 as i16),
+            _mm_set1_epi16(0x3c03 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mul_round_sh::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c01 as i16),
+            _mm_set1_epi16(0x3c02 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_div_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_div_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_div_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_div_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_div_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_div_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_div_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_div_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x4200 as i16),
+            _mm_set1_epi16(0x3e00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_div_round_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4200 as i16));
+        let r = _mm_div_round_sh::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3555 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_div_round_sh::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3555 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_div_round_sh::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3556 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_div_round_sh::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3555 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_min_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_min_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_min_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_min_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_min_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_min_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_min_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_min_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x3c00 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_max_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_max_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_mask_max_sh() {
+        let src = _mm_castsi128_ph(_mm_set1_epi16(0x5000 as i16));
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_mask_max_sh(src, 0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x5000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_mask_max_sh(src, 1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_maskz_max_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let r = _mm_maskz_max_sh(0, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x0000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+        let r = _mm_maskz_max_sh(1, a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_sqrt_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4400 as i16));
+        let r = _mm_sqrt_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fmadd_ph(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4400 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_fmadd_ph(a, 0b01010101, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_fmadd_ph(0b01010101, a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask3_fmadd_ph(a, b, c, 0b01010101);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_fmadd_ph(a, b, c);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4400 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask_fmadd_ph(a, 0b01010101_01010101, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3e00 as i16),
+            _mm256_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_maskz_fmadd_ph(0b01010101_01010101, a, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask3_fmadd_ph(a, b, c, 0b01010101_01010101);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3c00 as i16),
+            _mm256_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fmadd_ph(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4400 as i16));
+        // The intermediate product is not rounded.
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0xbc02 as i16));
+        let r = _mm512_fmadd_ph(a, a, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x0010 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask_fmadd_ph(a, 0b01010101_01010101_01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3e00 as i16),
+            _mm512_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_maskz_fmadd_ph(0b01010101_01010101_01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask3_fmadd_ph(a, b, c, 0b01010101_01010101_01010101_01010101);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3c00 as i16),
+            _mm512_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmadd_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fmadd_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4001 as i16));
+        let r = _mm512_fmadd_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4001 as i16));
+        let r = _mm512_fmadd_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4002 as i16));
+        let r = _mm512_fmadd_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4001 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_fmadd_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fmadd_sh(a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fmsub_ph(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_fmsub_ph(a, 0b01010101, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_fmsub_ph(0b01010101, a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask3_fmsub_ph(a, b, c, 0b01010101);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_fmsub_ph(a, b, c);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask_fmsub_ph(a, 0b01010101_01010101, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3e00 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_maskz_fmsub_ph(0b01010101_01010101, a, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask3_fmsub_ph(a, b, c, 0b01010101_01010101);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3c00 as i16),
+            _mm256_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fmsub_ph(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask_fmsub_ph(a, 0b01010101_01010101_01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3e00 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_maskz_fmsub_ph(0b01010101_01010101_01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask3_fmsub_ph(a, b, c, 0b01010101_01010101_01010101_01010101);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3c00 as i16),
+            _mm512_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmsub_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fmsub_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x1800 as i16));
+        let r = _mm512_fmsub_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x1800 as i16));
+        let r = _mm512_fmsub_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x1801 as i16));
+        let r = _mm512_fmsub_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x1800 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_fmsub_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fmsub_sh(a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0x4000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fnmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fnmadd_ph(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0xc000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fnmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_fnmadd_ph(a, 0b01010101, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fnmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_fnmadd_ph(0b01010101, a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fnmadd_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask3_fnmadd_ph(a, b, c, 0b01010101);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fnmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_fnmadd_ph(a, b, c);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0xc000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fnmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask_fnmadd_ph(a, 0b01010101_01010101, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3e00 as i16),
+            _mm256_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fnmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_maskz_fnmadd_ph(0b01010101_01010101, a, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fnmadd_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask3_fnmadd_ph(a, b, c, 0b01010101_01010101);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3c00 as i16),
+            _mm256_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fnmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fnmadd_ph(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc000 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fnmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask_fnmadd_ph(a, 0b01010101_01010101_01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3e00 as i16),
+            _mm512_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fnmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_maskz_fnmadd_ph(0b01010101_01010101_01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fnmadd_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask3_fnmadd_ph(a, b, c, 0b01010101_01010101_01010101_01010101);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3c00 as i16),
+            _mm512_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fnmadd_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r =
+            _mm512_fnmadd_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x9800 as i16));
+        let r = _mm512_fnmadd_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x9801 as i16));
+        let r = _mm512_fnmadd_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x9800 as i16));
+        let r = _mm512_fnmadd_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x9800 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_fnmadd_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fnmadd_sh(a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0xc000 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fnmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fnmsub_ph(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0xc400 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fnmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask_fnmsub_ph(a, 0b01010101, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fnmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_maskz_fnmsub_ph(0b01010101, a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x0000 as i16),
+            _mm_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fnmsub_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_mask3_fnmsub_ph(a, b, c, 0b01010101);
+        let e = _mm_mask_blend_epi16(
+            0b01010101,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fnmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_fnmsub_ph(a, b, c);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0xc400 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fnmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask_fnmsub_ph(a, 0b01010101_01010101, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3e00 as i16),
+            _mm256_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fnmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_maskz_fnmsub_ph(0b01010101_01010101, a, b, c);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x0000 as i16),
+            _mm256_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fnmsub_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi16(0x4000 as i16));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm256_mask3_fnmsub_ph(a, b, c, 0b01010101_01010101);
+        let e = _mm256_mask_blend_epi16(
+            0b01010101_01010101,
+            _mm256_set1_epi16(0x3c00 as i16),
+            _mm256_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fnmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_fnmsub_ph(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc400 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fnmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask_fnmsub_ph(a, 0b01010101_01010101_01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3e00 as i16),
+            _mm512_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fnmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_maskz_fnmsub_ph(0b01010101_01010101_01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x0000 as i16),
+            _mm512_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fnmsub_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3e00 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x4000 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r = _mm512_mask3_fnmsub_ph(a, b, c, 0b01010101_01010101_01010101_01010101);
+        let e = _mm512_mask_blend_epi16(
+            0b01010101_01010101_01010101_01010101,
+            _mm512_set1_epi16(0x3c00 as i16),
+            _mm512_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fnmsub_round_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c01 as i16));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        let r =
+            _mm512_fnmsub_round_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc001 as i16));
+        let r = _mm512_fnmsub_round_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc002 as i16));
+        let r = _mm512_fnmsub_round_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc001 as i16));
+        let r = _mm512_fnmsub_round_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a, b, c);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc001 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_fnmsub_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4000 as i16));
+        let c = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_fnmsub_sh(a, b, c);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3e00 as i16),
+            _mm_set1_epi16(0xc400 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxph_ps() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let r = _mm_cvtxph_ps(a);
+        assert_eq_m128(r, _mm_set1_ps(1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxph_ps() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3e00 as i16));
+        let r = _mm256_cvtxph_ps(a);
+        assert_eq_m256(r, _mm256_set1_ps(1.5));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxph_ps() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3e00 as i16));
+        let r = _mm512_cvtxph_ps(a);
+        assert_eq_m512(r, _mm512_set1_ps(1.5));
+        // Subnormals are converted exactly.
+        let r = _mm512_cvtxph_ps(_mm256_castsi256_ph(_mm256_set1_epi16(0x0001 as i16)));
+        assert_eq_m512(r, _mm512_set1_ps(5.9604645e-8));
+        // NaN payloads are preserved and the NaN is quieted.
+        let r = _mm512_cvtxph_ps(_mm256_castsi256_ph(_mm256_set1_epi16(0x7d55 as i16)));
+        assert_eq_m512i(_mm512_castps_si512(r), _mm512_set1_epi32(0x7feaa000));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxps_ph() {
+        let a = _mm_set1_ps(1.5);
+        let r = _mm_cvtxps_ph(a);
+        assert_eq_m128i(
+            _mm_castph_si128(r),
+            _mm_set_epi64x(0, 0x3e00_3e00_3e00_3e00),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxps_ph() {
+        let a = _mm256_set1_ps(1.5);
+        let r = _mm256_cvtxps_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x3e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxps_ph() {
+        let a = _mm512_set1_ps(1.5);
+        let r = _mm512_cvtxps_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3e00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtx_roundps_ph() {
+        // 1 + 2^-11 is halfway between two binary16 values.
+        let a = _mm512_set1_ps(1.00048828125);
+        let r = _mm512_cvtx_roundps_ph::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm512_cvtx_roundps_ph::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c00 as i16));
+        let r = _mm512_cvtx_roundps_ph::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c01 as i16));
+        let r = _mm512_cvtx_roundps_ph::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x3c00 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_pd() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xc100 as i16));
+        let r = _mm_cvtph_pd(a);
+        assert_eq_m128d(r, _mm_set1_pd(-2.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_pd() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xc100 as i16));
+        let r = _mm256_cvtph_pd(a);
+        assert_eq_m256d(r, _mm256_set1_pd(-2.5));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_pd() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xc100 as i16));
+        let r = _mm512_cvtph_pd(a);
+        assert_eq_m512d(r, _mm512_set1_pd(-2.5));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtpd_ph() {
+        let a = _mm_set1_pd(-2.5);
+        let r = _mm_cvtpd_ph(a);
+        assert_eq_m128i(
+            _mm_castph_si128(r),
+            _mm_set_epi32(0, 0, 0, 0xc100_c100_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtpd_ph() {
+        let a = _mm256_set1_pd(-2.5);
+        let r = _mm256_cvtpd_ph(a);
+        assert_eq_m128i(
+            _mm_castph_si128(r),
+            _mm_set_epi64x(0, 0xc100_c100_c100_c100_u64 as i64),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtpd_ph() {
+        let a = _mm512_set1_pd(-2.5);
+        let r = _mm512_cvtpd_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0xc100 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_epi16() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xbe00 as i16));
+        let r = _mm_cvtph_epi16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(-2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_epi16() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0xbe00 as i16));
+        let r = _mm256_cvtph_epi16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(-2));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_epi16() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0xbe00 as i16));
+        let r = _mm512_cvtph_epi16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(-2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvttph_epi16() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xbe00 as i16));
+        let r = _mm_cvttph_epi16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(-1));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvttph_epi16() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0xbe00 as i16));
+        let r = _mm256_cvttph_epi16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(-1));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvttph_epi16() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0xbe00 as i16));
+        let r = _mm512_cvttph_epi16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(-1));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_epu16() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4100 as i16));
+        let r = _mm_cvtph_epu16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_epu16() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4100 as i16));
+        let r = _mm256_cvtph_epu16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_epu16() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4100 as i16));
+        let r = _mm512_cvtph_epu16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvttph_epu16() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4100 as i16));
+        let r = _mm_cvttph_epu16(a);
+        assert_eq_m128i(r, _mm_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvttph_epu16() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x4100 as i16));
+        let r = _mm256_cvttph_epu16(a);
+        assert_eq_m256i(r, _mm256_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvttph_epu16() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x4100 as i16));
+        let r = _mm512_cvttph_epu16(a);
+        assert_eq_m512i(r, _mm512_set1_epi16(2));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtepi16_ph() {
+        let a = _mm_set1_epi16(-3);
+        let r = _mm_cvtepi16_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0xc200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtepi16_ph() {
+        let a = _mm256_set1_epi16(-3);
+        let r = _mm256_cvtepi16_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0xc200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtepi16_ph() {
+        let a = _mm512_set1_epi16(-3);
+        let r = _mm512_cvtepi16_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0xc200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtepu16_ph() {
+        let a = _mm_set1_epi16(3);
+        let r = _mm_cvtepu16_ph(a);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtepu16_ph() {
+        let a = _mm256_set1_epi16(3);
+        let r = _mm256_cvtepu16_ph(a);
+        assert_eq_m256i(_mm256_castph_si256(r), _mm256_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtepu16_ph() {
+        let a = _mm512_set1_epi16(3);
+        let r = _mm512_cvtepu16_ph(a);
+        assert_eq_m512i(_mm512_castph_si512(r), _mm512_set1_epi16(0x4200 as i16));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvtss_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_set1_ps(2.5);
+        let r = _mm_cvtss_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4100 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvtsd_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let b = _mm_set1_pd(2.5);
+        let r = _mm_cvtsd_sh(a, b);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0x4100 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvtsh_ss() {
+        let a = _mm_set1_ps(7.0);
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4100 as i16));
+        let r = _mm_cvtsh_ss(a, b);
+        assert_eq_m128(r, _mm_setr_ps(2.5, 7.0, 7.0, 7.0));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvtsh_sd() {
+        let a = _mm_set1_pd(7.0);
+        let b = _mm_castsi128_ph(_mm_set1_epi16(0x4100 as i16));
+        let r = _mm_cvtsh_sd(a, b);
+        assert_eq_m128d(r, _mm_setr_pd(2.5, 7.0));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvti32_sh() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        let r = _mm_cvti32_sh(a, -3);
+        let e = _mm_mask_blend_epi16(
+            0b00000001,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0xc200 as i16),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvtsh_i32() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x4100 as i16));
+        let r = _mm_cvtsh_i32(a);
+        assert_eq!(r, 2);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm_cvttsh_i32() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0xbe00 as i16));
+        let r = _mm_cvttsh_i32(a);
+        assert_eq!(r, -1);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_fmul_pch(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x4900c500_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmul_pch() {
+        let src = _mm_castsi128_ph(_mm_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_mask_fmul_pch(src, 0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x50005000_u32 as i32),
+            _mm_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_maskz_fmul_pch(0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_fmul_pch(a, b);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmul_pch() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_mask_fmul_pch(src, 0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x50005000_u32 as i32),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_maskz_fmul_pch(0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_fmul_pch(a, b);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmul_pch() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_mask_fmul_pch(src, 0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x50005000_u32 as i32),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_maskz_fmul_pch(0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_mul_pch(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x4900c500_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_mul_pch() {
+        let src = _mm_castsi128_ph(_mm_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_mask_mul_pch(src, 0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x50005000_u32 as i32),
+            _mm_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_mul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_maskz_mul_pch(0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_mul_pch(a, b);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_mul_pch() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_mask_mul_pch(src, 0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x50005000_u32 as i32),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_mul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_maskz_mul_pch(0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_mul_pch(a, b);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_mul_pch() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_mask_mul_pch(src, 0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x50005000_u32 as i32),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_mul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_maskz_mul_pch(0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x4900c500_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fcmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_fcmul_pch(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x40004980_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fcmul_pch() {
+        let src = _mm_castsi128_ph(_mm_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_mask_fcmul_pch(src, 0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x50005000_u32 as i32),
+            _mm_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fcmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_maskz_fcmul_pch(0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fcmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_fcmul_pch(a, b);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fcmul_pch() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_mask_fcmul_pch(src, 0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x50005000_u32 as i32),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fcmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_maskz_fcmul_pch(0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fcmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_fcmul_pch(a, b);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fcmul_pch() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_mask_fcmul_pch(src, 0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x50005000_u32 as i32),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fcmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_maskz_fcmul_pch(0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_cmul_pch(a, b);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x40004980_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cmul_pch() {
+        let src = _mm_castsi128_ph(_mm_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_mask_cmul_pch(src, 0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x50005000_u32 as i32),
+            _mm_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cmul_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm_maskz_cmul_pch(0b0101, a, b);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_cmul_pch(a, b);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cmul_pch() {
+        let src = _mm256_castsi256_ph(_mm256_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_mask_cmul_pch(src, 0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x50005000_u32 as i32),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cmul_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm256_maskz_cmul_pch(0b01010101, a, b);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_cmul_pch(a, b);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cmul_pch() {
+        let src = _mm512_castsi512_ph(_mm512_set1_epi32(0x50005000_u32 as i32));
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_mask_cmul_pch(src, 0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x50005000_u32 as i32),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cmul_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let r = _mm512_maskz_cmul_pch(0b01010101_01010101, a, b);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x40004980_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_fmadd_pch(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x4980c400_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_mask_fmadd_pch(a, 0b0101, b, c);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x40003c00_u32 as i32),
+            _mm_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_mask3_fmadd_pch(a, b, c, 0b0101);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x3c003c00_u32 as i32),
+            _mm_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_maskz_fmadd_pch(0b0101, a, b, c);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_fmadd_pch(a, b, c);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x4980c400_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_mask_fmadd_pch(a, 0b01010101, b, c);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x40003c00_u32 as i32),
+            _mm256_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_mask3_fmadd_pch(a, b, c, 0b01010101);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x3c003c00_u32 as i32),
+            _mm256_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_maskz_fmadd_pch(0b01010101, a, b, c);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_fmadd_pch(a, b, c);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x4980c400_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_mask_fmadd_pch(a, 0b01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x40003c00_u32 as i32),
+            _mm512_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_mask3_fmadd_pch(a, b, c, 0b01010101_01010101);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x3c003c00_u32 as i32),
+            _mm512_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_maskz_fmadd_pch(0b01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x4980c400_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fcmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_fcmadd_pch(a, b, c);
+        assert_eq_m128i(_mm_castph_si128(r), _mm_set1_epi32(0x42004a00_u32 as i32));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fcmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_mask_fcmadd_pch(a, 0b0101, b, c);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x40003c00_u32 as i32),
+            _mm_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fcmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_mask3_fcmadd_pch(a, b, c, 0b0101);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x3c003c00_u32 as i32),
+            _mm_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fcmadd_pch() {
+        let a = _mm_castsi128_ph(_mm_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm_castsi128_ph(_mm_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm_castsi128_ph(_mm_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm_maskz_fcmadd_pch(0b0101, a, b, c);
+        let e = _mm_mask_blend_epi32(
+            0b0101,
+            _mm_set1_epi32(0x00000000_u32 as i32),
+            _mm_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m128i(_mm_castph_si128(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fcmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_fcmadd_pch(a, b, c);
+        assert_eq_m256i(
+            _mm256_castph_si256(r),
+            _mm256_set1_epi32(0x42004a00_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fcmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_mask_fcmadd_pch(a, 0b01010101, b, c);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x40003c00_u32 as i32),
+            _mm256_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fcmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_mask3_fcmadd_pch(a, b, c, 0b01010101);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x3c003c00_u32 as i32),
+            _mm256_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fcmadd_pch() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm256_castsi256_ph(_mm256_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm256_castsi256_ph(_mm256_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm256_maskz_fcmadd_pch(0b01010101, a, b, c);
+        let e = _mm256_mask_blend_epi32(
+            0b01010101,
+            _mm256_set1_epi32(0x00000000_u32 as i32),
+            _mm256_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m256i(_mm256_castph_si256(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fcmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_fcmadd_pch(a, b, c);
+        assert_eq_m512i(
+            _mm512_castph_si512(r),
+            _mm512_set1_epi32(0x42004a00_u32 as i32),
+        );
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fcmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_mask_fcmadd_pch(a, 0b01010101_01010101, b, c);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x40003c00_u32 as i32),
+            _mm512_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fcmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_mask3_fcmadd_pch(a, b, c, 0b01010101_01010101);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x3c003c00_u32 as i32),
+            _mm512_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fcmadd_pch() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi32(0x40003c00_u32 as i32));
+        let b = _mm512_castsi512_ph(_mm512_set1_epi32(0x44004200_u32 as i32));
+        let c = _mm512_castsi512_ph(_mm512_set1_epi32(0x3c003c00_u32 as i32));
+        let r = _mm512_maskz_fcmadd_pch(0b01010101_01010101, a, b, c);
+        let e = _mm512_mask_blend_epi32(
+            0b01010101_01010101,
+            _mm512_set1_epi32(0x00000000_u32 as i32),
+            _mm512_set1_epi32(0x42004a00_u32 as i32),
+        );
+        assert_eq_m512i(_mm512_castph_si512(r), e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_reduce_add_ph() {
+        let a = _mm_castsi128_ph(_mm_set1_epi16(0x3c00 as i16));
+        assert_eq!(_mm_reduce_add_ph(a), 0x4800);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_reduce_add_ph() {
+        let a = _mm256_castsi256_ph(_mm256_set1_epi16(0x3c00 as i16));
+        assert_eq!(_mm256_reduce_add_ph(a), 0x4c00);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_reduce_add_ph() {
+        let a = _mm512_castsi512_ph(_mm512_set1_epi16(0x3c00 as i16));
+        assert_eq!(_mm512_reduce_add_ph(a), 0x5000);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_reduce_mul_ph() {
+        let a = _mm_castsi128_ph(_mm_mask_blend_epi16(
+            1 << 5,
+            _mm_set1_epi16(0x3c00 as i16),
+            _mm_set1_epi16(0xc000 as i16),
+        ));
+        assert_eq!(_mm_reduce_mul_ph(a), 0xc000);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_reduce_mul_ph() {
+        let a = _mm256_castsi256_ph(_mm256_mask_blend_epi16(
+            1 << 13,
+            _mm256_set1_epi16(0x3c00 as i16),
+            _mm256_set1_epi16(0xc000 as i16),
+        ));
+        assert_eq!(_mm256_reduce_mul_ph(a), 0xc000);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_reduce_mul_ph() {
+        let a = _mm512_castsi512_ph(_mm512_mask_blend_epi16(
+            1 << 29,
+            _mm512_set1_epi16(0x3c00 as i16),
+            _mm512_set1_epi16(0xc000 as i16),
+        ));
+        assert_eq!(_mm512_reduce_mul_ph(a), 0xc000);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_reduce_min_ph() {
+        let a = _mm_castsi128_ph(_mm_mask_blend_epi16(
+            1 << 5,
+            _mm_set1_epi16(0x4000 as i16),
+            _mm_set1_epi16(0xc200 as i16),
+        ));
+        assert_eq!(_mm_reduce_min_ph(a), 0xc200);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_reduce_min_ph() {
+        let a = _mm256_castsi256_ph(_mm256_mask_blend_epi16(
+            1 << 13,
+            _mm256_set1_epi16(0x4000 as i16),
+            _mm256_set1_epi16(0xc200 as i16),
+        ));
+        assert_eq!(_mm256_reduce_min_ph(a), 0xc200);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_reduce_min_ph() {
+        let a = _mm512_castsi512_ph(_mm512_mask_blend_epi16(
+            1 << 29,
+            _mm512_set1_epi16(0x4000 as i16),
+            _mm512_set1_epi16(0xc200 as i16),
+        ));
+        assert_eq!(_mm512_reduce_min_ph(a), 0xc200);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_reduce_max_ph() {
+        let a = _mm_castsi128_ph(_mm_mask_blend_epi16(
+            1 << 5,
+            _mm_set1_epi16(0xc000 as i16),
+            _mm_set1_epi16(0x4200 as i16),
+        ));
+        assert_eq!(_mm_reduce_max_ph(a), 0x4200);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_reduce_max_ph() {
+        let a = _mm256_castsi256_ph(_mm256_mask_blend_epi16(
+            1 << 13,
+            _mm256_set1_epi16(0xc000 as i16),
+            _mm256_set1_epi16(0x4200 as i16),
+        ));
+        assert_eq!(_mm256_reduce_max_ph(a), 0x4200);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_reduce_max_ph() {
+        let a = _mm512_castsi512_ph(_mm512_mask_blend_epi16(
+            1 << 29,
+            _mm512_set1_epi16(0xc000 as i16),
+            _mm512_set1_epi16(0x4200 as i16),
+        ));
+        assert_eq!(_mm512_reduce_max_ph(a), 0x4200);
+    }
+}
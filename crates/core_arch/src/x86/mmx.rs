@@ -0,0 +1,3885 @@
+//! `__m64` intrinsics: MMX and the SSE instructions that operate on MMX
+//! registers.
+//!
+//! LLVM no longer models the MMX register file, so these intrinsics are
+//! implemented with SSE2 instructions on the low 64 bits of an `xmm`
+//! register, require the `sse2` target feature in addition to `mmx`, and
+//! produce the same results as their MMX counterparts. None of
+//! them put the processor into the MMX state, which means the x87 registers
+//! stay usable and no `_mm_empty` is needed between them and floating-point
+//! code. `_mm_empty` still executes `emms`, for code that hands `__m64`
+//! values to or from real MMX instructions written in assembly.
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Zero-extends `a` to a 128-bit vector.
+#[inline]
+unsafe fn zext128(a: __m64) -> __m128i {
+    transmute(i64x2::new(transmute(a), 0))
+}
+
+/// Returns the low 64 bits of `a`.
+#[inline]
+unsafe fn trunc64(a: __m128i) -> __m64 {
+    transmute(simd_extract::<_, i64>(a.as_i64x2(), 0))
+}
+
+/// Empties the MMX state, marking the x87 registers as available for use by
+/// floating-point instructions.
+///
+/// None of the intrinsics in this module leave the processor in the MMX state,
+/// so this is only required when `__m64` values are exchanged with code (for
+/// example inline assembly or foreign functions) that executes real MMX
+/// instructions. That code must execute `emms` before any x87 instruction runs.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_empty)
+#[inline]
+#[target_feature(enable = "mmx")]
+#[cfg_attr(test, assert_instr(emms))]
+pub unsafe fn _mm_empty() {
+    asm!("emms", options(nomem, nostack, preserves_flags));
+}
+
+/// Empties the MMX state, marking the x87 registers as available for use by
+/// floating-point instructions.
+///
+/// This is an alias for [`_mm_empty`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_empty)
+#[inline]
+#[target_feature(enable = "mmx")]
+#[cfg_attr(test, assert_instr(emms))]
+pub unsafe fn _m_empty() {
+    _mm_empty()
+}
+
+/// Copies 32-bit integer `a` to the lower elements of the return value, and
+/// zeroes the upper element.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsi32_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, target_arch = "x86_64"), assert_instr(movd))]
+pub unsafe fn _mm_cvtsi32_si64(a: i32) -> __m64 {
+    transmute(i32x2::new(a, 0))
+}
+
+/// Copies 32-bit integer `a` to the lower elements of the return value, and
+/// zeroes the upper element.
+///
+/// This is an alias for [`_mm_cvtsi32_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_from_int)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, target_arch = "x86_64"), assert_instr(movd))]
+pub unsafe fn _m_from_int(a: i32) -> __m64 {
+    _mm_cvtsi32_si64(a)
+}
+
+/// Returns the lowest 32-bit integer element of `a`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsi64_si32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(movd))]
+pub unsafe fn _mm_cvtsi64_si32(a: __m64) -> i32 {
+    simd_extract(a.as_i32x2(), 0)
+}
+
+/// Returns the lowest 32-bit integer element of `a`.
+///
+/// This is an alias for [`_mm_cvtsi64_si32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_to_int)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(movd))]
+pub unsafe fn _m_to_int(a: __m64) -> i32 {
+    _mm_cvtsi64_si32(a)
+}
+
+/// Converts packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using signed saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_packs_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packsswb))]
+pub unsafe fn _mm_packs_pi16(a: __m64, b: __m64) -> __m64 {
+    let ab = _mm_unpacklo_epi64(zext128(a), zext128(b));
+    trunc64(_mm_packs_epi16(ab, ab))
+}
+
+/// Converts packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using signed saturation.
+///
+/// This is an alias for [`_mm_packs_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_packsswb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packsswb))]
+pub unsafe fn _m_packsswb(a: __m64, b: __m64) -> __m64 {
+    _mm_packs_pi16(a, b)
+}
+
+/// Converts packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using signed saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_packs_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packssdw))]
+pub unsafe fn _mm_packs_pi32(a: __m64, b: __m64) -> __m64 {
+    let ab = _mm_unpacklo_epi64(zext128(a), zext128(b));
+    trunc64(_mm_packs_epi32(ab, ab))
+}
+
+/// Converts packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using signed saturation.
+///
+/// This is an alias for [`_mm_packs_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_packssdw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packssdw))]
+pub unsafe fn _m_packssdw(a: __m64, b: __m64) -> __m64 {
+    _mm_packs_pi32(a, b)
+}
+
+/// Converts packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using unsigned saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_packs_pu16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packuswb))]
+pub unsafe fn _mm_packs_pu16(a: __m64, b: __m64) -> __m64 {
+    let ab = _mm_unpacklo_epi64(zext128(a), zext128(b));
+    trunc64(_mm_packus_epi16(ab, ab))
+}
+
+/// Converts packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using unsigned saturation.
+///
+/// This is an alias for [`_mm_packs_pu16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_packuswb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(packuswb))]
+pub unsafe fn _m_packuswb(a: __m64, b: __m64) -> __m64 {
+    _mm_packs_pu16(a, b)
+}
+
+/// Unpacks and interleaves 8-bit integers from the high half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpackhi_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklbw))]
+pub unsafe fn _mm_unpackhi_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_shuffle8!(
+        a.as_i8x8(),
+        b.as_i8x8(),
+        [4, 12, 5, 13, 6, 14, 7, 15],
+    ))
+}
+
+/// Unpacks and interleaves 8-bit integers from the high half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpackhi_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpckhbw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklbw))]
+pub unsafe fn _m_punpckhbw(a: __m64, b: __m64) -> __m64 {
+    _mm_unpackhi_pi8(a, b)
+}
+
+/// Unpacks and interleaves 16-bit integers from the high half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpackhi_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklwd))]
+pub unsafe fn _mm_unpackhi_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_shuffle4!(a.as_i16x4(), b.as_i16x4(), [2, 6, 3, 7]))
+}
+
+/// Unpacks and interleaves 16-bit integers from the high half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpackhi_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpckhwd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklwd))]
+pub unsafe fn _m_punpckhwd(a: __m64, b: __m64) -> __m64 {
+    _mm_unpackhi_pi16(a, b)
+}
+
+/// Unpacks and interleaves 32-bit integers from the high half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpackhi_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpckldq))]
+pub unsafe fn _mm_unpackhi_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_shuffle2!(a.as_i32x2(), b.as_i32x2(), [1, 3]))
+}
+
+/// Unpacks and interleaves 32-bit integers from the high half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpackhi_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpckhdq)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpckldq))]
+pub unsafe fn _m_punpckhdq(a: __m64, b: __m64) -> __m64 {
+    _mm_unpackhi_pi32(a, b)
+}
+
+/// Unpacks and interleaves 8-bit integers from the low half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpacklo_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklbw))]
+pub unsafe fn _mm_unpacklo_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_shuffle8!(
+        a.as_i8x8(),
+        b.as_i8x8(),
+        [0, 8, 1, 9, 2, 10, 3, 11],
+    ))
+}
+
+/// Unpacks and interleaves 8-bit integers from the low half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpacklo_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpcklbw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklbw))]
+pub unsafe fn _m_punpcklbw(a: __m64, b: __m64) -> __m64 {
+    _mm_unpacklo_pi8(a, b)
+}
+
+/// Unpacks and interleaves 16-bit integers from the low half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpacklo_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklwd))]
+pub unsafe fn _mm_unpacklo_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_shuffle4!(a.as_i16x4(), b.as_i16x4(), [0, 4, 1, 5]))
+}
+
+/// Unpacks and interleaves 16-bit integers from the low half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpacklo_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpcklwd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(punpcklwd))]
+pub unsafe fn _m_punpcklwd(a: __m64, b: __m64) -> __m64 {
+    _mm_unpacklo_pi16(a, b)
+}
+
+/// Unpacks and interleaves 32-bit integers from the low half of `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_unpacklo_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(unpcklps))]
+pub unsafe fn _mm_unpacklo_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_shuffle2!(a.as_i32x2(), b.as_i32x2(), [0, 2]))
+}
+
+/// Unpacks and interleaves 32-bit integers from the low half of `a` and `b`.
+///
+/// This is an alias for [`_mm_unpacklo_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_punpckldq)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(unpcklps))]
+pub unsafe fn _m_punpckldq(a: __m64, b: __m64) -> __m64 {
+    _mm_unpacklo_pi32(a, b)
+}
+
+/// Adds packed 8-bit integers in `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddb))]
+pub unsafe fn _mm_add_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_add(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`.
+///
+/// This is an alias for [`_mm_add_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddb))]
+pub unsafe fn _m_paddb(a: __m64, b: __m64) -> __m64 {
+    _mm_add_pi8(a, b)
+}
+
+/// Adds packed 16-bit integers in `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddw))]
+pub unsafe fn _mm_add_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_add(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`.
+///
+/// This is an alias for [`_mm_add_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddw))]
+pub unsafe fn _m_paddw(a: __m64, b: __m64) -> __m64 {
+    _mm_add_pi16(a, b)
+}
+
+/// Adds packed 32-bit integers in `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddd))]
+pub unsafe fn _mm_add_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_add(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Adds packed 32-bit integers in `a` and `b`.
+///
+/// This is an alias for [`_mm_add_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddd))]
+pub unsafe fn _m_paddd(a: __m64, b: __m64) -> __m64 {
+    _mm_add_pi32(a, b)
+}
+
+/// Adds packed 8-bit integers in `a` and `b` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_adds_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddsb))]
+pub unsafe fn _mm_adds_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_saturating_add(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b` using saturation.
+///
+/// This is an alias for [`_mm_adds_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddsb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddsb))]
+pub unsafe fn _m_paddsb(a: __m64, b: __m64) -> __m64 {
+    _mm_adds_pi8(a, b)
+}
+
+/// Adds packed 16-bit integers in `a` and `b` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_adds_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddsw))]
+pub unsafe fn _mm_adds_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_saturating_add(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b` using saturation.
+///
+/// This is an alias for [`_mm_adds_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddsw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddsw))]
+pub unsafe fn _m_paddsw(a: __m64, b: __m64) -> __m64 {
+    _mm_adds_pi16(a, b)
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_adds_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddusb))]
+pub unsafe fn _mm_adds_pu8(a: __m64, b: __m64) -> __m64 {
+    transmute::<u8x8, _>(simd_saturating_add(a.as_u8x8(), b.as_u8x8()))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation.
+///
+/// This is an alias for [`_mm_adds_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddusb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddusb))]
+pub unsafe fn _m_paddusb(a: __m64, b: __m64) -> __m64 {
+    _mm_adds_pu8(a, b)
+}
+
+/// Adds packed unsigned 16-bit integers in `a` and `b` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_adds_pu16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddusw))]
+pub unsafe fn _mm_adds_pu16(a: __m64, b: __m64) -> __m64 {
+    transmute::<u16x4, _>(simd_saturating_add(a.as_u16x4(), b.as_u16x4()))
+}
+
+/// Adds packed unsigned 16-bit integers in `a` and `b` using saturation.
+///
+/// This is an alias for [`_mm_adds_pu16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_paddusw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(paddusw))]
+pub unsafe fn _m_paddusw(a: __m64, b: __m64) -> __m64 {
+    _mm_adds_pu16(a, b)
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubb))]
+pub unsafe fn _mm_sub_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_sub(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`.
+///
+/// This is an alias for [`_mm_sub_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubb))]
+pub unsafe fn _m_psubb(a: __m64, b: __m64) -> __m64 {
+    _mm_sub_pi8(a, b)
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubw))]
+pub unsafe fn _mm_sub_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_sub(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`.
+///
+/// This is an alias for [`_mm_sub_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubw))]
+pub unsafe fn _m_psubw(a: __m64, b: __m64) -> __m64 {
+    _mm_sub_pi16(a, b)
+}
+
+/// Subtracts packed 32-bit integers in `b` from packed 32-bit integers in `a`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubd))]
+pub unsafe fn _mm_sub_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_sub(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Subtracts packed 32-bit integers in `b` from packed 32-bit integers in `a`.
+///
+/// This is an alias for [`_mm_sub_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubd))]
+pub unsafe fn _m_psubd(a: __m64, b: __m64) -> __m64 {
+    _mm_sub_pi32(a, b)
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`
+/// using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_subs_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubsb))]
+pub unsafe fn _mm_subs_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_saturating_sub(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`
+/// using saturation.
+///
+/// This is an alias for [`_mm_subs_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubsb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubsb))]
+pub unsafe fn _m_psubsb(a: __m64, b: __m64) -> __m64 {
+    _mm_subs_pi8(a, b)
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`
+/// using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_subs_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubsw))]
+pub unsafe fn _mm_subs_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_saturating_sub(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`
+/// using saturation.
+///
+/// This is an alias for [`_mm_subs_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubsw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubsw))]
+pub unsafe fn _m_psubsw(a: __m64, b: __m64) -> __m64 {
+    _mm_subs_pi16(a, b)
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from packed unsigned 8-bit
+/// integers in `a` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_subs_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubusb))]
+pub unsafe fn _mm_subs_pu8(a: __m64, b: __m64) -> __m64 {
+    transmute::<u8x8, _>(simd_saturating_sub(a.as_u8x8(), b.as_u8x8()))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from packed unsigned 8-bit
+/// integers in `a` using saturation.
+///
+/// This is an alias for [`_mm_subs_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubusb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubusb))]
+pub unsafe fn _m_psubusb(a: __m64, b: __m64) -> __m64 {
+    _mm_subs_pu8(a, b)
+}
+
+/// Subtracts packed unsigned 16-bit integers in `b` from packed unsigned 16-bit
+/// integers in `a` using saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_subs_pu16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubusw))]
+pub unsafe fn _mm_subs_pu16(a: __m64, b: __m64) -> __m64 {
+    transmute::<u16x4, _>(simd_saturating_sub(a.as_u16x4(), b.as_u16x4()))
+}
+
+/// Subtracts packed unsigned 16-bit integers in `b` from packed unsigned 16-bit
+/// integers in `a` using saturation.
+///
+/// This is an alias for [`_mm_subs_pu16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psubusw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psubusw))]
+pub unsafe fn _m_psubusw(a: __m64, b: __m64) -> __m64 {
+    _mm_subs_pu16(a, b)
+}
+
+/// Multiplies packed 16-bit integers in `a` and `b`, producing intermediate
+/// signed 32-bit integers. Horizontally adds adjacent pairs of intermediate
+/// 32-bit integers.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_madd_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaddwd))]
+pub unsafe fn _mm_madd_pi16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_madd_epi16(zext128(a), zext128(b)))
+}
+
+/// Multiplies packed 16-bit integers in `a` and `b`, producing intermediate
+/// signed 32-bit integers. Horizontally adds adjacent pairs of intermediate
+/// 32-bit integers.
+///
+/// This is an alias for [`_mm_madd_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmaddwd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaddwd))]
+pub unsafe fn _m_pmaddwd(a: __m64, b: __m64) -> __m64 {
+    _mm_madd_pi16(a, b)
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`.
+///
+/// The multiplication produces intermediate 32-bit integers, and returns the
+/// high 16 bits of the intermediate integers.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mulhi_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmulhw))]
+pub unsafe fn _mm_mulhi_pi16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_mulhi_epi16(zext128(a), zext128(b)))
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`.
+///
+/// This is an alias for [`_mm_mulhi_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmulhw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmulhw))]
+pub unsafe fn _m_pmulhw(a: __m64, b: __m64) -> __m64 {
+    _mm_mulhi_pi16(a, b)
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`.
+///
+/// The multiplication produces intermediate 32-bit integers, and returns the
+/// low 16 bits of the intermediate integers.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mullo_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmullw))]
+pub unsafe fn _mm_mullo_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_mul(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`.
+///
+/// This is an alias for [`_mm_mullo_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmullw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmullw))]
+pub unsafe fn _m_pmullw(a: __m64, b: __m64) -> __m64 {
+    _mm_mullo_pi16(a, b)
+}
+
+/// Shifts packed 16-bit integers in `a` left by `count` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sll_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllw))]
+pub unsafe fn _mm_sll_pi16(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_sll_epi16(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `count` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_sll_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psllw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllw))]
+pub unsafe fn _m_psllw(a: __m64, count: __m64) -> __m64 {
+    _mm_sll_pi16(a, count)
+}
+
+/// Shifts packed 16-bit integers in `a` left by `IMM8` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_slli_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_slli_pi16<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_slli_epi16::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `IMM8` while shifting in zeros.
+///
+/// This is an alias for [`_mm_slli_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psllwi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psllwi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_slli_pi16::<IMM8>(a)
+}
+
+/// Shifts packed 32-bit integers in `a` left by `count` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sll_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pslld))]
+pub unsafe fn _mm_sll_pi32(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_sll_epi32(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 32-bit integers in `a` left by `count` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_sll_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pslld)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pslld))]
+pub unsafe fn _m_pslld(a: __m64, count: __m64) -> __m64 {
+    _mm_sll_pi32(a, count)
+}
+
+/// Shifts packed 32-bit integers in `a` left by `IMM8` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_slli_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pslld, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_slli_pi32<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_slli_epi32::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 32-bit integers in `a` left by `IMM8` while shifting in zeros.
+///
+/// This is an alias for [`_mm_slli_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pslldi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pslld, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_pslldi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_slli_pi32::<IMM8>(a)
+}
+
+/// Shifts 64-bit integer `a` left by `count` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sll_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllq))]
+pub unsafe fn _mm_sll_si64(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_sll_epi64(zext128(a), zext128(count)))
+}
+
+/// Shifts 64-bit integer `a` left by `count` while shifting in zeros.
+///
+/// This is an alias for [`_mm_sll_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psllq)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllq))]
+pub unsafe fn _m_psllq(a: __m64, count: __m64) -> __m64 {
+    _mm_sll_si64(a, count)
+}
+
+/// Shifts 64-bit integer `a` left by `IMM8` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_slli_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllq, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_slli_si64<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_slli_epi64::<IMM8>(zext128(a)))
+}
+
+/// Shifts 64-bit integer `a` left by `IMM8` while shifting in zeros.
+///
+/// This is an alias for [`_mm_slli_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psllqi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psllq, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psllqi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_slli_si64::<IMM8>(a)
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// sign bits.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sra_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psraw))]
+pub unsafe fn _mm_sra_pi16(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_sra_epi16(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// sign bits.
+///
+/// This is an alias for [`_mm_sra_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psraw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psraw))]
+pub unsafe fn _m_psraw(a: __m64, count: __m64) -> __m64 {
+    _mm_sra_pi16(a, count)
+}
+
+/// Shifts packed 16-bit integers in `a` right by `IMM8` while shifting in sign
+/// bits.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srai_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psraw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_srai_pi16<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_srai_epi16::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `IMM8` while shifting in sign
+/// bits.
+///
+/// This is an alias for [`_mm_srai_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrawi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psraw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psrawi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_srai_pi16::<IMM8>(a)
+}
+
+/// Shifts packed 32-bit integers in `a` right by `count` while shifting in
+/// sign bits.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sra_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrad))]
+pub unsafe fn _mm_sra_pi32(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_sra_epi32(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `count` while shifting in
+/// sign bits.
+///
+/// This is an alias for [`_mm_sra_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrad)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrad))]
+pub unsafe fn _m_psrad(a: __m64, count: __m64) -> __m64 {
+    _mm_sra_pi32(a, count)
+}
+
+/// Shifts packed 32-bit integers in `a` right by `IMM8` while shifting in sign
+/// bits.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srai_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrad, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_srai_pi32<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_srai_epi32::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `IMM8` while shifting in sign
+/// bits.
+///
+/// This is an alias for [`_mm_srai_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psradi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrad, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psradi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_srai_pi32::<IMM8>(a)
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srl_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlw))]
+pub unsafe fn _mm_srl_pi16(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_srl_epi16(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_srl_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrlw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlw))]
+pub unsafe fn _m_psrlw(a: __m64, count: __m64) -> __m64 {
+    _mm_srl_pi16(a, count)
+}
+
+/// Shifts packed 16-bit integers in `a` right by `IMM8` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srli_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_srli_pi16<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_srli_epi16::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `IMM8` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_srli_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrlwi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlw, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psrlwi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_srli_pi16::<IMM8>(a)
+}
+
+/// Shifts packed 32-bit integers in `a` right by `count` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srl_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrld))]
+pub unsafe fn _mm_srl_pi32(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_srl_epi32(zext128(a), zext128(count)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `count` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_srl_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrld)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrld))]
+pub unsafe fn _m_psrld(a: __m64, count: __m64) -> __m64 {
+    _mm_srl_pi32(a, count)
+}
+
+/// Shifts packed 32-bit integers in `a` right by `IMM8` while shifting in
+/// zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srli_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrld, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_srli_pi32<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_srli_epi32::<IMM8>(zext128(a)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `IMM8` while shifting in
+/// zeros.
+///
+/// This is an alias for [`_mm_srli_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrldi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrld, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psrldi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_srli_pi32::<IMM8>(a)
+}
+
+/// Shifts 64-bit integer `a` right by `count` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srl_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlq))]
+pub unsafe fn _mm_srl_si64(a: __m64, count: __m64) -> __m64 {
+    trunc64(_mm_srl_epi64(zext128(a), zext128(count)))
+}
+
+/// Shifts 64-bit integer `a` right by `count` while shifting in zeros.
+///
+/// This is an alias for [`_mm_srl_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrlq)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlq))]
+pub unsafe fn _m_psrlq(a: __m64, count: __m64) -> __m64 {
+    _mm_srl_si64(a, count)
+}
+
+/// Shifts 64-bit integer `a` right by `IMM8` while shifting in zeros.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_srli_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlq, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_srli_si64<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_srli_epi64::<IMM8>(zext128(a)))
+}
+
+/// Shifts 64-bit integer `a` right by `IMM8` while shifting in zeros.
+///
+/// This is an alias for [`_mm_srli_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psrlqi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psrlq, IMM8 = 7))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_psrlqi<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_srli_si64::<IMM8>(a)
+}
+
+/// Computes the bitwise AND of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_and_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(andps))]
+pub unsafe fn _mm_and_si64(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_and(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Computes the bitwise AND of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is an alias for [`_mm_and_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pand)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(andps))]
+pub unsafe fn _m_pand(a: __m64, b: __m64) -> __m64 {
+    _mm_and_si64(a, b)
+}
+
+/// Computes the bitwise NOT of 64 bits (representing integer data) in `a` and
+/// then AND with `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_andnot_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(andnps))]
+pub unsafe fn _mm_andnot_si64(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_and(
+        simd_xor(a.as_i32x2(), i32x2::splat(-1)),
+        b.as_i32x2(),
+    ))
+}
+
+/// Computes the bitwise NOT of 64 bits (representing integer data) in `a` and
+/// then AND with `b`.
+///
+/// This is an alias for [`_mm_andnot_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pandn)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(andnps))]
+pub unsafe fn _m_pandn(a: __m64, b: __m64) -> __m64 {
+    _mm_andnot_si64(a, b)
+}
+
+/// Computes the bitwise OR of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_or_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(orps))]
+pub unsafe fn _mm_or_si64(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_or(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Computes the bitwise OR of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is an alias for [`_mm_or_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_por)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(orps))]
+pub unsafe fn _m_por(a: __m64, b: __m64) -> __m64 {
+    _mm_or_si64(a, b)
+}
+
+/// Computes the bitwise XOR of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_xor_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(xorps))]
+pub unsafe fn _mm_xor_si64(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_xor(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Computes the bitwise XOR of 64 bits (representing integer data) in `a` and
+/// `b`.
+///
+/// This is an alias for [`_mm_xor_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pxor)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(xorps))]
+pub unsafe fn _m_pxor(a: __m64, b: __m64) -> __m64 {
+    _mm_xor_si64(a, b)
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for equality.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqb))]
+pub unsafe fn _mm_cmpeq_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_eq(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for equality.
+///
+/// This is an alias for [`_mm_cmpeq_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpeqb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqb))]
+pub unsafe fn _m_pcmpeqb(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpeq_pi8(a, b)
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for equality.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqw))]
+pub unsafe fn _mm_cmpeq_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_eq(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for equality.
+///
+/// This is an alias for [`_mm_cmpeq_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpeqw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqw))]
+pub unsafe fn _m_pcmpeqw(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpeq_pi16(a, b)
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for equality.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqd))]
+pub unsafe fn _mm_cmpeq_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_eq(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for equality.
+///
+/// This is an alias for [`_mm_cmpeq_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpeqd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpeqd))]
+pub unsafe fn _m_pcmpeqd(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpeq_pi32(a, b)
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for greater-than.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtb))]
+pub unsafe fn _mm_cmpgt_pi8(a: __m64, b: __m64) -> __m64 {
+    transmute::<i8x8, _>(simd_gt(a.as_i8x8(), b.as_i8x8()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for greater-than.
+///
+/// This is an alias for [`_mm_cmpgt_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpgtb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtb))]
+pub unsafe fn _m_pcmpgtb(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpgt_pi8(a, b)
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for greater-than.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtw))]
+pub unsafe fn _mm_cmpgt_pi16(a: __m64, b: __m64) -> __m64 {
+    transmute::<i16x4, _>(simd_gt(a.as_i16x4(), b.as_i16x4()))
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for greater-than.
+///
+/// This is an alias for [`_mm_cmpgt_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpgtw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtw))]
+pub unsafe fn _m_pcmpgtw(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpgt_pi16(a, b)
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for greater-than.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtd))]
+pub unsafe fn _mm_cmpgt_pi32(a: __m64, b: __m64) -> __m64 {
+    transmute::<i32x2, _>(simd_gt(a.as_i32x2(), b.as_i32x2()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for greater-than.
+///
+/// This is an alias for [`_mm_cmpgt_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pcmpgtd)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pcmpgtd))]
+pub unsafe fn _m_pcmpgtd(a: __m64, b: __m64) -> __m64 {
+    _mm_cmpgt_pi32(a, b)
+}
+
+/// Returns a vector with all elements set to zero.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setzero_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(xorps))]
+pub unsafe fn _mm_setzero_si64() -> __m64 {
+    transmute(i32x2::new(0, 0))
+}
+
+/// Sets packed 32-bit integers with the supplied values, from highest to lowest.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set_pi32(e1: i32, e0: i32) -> __m64 {
+    transmute(i32x2::new(e0, e1))
+}
+
+/// Sets packed 16-bit integers with the supplied values, from highest to lowest.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set_pi16(e3: i16, e2: i16, e1: i16, e0: i16) -> __m64 {
+    transmute(i16x4::new(e0, e1, e2, e3))
+}
+
+/// Sets packed 8-bit integers with the supplied values, from highest to lowest.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set_pi8(e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8) -> __m64 {
+    transmute(i8x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Broadcasts 32-bit integer `a` to all elements.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set1_pi32(a: i32) -> __m64 {
+    _mm_set_pi32(a, a)
+}
+
+/// Broadcasts 16-bit integer `a` to all elements.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set1_pi16(a: i16) -> __m64 {
+    _mm_set_pi16(a, a, a, a)
+}
+
+/// Broadcasts 8-bit integer `a` to all elements.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_set1_pi8(a: i8) -> __m64 {
+    _mm_set_pi8(a, a, a, a, a, a, a, a)
+}
+
+/// Sets packed 32-bit integers with the supplied values in reverse order.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setr_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_setr_pi32(e1: i32, e0: i32) -> __m64 {
+    _mm_set_pi32(e0, e1)
+}
+
+/// Sets packed 16-bit integers with the supplied values in reverse order.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setr_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_setr_pi16(e3: i16, e2: i16, e1: i16, e0: i16) -> __m64 {
+    _mm_set_pi16(e0, e1, e2, e3)
+}
+
+/// Sets packed 8-bit integers with the supplied values in reverse order.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setr_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_setr_pi8(
+    e7: i8,
+    e6: i8,
+    e5: i8,
+    e4: i8,
+    e3: i8,
+    e2: i8,
+    e1: i8,
+    e0: i8,
+) -> __m64 {
+    _mm_set_pi8(e0, e1, e2, e3, e4, e5, e6, e7)
+}
+
+/// Computes the rounded averages of the packed unsigned 8-bit integer values
+/// in `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_avg_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pavgb))]
+pub unsafe fn _mm_avg_pu8(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_avg_epu8(zext128(a), zext128(b)))
+}
+
+/// Computes the rounded averages of the packed unsigned 8-bit integer values
+/// in `a` and `b`.
+///
+/// This is an alias for [`_mm_avg_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pavgb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pavgb))]
+pub unsafe fn _m_pavgb(a: __m64, b: __m64) -> __m64 {
+    _mm_avg_pu8(a, b)
+}
+
+/// Computes the rounded averages of the packed unsigned 16-bit integer values
+/// in `a` and `b`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_avg_pu16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pavgw))]
+pub unsafe fn _mm_avg_pu16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_avg_epu16(zext128(a), zext128(b)))
+}
+
+/// Computes the rounded averages of the packed unsigned 16-bit integer values
+/// in `a` and `b`.
+///
+/// This is an alias for [`_mm_avg_pu16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pavgw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pavgw))]
+pub unsafe fn _m_pavgw(a: __m64, b: __m64) -> __m64 {
+    _mm_avg_pu16(a, b)
+}
+
+/// Extracts 16-bit element from a 64-bit vector of `[4 x i16]` and
+/// returns it, as specified by the immediate integer operand.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_extract_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pextrw, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_extract_pi16<const IMM8: i32>(a: __m64) -> i32 {
+    static_assert_imm2!(IMM8);
+    simd_extract::<_, u16>(a.as_u16x4(), IMM8 as u32) as i32
+}
+
+/// Extracts 16-bit element from a 64-bit vector of `[4 x i16]` and
+/// returns it, as specified by the immediate integer operand.
+///
+/// This is an alias for [`_mm_extract_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pextrw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pextrw, IMM8 = 3))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_pextrw<const IMM8: i32>(a: __m64) -> i32 {
+    _mm_extract_pi16::<IMM8>(a)
+}
+
+/// Copies data from the 64-bit vector `a` to the result vector, and inserts
+/// the 16-bit integer `i` at the location specified by the immediate operand.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_insert_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pinsrw, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_insert_pi16<const IMM8: i32>(a: __m64, i: i32) -> __m64 {
+    static_assert_imm2!(IMM8);
+    transmute(simd_insert(a.as_i16x4(), IMM8 as u32, i as i16))
+}
+
+/// Copies data from the 64-bit vector `a` to the result vector, and inserts
+/// the 16-bit integer `i` at the location specified by the immediate operand.
+///
+/// This is an alias for [`_mm_insert_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pinsrw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pinsrw, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _m_pinsrw<const IMM8: i32>(a: __m64, i: i32) -> __m64 {
+    _mm_insert_pi16::<IMM8>(a, i)
+}
+
+/// Compares the packed 16-bit signed integers of `a` and `b` writing the
+/// greatest value into the result.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_max_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaxsw))]
+pub unsafe fn _mm_max_pi16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_max_epi16(zext128(a), zext128(b)))
+}
+
+/// Compares the packed 16-bit signed integers of `a` and `b` writing the
+/// greatest value into the result.
+///
+/// This is an alias for [`_mm_max_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmaxsw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaxsw))]
+pub unsafe fn _m_pmaxsw(a: __m64, b: __m64) -> __m64 {
+    _mm_max_pi16(a, b)
+}
+
+/// Compares the packed 8-bit unsigned integers of `a` and `b` writing the
+/// greatest value into the result.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_max_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaxub))]
+pub unsafe fn _mm_max_pu8(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_max_epu8(zext128(a), zext128(b)))
+}
+
+/// Compares the packed 8-bit unsigned integers of `a` and `b` writing the
+/// greatest value into the result.
+///
+/// This is an alias for [`_mm_max_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmaxub)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmaxub))]
+pub unsafe fn _m_pmaxub(a: __m64, b: __m64) -> __m64 {
+    _mm_max_pu8(a, b)
+}
+
+/// Compares the packed 16-bit signed integers of `a` and `b` writing the
+/// smallest value into the result.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_min_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pminsw))]
+pub unsafe fn _mm_min_pi16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_min_epi16(zext128(a), zext128(b)))
+}
+
+/// Compares the packed 16-bit signed integers of `a` and `b` writing the
+/// smallest value into the result.
+///
+/// This is an alias for [`_mm_min_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pminsw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pminsw))]
+pub unsafe fn _m_pminsw(a: __m64, b: __m64) -> __m64 {
+    _mm_min_pi16(a, b)
+}
+
+/// Compares the packed 8-bit unsigned integers of `a` and `b` writing the
+/// smallest value into the result.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_min_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pminub))]
+pub unsafe fn _mm_min_pu8(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_min_epu8(zext128(a), zext128(b)))
+}
+
+/// Compares the packed 8-bit unsigned integers of `a` and `b` writing the
+/// smallest value into the result.
+///
+/// This is an alias for [`_mm_min_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pminub)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pminub))]
+pub unsafe fn _m_pminub(a: __m64, b: __m64) -> __m64 {
+    _mm_min_pu8(a, b)
+}
+
+/// Takes the most significant bit from each 8-bit element in a 64-bit
+/// integer vector to create an 8-bit mask value. Zero-extends the value to
+/// 32-bit integer and writes it to the destination.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_movemask_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmovmskb))]
+pub unsafe fn _mm_movemask_pi8(a: __m64) -> i32 {
+    _mm_movemask_epi8(zext128(a))
+}
+
+/// Takes the most significant bit from each 8-bit element in a 64-bit
+/// integer vector to create an 8-bit mask value. Zero-extends the value to
+/// 32-bit integer and writes it to the destination.
+///
+/// This is an alias for [`_mm_movemask_pi8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmovmskb)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmovmskb))]
+pub unsafe fn _m_pmovmskb(a: __m64) -> i32 {
+    _mm_movemask_pi8(a)
+}
+
+/// Multiplies packed 16-bit unsigned integer values and writes the
+/// high-order 16 bits of each 32-bit product to the corresponding bits in
+/// the destination.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mulhi_pu16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmulhuw))]
+pub unsafe fn _mm_mulhi_pu16(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_mulhi_epu16(zext128(a), zext128(b)))
+}
+
+/// Multiplies packed 16-bit unsigned integer values and writes the
+/// high-order 16 bits of each 32-bit product to the corresponding bits in
+/// the destination.
+///
+/// This is an alias for [`_mm_mulhi_pu16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pmulhuw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pmulhuw))]
+pub unsafe fn _m_pmulhuw(a: __m64, b: __m64) -> __m64 {
+    _mm_mulhi_pu16(a, b)
+}
+
+/// Subtracts the corresponding 8-bit unsigned integer values of the two
+/// 64-bit vector operands and computes the absolute value for each of the
+/// difference. Then sum of the 8 absolute differences is written to the
+/// bits `[15:0]` of the destination; the remaining bits `[63:16]` are cleared.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sad_pu8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psadbw))]
+pub unsafe fn _mm_sad_pu8(a: __m64, b: __m64) -> __m64 {
+    trunc64(_mm_sad_epu8(zext128(a), zext128(b)))
+}
+
+/// Subtracts the corresponding 8-bit unsigned integer values of the two
+/// 64-bit vector operands and computes the absolute value for each of the
+/// difference. Then sum of the 8 absolute differences is written to the
+/// bits `[15:0]` of the destination; the remaining bits `[63:16]` are cleared.
+///
+/// This is an alias for [`_mm_sad_pu8`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_psadbw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(psadbw))]
+pub unsafe fn _m_psadbw(a: __m64, b: __m64) -> __m64 {
+    _mm_sad_pu8(a, b)
+}
+
+/// Shuffles the 4 16-bit integers from a 64-bit integer vector to the
+/// destination, as specified by the immediate value operand.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shuffle_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pshuflw, IMM8 = 0b00_01_01_11))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_shuffle_pi16<const IMM8: i32>(a: __m64) -> __m64 {
+    static_assert_imm8!(IMM8);
+    trunc64(_mm_shufflelo_epi16::<IMM8>(zext128(a)))
+}
+
+/// Shuffles the 4 16-bit integers from a 64-bit integer vector to the
+/// destination, as specified by the immediate value operand.
+///
+/// This is an alias for [`_mm_shuffle_pi16`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_pshufw)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(pshuflw, IMM8 = 0b00_01_01_11))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _m_pshufw<const IMM8: i32>(a: __m64) -> __m64 {
+    _mm_shuffle_pi16::<IMM8>(a)
+}
+
+/// Conditionally copies byte elements from the source operand `a` to memory
+/// at `mem_addr`, using the most significant bit of each byte in `mask` as
+/// the selector. `mem_addr` does not need to be aligned on any particular
+/// boundary.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskmove_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(maskmovdqu))]
+pub unsafe fn _mm_maskmove_si64(a: __m64, mask: __m64, mem_addr: *mut i8) {
+    let mut a = zext128(a);
+    let mut mask = zext128(mask);
+    let mut mem_addr = mem_addr;
+    // `maskmovdqu` may fault on the unselected bytes of its 16-byte operand,
+    // so if those would cross into the next page shift everything into the
+    // upper half and store 8 bytes earlier instead. That address may be
+    // outside of the allocation `mem_addr` points to, but the bytes there are
+    // never written.
+    let offset = mem_addr as usize & 0xFFF;
+    if offset > 4096 - 16 && offset <= 4096 - 8 {
+        mem_addr = mem_addr.wrapping_sub(8);
+        a = _mm_slli_si128::<8>(a);
+        mask = _mm_slli_si128::<8>(mask);
+    }
+    _mm_maskmoveu_si128(a, mask, mem_addr)
+}
+
+/// Conditionally copies byte elements from the source operand `a` to memory
+/// at `mem_addr`, using the most significant bit of each byte in `mask` as
+/// the selector. `mem_addr` does not need to be aligned on any particular
+/// boundary.
+///
+/// This is an alias for [`_mm_maskmove_si64`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_maskmovq)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(maskmovdqu))]
+pub unsafe fn _m_maskmovq(a: __m64, mask: __m64, mem_addr: *mut i8) {
+    _mm_maskmove_si64(a, mask, mem_addr)
+}
+
+/// Stores 64 bits of integer data from `a` into memory using a non-temporal
+/// memory hint.
+///
+/// This requires `sse2` and stores with `movnti` from a general-purpose
+/// register instead of `movntq`, so it does not put the processor into the
+/// MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_stream_pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(movnti))]
+pub unsafe fn _mm_stream_pi(mem_addr: *mut __m64, a: __m64) {
+    // LLVM lowers a non-temporal store of 64-bit vector data to a plain
+    // `movq`/`movlps`, so issue `movnti` directly.
+    #[cfg(target_arch = "x86")]
+    {
+        let a = a.as_i32x2();
+        asm!(
+            "movnti [{p}], {lo}",
+            "movnti [{p} + 4], {hi}",
+            p = in(reg) mem_addr,
+            lo = in(reg) simd_extract::<_, i32>(a, 0),
+            hi = in(reg) simd_extract::<_, i32>(a, 1),
+            options(nostack, preserves_flags),
+        );
+    }
+    #[cfg(target_arch = "x86_64")]
+    asm!(
+        "movnti [{p}], {a}",
+        p = in(reg) mem_addr,
+        a = in(reg) transmute::<_, i64>(a),
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Sets the upper two single-precision floating-point values with 64 bits of
+/// data loaded from the address `mem_addr`.
+///
+/// This requires `sse2` and, like the instruction it is named after, does not
+/// touch the MMX registers: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadh_pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(movhps))]
+pub unsafe fn _mm_loadh_pi(a: __m128, mem_addr: *const __m64) -> __m128 {
+    transmute(_mm_loadh_pd(transmute(a), mem_addr as *const f64))
+}
+
+/// Loads two floats from `mem_addr` into the lower half of the result; the
+/// upper half is copied from `a`.
+///
+/// This requires `sse2` and, like the instruction it is named after, does not
+/// touch the MMX registers: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_loadl_pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(movlps))]
+pub unsafe fn _mm_loadl_pi(a: __m128, mem_addr: *const __m64) -> __m128 {
+    transmute(_mm_loadl_pd(transmute(a), mem_addr as *const f64))
+}
+
+/// Stores the upper 64 bits of a 128-bit vector of `[4 x float]` to a
+/// memory location.
+///
+/// This requires `sse2` and, like the instruction it is named after, does not
+/// touch the MMX registers: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storeh_pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(movhps))]
+pub unsafe fn _mm_storeh_pi(mem_addr: *mut __m64, a: __m128) {
+    _mm_storeh_pd(mem_addr as *mut f64, transmute(a))
+}
+
+/// Stores the lower 64 bits of a 128-bit vector of `[4 x float]` to a
+/// memory location.
+///
+/// This requires `sse2` and, like the instruction it is named after, does not
+/// touch the MMX registers: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_storel_pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(movlps))]
+pub unsafe fn _mm_storel_pi(mem_addr: *mut __m64, a: __m128) {
+    _mm_storel_pd(mem_addr as *mut f64, transmute(a))
+}
+
+/// Converts two elements of a 64-bit vector of `[2 x i32]` into two
+/// floating point values and writes them to the lower 64-bits of the
+/// destination. The remaining higher order elements of the destination are
+/// copied from the corresponding elements in the first operand.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpi32_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpi32_ps(a: __m128, b: __m64) -> __m128 {
+    simd_shuffle4!(a, _mm_cvtepi32_ps(zext128(b)), [4, 5, 2, 3])
+}
+
+/// Converts two elements of a 64-bit vector of `[2 x i32]` into two
+/// floating point values and writes them to the lower 64-bits of the
+/// destination. The remaining higher order elements of the destination are
+/// copied from the corresponding elements in the first operand.
+///
+/// This is an alias for [`_mm_cvtpi32_ps`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvt_pi2ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvt_pi2ps(a: __m128, b: __m64) -> __m128 {
+    _mm_cvtpi32_ps(a, b)
+}
+
+/// Converts a 64-bit vector of `[4 x i16]` into a 128-bit vector of
+/// `[4 x float]`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpi16_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpi16_ps(a: __m64) -> __m128 {
+    let a = zext128(a);
+    _mm_cvtepi32_ps(_mm_srai_epi32::<16>(_mm_unpacklo_epi16(a, a)))
+}
+
+/// Converts a 64-bit vector of 16-bit unsigned integer values into a
+/// 128-bit vector of `[4 x float]`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpu16_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpu16_ps(a: __m64) -> __m128 {
+    _mm_cvtepi32_ps(_mm_unpacklo_epi16(zext128(a), _mm_setzero_si128()))
+}
+
+/// Converts the lower four 8-bit values from a 64-bit vector of `[8 x i8]`
+/// into a 128-bit vector of `[4 x float]`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpi8_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpi8_ps(a: __m64) -> __m128 {
+    let a = zext128(a);
+    let a = _mm_unpacklo_epi8(a, a);
+    _mm_cvtepi32_ps(_mm_srai_epi32::<24>(_mm_unpacklo_epi16(a, a)))
+}
+
+/// Converts the lower four unsigned 8-bit integer values from a 64-bit
+/// vector of `[8 x u8]` into a 128-bit vector of `[4 x float]`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpu8_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpu8_ps(a: __m64) -> __m128 {
+    let zero = _mm_setzero_si128();
+    let a = _mm_unpacklo_epi8(zext128(a), zero);
+    _mm_cvtepi32_ps(_mm_unpacklo_epi16(a, zero))
+}
+
+/// Converts the two 32-bit signed integer values from each 64-bit vector
+/// operand of `[2 x i32]` into a 128-bit vector of `[4 x float]`.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtpi32x2_ps)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtdq2ps))]
+pub unsafe fn _mm_cvtpi32x2_ps(a: __m64, b: __m64) -> __m128 {
+    _mm_cvtepi32_ps(_mm_unpacklo_epi64(zext128(a), zext128(b)))
+}
+
+/// Converts the two lower packed single-precision (32-bit) floating-point
+/// elements in `a` to packed 32-bit integers.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtps2dq))]
+pub unsafe fn _mm_cvtps_pi32(a: __m128) -> __m64 {
+    // Clear the upper elements so they can't raise floating-point exceptions.
+    trunc64(_mm_cvtps_epi32(simd_shuffle4!(
+        a,
+        _mm_setzero_ps(),
+        [0, 1, 4, 5]
+    )))
+}
+
+/// Converts the two lower packed single-precision (32-bit) floating-point
+/// elements in `a` to packed 32-bit integers.
+///
+/// This is an alias for [`_mm_cvtps_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvt_ps2pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtps2dq))]
+pub unsafe fn _mm_cvt_ps2pi(a: __m128) -> __m64 {
+    _mm_cvtps_pi32(a)
+}
+
+/// Converts the two lower packed single-precision (32-bit) floating-point
+/// elements in `a` to packed 32-bit integers with truncation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttps_pi32)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvttps2dq))]
+pub unsafe fn _mm_cvttps_pi32(a: __m128) -> __m64 {
+    // Clear the upper elements so they can't raise floating-point exceptions.
+    trunc64(_mm_cvttps_epi32(simd_shuffle4!(
+        a,
+        _mm_setzero_ps(),
+        [0, 1, 4, 5]
+    )))
+}
+
+/// Converts the two lower packed single-precision (32-bit) floating-point
+/// elements in `a` to packed 32-bit integers with truncation.
+///
+/// This is an alias for [`_mm_cvttps_pi32`].
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtt_ps2pi)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvttps2dq))]
+pub unsafe fn _mm_cvtt_ps2pi(a: __m128) -> __m64 {
+    _mm_cvttps_pi32(a)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 16-bit integers, using signed saturation.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_pi16)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtps2dq))]
+pub unsafe fn _mm_cvtps_pi16(a: __m128) -> __m64 {
+    let a = _mm_cvtps_epi32(a);
+    trunc64(_mm_packs_epi32(a, a))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 8-bit integers, using signed saturation, and stores them in the
+/// lower four elements of the result. The upper four elements are zeroed.
+///
+/// This is implemented with SSE2 instructions on an `xmm` register instead of
+/// MMX instructions, so it requires `sse2` and does not put the processor into
+/// the MMX state: no `_mm_empty` is needed afterwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_pi8)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(test, assert_instr(cvtps2dq))]
+pub unsafe fn _mm_cvtps_pi8(a: __m128) -> __m64 {
+    let a = _mm_cvtps_epi32(a);
+    let a = _mm_packs_epi32(a, a);
+    trunc64(_mm_packs_epi16(a, _mm_setzero_si128()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "mmx")]
+    unsafe fn test_mm_empty() {
+        _mm_empty();
+    }
+
+    #[simd_test(enable = "mmx")]
+    unsafe fn test_m_empty() {
+        _m_empty();
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtsi32_si64() {
+        let r = _mm_cvtsi32_si64(5);
+        assert_eq_m64(r, _mm_setr_pi32(5, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_from_int() {
+        assert_eq_m64(_m_from_int(-7), _mm_setr_pi32(-7, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtsi64_si32() {
+        let a = _mm_setr_pi32(5, 6);
+        assert_eq!(_mm_cvtsi64_si32(a), 5);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_to_int() {
+        assert_eq!(_m_to_int(_mm_setr_pi32(-7, 8)), -7);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_packs_pi16() {
+        let a = _mm_setr_pi16(-0x100, 0x7F, -0x80, 0x100);
+        let b = _mm_setr_pi16(1, -1, 0x1000, -0x1000);
+        let r = _mm_packs_pi16(a, b);
+        assert_eq_m64(
+            r,
+            _mm_setr_pi8(-0x80, 0x7F, -0x80, 0x7F, 1, -1, 0x7F, -0x80),
+        );
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_packsswb() {
+        let a = _mm_setr_pi16(0x200, 2, -3, 4);
+        let b = _mm_setr_pi16(5, -0x200, 7, 8);
+        assert_eq_m64(_m_packsswb(a, b), _mm_packs_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_packs_pi32() {
+        let a = _mm_setr_pi32(0x10000, -0x10000);
+        let b = _mm_setr_pi32(-1, 0x7FFF);
+        let r = _mm_packs_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0x7FFF, -0x8000, -1, 0x7FFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_packssdw() {
+        let a = _mm_setr_pi32(0x10000, 2);
+        let b = _mm_setr_pi32(-3, -0x10000);
+        assert_eq_m64(_m_packssdw(a, b), _mm_packs_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_packs_pu16() {
+        let a = _mm_setr_pi16(-1, 0x100, 0x7F, 0x80);
+        let b = _mm_setr_pi16(0, 1, 0xFF, -0x100);
+        let r = _mm_packs_pu16(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0, -1, 0x7F, -0x80, 0, 1, -1, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_packuswb() {
+        let a = _mm_setr_pi16(-1, 0x100, 3, 4);
+        let b = _mm_setr_pi16(5, 6, 0x200, -7);
+        assert_eq_m64(_m_packuswb(a, b), _mm_packs_pu16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpackhi_pi8() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(8, 9, 10, 11, 12, 13, 14, 15);
+        let r = _mm_unpackhi_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(4, 12, 5, 13, 6, 14, 7, 15));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpckhbw() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpckhbw(a, b), _mm_unpackhi_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpackhi_pi16() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        let r = _mm_unpackhi_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(2, 6, 3, 7));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpckhwd() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpckhwd(a, b), _mm_unpackhi_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpackhi_pi32() {
+        let a = _mm_setr_pi32(0, 1);
+        let b = _mm_setr_pi32(2, 3);
+        let r = _mm_unpackhi_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(1, 3));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpckhdq() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpckhdq(a, b), _mm_unpackhi_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpacklo_pi8() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(8, 9, 10, 11, 12, 13, 14, 15);
+        let r = _mm_unpacklo_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0, 8, 1, 9, 2, 10, 3, 11));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpcklbw() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpcklbw(a, b), _mm_unpacklo_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpacklo_pi16() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        let r = _mm_unpacklo_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0, 4, 1, 5));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpcklwd() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpcklwd(a, b), _mm_unpacklo_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_unpacklo_pi32() {
+        let a = _mm_setr_pi32(0, 1);
+        let b = _mm_setr_pi32(2, 3);
+        let r = _mm_unpacklo_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0, 2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_punpckldq() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(4, 5, 6, 7);
+        assert_eq_m64(_m_punpckldq(a, b), _mm_unpacklo_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_add_pi8() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 0x7F);
+        let b = _mm_setr_pi8(8, 9, 10, 11, 12, 13, 14, 1);
+        let r = _mm_add_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(8, 10, 12, 14, 16, 18, 20, -0x80));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddb(a, b), _mm_add_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_add_pi16() {
+        let a = _mm_setr_pi16(0, 1, 2, 0x7FFF);
+        let b = _mm_setr_pi16(4, 5, 6, 1);
+        let r = _mm_add_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(4, 6, 8, -0x8000));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddw(a, b), _mm_add_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_add_pi32() {
+        let a = _mm_setr_pi32(1, i32::MAX);
+        let b = _mm_setr_pi32(2, 1);
+        let r = _mm_add_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(3, i32::MIN));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddd() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddd(a, b), _mm_add_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_adds_pi8() {
+        let a = _mm_setr_pi8(0x7F, -0x80, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(1, -1, 10, 11, 12, 13, 14, 15);
+        let r = _mm_adds_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0x7F, -0x80, 12, 14, 16, 18, 20, 22));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddsb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddsb(a, b), _mm_adds_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_adds_pi16() {
+        let a = _mm_setr_pi16(0x7FFF, -0x8000, 2, 3);
+        let b = _mm_setr_pi16(1, -1, 6, 7);
+        let r = _mm_adds_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0x7FFF, -0x8000, 8, 10));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddsw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddsw(a, b), _mm_adds_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_adds_pu8() {
+        let a = _mm_setr_pi8(-1, 0x7F, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(1, 1, 10, 11, 12, 13, 14, 15);
+        let r = _mm_adds_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(-1, -0x80, 12, 14, 16, 18, 20, 22));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddusb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddusb(a, b), _mm_adds_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_adds_pu16() {
+        let a = _mm_setr_pi16(-1, 0x7FFF, 2, 3);
+        let b = _mm_setr_pi16(1, 1, 6, 7);
+        let r = _mm_adds_pu16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(-1, -0x8000, 8, 10));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_paddusw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_paddusw(a, b), _mm_adds_pu16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sub_pi8() {
+        let a = _mm_setr_pi8(8, 9, 10, 11, 12, 13, 14, -0x80);
+        let b = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 1);
+        let r = _mm_sub_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(8, 8, 8, 8, 8, 8, 8, 0x7F));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubb(a, b), _mm_sub_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sub_pi16() {
+        let a = _mm_setr_pi16(4, 5, 6, -0x8000);
+        let b = _mm_setr_pi16(0, 1, 2, 1);
+        let r = _mm_sub_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(4, 4, 4, 0x7FFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubw(a, b), _mm_sub_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sub_pi32() {
+        let a = _mm_setr_pi32(3, i32::MIN);
+        let b = _mm_setr_pi32(1, 1);
+        let r = _mm_sub_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(2, i32::MAX));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubd() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubd(a, b), _mm_sub_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_subs_pi8() {
+        let a = _mm_setr_pi8(-0x80, 0x7F, 10, 11, 12, 13, 14, 15);
+        let b = _mm_setr_pi8(1, -1, 2, 3, 4, 5, 6, 7);
+        let r = _mm_subs_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(-0x80, 0x7F, 8, 8, 8, 8, 8, 8));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubsb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubsb(a, b), _mm_subs_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_subs_pi16() {
+        let a = _mm_setr_pi16(-0x8000, 0x7FFF, 6, 7);
+        let b = _mm_setr_pi16(1, -1, 2, 3);
+        let r = _mm_subs_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(-0x8000, 0x7FFF, 4, 4));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubsw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubsw(a, b), _mm_subs_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_subs_pu8() {
+        let a = _mm_setr_pi8(0, -1, 10, 11, 12, 13, 14, 15);
+        let b = _mm_setr_pi8(1, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm_subs_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0, -2, 8, 8, 8, 8, 8, 8));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubusb() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubusb(a, b), _mm_subs_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_subs_pu16() {
+        let a = _mm_setr_pi16(0, -1, 6, 7);
+        let b = _mm_setr_pi16(1, 1, 2, 3);
+        let r = _mm_subs_pu16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0, -2, 4, 4));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psubusw() {
+        let a = _mm_setr_pi16(1, -2, 0x7FFF, -0x8000);
+        let b = _mm_setr_pi16(3, 0x1234, 1, 0x7F);
+        assert_eq_m64(_m_psubusw(a, b), _mm_subs_pu16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_madd_pi16() {
+        let a = _mm_setr_pi16(1, 2, -3, 4);
+        let b = _mm_setr_pi16(5, 6, 7, -0x8000);
+        let r = _mm_madd_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(17, -21 - 4 * 0x8000));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmaddwd() {
+        let a = _mm_setr_pi16(1, 2, 3, 4);
+        let b = _mm_setr_pi16(5, 6, 7, 8);
+        assert_eq_m64(_m_pmaddwd(a, b), _mm_madd_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_mulhi_pi16() {
+        let a = _mm_setr_pi16(0x1000, -0x1000, 3, 0x7FFF);
+        let b = _mm_setr_pi16(0x20, 0x20, 4, 0x7FFF);
+        let r = _mm_mulhi_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(2, -2, 0, 0x3FFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmulhw() {
+        let a = _mm_setr_pi16(0x1000, -0x1000, 3, 0x7FFF);
+        let b = _mm_setr_pi16(0x20, 0x20, 4, 0x7FFF);
+        assert_eq_m64(_m_pmulhw(a, b), _mm_mulhi_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_mullo_pi16() {
+        let a = _mm_setr_pi16(0x1001, -3, 3, 0x7FFF);
+        let b = _mm_setr_pi16(0x20, 5, 4, 2);
+        let r = _mm_mullo_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0x20, -15, 12, -2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmullw() {
+        let a = _mm_setr_pi16(0x1001, -3, 3, 0x7FFF);
+        let b = _mm_setr_pi16(0x20, 5, 4, 2);
+        assert_eq_m64(_m_pmullw(a, b), _mm_mullo_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sll_pi16() {
+        let a = _mm_setr_pi16(1, -1, 0x0F0F, 4);
+        let r = _mm_sll_pi16(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi16(0x10, -0x10, -0x0F10, 0x40));
+        let r = _mm_sll_pi16(a, _mm_cvtsi32_si64(16));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psllw() {
+        let a = _mm_setr_pi16(1, -1, 0x0F0F, 4);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psllw(a, count), _mm_sll_pi16(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_slli_pi16() {
+        let a = _mm_setr_pi16(1, -1, 0x0F0F, 4);
+        let r = _mm_slli_pi16::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi16(0x10, -0x10, -0x0F10, 0x40));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psllwi() {
+        let a = _mm_setr_pi16(1, -1, 0x0F0F, 4);
+        assert_eq_m64(_m_psllwi::<3>(a), _mm_slli_pi16::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sll_pi32() {
+        let a = _mm_setr_pi32(1, -1);
+        let r = _mm_sll_pi32(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi32(0x10, -0x10));
+        let r = _mm_sll_pi32(a, _mm_cvtsi32_si64(32));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pslld() {
+        let a = _mm_setr_pi32(1, -1);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_pslld(a, count), _mm_sll_pi32(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_slli_pi32() {
+        let a = _mm_setr_pi32(1, -1);
+        let r = _mm_slli_pi32::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi32(0x10, -0x10));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pslldi() {
+        let a = _mm_setr_pi32(1, -1);
+        assert_eq_m64(_m_pslldi::<3>(a), _mm_slli_pi32::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sll_si64() {
+        let a = _mm_setr_pi32(-1, 1);
+        let r = _mm_sll_si64(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi32(-0x10, 0x1F));
+        let r = _mm_sll_si64(a, _mm_cvtsi32_si64(64));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psllq() {
+        let a = _mm_setr_pi32(-1, 1);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psllq(a, count), _mm_sll_si64(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_slli_si64() {
+        let a = _mm_setr_pi32(-1, 1);
+        let r = _mm_slli_si64::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi32(-0x10, 0x1F));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psllqi() {
+        let a = _mm_setr_pi32(-1, 1);
+        assert_eq_m64(_m_psllqi::<3>(a), _mm_slli_si64::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sra_pi16() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let r = _mm_sra_pi16(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi16(0x10, -0x10, 0xF0, -1));
+        let r = _mm_sra_pi16(a, _mm_cvtsi32_si64(16));
+        assert_eq_m64(r, _mm_sra_pi16(a, _mm_cvtsi32_si64(15)));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psraw() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psraw(a, count), _mm_sra_pi16(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srai_pi16() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let r = _mm_srai_pi16::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi16(0x10, -0x10, 0xF0, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrawi() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        assert_eq_m64(_m_psrawi::<3>(a), _mm_srai_pi16::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sra_pi32() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let r = _mm_sra_pi32(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi32(0x10, -0x10));
+        let r = _mm_sra_pi32(a, _mm_cvtsi32_si64(32));
+        assert_eq_m64(r, _mm_sra_pi32(a, _mm_cvtsi32_si64(31)));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrad() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psrad(a, count), _mm_sra_pi32(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srai_pi32() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let r = _mm_srai_pi32::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi32(0x10, -0x10));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psradi() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        assert_eq_m64(_m_psradi::<3>(a), _mm_srai_pi32::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srl_pi16() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let r = _mm_srl_pi16(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi16(0x10, 0xFF0, 0xF0, 0xFFF));
+        let r = _mm_srl_pi16(a, _mm_cvtsi32_si64(16));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrlw() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psrlw(a, count), _mm_srl_pi16(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srli_pi16() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        let r = _mm_srli_pi16::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi16(0x10, 0xFF0, 0xF0, 0xFFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrlwi() {
+        let a = _mm_setr_pi16(0x100, -0x100, 0x0F0F, -1);
+        assert_eq_m64(_m_psrlwi::<3>(a), _mm_srli_pi16::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srl_pi32() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let r = _mm_srl_pi32(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi32(0x10, 0x0FFF_FFF0));
+        let r = _mm_srl_pi32(a, _mm_cvtsi32_si64(32));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrld() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psrld(a, count), _mm_srl_pi32(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srli_pi32() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        let r = _mm_srli_pi32::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi32(0x10, 0x0FFF_FFF0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrldi() {
+        let a = _mm_setr_pi32(0x100, -0x100);
+        assert_eq_m64(_m_psrldi::<3>(a), _mm_srli_pi32::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srl_si64() {
+        let a = _mm_setr_pi32(0x100, -1);
+        let r = _mm_srl_si64(a, _mm_cvtsi32_si64(4));
+        assert_eq_m64(r, _mm_setr_pi32(-0x0FFF_FFF0, 0x0FFF_FFFF));
+        let r = _mm_srl_si64(a, _mm_cvtsi32_si64(64));
+        assert_eq_m64(r, _mm_setzero_si64());
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrlq() {
+        let a = _mm_setr_pi32(0x100, -1);
+        let count = _mm_cvtsi32_si64(3);
+        assert_eq_m64(_m_psrlq(a, count), _mm_srl_si64(a, count));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_srli_si64() {
+        let a = _mm_setr_pi32(0x100, -1);
+        let r = _mm_srli_si64::<4>(a);
+        assert_eq_m64(r, _mm_setr_pi32(-0x0FFF_FFF0, 0x0FFF_FFFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psrlqi() {
+        let a = _mm_setr_pi32(0x100, -1);
+        assert_eq_m64(_m_psrlqi::<3>(a), _mm_srli_si64::<3>(a));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_and_si64() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        let r = _mm_and_si64(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0b0001, 0b0110));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pand() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        assert_eq_m64(_m_pand(a, b), _mm_and_si64(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_andnot_si64() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        let r = _mm_andnot_si64(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0b0010, !0b0110));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pandn() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        assert_eq_m64(_m_pandn(a, b), _mm_andnot_si64(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_or_si64() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, 0);
+        let r = _mm_or_si64(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0b0111, 0b0110));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_por() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, 0);
+        assert_eq_m64(_m_por(a, b), _mm_or_si64(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_xor_si64() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        let r = _mm_xor_si64(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0b0110, !0b0110));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pxor() {
+        let a = _mm_setr_pi32(0b0101, 0b0110);
+        let b = _mm_setr_pi32(0b0011, -1);
+        assert_eq_m64(_m_pxor(a, b), _mm_xor_si64(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpeq_pi8() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(0, 0, 2, 0, 4, 0, 6, 0);
+        let r = _mm_cmpeq_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(-1, 0, -1, 0, -1, 0, -1, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpeqb() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(0, 0, 2, 0, 4, 0, 6, 0);
+        assert_eq_m64(_m_pcmpeqb(a, b), _mm_cmpeq_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpeq_pi16() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(0, 0, 2, 0);
+        let r = _mm_cmpeq_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(-1, 0, -1, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpeqw() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let b = _mm_setr_pi16(0, 0, 2, 0);
+        assert_eq_m64(_m_pcmpeqw(a, b), _mm_cmpeq_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpeq_pi32() {
+        let a = _mm_setr_pi32(0, 1);
+        let b = _mm_setr_pi32(0, 0);
+        let r = _mm_cmpeq_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(-1, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpeqd() {
+        let a = _mm_setr_pi32(0, 1);
+        let b = _mm_setr_pi32(0, 0);
+        assert_eq_m64(_m_pcmpeqd(a, b), _mm_cmpeq_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpgt_pi8() {
+        let a = _mm_setr_pi8(0, 1, -2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(0, 0, 2, 0, 4, 0, 7, 0);
+        let r = _mm_cmpgt_pi8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0, -1, 0, -1, 0, -1, 0, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpgtb() {
+        let a = _mm_setr_pi8(0, 1, -2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_pi8(0, 0, 2, 0, 4, 0, 7, 0);
+        assert_eq_m64(_m_pcmpgtb(a, b), _mm_cmpgt_pi8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpgt_pi16() {
+        let a = _mm_setr_pi16(0, 1, -2, 3);
+        let b = _mm_setr_pi16(0, 0, 2, 0);
+        let r = _mm_cmpgt_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0, -1, 0, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpgtw() {
+        let a = _mm_setr_pi16(0, 1, -2, 3);
+        let b = _mm_setr_pi16(0, 0, 2, 0);
+        assert_eq_m64(_m_pcmpgtw(a, b), _mm_cmpgt_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cmpgt_pi32() {
+        let a = _mm_setr_pi32(-1, 1);
+        let b = _mm_setr_pi32(0, 0);
+        let r = _mm_cmpgt_pi32(a, b);
+        assert_eq_m64(r, _mm_setr_pi32(0, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pcmpgtd() {
+        let a = _mm_setr_pi32(-1, 1);
+        let b = _mm_setr_pi32(0, 0);
+        assert_eq_m64(_m_pcmpgtd(a, b), _mm_cmpgt_pi32(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_setzero_si64() {
+        assert_eq_m64(_mm_setzero_si64(), _mm_cvtsi32_si64(0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set_pi32() {
+        let r = _mm_set_pi32(1, 2);
+        assert_eq!(_mm_cvtsi64_si32(r), 2);
+        assert_eq_m64(r, _mm_setr_pi32(2, 1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set_pi16() {
+        let r = _mm_set_pi16(1, 2, 3, 4);
+        assert_eq!(_mm_cvtsi64_si32(r), 0x0003_0004);
+        assert_eq_m64(r, _mm_setr_pi16(4, 3, 2, 1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set_pi8() {
+        let r = _mm_set_pi8(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(_mm_cvtsi64_si32(r), 0x0506_0708);
+        assert_eq_m64(r, _mm_setr_pi8(8, 7, 6, 5, 4, 3, 2, 1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set1_pi32() {
+        assert_eq_m64(_mm_set1_pi32(-3), _mm_setr_pi32(-3, -3));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set1_pi16() {
+        assert_eq_m64(_mm_set1_pi16(-3), _mm_setr_pi16(-3, -3, -3, -3));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_set1_pi8() {
+        assert_eq_m64(_mm_set1_pi8(-3), _mm_set1_pi16(0xFDFD_u16 as i16));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_setr_pi32() {
+        assert_eq!(transmute::<_, [i32; 2]>(_mm_setr_pi32(1, 2)), [1, 2]);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_setr_pi16() {
+        assert_eq!(
+            transmute::<_, [i16; 4]>(_mm_setr_pi16(1, 2, 3, 4)),
+            [1, 2, 3, 4],
+        );
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_setr_pi8() {
+        assert_eq!(
+            transmute::<_, [i8; 8]>(_mm_setr_pi8(1, 2, 3, 4, 5, 6, 7, 8)),
+            [1, 2, 3, 4, 5, 6, 7, 8],
+        );
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_avg_pu8() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, -1);
+        let b = _mm_setr_pi8(0, 2, 2, 4, 4, 6, 6, -1);
+        let r = _mm_avg_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(0, 2, 2, 4, 4, 6, 6, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pavgb() {
+        let a = _mm_setr_pi8(0, 1, 2, 3, 4, 5, 6, -1);
+        let b = _mm_setr_pi8(0, 2, 2, 4, 4, 6, 6, -1);
+        assert_eq_m64(_m_pavgb(a, b), _mm_avg_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_avg_pu16() {
+        let a = _mm_setr_pi16(0, 1, 2, -1);
+        let b = _mm_setr_pi16(0, 2, 3, -3);
+        let r = _mm_avg_pu16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(0, 2, 3, -2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pavgw() {
+        let a = _mm_setr_pi16(0, 1, 2, -1);
+        let b = _mm_setr_pi16(0, 2, 3, -3);
+        assert_eq_m64(_m_pavgw(a, b), _mm_avg_pu16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_extract_pi16() {
+        let a = _mm_setr_pi16(-1, 1, 2, 3);
+        assert_eq!(_mm_extract_pi16::<0>(a), 0xFFFF);
+        assert_eq!(_mm_extract_pi16::<3>(a), 3);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pextrw() {
+        let a = _mm_setr_pi16(-1, 1, 2, 3);
+        assert_eq!(_m_pextrw::<2>(a), 2);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_insert_pi16() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        let r = _mm_insert_pi16::<2>(a, 0x1_0009);
+        assert_eq_m64(r, _mm_setr_pi16(0, 1, 9, 3));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pinsrw() {
+        let a = _mm_setr_pi16(0, 1, 2, 3);
+        assert_eq_m64(_m_pinsrw::<0>(a, 9), _mm_setr_pi16(9, 1, 2, 3));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_max_pi16() {
+        let a = _mm_setr_pi16(-1, 6, -0x8000, 0x7FFF);
+        let b = _mm_setr_pi16(2, -6, 0, 0);
+        let r = _mm_max_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(2, 6, 0, 0x7FFF));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmaxsw() {
+        let a = _mm_setr_pi16(-1, 6, -0x8000, 0x7FFF);
+        let b = _mm_setr_pi16(2, -6, 0, 0);
+        assert_eq_m64(_m_pmaxsw(a, b), _mm_max_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_max_pu8() {
+        let a = _mm_setr_pi8(-1, 6, 0, 1, 2, 3, 4, 5);
+        let b = _mm_setr_pi8(2, -6, 1, 0, 3, 2, 5, 4);
+        let r = _mm_max_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(-1, -6, 1, 1, 3, 3, 5, 5));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmaxub() {
+        let a = _mm_setr_pi8(-1, 6, 0, 1, 2, 3, 4, 5);
+        let b = _mm_setr_pi8(2, -6, 1, 0, 3, 2, 5, 4);
+        assert_eq_m64(_m_pmaxub(a, b), _mm_max_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_min_pi16() {
+        let a = _mm_setr_pi16(-1, 6, -0x8000, 0x7FFF);
+        let b = _mm_setr_pi16(2, -6, 0, 0);
+        let r = _mm_min_pi16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(-1, -6, -0x8000, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pminsw() {
+        let a = _mm_setr_pi16(-1, 6, -0x8000, 0x7FFF);
+        let b = _mm_setr_pi16(2, -6, 0, 0);
+        assert_eq_m64(_m_pminsw(a, b), _mm_min_pi16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_min_pu8() {
+        let a = _mm_setr_pi8(-1, 6, 0, 1, 2, 3, 4, 5);
+        let b = _mm_setr_pi8(2, -6, 1, 0, 3, 2, 5, 4);
+        let r = _mm_min_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi8(2, 6, 0, 0, 2, 2, 4, 4));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pminub() {
+        let a = _mm_setr_pi8(-1, 6, 0, 1, 2, 3, 4, 5);
+        let b = _mm_setr_pi8(2, -6, 1, 0, 3, 2, 5, 4);
+        assert_eq_m64(_m_pminub(a, b), _mm_min_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_movemask_pi8() {
+        let a = _mm_setr_pi8(-1, 0, -0x80, 0x7F, 0, 0, 0, -2);
+        assert_eq!(_mm_movemask_pi8(a), 0b1000_0101);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmovmskb() {
+        let a = _mm_setr_pi8(0, -1, 0, 0, -1, 0, 0, 0);
+        assert_eq!(_m_pmovmskb(a), 0b0001_0010);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_mulhi_pu16() {
+        let a = _mm_setr_pi16(0x1000, -1, 3, -0x8000);
+        let b = _mm_setr_pi16(0x20, -1, 4, 2);
+        let r = _mm_mulhi_pu16(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(2, -2, 0, 1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pmulhuw() {
+        let a = _mm_setr_pi16(0x1000, -1, 3, -0x8000);
+        let b = _mm_setr_pi16(0x20, -1, 4, 2);
+        assert_eq_m64(_m_pmulhuw(a, b), _mm_mulhi_pu16(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_sad_pu8() {
+        let a = _mm_setr_pi8(-1, 0, 1, 2, 3, 4, 5, 6);
+        let b = _mm_setr_pi8(0, -1, 2, 1, 3, 5, 4, 6);
+        let r = _mm_sad_pu8(a, b);
+        assert_eq_m64(r, _mm_setr_pi16(255 + 255 + 1 + 1 + 1 + 1, 0, 0, 0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_psadbw() {
+        let a = _mm_setr_pi8(-1, 0, 1, 2, 3, 4, 5, 6);
+        let b = _mm_setr_pi8(0, -1, 2, 1, 3, 5, 4, 6);
+        assert_eq_m64(_m_psadbw(a, b), _mm_sad_pu8(a, b));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_shuffle_pi16() {
+        let a = _mm_setr_pi16(1, 2, 3, 4);
+        let r = _mm_shuffle_pi16::<0b00_01_01_11>(a);
+        assert_eq_m64(r, _mm_setr_pi16(4, 2, 2, 1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_pshufw() {
+        let a = _mm_setr_pi16(1, 2, 3, 4);
+        assert_eq_m64(_m_pshufw::<0b00_00_00_00>(a), _mm_set1_pi16(1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_maskmove_si64() {
+        let a = _mm_setr_pi8(1, 2, 3, 4, 5, 6, 7, 8);
+        let mask = _mm_setr_pi8(-1, 0, -1, 0, 0, 0, 0, -1);
+        let mut r = [0_i8; 8];
+        _mm_maskmove_si64(a, mask, r.as_mut_ptr());
+        assert_eq!(r, [1, 0, 3, 0, 0, 0, 0, 8]);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_maskmovq() {
+        let a = _mm_set1_pi8(9);
+        let mask = _mm_setr_pi8(0, -1, 0, 0, 0, 0, -1, 0);
+        let mut r = [0_i8; 8];
+        _m_maskmovq(a, mask, r.as_mut_ptr());
+        assert_eq!(r, [0, 9, 0, 0, 0, 0, 9, 0]);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_stream_pi() {
+        let a = _mm_setr_pi32(1, 2);
+        let mut mem = _mm_setzero_si64();
+        _mm_stream_pi(&mut mem, a);
+        assert_eq_m64(mem, a);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_loadh_pi() {
+        let a = _mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+        let b = _mm_setr_ps(5.0, 6.0, 7.0, 8.0);
+        let p = &b as *const __m128 as *const __m64;
+        let r = _mm_loadh_pi(a, p);
+        assert_eq_m128(r, _mm_setr_ps(1.0, 2.0, 5.0, 6.0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_loadl_pi() {
+        let a = _mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+        let b = _mm_setr_ps(5.0, 6.0, 7.0, 8.0);
+        let p = &b as *const __m128 as *const __m64;
+        let r = _mm_loadl_pi(a, p);
+        assert_eq_m128(r, _mm_setr_ps(5.0, 6.0, 3.0, 4.0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_storeh_pi() {
+        let a = _mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+        let mut r = _mm_setzero_ps();
+        _mm_storeh_pi(&mut r as *mut __m128 as *mut __m64, a);
+        assert_eq_m128(r, _mm_setr_ps(3.0, 4.0, 0.0, 0.0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_storel_pi() {
+        let a = _mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+        let mut r = _mm_setzero_ps();
+        _mm_storel_pi(&mut r as *mut __m128 as *mut __m64, a);
+        assert_eq_m128(r, _mm_setr_ps(1.0, 2.0, 0.0, 0.0));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpi32_ps() {
+        let a = _mm_setr_ps(0., 0., 3., 4.);
+        let b = _mm_setr_pi32(1, 2);
+        let r = _mm_cvtpi32_ps(a, b);
+        assert_eq_m128(r, _mm_setr_ps(1., 2., 3., 4.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvt_pi2ps() {
+        let a = _mm_setr_ps(0., 0., 3., 4.);
+        let b = _mm_setr_pi32(-1, 2);
+        assert_eq_m128(_mm_cvt_pi2ps(a, b), _mm_setr_ps(-1., 2., 3., 4.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpi16_ps() {
+        let a = _mm_setr_pi16(1, -2, 3, -0x8000);
+        let r = _mm_cvtpi16_ps(a);
+        assert_eq_m128(r, _mm_setr_ps(1., -2., 3., -32768.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpu16_ps() {
+        let a = _mm_setr_pi16(1, -2, 3, -0x8000);
+        let r = _mm_cvtpu16_ps(a);
+        assert_eq_m128(r, _mm_setr_ps(1., 65534., 3., 32768.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpi8_ps() {
+        let a = _mm_setr_pi8(1, -2, 3, -0x80, 5, 6, 7, 8);
+        let r = _mm_cvtpi8_ps(a);
+        assert_eq_m128(r, _mm_setr_ps(1., -2., 3., -128.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpu8_ps() {
+        let a = _mm_setr_pi8(1, -2, 3, -0x80, 5, 6, 7, 8);
+        let r = _mm_cvtpu8_ps(a);
+        assert_eq_m128(r, _mm_setr_ps(1., 254., 3., 128.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtpi32x2_ps() {
+        let a = _mm_setr_pi32(1, 2);
+        let b = _mm_setr_pi32(-3, 4);
+        let r = _mm_cvtpi32x2_ps(a, b);
+        assert_eq_m128(r, _mm_setr_ps(1., 2., -3., 4.));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtps_pi32() {
+        let a = _mm_setr_ps(1.5, -2.5, f32::NAN, 4.0);
+        let r = _mm_cvtps_pi32(a);
+        assert_eq_m64(r, _mm_setr_pi32(2, -2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvt_ps2pi() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.0, 4.0);
+        assert_eq_m64(_mm_cvt_ps2pi(a), _mm_setr_pi32(2, 2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvttps_pi32() {
+        let a = _mm_setr_ps(1.5, -2.5, f32::NAN, 4.0);
+        let r = _mm_cvttps_pi32(a);
+        assert_eq_m64(r, _mm_setr_pi32(1, -2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtt_ps2pi() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.0, 4.0);
+        assert_eq_m64(_mm_cvtt_ps2pi(a), _mm_setr_pi32(1, 2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtps_pi16() {
+        let a = _mm_setr_ps(1.0, -2.0, 40000.0, -40000.0);
+        let r = _mm_cvtps_pi16(a);
+        assert_eq_m64(r, _mm_setr_pi16(1, -2, 0x7FFF, -0x8000));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtps_pi8() {
+        let a = _mm_setr_ps(1.0, -2.0, 200.0, -200.0);
+        let r = _mm_cvtps_pi8(a);
+        assert_eq_m64(r, _mm_setr_pi8(1, -2, 0x7F, -0x80, 0, 0, 0, 0));
+    }
+}
@@ -6,6 +6,33 @@ use crate::{intrinsics, marker::Sized, mem::transmute};
 mod macros;
 
 types! {
+    /// 64-bit wide integer vector type, x86-specific
+    ///
+    /// This type is the same as the `__m64` type defined by Intel,
+    /// representing a 64-bit MMX register. Usage of this type typically
+    /// corresponds to the `mmx` target feature.
+    ///
+    /// Internally this type may be viewed as:
+    ///
+    /// * `i8x8` - eight `i8` variables packed together
+    /// * `i16x4` - four `i16` variables packed together
+    /// * `i32x2` - two `i32` variables packed together
+    ///
+    /// (as well as unsigned versions). Each intrinsic may interpret the
+    /// internal bits differently, check the documentation of the intrinsic
+    /// to see how it's being used.
+    ///
+    /// Note that this means that an instance of `__m64` typically just means
+    /// a "bag of bits" which is left up to interpretation at the point of use.
+    ///
+    /// Most intrinsics using `__m64` are prefixed with `_mm_` or `_m_` and the
+    /// integer types tend to correspond to suffixes like "pi8" or "pi32".
+    //
+    // A single-element 64-bit vector is lowered to LLVM's `x86_mmx` type,
+    // which ties values to the MMX registers and the x87 state, so this is
+    // declared with two lanes instead.
+    pub struct __m64(i32, i32);
+
     /// 128-bit wide integer vector type, x86-specific
     ///
     /// This type is the same as the `__m128i` type defined by Intel,
@@ -327,6 +354,36 @@ types! {
         u16, u16, u16, u16, u16, u16, u16, u16,
         u16, u16, u16, u16, u16, u16, u16, u16
     );
+
+    /// 128-bit wide set of eight half-precision (16-bit) floating-point
+    /// values, x86-specific
+    ///
+    /// This type is the same as the `__m128h` type defined by Intel. Rust has
+    /// no `f16` type, so each lane is stored as the `u16` bit pattern of its
+    /// IEEE 754 binary16 value. It's used by the AVX512-FP16 intrinsics.
+    pub struct __m128h(u16, u16, u16, u16, u16, u16, u16, u16);
+
+    /// 256-bit wide set of 16 half-precision (16-bit) floating-point values,
+    /// x86-specific
+    ///
+    /// This type is the same as the `__m256h` type defined by Intel, with each
+    /// lane stored as the `u16` bit pattern of its binary16 value.
+    pub struct __m256h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16
+    );
+
+    /// 512-bit wide set of 32 half-precision (16-bit) floating-point values,
+    /// x86-specific
+    ///
+    /// This type is the same as the `__m512h` type defined by Intel, with each
+    /// lane stored as the `u16` bit pattern of its binary16 value.
+    pub struct __m512h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16
+    );
 }
 
 /// The `__mmask64` type used in AVX-512 intrinsics, a 64-bit integer
@@ -366,6 +423,49 @@ mod test;
 #[cfg(test)]
 pub use self::test::*;
 
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m64Ext: Sized {
+    fn as_m64(self) -> __m64;
+
+    #[inline]
+    fn as_u8x8(self) -> crate::core_arch::simd::u8x8 {
+        unsafe { transmute(self.as_m64()) }
+    }
+
+    #[inline]
+    fn as_u16x4(self) -> crate::core_arch::simd::u16x4 {
+        unsafe { transmute(self.as_m64()) }
+    }
+
+    #[inline]
+    fn as_u32x2(self) -> crate::core_arch::simd::u32x2 {
+        unsafe { transmute(self.as_m64()) }
+    }
+
+    #[inline]
+    fn as_i8x8(self) -> crate::core_arch::simd::i8x8 {
+        unsafe { transmute(self.as_m64()) }
+    }
+
+    #[inline]
+    fn as_i16x4(self) -> crate::core_arch::simd::i16x4 {
+        unsafe { transmute(self.as_m64()) }
+    }
+
+    #[inline]
+    fn as_i32x2(self) -> crate::core_arch::simd::i32x2 {
+        unsafe { transmute(self.as_m64()) }
+    }
+}
+
+impl m64Ext for __m64 {
+    #[inline]
+    fn as_m64(self) -> Self {
+        self
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[unstable(feature = "stdsimd_internal", issue = "none")]
 pub(crate) trait m128iExt: Sized {
@@ -753,6 +853,8 @@ mod sse;
 pub use self::sse::*;
 mod sse2;
 pub use self::sse2::*;
+mod mmx;
+pub use self::mmx::*;
 mod sse3;
 pub use self::sse3::*;
 mod ssse3;
@@ -820,6 +922,9 @@ pub use self::avx512bw::*;
 mod avx512cd;
 pub use self::avx512cd::*;
 
+mod avx512dq;
+pub use self::avx512dq::*;
+
 mod avx512ifma;
 pub use self::avx512ifma::*;
 
@@ -835,17 +940,17 @@ pub use self::avx512vnni::*;
 mod avx512bitalg;
 pub use self::avx512bitalg::*;
 
-mod avx512gfni;
-pub use self::avx512gfni::*;
+mod gfni;
+pub use self::gfni::*;
 
 mod avx512vpopcntdq;
 pub use self::avx512vpopcntdq::*;
 
-mod avx512vaes;
-pub use self::avx512vaes::*;
+mod vaes;
+pub use self::vaes::*;
 
-mod avx512vpclmulqdq;
-pub use self::avx512vpclmulqdq::*;
+mod vpclmulqdq;
+pub use self::vpclmulqdq::*;
 
 mod bt;
 pub use self::bt::*;
@@ -858,3 +963,9 @@ pub use self::f16c::*;
 
 mod avx512bf16;
 pub use self::avx512bf16::*;
+
+mod avx512fp16;
+pub use self::avx512fp16::*;
+
+mod svml;
+pub use self::svml::*;
@@ -0,0 +1,6575 @@
+//! Short Vector Math Library (SVML) intrinsics.
+//!
+//! Intel's compiler implements these with calls into its SVML runtime, and
+//! there are no instructions or LLVM intrinsics for them. They are
+//! implemented here with vector arithmetic only, so they are available
+//! wherever the corresponding vector width is.
+//!
+//! Zeros, infinities and NaN give the results C99 Annex F specifies for the
+//! corresponding `<math.h>` functions.
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, LN_2, LOG10_2, LOG2_10, LOG2_E};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Computes the sine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sin_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_sin_ps(a: __m128) -> __m128 {
+    transmute(sincos_f32(a.as_f32x4(), false))
+}
+
+/// Computes the sine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sin_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_sin_ps(a: __m256) -> __m256 {
+    transmute(sincos_f32(a.as_f32x8(), false))
+}
+
+/// Computes the sine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sin_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_sin_ps(a: __m512) -> __m512 {
+    transmute(sincos_f32(a.as_f32x16(), false))
+}
+
+/// Computes the sine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sin_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_sin_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = sincos_f32(a.as_f32x16(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the sine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sin_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_sin_pd(a: __m128d) -> __m128d {
+    transmute(sincos_f64(a.as_f64x2(), false))
+}
+
+/// Computes the sine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sin_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_sin_pd(a: __m256d) -> __m256d {
+    transmute(sincos_f64(a.as_f64x4(), false))
+}
+
+/// Computes the sine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sin_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_sin_pd(a: __m512d) -> __m512d {
+    transmute(sincos_f64(a.as_f64x8(), false))
+}
+
+/// Computes the sine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sin_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_sin_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = sincos_f64(a.as_f64x8(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the cosine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cos_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_cos_ps(a: __m128) -> __m128 {
+    transmute(sincos_f32(a.as_f32x4(), true))
+}
+
+/// Computes the cosine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cos_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_cos_ps(a: __m256) -> __m256 {
+    transmute(sincos_f32(a.as_f32x8(), true))
+}
+
+/// Computes the cosine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cos_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_cos_ps(a: __m512) -> __m512 {
+    transmute(sincos_f32(a.as_f32x16(), true))
+}
+
+/// Computes the cosine of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cos_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_cos_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = sincos_f32(a.as_f32x16(), true);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the cosine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cos_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_cos_pd(a: __m128d) -> __m128d {
+    transmute(sincos_f64(a.as_f64x2(), true))
+}
+
+/// Computes the cosine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cos_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_cos_pd(a: __m256d) -> __m256d {
+    transmute(sincos_f64(a.as_f64x4(), true))
+}
+
+/// Computes the cosine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cos_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_cos_pd(a: __m512d) -> __m512d {
+    transmute(sincos_f64(a.as_f64x8(), true))
+}
+
+/// Computes the cosine of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cos_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_cos_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = sincos_f64(a.as_f64x8(), true);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the tangent of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_tan_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_tan_ps(a: __m128) -> __m128 {
+    transmute(tan_f32(a.as_f32x4()))
+}
+
+/// Computes the tangent of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_tan_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_tan_ps(a: __m256) -> __m256 {
+    transmute(tan_f32(a.as_f32x8()))
+}
+
+/// Computes the tangent of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_tan_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_tan_ps(a: __m512) -> __m512 {
+    transmute(tan_f32(a.as_f32x16()))
+}
+
+/// Computes the tangent of packed single-precision (32-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_tan_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_tan_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = tan_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the tangent of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_tan_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_tan_pd(a: __m128d) -> __m128d {
+    transmute(tan_f64(a.as_f64x2()))
+}
+
+/// Computes the tangent of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_tan_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_tan_pd(a: __m256d) -> __m256d {
+    transmute(tan_f64(a.as_f64x4()))
+}
+
+/// Computes the tangent of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_tan_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_tan_pd(a: __m512d) -> __m512d {
+    transmute(tan_f64(a.as_f64x8()))
+}
+
+/// Computes the tangent of packed double-precision (64-bit) floating-point
+/// elements in `a` expressed in radians, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_tan_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_tan_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = tan_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_atan_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_atan_ps(a: __m128) -> __m128 {
+    transmute(atan_f32(a.as_f32x4()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_atan_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_atan_ps(a: __m256) -> __m256 {
+    transmute(atan_f32(a.as_f32x8()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_atan_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_atan_ps(a: __m512) -> __m512 {
+    transmute(atan_f32(a.as_f32x16()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_atan_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_atan_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = atan_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_atan_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_atan_pd(a: __m128d) -> __m128d {
+    transmute(atan_f64(a.as_f64x2()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_atan_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_atan_pd(a: __m256d) -> __m256d {
+    transmute(atan_f64(a.as_f64x4()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_atan_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_atan_pd(a: __m512d) -> __m512d {
+    transmute(atan_f64(a.as_f64x8()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` expressed in
+/// radians using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_atan_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_atan_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = atan_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_atan2_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_atan2_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(atan2_f32(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_atan2_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_atan2_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(atan2_f32(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_atan2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_atan2_ps(a: __m512, b: __m512) -> __m512 {
+    transmute(atan2_f32(a.as_f32x16(), b.as_f32x16()))
+}
+
+/// Computes the inverse tangent of packed single-precision (32-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_atan2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_atan2_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let r = atan2_f32(a.as_f32x16(), b.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_atan2_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_atan2_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(atan2_f64(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_atan2_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_atan2_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(atan2_f64(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_atan2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_atan2_pd(a: __m512d, b: __m512d) -> __m512d {
+    transmute(atan2_f64(a.as_f64x8(), b.as_f64x8()))
+}
+
+/// Computes the inverse tangent of packed double-precision (64-bit)
+/// floating-point elements in `a` divided by packed elements in `b`, and store
+/// the results in `dst` expressed in radians using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_atan2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_atan2_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let r = atan2_f64(a.as_f64x8(), b.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp_ps(a: __m128) -> __m128 {
+    transmute(exp_f32(a.as_f32x4()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp_ps(a: __m256) -> __m256 {
+    transmute(exp_f32(a.as_f32x8()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp_ps(a: __m512) -> __m512 {
+    transmute(exp_f32(a.as_f32x16()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = exp_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp_pd(a: __m128d) -> __m128d {
+    transmute(exp_f64(a.as_f64x2()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp_pd(a: __m256d) -> __m256d {
+    transmute(exp_f64(a.as_f64x4()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp_pd(a: __m512d) -> __m512d {
+    transmute(exp_f64(a.as_f64x8()))
+}
+
+/// Computes the exponential value of `e` raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = exp_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp2_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp2_ps(a: __m128) -> __m128 {
+    transmute(exp2_f32(a.as_f32x4(), f32x4::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp2_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp2_ps(a: __m256) -> __m256 {
+    transmute(exp2_f32(a.as_f32x8(), f32x8::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp2_ps(a: __m512) -> __m512 {
+    transmute(exp2_f32(a.as_f32x16(), f32x16::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp2_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = exp2_f32(a.as_f32x16(), f32x16::splat(0.0));
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp2_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp2_pd(a: __m128d) -> __m128d {
+    transmute(exp2_f64(a.as_f64x2(), f64x2::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp2_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp2_pd(a: __m256d) -> __m256d {
+    transmute(exp2_f64(a.as_f64x4(), f64x4::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp2_pd(a: __m512d) -> __m512d {
+    transmute(exp2_f64(a.as_f64x8(), f64x8::splat(0.0)))
+}
+
+/// Computes the exponential value of 2 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp2_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = exp2_f64(a.as_f64x8(), f64x8::splat(0.0));
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp10_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp10_ps(a: __m128) -> __m128 {
+    transmute(exp10_f32(a.as_f32x4()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp10_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp10_ps(a: __m256) -> __m256 {
+    transmute(exp10_f32(a.as_f32x8()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp10_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp10_ps(a: __m512) -> __m512 {
+    transmute(exp10_f32(a.as_f32x16()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// single-precision (32-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp10_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp10_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = exp10_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_exp10_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_exp10_pd(a: __m128d) -> __m128d {
+    transmute(exp10_f64(a.as_f64x2()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_exp10_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_exp10_pd(a: __m256d) -> __m256d {
+    transmute(exp10_f64(a.as_f64x4()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_exp10_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_exp10_pd(a: __m512d) -> __m512d {
+    transmute(exp10_f64(a.as_f64x8()))
+}
+
+/// Computes the exponential value of 10 raised to the power of packed
+/// double-precision (64-bit) floating-point elements in `a`, and store the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_exp10_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_exp10_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = exp10_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the natural logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log_ps(a: __m128) -> __m128 {
+    transmute(log_f32(a.as_f32x4()))
+}
+
+/// Computes the natural logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log_ps(a: __m256) -> __m256 {
+    transmute(log_f32(a.as_f32x8()))
+}
+
+/// Computes the natural logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log_ps(a: __m512) -> __m512 {
+    transmute(log_f32(a.as_f32x16()))
+}
+
+/// Computes the natural logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = log_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the natural logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log_pd(a: __m128d) -> __m128d {
+    transmute(log_f64(a.as_f64x2()))
+}
+
+/// Computes the natural logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log_pd(a: __m256d) -> __m256d {
+    transmute(log_f64(a.as_f64x4()))
+}
+
+/// Computes the natural logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log_pd(a: __m512d) -> __m512d {
+    transmute(log_f64(a.as_f64x8()))
+}
+
+/// Computes the natural logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = log_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the base-2 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log2_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log2_ps(a: __m128) -> __m128 {
+    transmute(log2_log10_f32(a.as_f32x4(), false))
+}
+
+/// Computes the base-2 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log2_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log2_ps(a: __m256) -> __m256 {
+    transmute(log2_log10_f32(a.as_f32x8(), false))
+}
+
+/// Computes the base-2 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log2_ps(a: __m512) -> __m512 {
+    transmute(log2_log10_f32(a.as_f32x16(), false))
+}
+
+/// Computes the base-2 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log2_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log2_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = log2_log10_f32(a.as_f32x16(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the base-2 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log2_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log2_pd(a: __m128d) -> __m128d {
+    transmute(log2_log10_f64(a.as_f64x2(), false))
+}
+
+/// Computes the base-2 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log2_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log2_pd(a: __m256d) -> __m256d {
+    transmute(log2_log10_f64(a.as_f64x4(), false))
+}
+
+/// Computes the base-2 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log2_pd(a: __m512d) -> __m512d {
+    transmute(log2_log10_f64(a.as_f64x8(), false))
+}
+
+/// Computes the base-2 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log2_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log2_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = log2_log10_f64(a.as_f64x8(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the base-10 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log10_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log10_ps(a: __m128) -> __m128 {
+    transmute(log2_log10_f32(a.as_f32x4(), true))
+}
+
+/// Computes the base-10 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log10_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log10_ps(a: __m256) -> __m256 {
+    transmute(log2_log10_f32(a.as_f32x8(), true))
+}
+
+/// Computes the base-10 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log10_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log10_ps(a: __m512) -> __m512 {
+    transmute(log2_log10_f32(a.as_f32x16(), true))
+}
+
+/// Computes the base-10 logarithm of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log10_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log10_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = log2_log10_f32(a.as_f32x16(), true);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the base-10 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_log10_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_log10_pd(a: __m128d) -> __m128d {
+    transmute(log2_log10_f64(a.as_f64x2(), true))
+}
+
+/// Computes the base-10 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_log10_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_log10_pd(a: __m256d) -> __m256d {
+    transmute(log2_log10_f64(a.as_f64x4(), true))
+}
+
+/// Computes the base-10 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_log10_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_log10_pd(a: __m512d) -> __m512d {
+    transmute(log2_log10_f64(a.as_f64x8(), true))
+}
+
+/// Computes the base-10 logarithm of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_log10_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_log10_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = log2_log10_f64(a.as_f64x8(), true);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the exponential value of packed single-precision (32-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_pow_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_pow_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(pow_f32(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Computes the exponential value of packed single-precision (32-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_pow_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_pow_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(pow_f32(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Computes the exponential value of packed single-precision (32-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_pow_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_pow_ps(a: __m512, b: __m512) -> __m512 {
+    transmute(pow_f32(a.as_f32x16(), b.as_f32x16()))
+}
+
+/// Computes the exponential value of packed single-precision (32-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_pow_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_pow_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let r = pow_f32(a.as_f32x16(), b.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the exponential value of packed double-precision (64-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_pow_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_pow_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(pow_f64(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Computes the exponential value of packed double-precision (64-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_pow_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_pow_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(pow_f64(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Computes the exponential value of packed double-precision (64-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_pow_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_pow_pd(a: __m512d, b: __m512d) -> __m512d {
+    transmute(pow_f64(a.as_f64x8(), b.as_f64x8()))
+}
+
+/// Computes the exponential value of packed double-precision (64-bit)
+/// floating-point elements in `a` raised by packed elements in `b`, and store
+/// the results in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_pow_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_pow_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let r = pow_f64(a.as_f64x8(), b.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the cube root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cbrt_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_cbrt_ps(a: __m128) -> __m128 {
+    transmute(cbrt_f32(a.as_f32x4(), false))
+}
+
+/// Computes the cube root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cbrt_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_cbrt_ps(a: __m256) -> __m256 {
+    transmute(cbrt_f32(a.as_f32x8(), false))
+}
+
+/// Computes the cube root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cbrt_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_cbrt_ps(a: __m512) -> __m512 {
+    transmute(cbrt_f32(a.as_f32x16(), false))
+}
+
+/// Computes the cube root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cbrt_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_cbrt_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = cbrt_f32(a.as_f32x16(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the cube root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cbrt_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_cbrt_pd(a: __m128d) -> __m128d {
+    transmute(cbrt_f64(a.as_f64x2(), false))
+}
+
+/// Computes the cube root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cbrt_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_cbrt_pd(a: __m256d) -> __m256d {
+    transmute(cbrt_f64(a.as_f64x4(), false))
+}
+
+/// Computes the cube root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cbrt_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_cbrt_pd(a: __m512d) -> __m512d {
+    transmute(cbrt_f64(a.as_f64x8(), false))
+}
+
+/// Computes the cube root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cbrt_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_cbrt_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = cbrt_f64(a.as_f64x8(), false);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the inverse cube root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_invcbrt_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_invcbrt_ps(a: __m128) -> __m128 {
+    transmute(cbrt_f32(a.as_f32x4(), true))
+}
+
+/// Computes the inverse cube root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_invcbrt_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_invcbrt_ps(a: __m256) -> __m256 {
+    transmute(cbrt_f32(a.as_f32x8(), true))
+}
+
+/// Computes the inverse cube root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_invcbrt_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_invcbrt_pd(a: __m128d) -> __m128d {
+    transmute(cbrt_f64(a.as_f64x2(), true))
+}
+
+/// Computes the inverse cube root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_invcbrt_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_invcbrt_pd(a: __m256d) -> __m256d {
+    transmute(cbrt_f64(a.as_f64x4(), true))
+}
+
+/// Computes the inverse square root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_invsqrt_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_invsqrt_ps(a: __m128) -> __m128 {
+    transmute(invsqrt(a.as_f32x4(), 1152921504606846976.0, 4294967296.0))
+}
+
+/// Computes the inverse square root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_invsqrt_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_invsqrt_ps(a: __m256) -> __m256 {
+    transmute(invsqrt(a.as_f32x8(), 1152921504606846976.0, 4294967296.0))
+}
+
+/// Computes the inverse square root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_invsqrt_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_invsqrt_ps(a: __m512) -> __m512 {
+    transmute(invsqrt(a.as_f32x16(), 1152921504606846976.0, 4294967296.0))
+}
+
+/// Computes the inverse square root of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_invsqrt_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_invsqrt_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = invsqrt(a.as_f32x16(), 1152921504606846976.0, 4294967296.0);
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the inverse square root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_invsqrt_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_invsqrt_pd(a: __m128d) -> __m128d {
+    transmute(invsqrt(
+        a.as_f64x2(),
+        3.273390607896142e150,
+        2.037035976334486e90,
+    ))
+}
+
+/// Computes the inverse square root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_invsqrt_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_invsqrt_pd(a: __m256d) -> __m256d {
+    transmute(invsqrt(
+        a.as_f64x4(),
+        3.273390607896142e150,
+        2.037035976334486e90,
+    ))
+}
+
+/// Computes the inverse square root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_invsqrt_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_invsqrt_pd(a: __m512d) -> __m512d {
+    transmute(invsqrt(
+        a.as_f64x8(),
+        3.273390607896142e150,
+        2.037035976334486e90,
+    ))
+}
+
+/// Computes the inverse square root of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_invsqrt_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_invsqrt_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = invsqrt(a.as_f64x8(), 3.273390607896142e150, 2.037035976334486e90);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Computes the square root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The results are correctly rounded.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_svml_sqrt_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(sqrtps))]
+pub unsafe fn _mm_svml_sqrt_ps(a: __m128) -> __m128 {
+    transmute(simd_fsqrt(a.as_f32x4()))
+}
+
+/// Computes the square root of packed single-precision (32-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The results are correctly rounded.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_svml_sqrt_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+#[cfg_attr(test, assert_instr(vsqrtps))]
+pub unsafe fn _mm256_svml_sqrt_ps(a: __m256) -> __m256 {
+    transmute(simd_fsqrt(a.as_f32x8()))
+}
+
+/// Computes the square root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The results are correctly rounded.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_svml_sqrt_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(sqrtpd))]
+pub unsafe fn _mm_svml_sqrt_pd(a: __m128d) -> __m128d {
+    transmute(simd_fsqrt(a.as_f64x2()))
+}
+
+/// Computes the square root of packed double-precision (64-bit) floating-point
+/// elements in `a`, and store the results in `dst`.
+///
+/// The results are correctly rounded.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_svml_sqrt_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+#[cfg_attr(test, assert_instr(vsqrtpd))]
+pub unsafe fn _mm256_svml_sqrt_pd(a: __m256d) -> __m256d {
+    transmute(simd_fsqrt(a.as_f64x4()))
+}
+
+/// Computes the error function of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_erf_ps)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_erf_ps(a: __m128) -> __m128 {
+    transmute(erf_f32(a.as_f32x4()))
+}
+
+/// Computes the error function of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_erf_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_erf_ps(a: __m256) -> __m256 {
+    transmute(erf_f32(a.as_f32x8()))
+}
+
+/// Computes the error function of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_erf_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_erf_ps(a: __m512) -> __m512 {
+    transmute(erf_f32(a.as_f32x16()))
+}
+
+/// Computes the error function of packed single-precision (32-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 2 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_erf_ps)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_erf_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+    let r = erf_f32(a.as_f32x16());
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Computes the error function of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_erf_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_erf_pd(a: __m128d) -> __m128d {
+    transmute(erf_f64(a.as_f64x2()))
+}
+
+/// Computes the error function of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_erf_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_erf_pd(a: __m256d) -> __m256d {
+    transmute(erf_f64(a.as_f64x4()))
+}
+
+/// Computes the error function of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst`.
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_erf_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_erf_pd(a: __m512d) -> __m512d {
+    transmute(erf_f64(a.as_f64x8()))
+}
+
+/// Computes the error function of packed double-precision (64-bit)
+/// floating-point elements in `a`, and store the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// The maximum error is 1 ULP.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_erf_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_erf_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = erf_f64(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Rounds the packed double-precision (64-bit) floating-point elements in `a`
+/// to the nearest integer value, and store the results as packed
+/// double-precision floating-point elements in `dst`. Halfway cases are rounded
+/// away from zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_svml_round_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_svml_round_pd(a: __m128d) -> __m128d {
+    transmute(round_half_away(a.as_f64x2()))
+}
+
+/// Rounds the packed double-precision (64-bit) floating-point elements in `a`
+/// to the nearest integer value, and store the results as packed
+/// double-precision floating-point elements in `dst`. Halfway cases are rounded
+/// away from zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_svml_round_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_svml_round_pd(a: __m256d) -> __m256d {
+    transmute(round_half_away(a.as_f64x4()))
+}
+
+/// Rounds the packed double-precision (64-bit) floating-point elements in `a`
+/// to the nearest integer value, and store the results as packed
+/// double-precision floating-point elements in `dst`. Halfway cases are rounded
+/// away from zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_svml_round_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_svml_round_pd(a: __m512d) -> __m512d {
+    transmute(round_half_away(a.as_f64x8()))
+}
+
+/// Rounds the packed double-precision (64-bit) floating-point elements in `a`
+/// to the nearest integer value, and store the results as packed
+/// double-precision floating-point elements in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+/// Halfway cases are rounded away from zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_svml_round_pd)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_svml_round_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+    let r = round_half_away(a.as_f64x8());
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epi8)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epi8)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epi8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epi16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epi16)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epi16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_i16x16(), b.as_i16x16()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epi16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epi32)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epi32)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epi32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_div_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    let r = simd_div(a.as_i32x16(), b.as_i32x16());
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epi64)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epi64)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epi64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epu8)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epu8)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epu8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epu16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epu16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_u16x8(), b.as_u16x8()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epu16)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epu16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_u16x16(), b.as_u16x16()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epu16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_u16x32(), b.as_u16x32()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epu32)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epu32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_u32x4(), b.as_u32x4()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epu32)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epu32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_u32x8(), b.as_u32x8()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epu32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epu32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_u32x16(), b.as_u32x16()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_epu32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_div_epu32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    let r = simd_div(a.as_u32x16(), b.as_u32x16());
+    transmute(simd_select_bitmask(k, r, src.as_u32x16()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_epu64)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_div_epu64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_div(a.as_u64x2(), b.as_u64x2()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_epu64)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_div_epu64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_div(a.as_u64x4(), b.as_u64x4()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the truncated results in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_epu64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_div_epu64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_div(a.as_u64x8(), b.as_u64x8()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 8-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epi8)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 8-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epi8)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Divides packed signed 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 8-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epi8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 16-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epi16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 16-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epi16)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epi16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_i16x16(), b.as_i16x16()))
+}
+
+/// Divides packed signed 16-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 16-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epi16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 32-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epi32)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 32-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epi32)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 32-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epi32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Divides packed signed 32-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 32-bit integers in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_rem_epi32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_rem_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    let r = simd_rem(a.as_i32x16(), b.as_i32x16());
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 64-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epi64)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 64-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epi64)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Divides packed signed 64-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed signed 64-bit integers in `dst`.
+///
+/// Dividing by zero, or the smallest value by -1, is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epi64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epi64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed unsigned 8-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epu8)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed unsigned 8-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epu8)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Divides packed unsigned 8-bit integers in `a` by packed elements in `b`, and
+/// store the remainders as packed unsigned 8-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epu8)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 16-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epu16)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epu16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_u16x8(), b.as_u16x8()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 16-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epu16)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epu16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_u16x16(), b.as_u16x16()))
+}
+
+/// Divides packed unsigned 16-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 16-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epu16)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_u16x32(), b.as_u16x32()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 32-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epu32)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epu32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_u32x4(), b.as_u32x4()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 32-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epu32)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epu32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_u32x8(), b.as_u32x8()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 32-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epu32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epu32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_u32x16(), b.as_u32x16()))
+}
+
+/// Divides packed unsigned 32-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 32-bit integers in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_rem_epu32)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_mask_rem_epu32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    let r = simd_rem(a.as_u32x16(), b.as_u32x16());
+    transmute(simd_select_bitmask(k, r, src.as_u32x16()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 64-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_rem_epu64)
+#[inline]
+#[target_feature(enable = "sse2")]
+// no particular instruction to test
+pub unsafe fn _mm_rem_epu64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_rem(a.as_u64x2(), b.as_u64x2()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 64-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_rem_epu64)
+#[inline]
+#[target_feature(enable = "avx")]
+// no particular instruction to test
+pub unsafe fn _mm256_rem_epu64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_rem(a.as_u64x4(), b.as_u64x4()))
+}
+
+/// Divides packed unsigned 64-bit integers in `a` by packed elements in `b`,
+/// and store the remainders as packed unsigned 64-bit integers in `dst`.
+///
+/// Dividing by zero is undefined behavior.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_rem_epu64)
+#[inline]
+#[target_feature(enable = "avx512f")]
+// no particular instruction to test
+pub unsafe fn _mm512_rem_epu64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_rem(a.as_u64x8(), b.as_u64x8()))
+}
+
+/// A vector of `f32` or `f64` lanes, paired with the unsigned integer vector
+/// of the same shape holding its bits and the signed vector used as a mask.
+///
+/// The algorithms below are written once against this trait and
+/// instantiated for each vector width.
+trait Float: Copy {
+    type Elem: Copy + From<f32>;
+    type Int: Copy + From<u8>;
+    type Bits: Copy;
+    type Mask: Copy;
+    /// `2^(MANTISSA_DIGITS - 1)`, the smallest value with no fractional bits.
+    const INTEGRAL: Self::Elem;
+    /// `1.5 * INTEGRAL`: adding and subtracting this rounds to the nearest
+    /// integer.
+    const ROUND: Self::Elem;
+    /// `2^ceil(MANTISSA_DIGITS / 2) + 1`, used to split a value in halves.
+    const SPLIT: Self::Elem;
+    /// The sign bit.
+    const SIGN: Self::Int;
+    const LANES: u32;
+    fn splat(x: Self::Elem) -> Self;
+    fn splat_bits(x: Self::Int) -> Self::Bits;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(x: Self::Bits) -> Self;
+}
+
+macro_rules! impl_float {
+    ($(
+        $ty:ident($elem:ident): $bits:ident($int:ident), $mask:ident, $lanes:literal,
+        $integral:expr, $split:expr;
+    )*) => {$(
+        impl Float for $ty {
+            type Elem = $elem;
+            type Int = $int;
+            type Bits = $bits;
+            type Mask = $mask;
+            const INTEGRAL: $elem = $integral;
+            const ROUND: $elem = 1.5 * $integral;
+            const SPLIT: $elem = $split;
+            const SIGN: $int = !($int::MAX >> 1);
+            const LANES: u32 = $lanes;
+            #[inline(always)]
+            fn splat(x: $elem) -> Self {
+                $ty::splat(x)
+            }
+            #[inline(always)]
+            fn splat_bits(x: $int) -> $bits {
+                $bits::splat(x)
+            }
+            #[inline(always)]
+            fn to_bits(self) -> $bits {
+                unsafe { transmute(self) }
+            }
+            #[inline(always)]
+            fn from_bits(x: $bits) -> Self {
+                unsafe { transmute(x) }
+            }
+        }
+    )*};
+}
+
+impl_float! {
+    f32x4(f32): u32x4(u32), i32x4, 4, 8388608.0, 4097.0;
+    f32x8(f32): u32x8(u32), i32x8, 8, 8388608.0, 4097.0;
+    f32x16(f32): u32x16(u32), i32x16, 16, 8388608.0, 4097.0;
+    f64x2(f64): u64x2(u64), i64x2, 2, 4503599627370496.0, 134217729.0;
+    f64x4(f64): u64x4(u64), i64x4, 4, 4503599627370496.0, 134217729.0;
+    f64x8(f64): u64x8(u64), i64x8, 8, 4503599627370496.0, 134217729.0;
+}
+
+#[inline(always)]
+unsafe fn splat<V: Float>(x: V::Elem) -> V {
+    V::splat(x)
+}
+
+/// Like `splat`, for constants that are exact in both `f32` and `f64`.
+#[inline(always)]
+unsafe fn constant<V: Float>(x: f32) -> V {
+    V::splat(x.into())
+}
+
+#[inline(always)]
+unsafe fn select<V: Float>(m: V::Mask, a: V, b: V) -> V {
+    simd_select(m, a, b)
+}
+
+/// Evaluates the polynomial with coefficients `c`, lowest degree first.
+#[inline(always)]
+unsafe fn poly<V: Float>(x: V, c: &[V::Elem]) -> V {
+    let mut r = splat(c[c.len() - 1]);
+    for &k in c[..c.len() - 1].iter().rev() {
+        r = simd_add(simd_mul(r, x), splat(k));
+    }
+    r
+}
+
+/// Rounds to the nearest integer, ties to even. Only valid for
+/// `|x| < 2^(MANTISSA_DIGITS - 2)`.
+#[inline(always)]
+unsafe fn round<V: Float>(x: V) -> V {
+    simd_sub(simd_add(x, splat(V::ROUND)), splat(V::ROUND))
+}
+
+#[inline(always)]
+unsafe fn abs<V: Float>(x: V) -> V {
+    simd_fabs(x)
+}
+
+/// Replaces `x` with `lo` or `hi` where it is outside of `[lo, hi]`; NaN
+/// passes through.
+#[inline(always)]
+unsafe fn clamp<V: Float>(x: V, lo: V::Elem, hi: V::Elem) -> V {
+    let x = select(simd_gt::<_, V::Mask>(x, splat(hi)), splat(hi), x);
+    select(simd_lt::<_, V::Mask>(x, splat(lo)), splat(lo), x)
+}
+
+/// `a + b` as an unevaluated sum of the rounded result and its error.
+#[inline(always)]
+unsafe fn two_sum<V: Float>(a: V, b: V) -> (V, V) {
+    let s = simd_add(a, b);
+    let bb = simd_sub(s, a);
+    let e = simd_add(simd_sub(a, simd_sub(s, bb)), simd_sub(b, bb));
+    (s, e)
+}
+
+/// Like `two_sum`, but requires `|a| >= |b|` (or `a == 0`).
+#[inline(always)]
+unsafe fn fast_two_sum<V: Float>(a: V, b: V) -> (V, V) {
+    let s = simd_add(a, b);
+    (s, simd_sub(b, simd_sub(s, a)))
+}
+
+/// `a * b` as an unevaluated sum of the rounded result and its error.
+#[inline(always)]
+unsafe fn two_prod<V: Float>(a: V, b: V) -> (V, V) {
+    #[inline(always)]
+    unsafe fn split<V: Float>(a: V) -> (V, V) {
+        let t = simd_mul(a, splat(V::SPLIT));
+        let hi = simd_sub(t, simd_sub(t, a));
+        (hi, simd_sub(a, hi))
+    }
+    let p = simd_mul(a, b);
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let e = simd_sub(simd_mul(ah, bh), p);
+    let e = simd_add(simd_add(e, simd_mul(ah, bl)), simd_mul(al, bh));
+    (p, simd_add(e, simd_mul(al, bl)))
+}
+
+/// `x * 2^n` for integer-valued `n`, `|n| <= 250`.
+#[inline(always)]
+unsafe fn ldexp_f32<V: Float<Elem = f32, Int = u32>>(x: V, n: V) -> V {
+    // `n + 2^23 + 127` has the biased exponent of `2^n` in its low bits
+    #[inline(always)]
+    unsafe fn exp2i<V: Float<Elem = f32, Int = u32>>(n: V) -> V {
+        let b = simd_add(n, splat(8388735.0)).to_bits();
+        V::from_bits(simd_shl(b, V::splat_bits(23)))
+    }
+    let n1 = round(simd_mul(n, splat(0.5)));
+    let n2 = simd_sub(n, n1);
+    simd_mul(simd_mul(x, exp2i(n1)), exp2i(n2))
+}
+
+/// `x * 2^n` for integer-valued `n`, `|n| <= 2044`.
+#[inline(always)]
+unsafe fn ldexp_f64<V: Float<Elem = f64, Int = u64>>(x: V, n: V) -> V {
+    #[inline(always)]
+    unsafe fn exp2i<V: Float<Elem = f64, Int = u64>>(n: V) -> V {
+        let b = simd_add(n, splat(4503599627371519.0)).to_bits();
+        V::from_bits(simd_shl(b, V::splat_bits(52)))
+    }
+    let n1 = round(simd_mul(n, splat(0.5)));
+    let n2 = simd_sub(n, n1);
+    simd_mul(simd_mul(x, exp2i(n1)), exp2i(n2))
+}
+
+/// `e^x`, using the reduction and polynomial of Cephes `expf`.
+#[inline(always)]
+unsafe fn exp_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    let x = clamp(x, -105.0, 89.0);
+    let k = round(simd_mul(x, splat(LOG2_E as f32)));
+    let r = simd_sub(x, simd_mul(k, splat(0.6933594)));
+    let r = simd_sub(r, simd_mul(k, splat(-2.1219444e-4)));
+    let p = poly(
+        r,
+        &[
+            5e-1,
+            1.6666666e-1,
+            4.1665796e-2,
+            8.333452e-3,
+            1.3981999e-3,
+            1.9875691e-4,
+        ],
+    );
+    let y = simd_add(simd_add(simd_mul(p, simd_mul(r, r)), r), splat(1.0));
+    ldexp_f32(y, k)
+}
+
+/// `e^(hi - lo)` for `|hi - lo| <= ln(2) / 2`.
+#[inline(always)]
+unsafe fn exp_kernel_f64<V: Float<Elem = f64>>(hi: V, lo: V) -> V {
+    let r = simd_sub(hi, lo);
+    let t = simd_mul(r, r);
+    let p = poly(
+        t,
+        &[
+            1.6666666666666602e-1,
+            -2.7777777777015593e-3,
+            6.613756321437934e-5,
+            -1.6533902205465252e-6,
+            4.1381367970572385e-8,
+        ],
+    );
+    let c = simd_sub(r, simd_mul(t, p));
+    let y = simd_div(simd_mul(r, c), simd_sub(splat(2.0), c));
+    simd_sub(splat(1.0), simd_sub(simd_sub(lo, y), hi))
+}
+
+/// `e^x`, using the reduction and kernel of fdlibm `exp`.
+#[inline(always)]
+unsafe fn exp_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let x = clamp(x, -746.0, 710.0);
+    let k = round(simd_mul(x, splat(LOG2_E)));
+    let hi = simd_sub(x, simd_mul(k, splat(6.931471803691238e-1)));
+    let lo = simd_mul(k, splat(1.9082149292705877e-10));
+    ldexp_f64(exp_kernel_f64(hi, lo), k)
+}
+
+/// Fixes up the result of a logarithm for zero, negative, infinite and NaN
+/// `x`.
+#[inline(always)]
+unsafe fn log_special<V: Float>(x: V, r: V, zero: V::Elem, inf: V::Elem, nan: V::Elem) -> V {
+    let neg_inf = V::from_bits(simd_xor(splat::<V>(inf).to_bits(), V::splat_bits(V::SIGN)));
+    let r = select(simd_eq::<_, V::Mask>(x, splat(zero)), neg_inf, r);
+    let r = select(simd_eq::<_, V::Mask>(x, splat(inf)), splat(inf), r);
+    select(simd_ge::<_, V::Mask>(x, splat(zero)), r, splat(nan))
+}
+
+/// `ln(x)`, using the reduction and polynomial of fdlibm `logf`.
+#[inline(always)]
+unsafe fn log_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    // scale subnormals into the normal range
+    let sub: V::Mask = simd_lt(x, splat(f32::MIN_POSITIVE));
+    let xs = select(sub, simd_mul(x, splat(33554432.0)), x);
+    // reduce to `m * 2^k` with `m` in `[sqrt(2) / 2, sqrt(2))`
+    let ix = simd_add(xs.to_bits(), V::splat_bits(0x3f800000 - 0x3f3504f3));
+    let k = V::from_bits(simd_or(
+        simd_shr(ix, V::splat_bits(23)),
+        V::splat_bits(0x4b000000),
+    ));
+    let k = simd_sub(
+        k,
+        select(
+            sub,
+            splat(8388608.0 + 127.0 + 25.0),
+            splat(8388608.0 + 127.0),
+        ),
+    );
+    let m = V::from_bits(simd_add(
+        simd_and(ix, V::splat_bits(0x007fffff)),
+        V::splat_bits(0x3f3504f3),
+    ));
+
+    let f = simd_sub(m, splat(1.0));
+    let s = simd_div(f, simd_add(splat(2.0), f));
+    let z = simd_mul(s, s);
+    let w = simd_mul(z, z);
+    let t1 = simd_mul(w, poly(w, &[0.40000972, 0.24279079]));
+    let t2 = simd_mul(z, poly(w, &[0.6666666, 0.28498787]));
+    let hfsq = simd_mul(simd_mul(splat(0.5), f), f);
+    let r = simd_mul(s, simd_add(hfsq, simd_add(t2, t1)));
+    let r = simd_add(r, simd_mul(k, splat(9.058001e-6)));
+    let r = simd_add(simd_sub(r, hfsq), f);
+    let r = simd_add(r, simd_mul(k, splat(6.931381e-1)));
+    log_special(x, r, 0.0, f32::INFINITY, f32::NAN)
+}
+
+/// `ln(x)`, using the reduction and polynomial of fdlibm `log`.
+#[inline(always)]
+unsafe fn log_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let sub: V::Mask = simd_lt(x, splat(f64::MIN_POSITIVE));
+    let xs = select(sub, simd_mul(x, splat(18014398509481984.0)), x);
+    let ix = simd_add(xs.to_bits(), V::splat_bits((0x3ff00000 - 0x3fe6a09e) << 32));
+    let k = V::from_bits(simd_or(
+        simd_shr(ix, V::splat_bits(52)),
+        V::splat_bits(0x4330000000000000),
+    ));
+    let k = simd_sub(
+        k,
+        select(
+            sub,
+            splat(4503599627370496.0 + 1023.0 + 54.0),
+            splat(4503599627370496.0 + 1023.0),
+        ),
+    );
+    let m = V::from_bits(simd_add(
+        simd_and(ix, V::splat_bits(0x000fffffffffffff)),
+        V::splat_bits(0x3fe6a09e << 32),
+    ));
+
+    let f = simd_sub(m, splat(1.0));
+    let s = simd_div(f, simd_add(splat(2.0), f));
+    let z = simd_mul(s, s);
+    let w = simd_mul(z, z);
+    let t1 = simd_mul(
+        w,
+        poly(
+            w,
+            &[
+                3.999999999940942e-1,
+                2.2222198432149784e-1,
+                1.5313837699209373e-1,
+            ],
+        ),
+    );
+    let t2 = simd_mul(
+        z,
+        poly(
+            w,
+            &[
+                6.666666666666735e-1,
+                2.857142874366239e-1,
+                1.818357216161805e-1,
+                1.4798198605116586e-1,
+            ],
+        ),
+    );
+    let hfsq = simd_mul(simd_mul(splat(0.5), f), f);
+    let r = simd_mul(s, simd_add(hfsq, simd_add(t2, t1)));
+    let r = simd_add(r, simd_mul(k, splat(1.9082149292705877e-10)));
+    let r = simd_add(simd_sub(r, hfsq), f);
+    let r = simd_add(r, simd_mul(k, splat(6.931471803691238e-1)));
+    log_special(x, r, 0.0, f64::INFINITY, f64::NAN)
+}
+
+/// `x - q * pi / 2` for the integer `q` nearest to `x * 2 / pi`, as the
+/// unevaluated sum `r + lo`. Only the low bits of `q` are kept.
+#[inline(always)]
+unsafe fn rem_pio2_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> (V::Bits, V, V) {
+    // `j = round(x * 2 / pi)`, with `j mod 4` in the low bits of `t`
+    let t = simd_add(simd_mul(x, splat(FRAC_2_PI as f32)), splat(V::ROUND));
+    let j = simd_sub(t, splat(V::ROUND));
+    let mut q = t.to_bits();
+    // `r = x - j * pi / 2`, where the parts of `pi / 2` are short enough
+    // that their products with `j` are exact
+    let r = simd_sub(x, simd_mul(j, splat(1.5703125)));
+    let (r, e1) = two_sum(r, simd_mul(j, splat(-4.837513e-4)));
+    let (r, e2) = two_sum(r, simd_mul(j, splat(-7.5495336e-8)));
+    let lo = simd_sub(simd_add(e1, e2), simd_mul(j, splat(2.563344e-12)));
+    let (mut r, mut lo) = two_sum(r, lo);
+    // that stops being exact from `j >= 2^13`
+    if simd_reduce_any(simd_ge::<_, V::Mask>(simd_fabs(x), splat(8192.0))) {
+        for i in 0..V::LANES {
+            let xi: f32 = simd_extract(x, i);
+            if xi >= 8192.0 || xi <= -8192.0 {
+                let (n, h, l) = rem_pio2_large(xi as f64);
+                let hi = h as f32;
+                q = simd_insert(q, i, n as u32);
+                r = simd_insert(r, i, hi);
+                lo = simd_insert(lo, i, ((h - hi as f64) + l) as f32);
+            }
+        }
+    }
+    (q, r, lo)
+}
+
+/// `sin(r + lo)` for `|r + lo| <= pi / 4`.
+#[inline(always)]
+unsafe fn sin_kernel_f32<V: Float<Elem = f32>>(r: V, lo: V) -> V {
+    let z = simd_mul(r, r);
+    let s = poly(z, &[-1.6666655e-1, 8.332161e-3, -1.9515296e-4]);
+    simd_add(simd_add(simd_mul(simd_mul(s, z), r), lo), r)
+}
+
+/// `cos(r + lo)` for `|r + lo| <= pi / 4`.
+#[inline(always)]
+unsafe fn cos_kernel_f32<V: Float<Elem = f32>>(r: V, lo: V) -> V {
+    let z = simd_mul(r, r);
+    let c = poly(z, &[4.1666646e-2, -1.3887316e-3, 2.4433157e-5]);
+    let c = simd_sub(simd_mul(simd_mul(c, z), z), simd_mul(r, lo));
+    simd_add(simd_sub(c, simd_mul(splat(0.5), z)), splat(1.0))
+}
+
+/// The sine (`cos == false`) or cosine of `x`.
+#[inline(always)]
+unsafe fn sincos_f32<V: Float<Elem = f32, Int = u32>>(x: V, cos: bool) -> V {
+    let (mut q, r, lo) = rem_pio2_f32(x);
+    if cos {
+        q = simd_add(q, V::splat_bits(1));
+    }
+    let odd: V::Mask = simd_ne(simd_and(q, V::splat_bits(1)), V::splat_bits(0));
+    let r = select(odd, cos_kernel_f32(r, lo), sin_kernel_f32(r, lo));
+    let sign = simd_shl(simd_and(q, V::splat_bits(2)), V::splat_bits(30));
+    let r = V::from_bits(simd_xor(r.to_bits(), sign));
+    // the reduction loses the sign of `sin(-0.0)`
+    if cos {
+        r
+    } else {
+        select(simd_eq::<_, V::Mask>(x, constant(0.0)), x, r)
+    }
+}
+
+/// The tangent of `x`.
+#[inline(always)]
+unsafe fn tan_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    let (q, r, lo) = rem_pio2_f32(x);
+    let s = sin_kernel_f32(r, lo);
+    let c = cos_kernel_f32(r, lo);
+    // `tan(r + pi / 2) = -cos(r) / sin(r)`
+    let odd: V::Mask = simd_ne(simd_and(q, V::splat_bits(1)), V::splat_bits(0));
+    let t = simd_div(select(odd, c, s), select(odd, s, c));
+    let t = select(
+        odd,
+        V::from_bits(simd_xor(t.to_bits(), V::splat_bits(V::SIGN))),
+        t,
+    );
+    select(simd_eq::<_, V::Mask>(x, constant(0.0)), x, t)
+}
+
+/// See `rem_pio2_f32`.
+#[inline(always)]
+unsafe fn rem_pio2_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> (V::Bits, V, V) {
+    let t = simd_add(simd_mul(x, splat(FRAC_2_PI)), splat(V::ROUND));
+    let j = simd_sub(t, splat(V::ROUND));
+    let mut q = t.to_bits();
+    let r = simd_sub(x, simd_mul(j, splat(1.5707963267341256)));
+    let (r, e1) = two_sum(r, simd_mul(j, splat(-6.077100506303966e-11)));
+    let (r, e2) = two_sum(r, simd_mul(j, splat(-2.0222662487111665e-21)));
+    let lo = simd_sub(simd_add(e1, e2), simd_mul(j, splat(8.4784276603689e-32)));
+    let (mut r, mut lo) = two_sum(r, lo);
+    if simd_reduce_any(simd_ge::<_, V::Mask>(simd_fabs(x), splat(1048576.0))) {
+        for i in 0..V::LANES {
+            let xi: f64 = simd_extract(x, i);
+            if xi >= 1048576.0 || xi <= -1048576.0 {
+                let (n, h, l) = rem_pio2_large(xi);
+                q = simd_insert(q, i, n);
+                r = simd_insert(r, i, h);
+                lo = simd_insert(lo, i, l);
+            }
+        }
+    }
+    (q, r, lo)
+}
+
+/// See `sin_kernel_f32`.
+#[inline(always)]
+unsafe fn sin_kernel_f64<V: Float<Elem = f64>>(r: V, lo: V) -> V {
+    let z = simd_mul(r, r);
+    let s = poly(
+        z,
+        &[
+            -1.6666666666666632e-1,
+            8.33333333332249e-3,
+            -1.984126982985795e-4,
+            2.7557313707070068e-6,
+            -2.5050760253406863e-8,
+            1.58969099521155e-10,
+        ],
+    );
+    simd_add(r, simd_add(simd_mul(simd_mul(z, r), s), lo))
+}
+
+/// See `cos_kernel_f32`.
+#[inline(always)]
+unsafe fn cos_kernel_f64<V: Float<Elem = f64>>(r: V, lo: V) -> V {
+    let z = simd_mul(r, r);
+    let c = poly(
+        z,
+        &[
+            4.16666666666666e-2,
+            -1.388888888887411e-3,
+            2.480158728947673e-5,
+            -2.7557314351390663e-7,
+            2.087572321298175e-9,
+            -1.1359647557788195e-11,
+        ],
+    );
+    let c = simd_sub(simd_mul(simd_mul(z, z), c), simd_mul(r, lo));
+    let hz = simd_mul(splat(0.5), z);
+    let w = simd_sub(splat(1.0), hz);
+    simd_add(w, simd_add(simd_sub(simd_sub(splat(1.0), w), hz), c))
+}
+
+/// See `sincos_f32`.
+#[inline(always)]
+unsafe fn sincos_f64<V: Float<Elem = f64, Int = u64>>(x: V, cos: bool) -> V {
+    let (mut q, r, lo) = rem_pio2_f64(x);
+    if cos {
+        q = simd_add(q, V::splat_bits(1));
+    }
+    let odd: V::Mask = simd_ne(simd_and(q, V::splat_bits(1)), V::splat_bits(0));
+    let r = select(odd, cos_kernel_f64(r, lo), sin_kernel_f64(r, lo));
+    let sign = simd_shl(simd_and(q, V::splat_bits(2)), V::splat_bits(62));
+    let r = V::from_bits(simd_xor(r.to_bits(), sign));
+    // the reduction loses the sign of `sin(-0.0)`
+    if cos {
+        r
+    } else {
+        select(simd_eq::<_, V::Mask>(x, constant(0.0)), x, r)
+    }
+}
+
+/// See `tan_f32`.
+#[inline(always)]
+unsafe fn tan_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let (q, r, lo) = rem_pio2_f64(x);
+    let s = sin_kernel_f64(r, lo);
+    let c = cos_kernel_f64(r, lo);
+    let odd: V::Mask = simd_ne(simd_and(q, V::splat_bits(1)), V::splat_bits(0));
+    let t = simd_div(select(odd, c, s), select(odd, s, c));
+    let t = select(
+        odd,
+        V::from_bits(simd_xor(t.to_bits(), V::splat_bits(V::SIGN))),
+        t,
+    );
+    select(simd_eq::<_, V::Mask>(x, constant(0.0)), x, t)
+}
+
+/// `atan(x + lo)` for non-negative `x`, using the reduction and polynomial of
+/// fdlibm `atanf`. `lo` is a correction below the precision of `x`.
+#[inline(always)]
+unsafe fn atan_kernel_f32<V: Float<Elem = f32, Int = u32>>(x: V, lo: V) -> V {
+    // `atan(x) = atan(c) + atan((x - c) / (1 + x * c))` for `c` of 0, 0.5,
+    // 1, 1.5 and infinity, where the last is `pi / 2 + atan(-1 / x)`; the
+    // quotient is computed as `(p * x - c) / (p + c * x)`, with `p` scaled
+    // for `c == 0.5` and 0 for infinity
+    let start = [0.0, 0.4375, 0.6875, 1.1875, 2.4375];
+    let p = pick(x, &start, &[1.0, 2.0, 1.0, 1.0, 0.0]);
+    let c = pick(x, &start, &[0.0, 1.0, 1.0, 1.5, 1.0]);
+    let t = simd_div(simd_sub(simd_mul(x, p), c), simd_add(p, simd_mul(x, c)));
+    let z = simd_mul(t, t);
+    let w = simd_mul(z, z);
+    let s1 = simd_mul(z, poly(w, &[3.3333328e-1, 1.4253636e-1, 6.1687607e-2]));
+    let s2 = simd_mul(w, poly(w, &[-1.9999158e-1, -1.06480174e-1]));
+    let hi = pick(
+        x,
+        &start,
+        &[0.0, 4.636476e-1, 7.853981e-1, 9.827937e-1, 1.5707963],
+    );
+    let lo = simd_add(
+        pick(
+            x,
+            &start,
+            &[0.0, 5.0121582e-9, 3.7748947e-8, 3.4473217e-8, 7.5497894e-8],
+        ),
+        simd_div(lo, simd_add(splat(1.0), simd_mul(x, x))),
+    );
+    let r = simd_sub(hi, simd_sub(simd_sub(simd_mul(t, simd_add(s1, s2)), lo), t));
+    select(
+        simd_eq::<_, V::Mask>(x, splat(f32::INFINITY)),
+        splat(FRAC_PI_2 as f32),
+        r,
+    )
+}
+
+/// The arctangent of `x`.
+#[inline(always)]
+unsafe fn atan_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    let r = atan_kernel_f32(abs(x), constant(0.0));
+    V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ))
+}
+
+/// The arctangent of `y / x`, in the quadrant of the point `(x, y)`.
+#[inline(always)]
+unsafe fn atan2_f32<V: Float<Elem = f32, Int = u32>>(y: V, x: V) -> V {
+    let (a, b) = (abs(y), abs(x));
+    // `a / b` as the unevaluated sum `q + lo`; `lo` is NaN or infinite when
+    // `q` is
+    let q = simd_div(a, b);
+    let (p, e) = two_prod(q, b);
+    let lo = simd_div(simd_sub(simd_sub(a, p), e), b);
+    let lo = select(
+        simd_lt::<_, V::Mask>(abs(lo), splat(f32::INFINITY)),
+        lo,
+        splat(0.0),
+    );
+    let r = atan_kernel_f32(q, lo);
+    let r = select(simd_eq::<_, V::Mask>(a, splat(0.0)), splat(0.0), r);
+    let inf: V::Mask = simd_and(
+        simd_eq(a, splat(f32::INFINITY)),
+        simd_eq(b, splat(f32::INFINITY)),
+    );
+    let r = select(inf, splat(core::f32::consts::FRAC_PI_4), r);
+    // `pi - r` for negative `x`, including `-0.0`
+    let neg: V::Mask = simd_ne(
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+        V::splat_bits(0),
+    );
+    let r = select(
+        neg,
+        simd_sub(splat(3.1415927), simd_sub(r, splat(-8.742278e-8))),
+        r,
+    );
+    let r = V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(y.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    let nan: V::Mask = simd_or(simd_ne(x, x), simd_ne(y, y));
+    select(nan, simd_add(x, y), r)
+}
+
+/// See `atan_kernel_f32`; this uses the polynomial of fdlibm `atan`.
+#[inline(always)]
+unsafe fn atan_kernel_f64<V: Float<Elem = f64, Int = u64>>(x: V, lo: V) -> V {
+    let start = [0.0, 0.4375, 0.6875, 1.1875, 2.4375];
+    let p = pick(x, &start, &[1.0, 2.0, 1.0, 1.0, 0.0]);
+    let c = pick(x, &start, &[0.0, 1.0, 1.0, 1.5, 1.0]);
+    let t = simd_div(simd_sub(simd_mul(x, p), c), simd_add(p, simd_mul(x, c)));
+    let z = simd_mul(t, t);
+    let w = simd_mul(z, z);
+    let s1 = simd_mul(
+        z,
+        poly(
+            w,
+            &[
+                3.333333333333293e-1,
+                1.4285714272503466e-1,
+                9.090887133436507e-2,
+                6.661073137387531e-2,
+                4.9768779946159324e-2,
+                1.6285820115365782e-2,
+            ],
+        ),
+    );
+    let s2 = simd_mul(
+        w,
+        poly(
+            w,
+            &[
+                -1.9999999999876483e-1,
+                -1.1111110405462356e-1,
+                -7.69187620504483e-2,
+                -5.8335701337905735e-2,
+                -3.6531572744216916e-2,
+            ],
+        ),
+    );
+    let hi = pick(
+        x,
+        &start,
+        &[
+            0.0,
+            4.636476090008061e-1,
+            7.853981633974483e-1,
+            9.82793723247329e-1,
+            1.5707963267948966,
+        ],
+    );
+    let lo = simd_add(
+        pick(
+            x,
+            &start,
+            &[
+                0.0,
+                2.2698777452961687e-17,
+                3.061616997868383e-17,
+                1.3903311031230998e-17,
+                6.123233995736766e-17,
+            ],
+        ),
+        simd_div(lo, simd_add(splat(1.0), simd_mul(x, x))),
+    );
+    let r = simd_sub(hi, simd_sub(simd_sub(simd_mul(t, simd_add(s1, s2)), lo), t));
+    select(
+        simd_eq::<_, V::Mask>(x, splat(f64::INFINITY)),
+        splat(FRAC_PI_2),
+        r,
+    )
+}
+
+/// See `atan_f32`.
+#[inline(always)]
+unsafe fn atan_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let r = atan_kernel_f64(abs(x), constant(0.0));
+    V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ))
+}
+
+/// See `atan2_f32`.
+#[inline(always)]
+unsafe fn atan2_f64<V: Float<Elem = f64, Int = u64>>(y: V, x: V) -> V {
+    let (a, b) = (abs(y), abs(x));
+    let q = simd_div(a, b);
+    let (p, e) = two_prod(q, b);
+    let lo = simd_div(simd_sub(simd_sub(a, p), e), b);
+    let lo = select(
+        simd_lt::<_, V::Mask>(abs(lo), splat(f64::INFINITY)),
+        lo,
+        splat(0.0),
+    );
+    let r = atan_kernel_f64(q, lo);
+    let r = select(simd_eq::<_, V::Mask>(a, splat(0.0)), splat(0.0), r);
+    let inf: V::Mask = simd_and(
+        simd_eq(a, splat(f64::INFINITY)),
+        simd_eq(b, splat(f64::INFINITY)),
+    );
+    let r = select(inf, splat(core::f64::consts::FRAC_PI_4), r);
+    let neg: V::Mask = simd_ne(
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+        V::splat_bits(0),
+    );
+    let r = select(
+        neg,
+        simd_sub(
+            splat(3.141592653589793),
+            simd_sub(r, splat(1.2246467991473532e-16)),
+        ),
+        r,
+    );
+    let r = V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(y.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    let nan: V::Mask = simd_or(simd_ne(x, x), simd_ne(y, y));
+    select(nan, simd_add(x, y), r)
+}
+
+/// Applies the special cases of `pow` that don't follow from computing
+/// `|x|^y` as `2^(y * log2(|x|))`: the sign of the result for negative `x`,
+/// and the cases that give 1 even for infinite or NaN arguments.
+#[inline(always)]
+unsafe fn pow_special<V: Float>(x: V, y: V, r: V) -> V {
+    let ay = abs(y);
+    // values of at least `INTEGRAL` are all integers, and from twice that on
+    // they are all even
+    let int: V::Mask = simd_or(simd_ge(ay, splat(V::INTEGRAL)), simd_eq(round(ay), ay));
+    let fract: V::Mask = simd_and(simd_lt(ay, splat(V::INTEGRAL)), simd_ne(round(ay), ay));
+    let t = select(
+        simd_lt::<_, V::Mask>(ay, splat(V::INTEGRAL)),
+        simd_add(ay, splat(V::INTEGRAL)),
+        ay,
+    );
+    let odd: V::Mask = simd_ne(
+        simd_and(t.to_bits(), V::splat_bits(1.into())),
+        V::splat_bits(0.into()),
+    );
+    let two_int = simd_add(splat::<V>(V::INTEGRAL), splat(V::INTEGRAL));
+    let odd = simd_and(simd_and(odd, int), simd_lt(ay, two_int));
+    let sign = simd_and(x.to_bits(), V::splat_bits(V::SIGN));
+    let r = select(odd, V::from_bits(simd_xor(r.to_bits(), sign)), r);
+    // finite negative `x` with a non-integer `y`
+    let neg: V::Mask = simd_and(
+        simd_lt(x, constant(0.0)),
+        simd_ne(x, constant(f32::NEG_INFINITY)),
+    );
+    let r = select(simd_and(neg, fract), constant(f32::NAN), r);
+    // `x == -1` with infinite `y`
+    let one = simd_and(
+        simd_eq(x, constant(-1.0)),
+        simd_eq(ay, constant(f32::INFINITY)),
+    );
+    let one = simd_or(
+        one,
+        simd_or(simd_eq(y, constant(0.0)), simd_eq(x, constant(1.0))),
+    );
+    select(one, constant(1.0), r)
+}
+
+/// `log2(x)` for positive `x` as an unevaluated sum `hi + lo`, accurate to
+/// about 2^-68 relative.
+#[inline(always)]
+unsafe fn log2_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> (V, V) {
+    let sub: V::Mask = simd_lt(x, splat(f64::MIN_POSITIVE));
+    let xs = select(sub, simd_mul(x, splat(18014398509481984.0)), x);
+    let ix = simd_add(xs.to_bits(), V::splat_bits((0x3ff00000 - 0x3fe6a09e) << 32));
+    let k = V::from_bits(simd_or(
+        simd_shr(ix, V::splat_bits(52)),
+        V::splat_bits(0x4330000000000000),
+    ));
+    let k = simd_sub(
+        k,
+        select(
+            sub,
+            splat(4503599627370496.0 + 1023.0 + 54.0),
+            splat(4503599627370496.0 + 1023.0),
+        ),
+    );
+    let m = V::from_bits(simd_add(
+        simd_and(ix, V::splat_bits(0x000fffffffffffff)),
+        V::splat_bits(0x3fe6a09e << 32),
+    ));
+
+    // `ln(m) = 2 * atanh(s)` with `s = f / (2 + f)`; the first two terms of
+    // the series are computed in double-word arithmetic
+    let f = simd_sub(m, splat(1.0));
+    let dh = simd_add(splat(2.0), f);
+    let dl = simd_sub(f, simd_sub(dh, splat(2.0)));
+    let sh = simd_div(f, dh);
+    let (p, pe) = two_prod(sh, dh);
+    let sl = simd_div(simd_sub(simd_sub(simd_sub(f, p), pe), simd_mul(sh, dl)), dh);
+    let (zh, zl) = two_prod(sh, sh);
+    let zl = simd_add(zl, simd_mul(simd_add(sh, sh), sl));
+    let (ch, cl) = two_prod(zh, sh);
+    let cl = simd_add(cl, simd_add(simd_mul(zl, sh), simd_mul(zh, sl)));
+    let (th, tl) = two_prod(ch, splat(0.6666666666666666));
+    let tl = simd_add(
+        tl,
+        simd_add(
+            simd_mul(cl, splat(0.6666666666666666)),
+            simd_mul(ch, splat(3.700743415417188e-17)),
+        ),
+    );
+    let v = simd_mul(
+        simd_mul(ch, zh),
+        poly(
+            zh,
+            &[
+                0.4,
+                0.2857142857142857,
+                0.2222222222222222,
+                0.18181818181818182,
+                0.15384615384615385,
+                0.13333333333333333,
+                0.11764705882352941,
+                0.10526315789473684,
+                0.09523809523809523,
+                0.08695652173913043,
+                0.08,
+            ],
+        ),
+    );
+    let (lh, ll) = fast_two_sum(simd_add(sh, sh), th);
+    let ll = simd_add(ll, simd_add(simd_add(simd_add(sl, sl), tl), v));
+    let (lh, ll) = fast_two_sum(lh, ll);
+
+    // multiply by `log2(e)` and add the exponent
+    let (ph, pl) = two_prod(lh, splat(LOG2_E));
+    let pl = simd_add(
+        pl,
+        simd_add(
+            simd_mul(lh, splat(2.0355273740931033e-17)),
+            simd_mul(ll, splat(LOG2_E)),
+        ),
+    );
+    let (qh, ql) = two_sum(k, ph);
+    let (h, l) = fast_two_sum(qh, simd_add(ql, pl));
+
+    let h = select(
+        simd_eq::<_, V::Mask>(x, splat(0.0)),
+        splat(f64::NEG_INFINITY),
+        h,
+    );
+    let h = select(simd_eq::<_, V::Mask>(x, splat(f64::INFINITY)), x, h);
+    let h = select(simd_ne::<_, V::Mask>(x, x), x, h);
+    (h, l)
+}
+
+/// `x^y`, computed as `2^(y * log2(|x|))` in double-word arithmetic.
+#[inline(always)]
+unsafe fn pow_f64<V: Float<Elem = f64, Int = u64>>(x: V, y: V) -> V {
+    let (h, l) = log2_f64(abs(x));
+    let (th, tl) = two_prod(y, h);
+    let tl = simd_add(tl, simd_mul(y, l));
+    pow_special(x, y, exp2_f64(th, tl))
+}
+
+/// `2^(th + tl)`, where `tl` is a correction below the precision of `th`.
+#[inline(always)]
+unsafe fn exp2_f64<V: Float<Elem = f64, Int = u64>>(th: V, tl: V) -> V {
+    // outside of this range the result overflows or underflows; the low
+    // part may be NaN there
+    let tl = select(
+        simd_lt::<_, V::Mask>(abs(th), splat(1100.0)),
+        tl,
+        splat(0.0),
+    );
+    let th = clamp(th, -1100.0, 1100.0);
+    // `2^t = 2^n * e^(f * ln(2))`
+    let n = round(th);
+    let (fh, fl) = fast_two_sum(simd_sub(th, n), tl);
+    let (rh, rl) = two_prod(fh, splat(LN_2));
+    let rl = simd_add(
+        rl,
+        simd_add(
+            simd_mul(fh, splat(2.3190468138462996e-17)),
+            simd_mul(fl, splat(LN_2)),
+        ),
+    );
+    let (hi, lo) = fast_two_sum(rh, rl);
+    ldexp_f64(exp_kernel_f64(hi, simd_sub(splat(0.0), lo)), n)
+}
+
+/// `10^x`, computed as `2^(x * log2(10))` in double-word arithmetic.
+#[inline(always)]
+unsafe fn exp10_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let (th, tl) = two_prod(x, splat(LOG2_10));
+    let tl = simd_add(tl, simd_mul(x, splat(1.661617516973592e-16)));
+    exp2_f64(th, tl)
+}
+
+/// `log2(x)`, or `log10(x)` if `log10`.
+#[inline(always)]
+unsafe fn log2_log10_f64<V: Float<Elem = f64, Int = u64>>(x: V, log10: bool) -> V {
+    let (h, l) = log2_f64(x);
+    let r = if log10 {
+        let (ph, pl) = two_prod(h, splat(LOG10_2));
+        let pl = simd_add(
+            pl,
+            simd_add(
+                simd_mul(h, splat(-2.8037281277851704e-18)),
+                simd_mul(l, splat(LOG10_2)),
+            ),
+        );
+        simd_add(ph, pl)
+    } else {
+        simd_add(h, l)
+    };
+    log_special(x, r, 0.0, f64::INFINITY, f64::NAN)
+}
+
+#[inline(always)]
+unsafe fn log2_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> (V, V) {
+    let sub: V::Mask = simd_lt(x, splat(f32::MIN_POSITIVE));
+    let xs = select(sub, simd_mul(x, splat(33554432.0)), x);
+    let ix = simd_add(xs.to_bits(), V::splat_bits(0x3f800000 - 0x3f3504f3));
+    let k = V::from_bits(simd_or(
+        simd_shr(ix, V::splat_bits(23)),
+        V::splat_bits(0x4b000000),
+    ));
+    let k = simd_sub(
+        k,
+        select(
+            sub,
+            splat(8388608.0 + 127.0 + 25.0),
+            splat(8388608.0 + 127.0),
+        ),
+    );
+    let m = V::from_bits(simd_add(
+        simd_and(ix, V::splat_bits(0x007fffff)),
+        V::splat_bits(0x3f3504f3),
+    ));
+
+    let f = simd_sub(m, splat(1.0));
+    let dh = simd_add(splat(2.0), f);
+    let dl = simd_sub(f, simd_sub(dh, splat(2.0)));
+    let sh = simd_div(f, dh);
+    let (p, pe) = two_prod(sh, dh);
+    let sl = simd_div(simd_sub(simd_sub(simd_sub(f, p), pe), simd_mul(sh, dl)), dh);
+    let (zh, zl) = two_prod(sh, sh);
+    let zl = simd_add(zl, simd_mul(simd_add(sh, sh), sl));
+    let (ch, cl) = two_prod(zh, sh);
+    let cl = simd_add(cl, simd_add(simd_mul(zl, sh), simd_mul(zh, sl)));
+    let (th, tl) = two_prod(ch, splat(0.6666667));
+    let tl = simd_add(
+        tl,
+        simd_add(
+            simd_mul(cl, splat(0.6666667)),
+            simd_mul(ch, splat(-1.9868216e-8)),
+        ),
+    );
+    let v = simd_mul(
+        simd_mul(ch, zh),
+        poly(zh, &[0.4, 0.2857143, 0.22222222, 0.18181819, 0.15384616]),
+    );
+    let (lh, ll) = fast_two_sum(simd_add(sh, sh), th);
+    let ll = simd_add(ll, simd_add(simd_add(simd_add(sl, sl), tl), v));
+    let (lh, ll) = fast_two_sum(lh, ll);
+
+    let (ph, pl) = two_prod(lh, splat(LOG2_E as f32));
+    let pl = simd_add(
+        pl,
+        simd_add(
+            simd_mul(lh, splat(1.925963e-8)),
+            simd_mul(ll, splat(LOG2_E as f32)),
+        ),
+    );
+    let (qh, ql) = two_sum(k, ph);
+    let (h, l) = fast_two_sum(qh, simd_add(ql, pl));
+
+    let h = select(
+        simd_eq::<_, V::Mask>(x, splat(0.0)),
+        splat(f32::NEG_INFINITY),
+        h,
+    );
+    let h = select(simd_eq::<_, V::Mask>(x, splat(f32::INFINITY)), x, h);
+    let h = select(simd_ne::<_, V::Mask>(x, x), x, h);
+    (h, l)
+}
+
+/// `x^y`, computed as `2^(y * log2(|x|))` in double-word arithmetic.
+#[inline(always)]
+unsafe fn pow_f32<V: Float<Elem = f32, Int = u32>>(x: V, y: V) -> V {
+    let (h, l) = log2_f32(abs(x));
+    let (th, tl) = two_prod(y, h);
+    let tl = simd_add(tl, simd_mul(y, l));
+    pow_special(x, y, exp2_f32(th, tl))
+}
+
+/// `2^(th + tl)`, where `tl` is a correction below the precision of `th`.
+#[inline(always)]
+unsafe fn exp2_f32<V: Float<Elem = f32, Int = u32>>(th: V, tl: V) -> V {
+    let tl = select(simd_lt::<_, V::Mask>(abs(th), splat(160.0)), tl, splat(0.0));
+    let th = clamp(th, -160.0, 160.0);
+    let n = round(th);
+    let (fh, fl) = fast_two_sum(simd_sub(th, n), tl);
+    let (rh, rl) = two_prod(fh, splat(LN_2 as f32));
+    let rl = simd_add(
+        rl,
+        simd_add(
+            simd_mul(fh, splat(-1.9046542e-9)),
+            simd_mul(fl, splat(LN_2 as f32)),
+        ),
+    );
+    let (hi, lo) = fast_two_sum(rh, rl);
+    let p = poly(
+        hi,
+        &[
+            5e-1,
+            1.6666666e-1,
+            4.1665796e-2,
+            8.333452e-3,
+            1.3981999e-3,
+            1.9875691e-4,
+        ],
+    );
+    let e = simd_add(
+        simd_add(simd_add(simd_mul(p, simd_mul(hi, hi)), lo), hi),
+        splat(1.0),
+    );
+    ldexp_f32(e, n)
+}
+
+/// `10^x`, computed as `2^(x * log2(10))` in double-word arithmetic.
+#[inline(always)]
+unsafe fn exp10_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    let (th, tl) = two_prod(x, splat(LOG2_10 as f32));
+    let tl = simd_add(tl, simd_mul(x, splat(7.059537e-8)));
+    exp2_f32(th, tl)
+}
+
+/// `log2(x)`, or `log10(x)` if `log10`.
+#[inline(always)]
+unsafe fn log2_log10_f32<V: Float<Elem = f32, Int = u32>>(x: V, log10: bool) -> V {
+    let (h, l) = log2_f32(x);
+    let r = if log10 {
+        let (ph, pl) = two_prod(h, splat(LOG10_2 as f32));
+        let pl = simd_add(
+            pl,
+            simd_add(
+                simd_mul(h, splat(-1.4320989e-8)),
+                simd_mul(l, splat(LOG10_2 as f32)),
+            ),
+        );
+        simd_add(ph, pl)
+    } else {
+        simd_add(h, l)
+    };
+    log_special(x, r, 0.0, f32::INFINITY, f32::NAN)
+}
+
+/// `cbrt(x)`, or `1 / cbrt(x)` if `inv`.
+#[inline(always)]
+unsafe fn cbrt_f32<V: Float<Elem = f32, Int = u32>>(x: V, inv: bool) -> V {
+    // `|x| = u * 2^(3 * q)` with `u` in `[1, 8)`, after scaling subnormals
+    // into the normal range
+    let a = abs(x);
+    let sub: V::Mask = simd_lt(a, splat(f32::MIN_POSITIVE));
+    let a = select(sub, simd_mul(a, splat(16777216.0)), a);
+    let e = V::from_bits(simd_or(
+        simd_shr(a.to_bits(), V::splat_bits(23)),
+        V::splat_bits(0x4b000000),
+    ));
+    let e = simd_sub(
+        e,
+        select(
+            sub,
+            splat(8388608.0 + 127.0 + 24.0),
+            splat(8388608.0 + 127.0),
+        ),
+    );
+    let q = round(simd_mul(simd_sub(e, splat(1.0)), splat(1.0 / 3.0)));
+    let m = V::from_bits(simd_or(
+        simd_and(a.to_bits(), V::splat_bits(0x007fffff)),
+        V::splat_bits(0x3f800000),
+    ));
+    let k = simd_sub(e, simd_mul(q, splat(3.0)));
+    let u = simd_mul(m, pick(k, &[0.0, 1.0, 2.0], &[1.0, 2.0, 4.0]));
+    // a cubic approximation to within 1.4%, refined with Newton's method
+    let mut y = poly(
+        u,
+        &[7.1673465e-1, 3.2883644e-1, -3.4026053e-2, 1.6274954e-3],
+    );
+    for _ in 0..2 {
+        let d = simd_div(u, simd_mul(y, y));
+        y = simd_mul(simd_add(simd_add(y, y), d), splat(1.0 / 3.0));
+    }
+    // the last step computes the residual in double-word arithmetic
+    let r = if inv {
+        // `z + z * (1 - u * z^3) / 3`
+        let z = simd_div(splat(1.0), y);
+        let (z2, z2l) = two_prod(z, z);
+        let (z3, z3l) = two_prod(z2, z);
+        let (p, pl) = two_prod(u, z3);
+        let pl = simd_add(pl, simd_mul(u, simd_add(z3l, simd_mul(z2l, z))));
+        let d = simd_sub(simd_sub(splat(1.0), p), pl);
+        let z = simd_add(z, simd_mul(simd_mul(z, d), splat(1.0 / 3.0)));
+        ldexp_f32(z, simd_sub(splat(0.0), q))
+    } else {
+        // `y - (y^3 - u) / (3 * y^2)`
+        let (y2, y2l) = two_prod(y, y);
+        let (y3, y3l) = two_prod(y2, y);
+        let d = simd_add(simd_sub(y3, u), simd_add(y3l, simd_mul(y2l, y)));
+        let y = simd_sub(y, simd_div(d, simd_mul(splat(3.0), y2)));
+        ldexp_f32(y, q)
+    };
+    let r = V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    // zeros, infinities and NaN
+    let special = if inv { simd_div(splat(1.0), x) } else { x };
+    let finite: V::Mask = simd_and(
+        simd_ne(x, splat(0.0)),
+        simd_lt(abs(x), splat(f32::INFINITY)),
+    );
+    select(finite, r, special)
+}
+
+/// See `cbrt_f32`.
+#[inline(always)]
+unsafe fn cbrt_f64<V: Float<Elem = f64, Int = u64>>(x: V, inv: bool) -> V {
+    let a = abs(x);
+    let sub: V::Mask = simd_lt(a, splat(f64::MIN_POSITIVE));
+    let a = select(sub, simd_mul(a, splat(18014398509481984.0)), a);
+    let e = V::from_bits(simd_or(
+        simd_shr(a.to_bits(), V::splat_bits(52)),
+        V::splat_bits(0x4330000000000000),
+    ));
+    let e = simd_sub(
+        e,
+        select(
+            sub,
+            splat(4503599627370496.0 + 1023.0 + 54.0),
+            splat(4503599627370496.0 + 1023.0),
+        ),
+    );
+    let q = round(simd_mul(simd_sub(e, splat(1.0)), splat(1.0 / 3.0)));
+    let m = V::from_bits(simd_or(
+        simd_and(a.to_bits(), V::splat_bits(0x000fffffffffffff)),
+        V::splat_bits(0x3ff0000000000000),
+    ));
+    let k = simd_sub(e, simd_mul(q, splat(3.0)));
+    let u = simd_mul(m, pick(k, &[0.0, 1.0, 2.0], &[1.0, 2.0, 4.0]));
+    let mut y = poly(
+        u,
+        &[7.1673465e-1, 3.2883644e-1, -3.4026053e-2, 1.6274954e-3],
+    );
+    for _ in 0..3 {
+        let d = simd_div(u, simd_mul(y, y));
+        y = simd_mul(simd_add(simd_add(y, y), d), splat(1.0 / 3.0));
+    }
+    let r = if inv {
+        let z = simd_div(splat(1.0), y);
+        let (z2, z2l) = two_prod(z, z);
+        let (z3, z3l) = two_prod(z2, z);
+        let (p, pl) = two_prod(u, z3);
+        let pl = simd_add(pl, simd_mul(u, simd_add(z3l, simd_mul(z2l, z))));
+        let d = simd_sub(simd_sub(splat(1.0), p), pl);
+        let z = simd_add(z, simd_mul(simd_mul(z, d), splat(1.0 / 3.0)));
+        ldexp_f64(z, simd_sub(splat(0.0), q))
+    } else {
+        let (y2, y2l) = two_prod(y, y);
+        let (y3, y3l) = two_prod(y2, y);
+        let d = simd_add(simd_sub(y3, u), simd_add(y3l, simd_mul(y2l, y)));
+        let y = simd_sub(y, simd_div(d, simd_mul(splat(3.0), y2)));
+        ldexp_f64(y, q)
+    };
+    let r = V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    let special = if inv { simd_div(splat(1.0), x) } else { x };
+    let finite: V::Mask = simd_and(
+        simd_ne(x, splat(0.0)),
+        simd_lt(abs(x), splat(f64::INFINITY)),
+    );
+    select(finite, r, special)
+}
+
+/// `1 / sqrt(x)`, corrected with a Newton step in double-word arithmetic.
+///
+/// `x` is first multiplied by `scale^2` where it is less than `1 / big`, and
+/// divided by it where it is greater than `big`, so that the products in
+/// that step can be split without overflowing or losing precision.
+#[inline(always)]
+unsafe fn invsqrt<V: Float>(x: V, big: V::Elem, scale: V::Elem) -> V {
+    let one: V = constant(1.0);
+    let (big, scale) = (splat::<V>(big), splat::<V>(scale));
+    let large: V::Mask = simd_gt(x, big);
+    let small: V::Mask = simd_lt(x, simd_div(one, big));
+    let s2 = simd_mul(scale, scale);
+    let xs = select(small, simd_mul(x, s2), x);
+    let xs = select(large, simd_div(xs, s2), xs);
+    // `z + z * (1 - x * z^2) / 2`
+    let z = simd_div(one, simd_fsqrt(xs));
+    let (p, pl) = two_prod(xs, z);
+    let (t, tl) = two_prod(p, z);
+    let d = simd_sub(simd_sub(simd_sub(one, t), tl), simd_mul(pl, z));
+    let z = simd_add(z, simd_mul(simd_mul(z, d), constant(0.5)));
+    let z = select(small, simd_mul(z, scale), z);
+    let z = select(large, simd_div(z, scale), z);
+    // zeros, infinities, negative values and NaN
+    let finite: V::Mask = simd_and(
+        simd_gt(x, constant(0.0)),
+        simd_lt(x, constant(f32::INFINITY)),
+    );
+    select(finite, z, simd_div(one, simd_fsqrt(x)))
+}
+
+/// Rounds to the nearest integer, with ties away from zero.
+#[inline(always)]
+unsafe fn round_half_away<V: Float>(x: V) -> V {
+    let a = abs(x);
+    // rounds to nearest even for `a < INTEGRAL`, where it is exact
+    let r = simd_sub(simd_add(a, splat(V::INTEGRAL)), splat(V::INTEGRAL));
+    let r = select(
+        simd_eq::<_, V::Mask>(simd_sub(a, r), constant(0.5)),
+        simd_add(r, constant(1.0)),
+        r,
+    );
+    // larger values, infinities and NaN are kept as they are
+    let r = select(simd_lt::<_, V::Mask>(a, splat(V::INTEGRAL)), r, a);
+    V::from_bits(simd_or(
+        r.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ))
+}
+
+/// Selects `v[i]` in each lane where `x >= start[i]` for the largest such
+/// `i`, or `v[0]` if there is none.
+#[inline(always)]
+unsafe fn pick<V: Float, const N: usize>(x: V, start: &[V::Elem; N], v: &[V::Elem; N]) -> V {
+    let mut r = splat(v[0]);
+    for i in 1..N {
+        r = select(simd_ge::<_, V::Mask>(x, splat(start[i])), splat(v[i]), r);
+    }
+    r
+}
+
+/// Evaluates a piecewise polynomial, where piece `i` starts at `start[i]` and
+/// has coefficients `c[k][i]` for the powers `k` of `x - mid[i]`.
+#[inline(always)]
+unsafe fn piecewise<V: Float, const N: usize, const D: usize>(
+    x: V,
+    start: &[V::Elem; N],
+    mid: &[V::Elem; N],
+    c: &[[V::Elem; N]; D],
+) -> V {
+    let t = simd_sub(x, pick(x, start, mid));
+    let mut r = pick(x, start, &c[D - 1]);
+    for k in (0..D - 1).rev() {
+        r = simd_add(simd_mul(r, t), pick(x, start, &c[k]));
+    }
+    r
+}
+
+/// The error function of `x`.
+#[inline(always)]
+unsafe fn erf_f64<V: Float<Elem = f64, Int = u64>>(x: V) -> V {
+    let ax = abs(x);
+    // `erf(x) = x + x * P(x^2)` near zero
+    let p = poly(
+        simd_mul(x, x),
+        &[
+            0.12837916709551256,
+            -0.376126389031834,
+            0.11283791670935311,
+            -0.02686617064077985,
+            0.005223977576377844,
+            -0.000854832379116413,
+            0.00012055199941321885,
+            -1.4922121574443055e-05,
+            1.640162967934702e-06,
+            -1.571446067260244e-07,
+            1.0726571432176348e-08,
+        ],
+    );
+    let small = simd_add(x, simd_mul(x, p));
+    // `erf(x) = 1 - e^(-x^2) * erfcx(x)` elsewhere; beyond 6 this rounds to 1
+    let ax6 = clamp(ax, 0.0, 6.0);
+    let g = piecewise(
+        ax6,
+        &[0.0, 1.5, 2.5, 3.5],
+        &[1.171875, 2.0, 3.0, 4.75],
+        &ERFCX_F64,
+    );
+    let (zh, zl) = two_prod(ax6, ax6);
+    let e = exp_f64(simd_sub(splat(0.0), zh));
+    let e = simd_sub(e, simd_mul(e, zl));
+    let large = simd_sub(splat(1.0), simd_mul(e, g));
+    let large = V::from_bits(simd_or(
+        large.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    select(simd_lt::<_, V::Mask>(ax, splat(0.84375)), small, large)
+}
+
+/// The error function of `x`.
+#[inline(always)]
+unsafe fn erf_f32<V: Float<Elem = f32, Int = u32>>(x: V) -> V {
+    let ax = abs(x);
+    let p = poly(
+        simd_mul(x, x),
+        &[
+            0.12837917,
+            -0.37612578,
+            0.11282805,
+            -0.026806252,
+            0.0050574434,
+            -0.00063530606,
+        ],
+    );
+    let small = simd_add(x, simd_mul(x, p));
+    let ax4 = clamp(ax, 0.0, 4.0);
+    let g = piecewise(ax4, &[0.0, 2.0], &[1.421875, 3.0], &ERFCX_F32);
+    let (zh, zl) = two_prod(ax4, ax4);
+    let e = exp_f32(simd_sub(splat(0.0), zh));
+    let e = simd_sub(e, simd_mul(e, zl));
+    let large = simd_sub(splat(1.0), simd_mul(e, g));
+    let large = V::from_bits(simd_or(
+        large.to_bits(),
+        simd_and(x.to_bits(), V::splat_bits(V::SIGN)),
+    ));
+    select(simd_lt::<_, V::Mask>(ax, splat(0.84375)), small, large)
+}
+
+/// Coefficients of `erfcx(x) = e^(x^2) * erfc(x)`, see `piecewise`.
+const ERFCX_F64: [[f64; 4]; 15] = [
+    [
+        0.38481374714100164,
+        0.25539567631050575,
+        0.17900115118138996,
+        0.11630270721024731,
+    ],
+    [
+        -0.22647194723379005,
+        -0.10679646185348994,
+        -0.05437226000717288,
+        -0.023503448598184106,
+    ],
+    [
+        0.11941693397640396,
+        0.04180275260352663,
+        0.01588437115987134,
+        0.004661326368975751,
+    ],
+    [
+        -0.05768681848678368,
+        -0.015460637764239173,
+        -0.004479431018371238,
+        -0.0009080988965295504,
+    ],
+    [
+        0.025907596781092827,
+        0.005440738537459517,
+        0.0012230390523765252,
+        0.00017392830395924388,
+    ],
+    [
+        -0.010930541404656106,
+        -0.0018316642779754838,
+        -0.00032412554455455204,
+        -3.277578458228431e-05,
+    ],
+    [
+        0.004366122858230536,
+        0.0005924699958821647,
+        8.355413964084734e-05,
+        6.081115111613391e-06,
+    ],
+    [
+        -0.0016611402893969554,
+        -0.00018477832463095726,
+        -2.098946336275049e-05,
+        -1.1115572633944996e-06,
+    ],
+    [
+        0.0006048684929037317,
+        5.5728304783301664e-05,
+        5.146436331686994e-06,
+        2.002902676243289e-07,
+    ],
+    [
+        -0.00021162553552914374,
+        -1.629413391296476e-05,
+        -1.2333784348997244e-06,
+        -3.561197334926809e-08,
+    ],
+    [
+        7.13745246700007e-05,
+        4.62827740445235e-06,
+        2.892689235575255e-07,
+        6.245076194116008e-09,
+    ],
+    [
+        -2.3256523265588112e-05,
+        -1.2773616807125507e-06,
+        -6.641100228469703e-08,
+        -1.067362355598649e-09,
+    ],
+    [
+        7.347155988784464e-06,
+        3.4432366346395997e-07,
+        1.4965959482387232e-08,
+        1.8262093529973395e-10,
+    ],
+    [
+        -2.3351255759154076e-06,
+        -9.643712186456406e-08,
+        -3.4606465580299747e-09,
+        -3.67690156375927e-11,
+    ],
+    [
+        6.954654369897129e-07,
+        2.4742339076650453e-08,
+        7.508862615589422e-10,
+        6.1178444674710355e-12,
+    ],
+];
+
+/// Coefficients of `erfcx(x) = e^(x^2) * erfc(x)`, see `piecewise`.
+const ERFCX_F32: [[f32; 2]; 9] = [
+    [0.33484948, 0.17900115],
+    [-0.17615096, -0.05437221],
+    [0.08438473, 0.01588436],
+    [-0.037446048, -0.004480082],
+    [0.015572377, 0.001223191],
+    [-0.006102502, -0.0003218066],
+    [0.0022834176, 8.3012594e-05],
+    [-0.0008924857, -2.4003793e-05],
+    [0.00030474633, 5.8510736e-6],
+];
+
+/// The bits of `2 / pi`, the most significant being worth `2^-1`.
+const TWO_OVER_PI: [u64; 20] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+];
+
+/// Reduces `|x| >= 2^13` to `x - n * pi / 2` in `[-pi / 4, pi / 4]`,
+/// returning `n mod 4` and the result as `hi + lo`.
+///
+/// This multiplies the significand of `x` by the 192 bits of `2 / pi` that
+/// can affect `x * 2 / pi mod 4` (Payne-Hanek reduction). It is only used
+/// for the rare lanes that are too large for the vector code.
+fn rem_pio2_large(x: f64) -> (u64, f64, f64) {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    if e == 1024 {
+        return (0, f64::NAN, f64::NAN);
+    }
+    let m = (bits & 0x000fffffffffffff) | 0x0010000000000000;
+    // bit `k` of `2 / pi` adds `m * 2^(e - 52 - k)`, a multiple of 4 for `k < e - 53`
+    let k = if e >= 54 { e - 54 } else { 0 } as usize;
+    let window = |i: usize| {
+        let (w, s) = ((k + 64 * i) / 64, (k + 64 * i) % 64);
+        if s == 0 {
+            TWO_OVER_PI[w]
+        } else {
+            TWO_OVER_PI[w] << s | TWO_OVER_PI[w + 1] >> (64 - s)
+        }
+    };
+    // `p = m * window` as a 256-bit number in `(hi, lo)`, with the binary
+    // point of `x * 2 / pi` at bit `point`
+    let a = m as u128 * window(2) as u128;
+    let b = m as u128 * window(1) as u128;
+    let c = m as u128 * window(0) as u128;
+    let t = (a >> 64) + (b as u64 as u128);
+    let lo = (t << 64) | (a as u64 as u128);
+    let hi = (t >> 64) + (b >> 64) + c;
+    let point = k as i32 + 192 - (e - 52);
+    let shr = |n: i32| {
+        if n >= 128 {
+            hi >> (n - 128)
+        } else {
+            (lo >> n) | (hi << (128 - n))
+        }
+    };
+    // the fraction as a signed 128-bit fixed point number in `[-1/2, 1/2)`
+    let f = shr(point - 128) as i128;
+    let n = (shr(point) as u64).wrapping_add((f < 0) as u64);
+    let fh = f as f64;
+    let fl = f.wrapping_sub(fh as i128) as f64;
+    // times `pi / 2 * 2^-128`
+    const PIO2_HI: f64 = FRAC_PI_2 * 2.938735877055719e-39;
+    const PIO2_LO: f64 = 6.123233995736766e-17 * 2.938735877055719e-39;
+    let split = |a: f64| {
+        let t = a * 134217729.0;
+        let h = t - (t - a);
+        (h, a - h)
+    };
+    let hi = fh * PIO2_HI;
+    let ((ah, al), (bh, bl)) = (split(fh), split(PIO2_HI));
+    let lo = ((ah * bh - hi) + ah * bl + al * bh) + al * bl;
+    let lo = lo + (fh * PIO2_LO + fl * PIO2_HI);
+    let (r, lo) = (hi + lo, lo - ((hi + lo) - hi));
+    if x < 0.0 {
+        (n.wrapping_neg() & 3, -r, -lo)
+    } else {
+        (n & 3, r, lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute_copy};
+    use std::{f64::consts::*, fmt::Debug, mem::size_of};
+    use stdarch_test::simd_test;
+
+    // Each function is checked against a table of correctly rounded results,
+    // and for the documented maximum error on seeded random inputs against a
+    // reference computed in double precision for the single-precision
+    // functions and in double-double precision for the others.
+
+    /// The distance between `r` and `e` in units in the last place. A NaN
+    /// only matches a NaN, and zeros must have the expected sign.
+    fn ulp_ps(r: f32, e: f32) -> u64 {
+        if e.is_nan() || e == 0.0 {
+            let eq = r.to_bits() == e.to_bits() || r.is_nan() && e.is_nan();
+            return if eq { 0 } else { u64::MAX };
+        }
+        let ord = |x: f32| {
+            let b = x.to_bits() as i32;
+            if b < 0 {
+                -((b & i32::MAX) as i64)
+            } else {
+                b as i64
+            }
+        };
+        (ord(r) - ord(e)).unsigned_abs()
+    }
+
+    fn ulp_pd(r: f64, e: f64) -> u64 {
+        if e.is_nan() || e == 0.0 {
+            let eq = r.to_bits() == e.to_bits() || r.is_nan() && e.is_nan();
+            return if eq { 0 } else { u64::MAX };
+        }
+        let ord = |x: f64| {
+            let b = x.to_bits() as i64;
+            if b < 0 {
+                -((b & i64::MAX) as i128)
+            } else {
+                b as i128
+            }
+        };
+        (ord(r) - ord(e)).unsigned_abs() as u64
+    }
+
+    /// Loads `N` lanes from `x` into a vector.
+    unsafe fn load<V, T: Copy + Default, const N: usize>(x: impl Iterator<Item = T>) -> V {
+        let mut a = [T::default(); N];
+        for (a, x) in a.iter_mut().zip(x) {
+            *a = x;
+        }
+        transmute_copy(&a)
+    }
+
+    /// Checks `f` against the correctly rounded results in `table`, `N`
+    /// lanes at a time.
+    unsafe fn check_ps<V, const N: usize>(f: unsafe fn(V) -> V, table: &[(f32, f32)], ulp: u64) {
+        for t in table.chunks(N) {
+            let r: [f32; N] = transmute_copy(&f(load::<V, f32, N>(t.iter().map(|t| t.0))));
+            for (&(x, e), &r) in t.iter().zip(r.iter()) {
+                assert!(
+                    ulp_ps(r, e) <= ulp,
+                    "f({:e}) = {:e}, expected {:e}",
+                    x,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    unsafe fn check_pd<V, const N: usize>(f: unsafe fn(V) -> V, table: &[(f64, f64)], ulp: u64) {
+        for t in table.chunks(N) {
+            let r: [f64; N] = transmute_copy(&f(load::<V, f64, N>(t.iter().map(|t| t.0))));
+            for (&(x, e), &r) in t.iter().zip(r.iter()) {
+                assert!(
+                    ulp_pd(r, e) <= ulp,
+                    "f({:e}) = {:e}, expected {:e}",
+                    x,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    unsafe fn check2_ps<V, const N: usize>(
+        f: unsafe fn(V, V) -> V,
+        table: &[(f32, f32, f32)],
+        ulp: u64,
+    ) {
+        for t in table.chunks(N) {
+            let a = load::<V, f32, N>(t.iter().map(|t| t.0));
+            let b = load::<V, f32, N>(t.iter().map(|t| t.1));
+            let r: [f32; N] = transmute_copy(&f(a, b));
+            for (&(x, y, e), &r) in t.iter().zip(r.iter()) {
+                assert!(
+                    ulp_ps(r, e) <= ulp,
+                    "f({:e}, {:e}) = {:e}, expected {:e}",
+                    x,
+                    y,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    unsafe fn check2_pd<V, const N: usize>(
+        f: unsafe fn(V, V) -> V,
+        table: &[(f64, f64, f64)],
+        ulp: u64,
+    ) {
+        for t in table.chunks(N) {
+            let a = load::<V, f64, N>(t.iter().map(|t| t.0));
+            let b = load::<V, f64, N>(t.iter().map(|t| t.1));
+            let r: [f64; N] = transmute_copy(&f(a, b));
+            for (&(x, y, e), &r) in t.iter().zip(r.iter()) {
+                assert!(
+                    ulp_pd(r, e) <= ulp,
+                    "f({:e}, {:e}) = {:e}, expected {:e}",
+                    x,
+                    y,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    /// The writemask of the `_mask_` tests.
+    const MASK: u16 = 0b0110_1100_1011_0001;
+
+    /// Checks that `r` has the lanes of `e` where `k` is set, and the lanes
+    /// of `src` elsewhere.
+    unsafe fn check_mask<V, T: Copy + PartialEq + Debug, const N: usize>(
+        r: V,
+        e: V,
+        src: V,
+        k: u16,
+    ) {
+        let r: [T; N] = transmute_copy(&r);
+        let e: [T; N] = transmute_copy(&e);
+        let src: [T; N] = transmute_copy(&src);
+        for i in 0..N {
+            let e = if (k >> i) & 1 != 0 { e[i] } else { src[i] };
+            assert_eq!(r[i], e, "lane {}", i);
+        }
+    }
+
+    /// Checks the `_mask_` form `f` of `g` with `MASK`.
+    unsafe fn check_mask_ps(
+        f: unsafe fn(__m512, __mmask16, __m512) -> __m512,
+        g: unsafe fn(__m512) -> __m512,
+    ) {
+        let src = load::<_, f32, 16>((0..16).map(|i| -(i as f32)));
+        let a = load::<_, f32, 16>((0..16).map(|i| 0.5 + i as f32 * 0.25));
+        check_mask::<_, u32, 16>(f(src, MASK, a), g(a), src, MASK);
+    }
+
+    unsafe fn check_mask_pd(
+        f: unsafe fn(__m512d, __mmask8, __m512d) -> __m512d,
+        g: unsafe fn(__m512d) -> __m512d,
+    ) {
+        let src = load::<_, f64, 8>((0..8).map(|i| -(i as f64)));
+        let a = load::<_, f64, 8>((0..8).map(|i| 0.5 + i as f64 * 0.25));
+        check_mask::<_, u64, 8>(f(src, MASK as u8, a), g(a), src, MASK & 0xff);
+    }
+
+    unsafe fn check_mask2_ps(
+        f: unsafe fn(__m512, __mmask16, __m512, __m512) -> __m512,
+        g: unsafe fn(__m512, __m512) -> __m512,
+    ) {
+        let src = load::<_, f32, 16>((0..16).map(|i| -(i as f32)));
+        let a = load::<_, f32, 16>((0..16).map(|i| 0.5 + i as f32 * 0.25));
+        let b = load::<_, f32, 16>((0..16).map(|i| 2.0 - i as f32 * 0.125));
+        check_mask::<_, u32, 16>(f(src, MASK, a, b), g(a, b), src, MASK);
+    }
+
+    unsafe fn check_mask2_pd(
+        f: unsafe fn(__m512d, __mmask8, __m512d, __m512d) -> __m512d,
+        g: unsafe fn(__m512d, __m512d) -> __m512d,
+    ) {
+        let src = load::<_, f64, 8>((0..8).map(|i| -(i as f64)));
+        let a = load::<_, f64, 8>((0..8).map(|i| 0.5 + i as f64 * 0.25));
+        let b = load::<_, f64, 8>((0..8).map(|i| 2.0 - i as f64 * 0.125));
+        check_mask::<_, u64, 8>(f(src, MASK as u8, a, b), g(a, b), src, MASK & 0xff);
+    }
+
+    unsafe fn check_mask_epi32(
+        f: unsafe fn(__m512i, __mmask16, __m512i, __m512i) -> __m512i,
+        g: unsafe fn(__m512i, __m512i) -> __m512i,
+    ) {
+        let src = load::<_, i32, 16>((0..16).map(|i| -i));
+        let a = load::<_, i32, 16>((0..16).map(|i| i * 1234 - 9000));
+        // never zero or -1
+        let b = load::<_, i32, 16>((0..16).map(|i| (i % 5 + 2) * (1 - i % 2 * 2)));
+        check_mask::<_, u32, 16>(f(src, MASK, a, b), g(a, b), src, MASK);
+    }
+
+    /// The number of random inputs each function is tested with.
+    const RANDOM: usize = 1 << 14;
+
+    /// A xorshift generator, so that every run tests the same inputs.
+    struct Rng(u64);
+
+    impl Rng {
+        fn new() -> Self {
+            Rng(0x2545_f491_4f6c_dd1d)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// A value in `[lo, hi)`.
+        fn uniform(&mut self, lo: f64, hi: f64) -> f64 {
+            lo + (hi - lo) * ((self.next() >> 11) as f64 * exp2i(-53))
+        }
+
+        /// A value of random sign whose magnitude is in `[lo, hi)`, with a
+        /// uniformly distributed logarithm.
+        fn magnitude(&mut self, lo: f64, hi: f64) -> f64 {
+            let x = self.uniform(lo.log2(), hi.log2()).exp2();
+            if self.next() & 1 == 0 {
+                x
+            } else {
+                -x
+            }
+        }
+
+        /// A finite `f32` with random bits, so that every exponent, and
+        /// subnormals, are equally likely.
+        fn finite_ps(&mut self) -> f32 {
+            loop {
+                let x = f32::from_bits(self.next() as u32);
+                if x.is_finite() {
+                    return x;
+                }
+            }
+        }
+
+        fn finite_pd(&mut self) -> f64 {
+            loop {
+                let x = f64::from_bits(self.next());
+                if x.is_finite() {
+                    return x;
+                }
+            }
+        }
+
+        /// `x` and `y` such that `x^y` is in `[2^lo, 2^hi)`, with `x` any
+        /// positive finite value.
+        fn pow(&mut self, finite: impl Fn(&mut Self) -> f64, lo: f64, hi: f64) -> (f64, f64) {
+            loop {
+                let x = finite(self).abs();
+                if x != 0.0 && x != 1.0 {
+                    return (x, self.uniform(lo, hi) / x.log2());
+                }
+            }
+        }
+    }
+
+    /// `2^n`, for `n` in the range of finite `f64`.
+    fn exp2i(n: i32) -> f64 {
+        if n >= -1022 {
+            f64::from_bits(((n + 1023) as u64) << 52)
+        } else {
+            f64::from_bits(1 << (n + 1074))
+        }
+    }
+
+    /// A double-double number, the unevaluated sum of two `f64` with
+    /// `|lo| <= ulp(hi) / 2`, which has about 106 bits of precision. The
+    /// references of the random tests are computed with these.
+    #[derive(Copy, Clone)]
+    struct Dd(f64, f64);
+
+    impl Dd {
+        fn new(x: f64) -> Self {
+            Dd(x, 0.0)
+        }
+
+        /// `a + b`, exactly.
+        fn sum(a: f64, b: f64) -> Self {
+            let s = a + b;
+            let bb = s - a;
+            Dd(s, (a - (s - bb)) + (b - bb))
+        }
+
+        /// `a * b`, exactly.
+        fn prod(a: f64, b: f64) -> Self {
+            let p = a * b;
+            Dd(p, a.mul_add(b, -p))
+        }
+
+        fn neg(self) -> Self {
+            Dd(-self.0, -self.1)
+        }
+
+        fn add(self, b: Self) -> Self {
+            let s = Dd::sum(self.0, b.0);
+            let t = Dd::sum(self.1, b.1);
+            let s = Dd::sum(s.0, s.1 + t.0);
+            Dd::sum(s.0, s.1 + t.1)
+        }
+
+        fn mul(self, b: Self) -> Self {
+            let p = Dd::prod(self.0, b.0);
+            Dd::sum(p.0, p.1 + (self.0 * b.1 + self.1 * b.0))
+        }
+
+        fn div(self, b: Self) -> Self {
+            let q1 = self.0 / b.0;
+            let r = self.add(b.mul(Dd::new(-q1)));
+            let q2 = r.0 / b.0;
+            let r = r.add(b.mul(Dd::new(-q2)));
+            Dd::sum(q1, q2).add(Dd::new(r.0 / b.0))
+        }
+
+        /// `self * 2^n`, for a result in the normal range.
+        fn ldexp(self, n: i32) -> Self {
+            let (a, b) = (exp2i(n / 2), exp2i(n - n / 2));
+            Dd(self.0 * a * b, self.1 * a * b)
+        }
+
+        /// `self - k * (c[0] + c[1] + ...)`, where `k * c[0]` is about
+        /// `self`, without losing precision to cancellation.
+        fn reduce(self, k: f64, c: &[f64]) -> Self {
+            c.iter().fold(self, |r, &c| r.add(Dd::prod(-k, c)))
+        }
+    }
+
+    /// `pi / 2` and `ln(2)` as sums of `f64`.
+    const PI_2_DD: [f64; 4] = [
+        FRAC_PI_2,
+        6.123233995736766e-17,
+        -1.4973849048591698e-33,
+        5.562271104316826e-50,
+    ];
+    const LN_2_DD: [f64; 3] = [LN_2, 2.3190468138462996e-17, 5.707708438416212e-34];
+
+    /// `e^x`, for a result in the normal range.
+    fn exp_dd(x: Dd) -> Dd {
+        let k = (x.0 / LN_2).round();
+        let r = x.reduce(k, &LN_2_DD);
+        // `|r| <= ln(2) / 2`, so that the terms after these are negligible
+        let mut term = Dd::new(1.0);
+        let mut sum = term;
+        for n in 1..30 {
+            term = term.mul(r).div(Dd::new(n as f64));
+            sum = sum.add(term);
+        }
+        sum.ldexp(k as i32)
+    }
+
+    /// `ln(x)`, for positive finite `x`.
+    fn log_dd(x: f64) -> Dd {
+        // `x = m * 2^k` with `m` in `[sqrt(2) / 2, sqrt(2))`
+        let (x, k) = if x < f64::MIN_POSITIVE {
+            (x * exp2i(54), -54)
+        } else {
+            (x, 0)
+        };
+        let mut k = k + (x.to_bits() >> 52) as i32 - 1023;
+        let mut m = f64::from_bits(x.to_bits() & !(0x7ff << 52) | 1023 << 52);
+        if m > SQRT_2 {
+            m /= 2.0;
+            k += 1;
+        }
+        // `ln(m) = 2 * atanh(s)`, with `|s| < 0.18`
+        let s = Dd::new(m - 1.0).div(Dd::sum(m, 1.0));
+        let s2 = s.mul(s);
+        let mut power = s;
+        let mut sum = Dd::new(0.0);
+        for n in 0..40 {
+            sum = sum.add(power.div(Dd::new((2 * n + 1) as f64)));
+            power = power.mul(s2);
+        }
+        sum.add(sum).reduce(-k as f64, &LN_2_DD)
+    }
+
+    /// `sin(x)`, or `cos(x)` if `cos`, for `|x| < 2^50`.
+    fn sincos_dd(x: f64, cos: bool) -> Dd {
+        let k = (x * FRAC_2_PI).round();
+        let r = Dd::new(x).reduce(k, &PI_2_DD);
+        let r2 = r.mul(r);
+        // `sin(r + q * pi / 2)`, with the Taylor series of `sin(r)` for even
+        // `q` and `cos(r)` for odd `q`, and `|r| < 1`
+        let q = k as i64 + cos as i64;
+        let (mut n, mut term) = if q & 1 == 0 {
+            (1, r)
+        } else {
+            (0, Dd::new(1.0))
+        };
+        let mut sum = term;
+        for _ in 0..30 {
+            term = term.mul(r2).div(Dd::new(-((n + 1) * (n + 2)) as f64));
+            sum = sum.add(term);
+            n += 2;
+        }
+        if q & 2 == 0 {
+            sum
+        } else {
+            sum.neg()
+        }
+    }
+
+    /// `erf(x)`, for `|x| <= 6`.
+    fn erf_dd(x: f64) -> Dd {
+        // `erf(x) = 2 / sqrt(pi) * e^(-x^2) * sum(2^n * x^(2n + 1) / (2n + 1)!!)`,
+        // whose terms all have the same sign
+        let x2 = Dd::prod(x, x);
+        let mut term = Dd::new(x);
+        let mut sum = term;
+        for n in 1.. {
+            term = term.mul(x2).div(Dd::new(n as f64 + 0.5));
+            sum = sum.add(term);
+            if term.0.abs() < sum.0.abs() * 1e-34 {
+                break;
+            }
+        }
+        let frac_2_sqrt_pi = Dd(FRAC_2_SQRT_PI, 1.533545961316588e-17);
+        sum.mul(frac_2_sqrt_pi).mul(exp_dd(x2.neg()))
+    }
+
+    /// `x^y`, for positive `x` and a result in the normal range.
+    fn pow_dd(x: f64, y: f64) -> Dd {
+        let l = log_dd(x);
+        exp_dd(Dd::prod(y, l.0).add(Dd::new(y * l.1)))
+    }
+
+    /// `atan(x)`.
+    fn atan_dd(x: Dd) -> Dd {
+        // `atan(x) = y + atan((x - tan(y)) / (1 + x * tan(y)))` for `y` close
+        // to `atan(x)`, where the second arctangent is its argument to about
+        // twice double precision
+        let y = x.0.atan();
+        let (s, c) = (sincos_dd(y, false), sincos_dd(y, true));
+        Dd::new(y).add(x.mul(c).add(s.neg()).div(c.add(x.mul(s))))
+    }
+
+    /// `atan2(y, x)`, for non-zero finite `x` and `y`.
+    fn atan2_dd(y: f64, x: f64) -> Dd {
+        let r = atan_dd(Dd::new(y.abs()).div(Dd::new(x.abs())));
+        let r = if x < 0.0 {
+            Dd(PI, 1.2246467991473532e-16).add(r.neg())
+        } else {
+            r
+        };
+        if y < 0.0 {
+            r.neg()
+        } else {
+            r
+        }
+    }
+
+    /// `sqrt(x)`, for positive finite `x`.
+    fn sqrt_dd(x: f64) -> Dd {
+        if x < exp2i(-900) {
+            return sqrt_dd(x * exp2i(1000)).ldexp(-500);
+        }
+        // a Newton step from `s = sqrt(x)`: `s + (x - s^2) / (2 * s)`
+        let s = x.sqrt();
+        Dd::new(s).add(Dd::new(x).add(Dd::prod(-s, s)).div(Dd::new(s + s)))
+    }
+
+    /// `cbrt(x)`, for non-zero finite `x`.
+    fn cbrt_dd(x: f64) -> Dd {
+        if x.abs() < exp2i(-900) {
+            return cbrt_dd(x * exp2i(600) * exp2i(600)).ldexp(-400);
+        }
+        // a Newton step from `y = cbrt(x)`: `y - (y^3 - x) / (3 * y^2)`
+        let y = x.cbrt();
+        let y2 = Dd::prod(y, y);
+        let r = y2.mul(Dd::new(y)).add(Dd::new(-x));
+        Dd::new(y).add(r.div(y2.mul(Dd::new(3.0))).neg())
+    }
+
+    /// The error of `r` in units in the last place of the exact result `e`.
+    fn error_ps(r: f32, e: f64) -> f64 {
+        let exp = ((e.abs().to_bits() >> 52) as i32 - 1023).max(-126);
+        (r as f64 - e).abs() / exp2i(exp - 23)
+    }
+
+    fn error_pd(r: f64, e: Dd) -> f64 {
+        let exp = ((e.0.abs().to_bits() >> 52) as i32 - 1023).max(-1022);
+        Dd::new(r).add(e.neg()).0.abs() / exp2i(exp - 52)
+    }
+
+    /// Checks that the error of `f` is at most `ulp` on `RANDOM` inputs
+    /// from `input`, `N` lanes at a time. `e` computes the exact result to
+    /// double precision.
+    unsafe fn random_ps<V, const N: usize>(
+        f: unsafe fn(V) -> V,
+        input: impl Fn(&mut Rng) -> f32,
+        e: impl Fn(f64) -> f64,
+        ulp: f64,
+    ) {
+        let mut rng = Rng::new();
+        for _ in 0..RANDOM / N {
+            let mut x = [0.0; N];
+            for x in x.iter_mut() {
+                *x = input(&mut rng);
+            }
+            let r: [f32; N] = transmute_copy(&f(load::<V, f32, N>(x.iter().copied())));
+            for (&x, &r) in x.iter().zip(r.iter()) {
+                let e = e(x.into());
+                assert!(
+                    error_ps(r, e) <= ulp,
+                    "f({:e}) = {:e}, expected {:e}",
+                    x,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Like `random_ps`, with `e` computing the exact result to about twice
+    /// double precision.
+    unsafe fn random_pd<V, const N: usize>(
+        f: unsafe fn(V) -> V,
+        input: impl Fn(&mut Rng) -> f64,
+        e: impl Fn(f64) -> Dd,
+        ulp: f64,
+    ) {
+        let mut rng = Rng::new();
+        for _ in 0..RANDOM / N {
+            let mut x = [0.0; N];
+            for x in x.iter_mut() {
+                *x = input(&mut rng);
+            }
+            let r: [f64; N] = transmute_copy(&f(load::<V, f64, N>(x.iter().copied())));
+            for (&x, &r) in x.iter().zip(r.iter()) {
+                let e = e(x);
+                assert!(
+                    error_pd(r, e) <= ulp,
+                    "f({:e}) = {:e}, expected {:e}",
+                    x,
+                    r,
+                    e.0
+                );
+            }
+        }
+    }
+
+    /// Like `random_ps`, for functions of two arguments.
+    unsafe fn random2_ps<V, const N: usize>(
+        f: unsafe fn(V, V) -> V,
+        input: impl Fn(&mut Rng) -> (f32, f32),
+        e: impl Fn(f64, f64) -> f64,
+        ulp: f64,
+    ) {
+        let mut rng = Rng::new();
+        for _ in 0..RANDOM / N {
+            let mut xy = [(0.0, 0.0); N];
+            for xy in xy.iter_mut() {
+                *xy = input(&mut rng);
+            }
+            let a = load::<V, f32, N>(xy.iter().map(|xy| xy.0));
+            let b = load::<V, f32, N>(xy.iter().map(|xy| xy.1));
+            let r: [f32; N] = transmute_copy(&f(a, b));
+            for (&(x, y), &r) in xy.iter().zip(r.iter()) {
+                let e = e(x.into(), y.into());
+                assert!(
+                    error_ps(r, e) <= ulp,
+                    "f({:e}, {:e}) = {:e}, expected {:e}",
+                    x,
+                    y,
+                    r,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Like `random_pd`, for functions of two arguments.
+    unsafe fn random2_pd<V, const N: usize>(
+        f: unsafe fn(V, V) -> V,
+        input: impl Fn(&mut Rng) -> (f64, f64),
+        e: impl Fn(f64, f64) -> Dd,
+        ulp: f64,
+    ) {
+        let mut rng = Rng::new();
+        for _ in 0..RANDOM / N {
+            let mut xy = [(0.0, 0.0); N];
+            for xy in xy.iter_mut() {
+                *xy = input(&mut rng);
+            }
+            let a = load::<V, f64, N>(xy.iter().map(|xy| xy.0));
+            let b = load::<V, f64, N>(xy.iter().map(|xy| xy.1));
+            let r: [f64; N] = transmute_copy(&f(a, b));
+            for (&(x, y), &r) in xy.iter().zip(r.iter()) {
+                let e = e(x, y);
+                assert!(
+                    error_pd(r, e) <= ulp,
+                    "f({:e}, {:e}) = {:e}, expected {:e}",
+                    x,
+                    y,
+                    r,
+                    e.0
+                );
+            }
+        }
+    }
+
+    /// Checks the integer division or remainder `f` against `e` on `RANDOM`
+    /// random inputs, `N` lanes of `T` at a time. The divisors have a random
+    /// number of significant bytes, and are never zero or -1.
+    unsafe fn check_div<V, T: Copy + PartialEq + Debug, const N: usize>(
+        f: unsafe fn(V, V) -> V,
+        e: impl Fn(T, T) -> Option<T>,
+    ) {
+        let size = size_of::<T>();
+        let mut rng = Rng::new();
+        for _ in 0..RANDOM / N {
+            let mut a = [0u8; 64];
+            let mut b = [0u8; 64];
+            for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+                *a = rng.next() as u8;
+                *b = rng.next() as u8;
+            }
+            for b in b.chunks_mut(size) {
+                let n = rng.next() as usize % size;
+                for b in b[size - n..].iter_mut() {
+                    *b = 0;
+                }
+                // bit 1 is set in -1
+                b[0] = (b[0] & !2) | 1;
+            }
+            let a: [T; N] = transmute_copy(&a);
+            let b: [T; N] = transmute_copy(&b);
+            let r: [T; N] = transmute_copy(&f(transmute_copy(&a), transmute_copy(&b)));
+            for ((&a, &b), &r) in a.iter().zip(b.iter()).zip(r.iter()) {
+                assert_eq!(Some(r), e(a, b), "f({:?}, {:?})", a, b);
+            }
+        }
+    }
+
+    // The random inputs. Their results are finite, and normal for the
+    // double precision functions, whose references lose precision otherwise.
+    fn sincos_ps(rng: &mut Rng) -> f32 {
+        rng.finite_ps()
+    }
+    fn sincos_pd(rng: &mut Rng) -> f64 {
+        rng.magnitude(exp2i(-30), exp2i(50))
+    }
+    fn exp_ps(rng: &mut Rng) -> f32 {
+        rng.uniform(-103.9, 88.7) as f32
+    }
+    fn exp_pd(rng: &mut Rng) -> f64 {
+        rng.uniform(-700.0, 709.7)
+    }
+    fn log_ps(rng: &mut Rng) -> f32 {
+        rng.finite_ps().abs()
+    }
+    fn log_pd(rng: &mut Rng) -> f64 {
+        rng.finite_pd().abs()
+    }
+    fn pow_ps(rng: &mut Rng) -> (f32, f32) {
+        let (x, y) = rng.pow(|rng| rng.finite_ps().into(), -149.0, 127.9);
+        (x as f32, y as f32)
+    }
+    fn pow_pd(rng: &mut Rng) -> (f64, f64) {
+        rng.pow(Rng::finite_pd, -1000.0, 1023.9)
+    }
+    fn erf_ps(rng: &mut Rng) -> f32 {
+        rng.magnitude(exp2i(-40), 6.0) as f32
+    }
+    fn erf_pd(rng: &mut Rng) -> f64 {
+        rng.magnitude(exp2i(-40), 6.0)
+    }
+    fn atan_ps(rng: &mut Rng) -> f32 {
+        rng.finite_ps()
+    }
+    fn atan_pd(rng: &mut Rng) -> f64 {
+        rng.magnitude(exp2i(-1000), exp2i(1000))
+    }
+    fn atan2_ps(rng: &mut Rng) -> (f32, f32) {
+        (rng.finite_ps(), rng.finite_ps())
+    }
+    fn atan2_pd(rng: &mut Rng) -> (f64, f64) {
+        let y = rng.magnitude(exp2i(-500), exp2i(500));
+        (y, rng.magnitude(exp2i(-500), exp2i(500)))
+    }
+    fn exp2_ps(rng: &mut Rng) -> f32 {
+        rng.uniform(-149.0, 127.9) as f32
+    }
+    fn exp2_pd(rng: &mut Rng) -> f64 {
+        rng.uniform(-1000.0, 1023.9)
+    }
+    fn exp10_ps(rng: &mut Rng) -> f32 {
+        rng.uniform(-44.8, 38.5) as f32
+    }
+    fn exp10_pd(rng: &mut Rng) -> f64 {
+        rng.uniform(-300.0, 308.2)
+    }
+    fn cbrt_ps(rng: &mut Rng) -> f32 {
+        rng.finite_ps()
+    }
+    fn cbrt_pd(rng: &mut Rng) -> f64 {
+        rng.finite_pd()
+    }
+
+    // Inputs and their correctly rounded results, computed with arbitrary
+    // precision arithmetic.
+    const EXP_PS: [(f32, f32); 16] = [
+        (0.0, 1.0),
+        (1.0, E as f32),
+        (-1.0, 0.36787945),
+        (0.5, 1.6487212),
+        (10.0, 22026.465),
+        (-10.0, 4.539993e-5),
+        (88.5, 2.723088e38),
+        (89.0, f32::INFINITY),
+        (-87.5, 9.982351e-39),
+        (-103.0, 1.0e-45),
+        (-110.0, 0.0),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, 0.0),
+        (f32::NAN, f32::NAN),
+        (3.3e-8, 1.0),
+        (42.125, 1.9708567e18),
+    ];
+    const EXP_PD: [(f64, f64); 16] = [
+        (0.0, 1.0),
+        (1.0, E),
+        (-1.0, 0.36787944117144233),
+        (0.5, 1.6487212707001282),
+        (10.0, 22026.465794806718),
+        (-10.0, 4.5399929762484854e-5),
+        (709.5, 1.3549863193146328e308),
+        (710.0, f64::INFINITY),
+        (-708.5, 2.006132305331306e-308),
+        (-744.0, 1.0e-323),
+        (-750.0, 0.0),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, 0.0),
+        (f64::NAN, f64::NAN),
+        (1.0e-17, 1.0),
+        (123.456, 4.132944352778106e53),
+    ];
+    const LOG_PS: [(f32, f32); 16] = [
+        (1.0, 0.0),
+        (2.0, LN_2 as f32),
+        (0.5, -LN_2 as f32),
+        (10.0, LN_10 as f32),
+        (0.0, f32::NEG_INFINITY),
+        (-0.0, f32::NEG_INFINITY),
+        (-1.0, f32::NAN),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NAN, f32::NAN),
+        (1.0e-45, -103.27893),
+        (1.0e-40, -92.10341),
+        (3.4e38, 88.72201),
+        (0.99999994, -5.9604645e-8),
+        (1.0000001, 1.1920928e-7),
+        (7.25, 1.9810015),
+        (1.2e-5, -11.330604),
+    ];
+    const LOG_PD: [(f64, f64); 16] = [
+        (1.0, 0.0),
+        (2.0, LN_2),
+        (0.5, -LN_2),
+        (10.0, LN_10),
+        (0.0, f64::NEG_INFINITY),
+        (-0.0, f64::NEG_INFINITY),
+        (-1.0, f64::NAN),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NAN, f64::NAN),
+        (5.0e-324, -744.4400719213812),
+        (1.0e-310, -713.8013788281542),
+        (1.7e308, 709.7268368932282),
+        (0.9999999999999999, -1.1102230246251565e-16),
+        (1.0000000000000002, 2.2204460492503128e-16),
+        (7.25, 1.9810014688665833),
+        (1.2e-5, -11.330603908176274),
+    ];
+    const SIN_PS: [(f32, f32); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 0.84147096),
+        (-2.5, -0.5984721),
+        (PI as f32, -8.742278e-8),
+        (FRAC_PI_2 as f32, 1.0),
+        (100.0, -0.50636566),
+        (-1000.25, -0.9403087),
+        (8191.0, -0.7630068),
+        (10000.0, -0.30561438),
+        (1.0e20, 0.6565767),
+        (-3.0e38, -0.8749049),
+        (f32::INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+        (1.0e-30, 1.0e-30),
+        (FRAC_PI_4 as f32, FRAC_1_SQRT_2 as f32),
+    ];
+    const SIN_PD: [(f64, f64); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 0.8414709848078965),
+        (-2.5, -0.5984721441039565),
+        (PI, 1.2246467991473532e-16),
+        (FRAC_PI_2, 1.0),
+        (100.0, -0.5063656411097588),
+        (-1000.25, -0.9403086681560692),
+        (1048575.0, -0.6156211730587509),
+        (2000000.0, -0.65571431556347),
+        (1.0e22, -0.8522008497671888),
+        (-1.7e308, 0.5952560848632077),
+        (f64::INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+        (1.0e-300, 1.0e-300),
+        (FRAC_PI_4, 0.7071067811865475),
+    ];
+    const COS_PS: [(f32, f32); 16] = [
+        (0.0, 1.0),
+        (-0.0, 1.0),
+        (1.0, 0.5403023),
+        (-2.5, -0.8011436),
+        (PI as f32, -1.0),
+        (FRAC_PI_2 as f32, -4.371139e-8),
+        (100.0, 0.8623189),
+        (-1000.25, 0.3403228),
+        (8191.0, -0.6463905),
+        (10000.0, -0.95215535),
+        (1.0e20, 0.7542593),
+        (-3.0e38, -0.48429477),
+        (f32::INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+        (1.0e-30, 1.0),
+        (FRAC_PI_4 as f32, FRAC_1_SQRT_2 as f32),
+    ];
+    const COS_PD: [(f64, f64); 16] = [
+        (0.0, 1.0),
+        (-0.0, 1.0),
+        (1.0, 0.5403023058681398),
+        (-2.5, -0.8011436155469337),
+        (PI, -1.0),
+        (FRAC_PI_2, 6.123233995736766e-17),
+        (100.0, 0.8623188722876839),
+        (-1000.25, 0.3403228005740423),
+        (1048575.0, 0.7880422395289275),
+        (2000000.0, 0.7550090968757464),
+        (1.0e22, 0.523214785395139),
+        (-1.7e308, 0.803536056087918),
+        (f64::INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+        (1.0e-300, 1.0),
+        (FRAC_PI_4, FRAC_1_SQRT_2),
+    ];
+    const ERF_PS: [(f32, f32); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (0.1, 0.112462915),
+        (-0.5, -0.5204999),
+        (0.84375, 0.7672257),
+        (1.0, 0.8427008),
+        (-1.5, -0.96610516),
+        (2.0, 0.9953223),
+        (3.5, 0.9999993),
+        (-3.9, -0.99999994),
+        (4.5, 1.0),
+        (f32::INFINITY, 1.0),
+        (f32::NEG_INFINITY, -1.0),
+        (f32::NAN, f32::NAN),
+        (1.0e-30, 1.1283791e-30),
+        // `erf(x)` rounds to `x * 2 / sqrt(pi)` for tiny `x`, here a subnormal
+        (1.0e-42, (1.0e-42_f32 as f64 * FRAC_2_SQRT_PI) as f32),
+    ];
+    const ERF_PD: [(f64, f64); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (0.1, 0.1124629160182849),
+        (-0.5, -0.5204998778130465),
+        (0.84375, 0.7672256612323416),
+        (1.0, 0.8427007929497149),
+        (-1.5, -0.9661051464753108),
+        (2.5, 0.999593047982555),
+        (3.5, 0.9999992569016276),
+        (-4.0, -0.9999999845827421),
+        (5.9, 0.9999999999999999),
+        (6.5, 1.0),
+        (f64::INFINITY, 1.0),
+        (f64::NEG_INFINITY, -1.0),
+        (f64::NAN, f64::NAN),
+        (1.0e-310, 1.1283791670955e-310),
+    ];
+    const POW_PS: [(f32, f32, f32); 32] = [
+        (2.0, 10.0, 1024.0),
+        (2.0, 0.5, SQRT_2 as f32),
+        (10.0, -3.0, 0.001),
+        (0.5, 126.5, 8.312001e-39),
+        (3.5, 70.25, 1.6625707e38),
+        (1.0001, 12345.0, 3.4371517),
+        (7.0, -45.5, 3.532151e-39),
+        (0.1, 2.0, 0.010000001),
+        (-2.0, 3.0, -8.0),
+        (-2.0, 4.0, 16.0),
+        (-2.0, 0.5, f32::NAN),
+        (-8.0, -0.33333334, f32::NAN),
+        (0.0, -1.0, f32::INFINITY),
+        (-0.0, -3.0, f32::NEG_INFINITY),
+        (-0.0, 3.0, -0.0),
+        (0.0, 2.0, 0.0),
+        (1.0, f32::NAN, 1.0),
+        (f32::NAN, 0.0, 1.0),
+        (-1.0, f32::INFINITY, 1.0),
+        (-1.0, f32::NEG_INFINITY, 1.0),
+        (0.5, f32::INFINITY, 0.0),
+        (2.0, f32::INFINITY, f32::INFINITY),
+        (0.5, f32::NEG_INFINITY, f32::INFINITY),
+        (2.0, f32::NEG_INFINITY, 0.0),
+        (f32::INFINITY, -1.0, 0.0),
+        (f32::NEG_INFINITY, 3.0, f32::NEG_INFINITY),
+        (f32::NEG_INFINITY, 2.0, f32::INFINITY),
+        (f32::NEG_INFINITY, -3.0, -0.0),
+        (f32::NAN, 1.0, f32::NAN),
+        (1.5, f32::NAN, f32::NAN),
+        (1.0e-40, 0.5, 9.999973e-21),
+        (3.0e38, 0.999, 2.7456466e38),
+    ];
+    const POW_PD: [(f64, f64, f64); 32] = [
+        (2.0, 10.0, 1024.0),
+        (2.0, 0.5, SQRT_2),
+        (10.0, -3.0, 0.001),
+        (0.5, 1022.5, 1.5733648139913585e-308),
+        (3.5, 555.25, 1.2410281296051572e302),
+        (1.0000001, 123456789.0, 229964.05427356533),
+        (7.0, -380.5, 2.77e-322),
+        (0.1, 2.0, 0.010000000000000002),
+        (-2.0, 3.0, -8.0),
+        (-2.0, 4.0, 16.0),
+        (-2.0, 0.5, f64::NAN),
+        (-8.0, -0.3333333333333333, f64::NAN),
+        (0.0, -1.0, f64::INFINITY),
+        (-0.0, -3.0, f64::NEG_INFINITY),
+        (-0.0, 3.0, -0.0),
+        (0.0, 2.0, 0.0),
+        (1.0, f64::NAN, 1.0),
+        (f64::NAN, 0.0, 1.0),
+        (-1.0, f64::INFINITY, 1.0),
+        (-1.0, f64::NEG_INFINITY, 1.0),
+        (0.5, f64::INFINITY, 0.0),
+        (2.0, f64::INFINITY, f64::INFINITY),
+        (0.5, f64::NEG_INFINITY, f64::INFINITY),
+        (2.0, f64::NEG_INFINITY, 0.0),
+        (f64::INFINITY, -1.0, 0.0),
+        (f64::NEG_INFINITY, 3.0, f64::NEG_INFINITY),
+        (f64::NEG_INFINITY, 2.0, f64::INFINITY),
+        (f64::NEG_INFINITY, -3.0, -0.0),
+        (f64::NAN, 1.0, f64::NAN),
+        (1.5, f64::NAN, f64::NAN),
+        (1.0e-310, 0.5, 9.999999999999986e-156),
+        (1.7e308, 0.999, 8.360234752650831e307),
+    ];
+
+    const TAN_PS: [(f32, f32); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 1.5574077),
+        (-1.0, -1.5574077),
+        (0.5, 0.5463025),
+        (FRAC_PI_2 as f32, -2.2877332e7),
+        (100.0, -0.58721393),
+        (1.0e-20, 1.0e-20),
+        (1.0e10, -0.5583496),
+        (-3.0, 0.14254655),
+        (FRAC_PI_4 as f32, 1.0),
+        (3.4e38, -0.25169775),
+        (1.0e-40, 1.0e-40),
+        (f32::INFINITY, f32::NAN),
+        (f32::NEG_INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+    ];
+    const TAN_PD: [(f64, f64); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 1.5574077246549023),
+        (-1.0, -1.5574077246549023),
+        (0.5, 0.5463024898437905),
+        (FRAC_PI_2, 1.633123935319537e16),
+        (100.0, -0.5872139151569291),
+        (1.0e-300, 1.0e-300),
+        (1.0e22, -1.6287782256068988),
+        (-3.0, 0.1425465430742778),
+        (FRAC_PI_4, 0.9999999999999999),
+        (1.7e308, -0.7407957369844252),
+        (1.0e-310, 1.0e-310),
+        (f64::INFINITY, f64::NAN),
+        (f64::NEG_INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+    ];
+    const ATAN_PS: [(f32, f32); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, FRAC_PI_4 as f32),
+        (-1.0, -FRAC_PI_4 as f32),
+        (0.4375, 0.41241044),
+        (0.6875, 0.60228735),
+        (1.1875, 0.87090343),
+        (2.4375, 1.1814796),
+        (10.0, 1.4711276),
+        (-1.0e-30, -1.0e-30),
+        (1.0e30, FRAC_PI_2 as f32),
+        (1.0e-40, 1.0e-40),
+        (0.3, 0.29145682),
+        (f32::INFINITY, FRAC_PI_2 as f32),
+        (f32::NEG_INFINITY, -FRAC_PI_2 as f32),
+        (f32::NAN, f32::NAN),
+    ];
+    const ATAN_PD: [(f64, f64); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, FRAC_PI_4),
+        (-1.0, -FRAC_PI_4),
+        (0.4375, 0.4124104415973873),
+        (0.6875, 0.6022873461349642),
+        (1.1875, 0.870903457075653),
+        (2.4375, 1.1814796049617557),
+        (10.0, 1.4711276743037347),
+        (-1.0e-300, -1.0e-300),
+        (1.0e300, FRAC_PI_2),
+        (1.0e-310, 1.0e-310),
+        (0.3, 0.2914567944778671),
+        (f64::INFINITY, FRAC_PI_2),
+        (f64::NEG_INFINITY, -FRAC_PI_2),
+        (f64::NAN, f64::NAN),
+    ];
+    const ATAN2_PS: [(f32, f32, f32); 24] = [
+        (0.0, 1.0, 0.0),
+        (-0.0, 1.0, -0.0),
+        (0.0, -1.0, PI as f32),
+        (-0.0, -1.0, -PI as f32),
+        (0.0, 0.0, 0.0),
+        (-0.0, -0.0, -PI as f32),
+        (0.0, -0.0, PI as f32),
+        (1.0, 0.0, FRAC_PI_2 as f32),
+        (-1.0, -0.0, -FRAC_PI_2 as f32),
+        (f32::INFINITY, 1.0, FRAC_PI_2 as f32),
+        (f32::INFINITY, f32::INFINITY, FRAC_PI_4 as f32),
+        (f32::INFINITY, f32::NEG_INFINITY, 2.3561945),
+        (f32::NEG_INFINITY, f32::NEG_INFINITY, -2.3561945),
+        (1.0, f32::INFINITY, 0.0),
+        (-1.0, f32::NEG_INFINITY, -PI as f32),
+        (1.0, f32::NEG_INFINITY, PI as f32),
+        (f32::NAN, 1.0, f32::NAN),
+        (1.0, f32::NAN, f32::NAN),
+        (1.0, 1.0, FRAC_PI_4 as f32),
+        (1.0, -1.0, 2.3561945),
+        (-3.0, 2.0, -0.98279375),
+        (5.0, -0.001, 1.5709963),
+        (1.0e-30, 1.0e30, 0.0),
+        (1.0e30, -1.0e-30, FRAC_PI_2 as f32),
+    ];
+    const ATAN2_PD: [(f64, f64, f64); 24] = [
+        (0.0, 1.0, 0.0),
+        (-0.0, 1.0, -0.0),
+        (0.0, -1.0, PI),
+        (-0.0, -1.0, -PI),
+        (0.0, 0.0, 0.0),
+        (-0.0, -0.0, -PI),
+        (0.0, -0.0, PI),
+        (1.0, 0.0, FRAC_PI_2),
+        (-1.0, -0.0, -FRAC_PI_2),
+        (f64::INFINITY, 1.0, FRAC_PI_2),
+        (f64::INFINITY, f64::INFINITY, FRAC_PI_4),
+        (f64::INFINITY, f64::NEG_INFINITY, 2.356194490192345),
+        (f64::NEG_INFINITY, f64::NEG_INFINITY, -2.356194490192345),
+        (1.0, f64::INFINITY, 0.0),
+        (-1.0, f64::NEG_INFINITY, -PI),
+        (1.0, f64::NEG_INFINITY, PI),
+        (f64::NAN, 1.0, f64::NAN),
+        (1.0, f64::NAN, f64::NAN),
+        (1.0, 1.0, FRAC_PI_4),
+        (1.0, -1.0, 2.356194490192345),
+        (-3.0, 2.0, -0.982793723247329),
+        (5.0, -0.001, 1.57099632679223),
+        (1.0e-300, 1.0e300, 0.0),
+        (1.0e300, -1.0e-300, FRAC_PI_2),
+    ];
+    const EXP2_PS: [(f32, f32); 16] = [
+        (0.0, 1.0),
+        (1.0, 2.0),
+        (-1.0, 0.5),
+        (10.0, 1024.0),
+        (0.5, SQRT_2 as f32),
+        (-149.0, 1.0e-45),
+        (127.99, 3.3793137e38),
+        (128.0, f32::INFINITY),
+        (-150.0, 0.0),
+        (1.0e-10, 1.0),
+        (-0.1, 0.933033),
+        (3.3, 9.849155),
+        (-126.5, 8.312001e-39),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, 0.0),
+        (f32::NAN, f32::NAN),
+    ];
+    const EXP2_PD: [(f64, f64); 16] = [
+        (0.0, 1.0),
+        (1.0, 2.0),
+        (-1.0, 0.5),
+        (10.0, 1024.0),
+        (0.5, SQRT_2),
+        (-1074.0, 5.0e-324),
+        (1023.99, 1.7852755613304564e308),
+        (1024.0, f64::INFINITY),
+        (-1075.0, 0.0),
+        (1.0e-20, 1.0),
+        (-0.1, 0.9330329915368074),
+        (3.3, 9.849155306759329),
+        (-1022.5, 1.5733648139913585e-308),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, 0.0),
+        (f64::NAN, f64::NAN),
+    ];
+    const EXP10_PS: [(f32, f32); 16] = [
+        (0.0, 1.0),
+        (1.0, 10.0),
+        (2.0, 100.0),
+        (-1.0, 0.1),
+        (0.5, 3.1622777),
+        (38.5, 3.1622777e38),
+        (39.0, f32::INFINITY),
+        (-45.0, 1.0e-45),
+        (-46.0, 0.0),
+        (1.0e-10, 1.0),
+        (-20.0, 1.0e-20),
+        (7.5, 3.1622776e7),
+        (-37.5, 3.1622777e-38),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, 0.0),
+        (f32::NAN, f32::NAN),
+    ];
+    const EXP10_PD: [(f64, f64); 16] = [
+        (0.0, 1.0),
+        (1.0, 10.0),
+        (2.0, 100.0),
+        (-1.0, 0.1),
+        (0.5, 3.1622776601683795),
+        (308.25, 1.7782794100389228e308),
+        (309.0, f64::INFINITY),
+        (-323.5, 5.0e-324),
+        (-324.0, 0.0),
+        (1.0e-20, 1.0),
+        (-200.0, 1.0e-200),
+        (22.5, 3.1622776601683792e22),
+        (-307.5, 3.162277660168379e-308),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, 0.0),
+        (f64::NAN, f64::NAN),
+    ];
+    const LOG2_PS: [(f32, f32); 16] = [
+        (1.0, 0.0),
+        (2.0, 1.0),
+        (0.5, -1.0),
+        (1024.0, 10.0),
+        (3.0, 1.5849625),
+        (10.0, LOG2_10 as f32),
+        (1.0e-40, -132.87714),
+        (1.0e-45, -149.0),
+        (3.4e38, 127.9988),
+        (1.0000001, 1.7198263e-7),
+        (0.0, f32::NEG_INFINITY),
+        (-0.0, f32::NEG_INFINITY),
+        (-1.0, f32::NAN),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+    ];
+    const LOG2_PD: [(f64, f64); 16] = [
+        (1.0, 0.0),
+        (2.0, 1.0),
+        (0.5, -1.0),
+        (1024.0, 10.0),
+        (3.0, 1.584962500721156),
+        (10.0, LOG2_10),
+        (1.0e-310, -1029.7977094150824),
+        (5.0e-324, -1074.0),
+        (1.7e308, 1023.9193879716706),
+        (1.0000000000000002, 3.203426503814917e-16),
+        (0.0, f64::NEG_INFINITY),
+        (-0.0, f64::NEG_INFINITY),
+        (-1.0, f64::NAN),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+    ];
+    const LOG10_PS: [(f32, f32); 16] = [
+        (1.0, 0.0),
+        (10.0, 1.0),
+        (100.0, 2.0),
+        (0.1, -1.0),
+        (1000.0, 3.0),
+        (2.0, LOG10_2 as f32),
+        (1.0e-40, -40.000004),
+        (1.0e30, 30.0),
+        (3.4e38, 38.53148),
+        (0.999, -0.0004345062),
+        (0.0, f32::NEG_INFINITY),
+        (-0.0, f32::NEG_INFINITY),
+        (-1.0, f32::NAN),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+    ];
+    const LOG10_PD: [(f64, f64); 16] = [
+        (1.0, 0.0),
+        (10.0, 1.0),
+        (100.0, 2.0),
+        (0.1, -1.0),
+        (1.0e22, 22.0),
+        (2.0, LOG10_2),
+        (1.0e-310, -310.0),
+        (1.0e300, 300.0),
+        (1.7e308, 308.2304489213783),
+        (0.999, -0.0004345117740176917),
+        (0.0, f64::NEG_INFINITY),
+        (-0.0, f64::NEG_INFINITY),
+        (-1.0, f64::NAN),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+    ];
+    const CBRT_PS: [(f32, f32); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 1.0),
+        (8.0, 2.0),
+        (-27.0, -3.0),
+        (2.0, 1.2599211),
+        (1.0e-40, 4.6415806e-14),
+        (1.0e-45, 1.1190347e-15),
+        (1.0e30, 1.0e10),
+        (3.4e38, 6.979532e12),
+        (0.001, 0.1),
+        (-5.0, -1.709976),
+        (7.0, 1.9129312),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, f32::NEG_INFINITY),
+        (f32::NAN, f32::NAN),
+    ];
+    const CBRT_PD: [(f64, f64); 16] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (1.0, 1.0),
+        (8.0, 2.0),
+        (-27.0, -3.0),
+        (2.0, 1.2599210498948732),
+        (1.0e-310, 4.641588833612774e-104),
+        (5.0e-324, 1.7031839360032603e-108),
+        (1.0e300, 1.0e100),
+        (1.7e308, 5.539658256754465e102),
+        (0.001, 0.1),
+        (-5.0, -1.709975946676697),
+        (1.0e-300, 1.0e-100),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, f64::NEG_INFINITY),
+        (f64::NAN, f64::NAN),
+    ];
+    const INVCBRT_PS: [(f32, f32); 16] = [
+        (0.0, f32::INFINITY),
+        (-0.0, f32::NEG_INFINITY),
+        (1.0, 1.0),
+        (8.0, 0.5),
+        (-27.0, -0.33333334),
+        (2.0, 0.7937005),
+        (1.0e-40, 2.1544386e13),
+        (1.0e-45, 8.9362734e14),
+        (1.0e30, 1.0e-10),
+        (3.4e38, 1.4327608e-13),
+        (0.001, 10.0),
+        (-5.0, -0.5848035),
+        (100.0, 0.21544346),
+        (f32::INFINITY, 0.0),
+        (f32::NEG_INFINITY, -0.0),
+        (f32::NAN, f32::NAN),
+    ];
+    const INVCBRT_PD: [(f64, f64); 16] = [
+        (0.0, f64::INFINITY),
+        (-0.0, f64::NEG_INFINITY),
+        (1.0, 1.0),
+        (8.0, 0.5),
+        (-27.0, -0.3333333333333333),
+        (2.0, 0.7937005259840998),
+        (1.0e-310, 2.154434690031886e103),
+        (5.0e-324, 5.871356456934583e107),
+        (1.0e300, 1.0e-100),
+        (1.7e308, 1.8051655059781124e-103),
+        (0.001, 10.0),
+        (-5.0, -0.5848035476425733),
+        (100.0, 0.21544346900318836),
+        (f64::INFINITY, 0.0),
+        (f64::NEG_INFINITY, -0.0),
+        (f64::NAN, f64::NAN),
+    ];
+    const INVSQRT_PS: [(f32, f32); 16] = [
+        (0.0, f32::INFINITY),
+        (-0.0, f32::NEG_INFINITY),
+        (1.0, 1.0),
+        (4.0, 0.5),
+        (2.0, FRAC_1_SQRT_2 as f32),
+        (0.25, 2.0),
+        (1.0e-40, 1.0000027e20),
+        (1.0e-45, 2.6713738e22),
+        (3.4e38, 5.423262e-20),
+        (1.0e20, 1.0e-10),
+        (1.0e-20, 1.0e10),
+        (3.0, 0.57735026),
+        (-1.0, f32::NAN),
+        (f32::INFINITY, 0.0),
+        (f32::NEG_INFINITY, f32::NAN),
+        (f32::NAN, f32::NAN),
+    ];
+    const INVSQRT_PD: [(f64, f64); 16] = [
+        (0.0, f64::INFINITY),
+        (-0.0, f64::NEG_INFINITY),
+        (1.0, 1.0),
+        (4.0, 0.5),
+        (2.0, FRAC_1_SQRT_2),
+        (0.25, 2.0),
+        (1.0e-310, 1.0000000000000016e155),
+        (5.0e-324, 4.4989137945431964e161),
+        (1.7e308, 7.669649888473705e-155),
+        (1.0e200, 1.0e-100),
+        (1.0e-200, 1.0e100),
+        (3.0, 0.5773502691896257),
+        (-1.0, f64::NAN),
+        (f64::INFINITY, 0.0),
+        (f64::NEG_INFINITY, f64::NAN),
+        (f64::NAN, f64::NAN),
+    ];
+    const SQRT_PS: [(f32, f32); 10] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (4.0, 2.0),
+        (2.0, SQRT_2 as f32),
+        (1.0e-40, 9.999973e-21),
+        (3.4e38, 1.8439089e19),
+        (0.01, 0.1),
+        (-1.0, f32::NAN),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NAN, f32::NAN),
+    ];
+    const SQRT_PD: [(f64, f64); 10] = [
+        (0.0, 0.0),
+        (-0.0, -0.0),
+        (4.0, 2.0),
+        (2.0, SQRT_2),
+        (1.0e-310, 9.999999999999986e-156),
+        (1.7e308, 1.3038404810405297e154),
+        (0.01, 0.1),
+        (-1.0, f64::NAN),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NAN, f64::NAN),
+    ];
+
+    const ROUND_PD: [(f64, f64); 8] = [
+        (0.5, 1.0),
+        (2.5, 3.0),
+        (-2.5, -3.0),
+        (-0.3, -0.0),
+        (0.49999999999999994, 0.0),
+        (4503599627370495.5, 4503599627370496.0),
+        (f64::NEG_INFINITY, f64::NEG_INFINITY),
+        (f64::NAN, f64::NAN),
+    ];
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_sin_ps() {
+        check_ps::<_, 4>(_mm_sin_ps, &SIN_PS, 2);
+        random_ps::<_, 4>(_mm_sin_ps, sincos_ps, f64::sin, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_sin_ps() {
+        check_ps::<_, 8>(_mm256_sin_ps, &SIN_PS, 2);
+        random_ps::<_, 8>(_mm256_sin_ps, sincos_ps, f64::sin, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_sin_ps() {
+        check_ps::<_, 16>(_mm512_sin_ps, &SIN_PS, 2);
+        random_ps::<_, 16>(_mm512_sin_ps, sincos_ps, f64::sin, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_sin_ps() {
+        check_mask_ps(_mm512_mask_sin_ps, _mm512_sin_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_sin_pd() {
+        check_pd::<_, 2>(_mm_sin_pd, &SIN_PD, 1);
+        random_pd::<_, 2>(_mm_sin_pd, sincos_pd, |x| sincos_dd(x, false), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_sin_pd() {
+        check_pd::<_, 4>(_mm256_sin_pd, &SIN_PD, 1);
+        random_pd::<_, 4>(_mm256_sin_pd, sincos_pd, |x| sincos_dd(x, false), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_sin_pd() {
+        check_pd::<_, 8>(_mm512_sin_pd, &SIN_PD, 1);
+        random_pd::<_, 8>(_mm512_sin_pd, sincos_pd, |x| sincos_dd(x, false), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_sin_pd() {
+        check_mask_pd(_mm512_mask_sin_pd, _mm512_sin_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_cos_ps() {
+        check_ps::<_, 4>(_mm_cos_ps, &COS_PS, 2);
+        random_ps::<_, 4>(_mm_cos_ps, sincos_ps, f64::cos, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_cos_ps() {
+        check_ps::<_, 8>(_mm256_cos_ps, &COS_PS, 2);
+        random_ps::<_, 8>(_mm256_cos_ps, sincos_ps, f64::cos, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cos_ps() {
+        check_ps::<_, 16>(_mm512_cos_ps, &COS_PS, 2);
+        random_ps::<_, 16>(_mm512_cos_ps, sincos_ps, f64::cos, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_cos_ps() {
+        check_mask_ps(_mm512_mask_cos_ps, _mm512_cos_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_cos_pd() {
+        check_pd::<_, 2>(_mm_cos_pd, &COS_PD, 1);
+        random_pd::<_, 2>(_mm_cos_pd, sincos_pd, |x| sincos_dd(x, true), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_cos_pd() {
+        check_pd::<_, 4>(_mm256_cos_pd, &COS_PD, 1);
+        random_pd::<_, 4>(_mm256_cos_pd, sincos_pd, |x| sincos_dd(x, true), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cos_pd() {
+        check_pd::<_, 8>(_mm512_cos_pd, &COS_PD, 1);
+        random_pd::<_, 8>(_mm512_cos_pd, sincos_pd, |x| sincos_dd(x, true), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_cos_pd() {
+        check_mask_pd(_mm512_mask_cos_pd, _mm512_cos_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_tan_ps() {
+        check_ps::<_, 4>(_mm_tan_ps, &TAN_PS, 2);
+        random_ps::<_, 4>(_mm_tan_ps, sincos_ps, f64::tan, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_tan_ps() {
+        check_ps::<_, 8>(_mm256_tan_ps, &TAN_PS, 2);
+        random_ps::<_, 8>(_mm256_tan_ps, sincos_ps, f64::tan, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_tan_ps() {
+        check_ps::<_, 16>(_mm512_tan_ps, &TAN_PS, 2);
+        random_ps::<_, 16>(_mm512_tan_ps, sincos_ps, f64::tan, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_tan_ps() {
+        check_mask_ps(_mm512_mask_tan_ps, _mm512_tan_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_tan_pd() {
+        check_pd::<_, 2>(_mm_tan_pd, &TAN_PD, 2);
+        random_pd::<_, 2>(
+            _mm_tan_pd,
+            sincos_pd,
+            |x| sincos_dd(x, false).div(sincos_dd(x, true)),
+            2.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_tan_pd() {
+        check_pd::<_, 4>(_mm256_tan_pd, &TAN_PD, 2);
+        random_pd::<_, 4>(
+            _mm256_tan_pd,
+            sincos_pd,
+            |x| sincos_dd(x, false).div(sincos_dd(x, true)),
+            2.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_tan_pd() {
+        check_pd::<_, 8>(_mm512_tan_pd, &TAN_PD, 2);
+        random_pd::<_, 8>(
+            _mm512_tan_pd,
+            sincos_pd,
+            |x| sincos_dd(x, false).div(sincos_dd(x, true)),
+            2.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_tan_pd() {
+        check_mask_pd(_mm512_mask_tan_pd, _mm512_tan_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_atan_ps() {
+        check_ps::<_, 4>(_mm_atan_ps, &ATAN_PS, 1);
+        random_ps::<_, 4>(_mm_atan_ps, atan_ps, f64::atan, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_atan_ps() {
+        check_ps::<_, 8>(_mm256_atan_ps, &ATAN_PS, 1);
+        random_ps::<_, 8>(_mm256_atan_ps, atan_ps, f64::atan, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_atan_ps() {
+        check_ps::<_, 16>(_mm512_atan_ps, &ATAN_PS, 1);
+        random_ps::<_, 16>(_mm512_atan_ps, atan_ps, f64::atan, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_atan_ps() {
+        check_mask_ps(_mm512_mask_atan_ps, _mm512_atan_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_atan_pd() {
+        check_pd::<_, 2>(_mm_atan_pd, &ATAN_PD, 1);
+        random_pd::<_, 2>(_mm_atan_pd, atan_pd, |x| atan_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_atan_pd() {
+        check_pd::<_, 4>(_mm256_atan_pd, &ATAN_PD, 1);
+        random_pd::<_, 4>(_mm256_atan_pd, atan_pd, |x| atan_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_atan_pd() {
+        check_pd::<_, 8>(_mm512_atan_pd, &ATAN_PD, 1);
+        random_pd::<_, 8>(_mm512_atan_pd, atan_pd, |x| atan_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_atan_pd() {
+        check_mask_pd(_mm512_mask_atan_pd, _mm512_atan_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_atan2_ps() {
+        check2_ps::<_, 4>(_mm_atan2_ps, &ATAN2_PS, 2);
+        random2_ps::<_, 4>(_mm_atan2_ps, atan2_ps, f64::atan2, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_atan2_ps() {
+        check2_ps::<_, 8>(_mm256_atan2_ps, &ATAN2_PS, 2);
+        random2_ps::<_, 8>(_mm256_atan2_ps, atan2_ps, f64::atan2, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_atan2_ps() {
+        check2_ps::<_, 16>(_mm512_atan2_ps, &ATAN2_PS, 2);
+        random2_ps::<_, 16>(_mm512_atan2_ps, atan2_ps, f64::atan2, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_atan2_ps() {
+        check_mask2_ps(_mm512_mask_atan2_ps, _mm512_atan2_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_atan2_pd() {
+        check2_pd::<_, 2>(_mm_atan2_pd, &ATAN2_PD, 2);
+        random2_pd::<_, 2>(_mm_atan2_pd, atan2_pd, atan2_dd, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_atan2_pd() {
+        check2_pd::<_, 4>(_mm256_atan2_pd, &ATAN2_PD, 2);
+        random2_pd::<_, 4>(_mm256_atan2_pd, atan2_pd, atan2_dd, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_atan2_pd() {
+        check2_pd::<_, 8>(_mm512_atan2_pd, &ATAN2_PD, 2);
+        random2_pd::<_, 8>(_mm512_atan2_pd, atan2_pd, atan2_dd, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_atan2_pd() {
+        check_mask2_pd(_mm512_mask_atan2_pd, _mm512_atan2_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp_ps() {
+        check_ps::<_, 4>(_mm_exp_ps, &EXP_PS, 1);
+        random_ps::<_, 4>(_mm_exp_ps, exp_ps, f64::exp, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp_ps() {
+        check_ps::<_, 8>(_mm256_exp_ps, &EXP_PS, 1);
+        random_ps::<_, 8>(_mm256_exp_ps, exp_ps, f64::exp, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp_ps() {
+        check_ps::<_, 16>(_mm512_exp_ps, &EXP_PS, 1);
+        random_ps::<_, 16>(_mm512_exp_ps, exp_ps, f64::exp, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp_ps() {
+        check_mask_ps(_mm512_mask_exp_ps, _mm512_exp_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp_pd() {
+        check_pd::<_, 2>(_mm_exp_pd, &EXP_PD, 1);
+        random_pd::<_, 2>(_mm_exp_pd, exp_pd, |x| exp_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp_pd() {
+        check_pd::<_, 4>(_mm256_exp_pd, &EXP_PD, 1);
+        random_pd::<_, 4>(_mm256_exp_pd, exp_pd, |x| exp_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp_pd() {
+        check_pd::<_, 8>(_mm512_exp_pd, &EXP_PD, 1);
+        random_pd::<_, 8>(_mm512_exp_pd, exp_pd, |x| exp_dd(Dd::new(x)), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp_pd() {
+        check_mask_pd(_mm512_mask_exp_pd, _mm512_exp_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp2_ps() {
+        check_ps::<_, 4>(_mm_exp2_ps, &EXP2_PS, 1);
+        random_ps::<_, 4>(_mm_exp2_ps, exp2_ps, f64::exp2, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp2_ps() {
+        check_ps::<_, 8>(_mm256_exp2_ps, &EXP2_PS, 1);
+        random_ps::<_, 8>(_mm256_exp2_ps, exp2_ps, f64::exp2, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp2_ps() {
+        check_ps::<_, 16>(_mm512_exp2_ps, &EXP2_PS, 1);
+        random_ps::<_, 16>(_mm512_exp2_ps, exp2_ps, f64::exp2, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp2_ps() {
+        check_mask_ps(_mm512_mask_exp2_ps, _mm512_exp2_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp2_pd() {
+        check_pd::<_, 2>(_mm_exp2_pd, &EXP2_PD, 1);
+        random_pd::<_, 2>(
+            _mm_exp2_pd,
+            exp2_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_2, 2.3190468138462996e-17))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp2_pd() {
+        check_pd::<_, 4>(_mm256_exp2_pd, &EXP2_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_exp2_pd,
+            exp2_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_2, 2.3190468138462996e-17))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp2_pd() {
+        check_pd::<_, 8>(_mm512_exp2_pd, &EXP2_PD, 1);
+        random_pd::<_, 8>(
+            _mm512_exp2_pd,
+            exp2_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_2, 2.3190468138462996e-17))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp2_pd() {
+        check_mask_pd(_mm512_mask_exp2_pd, _mm512_exp2_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp10_ps() {
+        check_ps::<_, 4>(_mm_exp10_ps, &EXP10_PS, 1);
+        random_ps::<_, 4>(_mm_exp10_ps, exp10_ps, |x| 10f64.powf(x), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp10_ps() {
+        check_ps::<_, 8>(_mm256_exp10_ps, &EXP10_PS, 1);
+        random_ps::<_, 8>(_mm256_exp10_ps, exp10_ps, |x| 10f64.powf(x), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp10_ps() {
+        check_ps::<_, 16>(_mm512_exp10_ps, &EXP10_PS, 1);
+        random_ps::<_, 16>(_mm512_exp10_ps, exp10_ps, |x| 10f64.powf(x), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp10_ps() {
+        check_mask_ps(_mm512_mask_exp10_ps, _mm512_exp10_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_exp10_pd() {
+        check_pd::<_, 2>(_mm_exp10_pd, &EXP10_PD, 1);
+        random_pd::<_, 2>(
+            _mm_exp10_pd,
+            exp10_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_10, -2.1707562233822494e-16))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_exp10_pd() {
+        check_pd::<_, 4>(_mm256_exp10_pd, &EXP10_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_exp10_pd,
+            exp10_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_10, -2.1707562233822494e-16))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_exp10_pd() {
+        check_pd::<_, 8>(_mm512_exp10_pd, &EXP10_PD, 1);
+        random_pd::<_, 8>(
+            _mm512_exp10_pd,
+            exp10_pd,
+            |x| exp_dd(Dd::new(x).mul(Dd(LN_10, -2.1707562233822494e-16))),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_exp10_pd() {
+        check_mask_pd(_mm512_mask_exp10_pd, _mm512_exp10_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log_ps() {
+        check_ps::<_, 4>(_mm_log_ps, &LOG_PS, 1);
+        random_ps::<_, 4>(_mm_log_ps, log_ps, f64::ln, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log_ps() {
+        check_ps::<_, 8>(_mm256_log_ps, &LOG_PS, 1);
+        random_ps::<_, 8>(_mm256_log_ps, log_ps, f64::ln, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log_ps() {
+        check_ps::<_, 16>(_mm512_log_ps, &LOG_PS, 1);
+        random_ps::<_, 16>(_mm512_log_ps, log_ps, f64::ln, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log_ps() {
+        check_mask_ps(_mm512_mask_log_ps, _mm512_log_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log_pd() {
+        check_pd::<_, 2>(_mm_log_pd, &LOG_PD, 1);
+        random_pd::<_, 2>(_mm_log_pd, log_pd, log_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log_pd() {
+        check_pd::<_, 4>(_mm256_log_pd, &LOG_PD, 1);
+        random_pd::<_, 4>(_mm256_log_pd, log_pd, log_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log_pd() {
+        check_pd::<_, 8>(_mm512_log_pd, &LOG_PD, 1);
+        random_pd::<_, 8>(_mm512_log_pd, log_pd, log_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log_pd() {
+        check_mask_pd(_mm512_mask_log_pd, _mm512_log_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log2_ps() {
+        check_ps::<_, 4>(_mm_log2_ps, &LOG2_PS, 1);
+        random_ps::<_, 4>(_mm_log2_ps, log_ps, f64::log2, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log2_ps() {
+        check_ps::<_, 8>(_mm256_log2_ps, &LOG2_PS, 1);
+        random_ps::<_, 8>(_mm256_log2_ps, log_ps, f64::log2, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log2_ps() {
+        check_ps::<_, 16>(_mm512_log2_ps, &LOG2_PS, 1);
+        random_ps::<_, 16>(_mm512_log2_ps, log_ps, f64::log2, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log2_ps() {
+        check_mask_ps(_mm512_mask_log2_ps, _mm512_log2_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log2_pd() {
+        check_pd::<_, 2>(_mm_log2_pd, &LOG2_PD, 1);
+        random_pd::<_, 2>(
+            _mm_log2_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_2, 2.3190468138462996e-17)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log2_pd() {
+        check_pd::<_, 4>(_mm256_log2_pd, &LOG2_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_log2_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_2, 2.3190468138462996e-17)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log2_pd() {
+        check_pd::<_, 8>(_mm512_log2_pd, &LOG2_PD, 1);
+        random_pd::<_, 8>(
+            _mm512_log2_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_2, 2.3190468138462996e-17)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log2_pd() {
+        check_mask_pd(_mm512_mask_log2_pd, _mm512_log2_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log10_ps() {
+        check_ps::<_, 4>(_mm_log10_ps, &LOG10_PS, 1);
+        random_ps::<_, 4>(_mm_log10_ps, log_ps, f64::log10, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log10_ps() {
+        check_ps::<_, 8>(_mm256_log10_ps, &LOG10_PS, 1);
+        random_ps::<_, 8>(_mm256_log10_ps, log_ps, f64::log10, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log10_ps() {
+        check_ps::<_, 16>(_mm512_log10_ps, &LOG10_PS, 1);
+        random_ps::<_, 16>(_mm512_log10_ps, log_ps, f64::log10, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log10_ps() {
+        check_mask_ps(_mm512_mask_log10_ps, _mm512_log10_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_log10_pd() {
+        check_pd::<_, 2>(_mm_log10_pd, &LOG10_PD, 1);
+        random_pd::<_, 2>(
+            _mm_log10_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_10, -2.1707562233822494e-16)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_log10_pd() {
+        check_pd::<_, 4>(_mm256_log10_pd, &LOG10_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_log10_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_10, -2.1707562233822494e-16)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_log10_pd() {
+        check_pd::<_, 8>(_mm512_log10_pd, &LOG10_PD, 1);
+        random_pd::<_, 8>(
+            _mm512_log10_pd,
+            log_pd,
+            |x| log_dd(x).div(Dd(LN_10, -2.1707562233822494e-16)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_log10_pd() {
+        check_mask_pd(_mm512_mask_log10_pd, _mm512_log10_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_pow_ps() {
+        check2_ps::<_, 4>(_mm_pow_ps, &POW_PS, 1);
+        random2_ps::<_, 4>(_mm_pow_ps, pow_ps, f64::powf, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_pow_ps() {
+        check2_ps::<_, 8>(_mm256_pow_ps, &POW_PS, 1);
+        random2_ps::<_, 8>(_mm256_pow_ps, pow_ps, f64::powf, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_pow_ps() {
+        check2_ps::<_, 16>(_mm512_pow_ps, &POW_PS, 1);
+        random2_ps::<_, 16>(_mm512_pow_ps, pow_ps, f64::powf, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_pow_ps() {
+        check_mask2_ps(_mm512_mask_pow_ps, _mm512_pow_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_pow_pd() {
+        check2_pd::<_, 2>(_mm_pow_pd, &POW_PD, 1);
+        random2_pd::<_, 2>(_mm_pow_pd, pow_pd, pow_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_pow_pd() {
+        check2_pd::<_, 4>(_mm256_pow_pd, &POW_PD, 1);
+        random2_pd::<_, 4>(_mm256_pow_pd, pow_pd, pow_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_pow_pd() {
+        check2_pd::<_, 8>(_mm512_pow_pd, &POW_PD, 1);
+        random2_pd::<_, 8>(_mm512_pow_pd, pow_pd, pow_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_pow_pd() {
+        check_mask2_pd(_mm512_mask_pow_pd, _mm512_pow_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_cbrt_ps() {
+        check_ps::<_, 4>(_mm_cbrt_ps, &CBRT_PS, 1);
+        random_ps::<_, 4>(_mm_cbrt_ps, cbrt_ps, f64::cbrt, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_cbrt_ps() {
+        check_ps::<_, 8>(_mm256_cbrt_ps, &CBRT_PS, 1);
+        random_ps::<_, 8>(_mm256_cbrt_ps, cbrt_ps, f64::cbrt, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cbrt_ps() {
+        check_ps::<_, 16>(_mm512_cbrt_ps, &CBRT_PS, 1);
+        random_ps::<_, 16>(_mm512_cbrt_ps, cbrt_ps, f64::cbrt, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_cbrt_ps() {
+        check_mask_ps(_mm512_mask_cbrt_ps, _mm512_cbrt_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_cbrt_pd() {
+        check_pd::<_, 2>(_mm_cbrt_pd, &CBRT_PD, 1);
+        random_pd::<_, 2>(_mm_cbrt_pd, cbrt_pd, cbrt_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_cbrt_pd() {
+        check_pd::<_, 4>(_mm256_cbrt_pd, &CBRT_PD, 1);
+        random_pd::<_, 4>(_mm256_cbrt_pd, cbrt_pd, cbrt_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_cbrt_pd() {
+        check_pd::<_, 8>(_mm512_cbrt_pd, &CBRT_PD, 1);
+        random_pd::<_, 8>(_mm512_cbrt_pd, cbrt_pd, cbrt_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_cbrt_pd() {
+        check_mask_pd(_mm512_mask_cbrt_pd, _mm512_cbrt_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_invcbrt_ps() {
+        check_ps::<_, 4>(_mm_invcbrt_ps, &INVCBRT_PS, 1);
+        random_ps::<_, 4>(_mm_invcbrt_ps, cbrt_ps, |x| 1.0 / x.cbrt(), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_invcbrt_ps() {
+        check_ps::<_, 8>(_mm256_invcbrt_ps, &INVCBRT_PS, 1);
+        random_ps::<_, 8>(_mm256_invcbrt_ps, cbrt_ps, |x| 1.0 / x.cbrt(), 1.0);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_invcbrt_pd() {
+        check_pd::<_, 2>(_mm_invcbrt_pd, &INVCBRT_PD, 1);
+        random_pd::<_, 2>(
+            _mm_invcbrt_pd,
+            cbrt_pd,
+            |x| Dd::new(1.0).div(cbrt_dd(x)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_invcbrt_pd() {
+        check_pd::<_, 4>(_mm256_invcbrt_pd, &INVCBRT_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_invcbrt_pd,
+            cbrt_pd,
+            |x| Dd::new(1.0).div(cbrt_dd(x)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_invsqrt_ps() {
+        check_ps::<_, 4>(_mm_invsqrt_ps, &INVSQRT_PS, 1);
+        random_ps::<_, 4>(_mm_invsqrt_ps, log_ps, |x| 1.0 / x.sqrt(), 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_invsqrt_ps() {
+        check_ps::<_, 8>(_mm256_invsqrt_ps, &INVSQRT_PS, 1);
+        random_ps::<_, 8>(_mm256_invsqrt_ps, log_ps, |x| 1.0 / x.sqrt(), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_invsqrt_ps() {
+        check_ps::<_, 16>(_mm512_invsqrt_ps, &INVSQRT_PS, 1);
+        random_ps::<_, 16>(_mm512_invsqrt_ps, log_ps, |x| 1.0 / x.sqrt(), 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_invsqrt_ps() {
+        check_mask_ps(_mm512_mask_invsqrt_ps, _mm512_invsqrt_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_invsqrt_pd() {
+        check_pd::<_, 2>(_mm_invsqrt_pd, &INVSQRT_PD, 1);
+        random_pd::<_, 2>(
+            _mm_invsqrt_pd,
+            log_pd,
+            |x| Dd::new(1.0).div(sqrt_dd(x)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_invsqrt_pd() {
+        check_pd::<_, 4>(_mm256_invsqrt_pd, &INVSQRT_PD, 1);
+        random_pd::<_, 4>(
+            _mm256_invsqrt_pd,
+            log_pd,
+            |x| Dd::new(1.0).div(sqrt_dd(x)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_invsqrt_pd() {
+        check_pd::<_, 8>(_mm512_invsqrt_pd, &INVSQRT_PD, 1);
+        random_pd::<_, 8>(
+            _mm512_invsqrt_pd,
+            log_pd,
+            |x| Dd::new(1.0).div(sqrt_dd(x)),
+            1.0,
+        );
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_invsqrt_pd() {
+        check_mask_pd(_mm512_mask_invsqrt_pd, _mm512_invsqrt_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_svml_sqrt_ps() {
+        check_ps::<_, 4>(_mm_svml_sqrt_ps, &SQRT_PS, 0);
+        random_ps::<_, 4>(_mm_svml_sqrt_ps, log_ps, f64::sqrt, 0.5);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_svml_sqrt_ps() {
+        check_ps::<_, 8>(_mm256_svml_sqrt_ps, &SQRT_PS, 0);
+        random_ps::<_, 8>(_mm256_svml_sqrt_ps, log_ps, f64::sqrt, 0.5);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_svml_sqrt_pd() {
+        check_pd::<_, 2>(_mm_svml_sqrt_pd, &SQRT_PD, 0);
+        random_pd::<_, 2>(_mm_svml_sqrt_pd, log_pd, sqrt_dd, 0.5);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_svml_sqrt_pd() {
+        check_pd::<_, 4>(_mm256_svml_sqrt_pd, &SQRT_PD, 0);
+        random_pd::<_, 4>(_mm256_svml_sqrt_pd, log_pd, sqrt_dd, 0.5);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_erf_ps() {
+        check_ps::<_, 4>(_mm_erf_ps, &ERF_PS, 2);
+        random_ps::<_, 4>(_mm_erf_ps, erf_ps, |x| erf_dd(x).0, 2.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_erf_ps() {
+        check_ps::<_, 8>(_mm256_erf_ps, &ERF_PS, 2);
+        random_ps::<_, 8>(_mm256_erf_ps, erf_ps, |x| erf_dd(x).0, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_erf_ps() {
+        check_ps::<_, 16>(_mm512_erf_ps, &ERF_PS, 2);
+        random_ps::<_, 16>(_mm512_erf_ps, erf_ps, |x| erf_dd(x).0, 2.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_erf_ps() {
+        check_mask_ps(_mm512_mask_erf_ps, _mm512_erf_ps);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_erf_pd() {
+        check_pd::<_, 2>(_mm_erf_pd, &ERF_PD, 1);
+        random_pd::<_, 2>(_mm_erf_pd, erf_pd, erf_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_erf_pd() {
+        check_pd::<_, 4>(_mm256_erf_pd, &ERF_PD, 1);
+        random_pd::<_, 4>(_mm256_erf_pd, erf_pd, erf_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_erf_pd() {
+        check_pd::<_, 8>(_mm512_erf_pd, &ERF_PD, 1);
+        random_pd::<_, 8>(_mm512_erf_pd, erf_pd, erf_dd, 1.0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_erf_pd() {
+        check_mask_pd(_mm512_mask_erf_pd, _mm512_erf_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_svml_round_pd() {
+        check_pd::<_, 2>(_mm_svml_round_pd, &ROUND_PD, 0);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_svml_round_pd() {
+        check_pd::<_, 4>(_mm256_svml_round_pd, &ROUND_PD, 0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_svml_round_pd() {
+        check_pd::<_, 8>(_mm512_svml_round_pd, &ROUND_PD, 0);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_svml_round_pd() {
+        check_mask_pd(_mm512_mask_svml_round_pd, _mm512_svml_round_pd);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epi8() {
+        check_div::<_, i8, 16>(_mm_div_epi8, i8::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epi8() {
+        check_div::<_, i8, 32>(_mm256_div_epi8, i8::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epi8() {
+        check_div::<_, i8, 64>(_mm512_div_epi8, i8::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epi16() {
+        check_div::<_, i16, 8>(_mm_div_epi16, i16::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epi16() {
+        check_div::<_, i16, 16>(_mm256_div_epi16, i16::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epi16() {
+        check_div::<_, i16, 32>(_mm512_div_epi16, i16::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epi32() {
+        check_div::<_, i32, 4>(_mm_div_epi32, i32::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epi32() {
+        check_div::<_, i32, 8>(_mm256_div_epi32, i32::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epi32() {
+        check_div::<_, i32, 16>(_mm512_div_epi32, i32::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_div_epi32() {
+        check_mask_epi32(_mm512_mask_div_epi32, _mm512_div_epi32);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epi64() {
+        check_div::<_, i64, 2>(_mm_div_epi64, i64::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epi64() {
+        check_div::<_, i64, 4>(_mm256_div_epi64, i64::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epi64() {
+        check_div::<_, i64, 8>(_mm512_div_epi64, i64::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epu8() {
+        check_div::<_, u8, 16>(_mm_div_epu8, u8::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epu8() {
+        check_div::<_, u8, 32>(_mm256_div_epu8, u8::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epu8() {
+        check_div::<_, u8, 64>(_mm512_div_epu8, u8::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epu16() {
+        check_div::<_, u16, 8>(_mm_div_epu16, u16::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epu16() {
+        check_div::<_, u16, 16>(_mm256_div_epu16, u16::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epu16() {
+        check_div::<_, u16, 32>(_mm512_div_epu16, u16::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epu32() {
+        check_div::<_, u32, 4>(_mm_div_epu32, u32::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epu32() {
+        check_div::<_, u32, 8>(_mm256_div_epu32, u32::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epu32() {
+        check_div::<_, u32, 16>(_mm512_div_epu32, u32::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_div_epu32() {
+        check_mask_epi32(_mm512_mask_div_epu32, _mm512_div_epu32);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_div_epu64() {
+        check_div::<_, u64, 2>(_mm_div_epu64, u64::checked_div);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_div_epu64() {
+        check_div::<_, u64, 4>(_mm256_div_epu64, u64::checked_div);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_div_epu64() {
+        check_div::<_, u64, 8>(_mm512_div_epu64, u64::checked_div);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epi8() {
+        check_div::<_, i8, 16>(_mm_rem_epi8, i8::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epi8() {
+        check_div::<_, i8, 32>(_mm256_rem_epi8, i8::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epi8() {
+        check_div::<_, i8, 64>(_mm512_rem_epi8, i8::checked_rem);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epi16() {
+        check_div::<_, i16, 8>(_mm_rem_epi16, i16::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epi16() {
+        check_div::<_, i16, 16>(_mm256_rem_epi16, i16::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epi16() {
+        check_div::<_, i16, 32>(_mm512_rem_epi16, i16::checked_rem);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epi32() {
+        check_div::<_, i32, 4>(_mm_rem_epi32, i32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epi32() {
+        check_div::<_, i32, 8>(_mm256_rem_epi32, i32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epi32() {
+        check_div::<_, i32, 16>(_mm512_rem_epi32, i32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_rem_epi32() {
+        check_mask_epi32(_mm512_mask_rem_epi32, _mm512_rem_epi32);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epi64() {
+        check_div::<_, i64, 2>(_mm_rem_epi64, i64::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epi64() {
+        check_div::<_, i64, 4>(_mm256_rem_epi64, i64::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epi64() {
+        check_div::<_, i64, 8>(_mm512_rem_epi64, i64::checked_rem);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epu8() {
+        check_div::<_, u8, 16>(_mm_rem_epu8, u8::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epu8() {
+        check_div::<_, u8, 32>(_mm256_rem_epu8, u8::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epu8() {
+        check_div::<_, u8, 64>(_mm512_rem_epu8, u8::checked_rem);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epu16() {
+        check_div::<_, u16, 8>(_mm_rem_epu16, u16::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epu16() {
+        check_div::<_, u16, 16>(_mm256_rem_epu16, u16::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epu16() {
+        check_div::<_, u16, 32>(_mm512_rem_epu16, u16::checked_rem);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epu32() {
+        check_div::<_, u32, 4>(_mm_rem_epu32, u32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epu32() {
+        check_div::<_, u32, 8>(_mm256_rem_epu32, u32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epu32() {
+        check_div::<_, u32, 16>(_mm512_rem_epu32, u32::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_mask_rem_epu32() {
+        check_mask_epi32(_mm512_mask_rem_epu32, _mm512_rem_epu32);
+    }
+
+    #[simd_test(enable = "sse2")]
+    unsafe fn test_mm_rem_epu64() {
+        check_div::<_, u64, 2>(_mm_rem_epu64, u64::checked_rem);
+    }
+
+    #[simd_test(enable = "avx")]
+    unsafe fn test_mm256_rem_epu64() {
+        check_div::<_, u64, 4>(_mm256_rem_epu64, u64::checked_rem);
+    }
+
+    #[simd_test(enable = "avx512f")]
+    unsafe fn test_mm512_rem_epu64() {
+        check_div::<_, u64, 8>(_mm512_rem_epu64, u64::checked_rem);
+    }
+}
@@ -3,6 +3,11 @@
 use crate::core_arch::x86::*;
 use std::mem::transmute;
 
+#[target_feature(enable = "mmx")]
+pub unsafe fn assert_eq_m64(a: __m64, b: __m64) {
+    assert_eq!(transmute::<_, u64>(a), transmute::<_, u64>(b))
+}
+
 #[target_feature(enable = "sse2")]
 pub unsafe fn assert_eq_m128i(a: __m128i, b: __m128i) {
     assert_eq!(transmute::<_, [u64; 2]>(a), transmute::<_, [u64; 2]>(b))
@@ -0,0 +1,388 @@
+//! Advanced Matrix Extensions (AMX).
+//!
+//! AMX adds eight two-dimensional tile registers of at most 16 rows of 64
+//! bytes each, and instructions multiplying them. The tiles must be configured
+//! with [`_tile_loadconfig`] before use, and released with [`_tile_release`]
+//! once done.
+//!
+//! On Linux the process must also be allowed to use the tile data, which
+//! `is_x86_feature_detected!("amx-tile")` requests from the kernel. Using the
+//! tiles without that permission raises `SIGILL`.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 1][intel64_ref], chapter 18, "Programming with Intel AMX".
+//!
+//! [intel64_ref]: https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sdm.html
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.x86.ldtilecfg"]
+    fn ldtilecfg(mem_addr: *const u8);
+    #[link_name = "llvm.x86.sttilecfg"]
+    fn sttilecfg(mem_addr: *mut u8);
+    #[link_name = "llvm.x86.tileloadd64"]
+    fn tileloadd64(dst: i8, base: *const u8, stride: usize);
+    #[link_name = "llvm.x86.tilestored64"]
+    fn tilestored64(dst: i8, base: *mut u8, stride: usize);
+    #[link_name = "llvm.x86.tilezero"]
+    fn tilezero(dst: i8);
+    #[link_name = "llvm.x86.tilerelease"]
+    fn tilerelease();
+    #[link_name = "llvm.x86.tdpbssd"]
+    fn tdpbssd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbsud"]
+    fn tdpbsud(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbusd"]
+    fn tdpbusd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbuud"]
+    fn tdpbuud(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbf16ps"]
+    fn tdpbf16ps(dst: i8, a: i8, b: i8);
+}
+
+/// The 64-byte tile configuration read by [`_tile_loadconfig`] and written by
+/// [`_tile_storeconfig`].
+///
+/// Tile `i` is configured with `rows[i]` rows of `colsb[i]` bytes each. Palette
+/// 0 leaves the tiles unconfigured, and palette 1 provides tiles 0 to 7 with at
+/// most 16 rows of 64 bytes. The reserved bytes are always zero.
+#[allow(clippy::missing_inline_in_public_items)]
+// ^^ the derived impls for TileConfig are not #[inline] and that's OK.
+#[repr(C, align(64))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TileConfig {
+    /// Palette of the configuration.
+    pub palette_id: u8,
+    /// Row at which interrupted tile loads and stores restart.
+    pub start_row: u8,
+    reserved: [u8; 14],
+    /// Number of bytes per row of each tile.
+    pub colsb: [u16; 16],
+    /// Number of rows of each tile.
+    pub rows: [u8; 16],
+}
+
+/// Load tile configuration from a 64-byte memory location specified by
+/// `mem_addr`, which is usually a [`TileConfig`]. If a palette is not
+/// supported or the configuration is invalid, a general protection fault is
+/// raised. Palette 0 releases the tiles like [`_tile_release`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_loadconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(ldtilecfg))]
+pub unsafe fn _tile_loadconfig(mem_addr: *const u8) {
+    ldtilecfg(mem_addr);
+}
+
+/// Store the current tile configuration to a 64-byte memory location
+/// specified by `mem_addr`, which is usually a [`TileConfig`]. If the tiles
+/// are not configured, all zeroes are stored.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_storeconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(sttilecfg))]
+pub unsafe fn _tile_storeconfig(mem_addr: *mut u8) {
+    sttilecfg(mem_addr);
+}
+
+/// Load the rows of tile `DST` from memory, starting at `base` and
+/// advancing by `stride` bytes per row.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloadd, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_loadd<const DST: i32>(base: *const u8, stride: usize) {
+    static_assert_imm3!(DST);
+    tileloadd64(DST as i8, base, stride);
+}
+
+/// Store the rows of tile `DST` to memory, starting at `base` and advancing
+/// by `stride` bytes per row.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_stored)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilestored, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_stored<const DST: i32>(base: *mut u8, stride: usize) {
+    static_assert_imm3!(DST);
+    tilestored64(DST as i8, base, stride);
+}
+
+/// Zero the tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_zero)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilezero, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_zero<const DST: i32>() {
+    static_assert_imm3!(DST);
+    tilezero(DST as i8);
+}
+
+/// Release the tile configuration, returning the tiles to the init state,
+/// which frees the resources allocated for them.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_release)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilerelease))]
+pub unsafe fn _tile_release() {
+    tilerelease();
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers
+/// in `A` with corresponding signed 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbssd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbssd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbssd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbssd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers
+/// in `A` with corresponding unsigned 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbsud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbsud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbsud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbsud(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers
+/// in `A` with corresponding signed 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbusd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbusd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbusd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbusd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination
+/// accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers
+/// in `A` with corresponding unsigned 8-bit integers in `B`, producing 4
+/// intermediate 32-bit results. Sum these 4 results with the corresponding
+/// 32-bit integer in `DST`, and store the 32-bit result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbuud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbuud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbuud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbuud(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of BF16 (16-bit) floating-point pairs in tiles `A` and
+/// `B`, accumulating the intermediate single-precision (32-bit)
+/// floating-point elements with elements in `DST`, and store the 32-bit
+/// result back to tile `DST`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_tile_dpbf16ps)
+#[inline]
+#[target_feature(enable = "amx-bf16")]
+#[cfg_attr(test, assert_instr(tdpbf16ps, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbf16ps<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_imm3!(DST);
+    static_assert_imm3!(A);
+    static_assert_imm3!(B);
+    tdpbf16ps(DST as i8, A as i8, B as i8);
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::x86_64::*;
+
+    /// Configures tiles 0 to 2 with 16 rows of 64 bytes.
+    unsafe fn init() {
+        let mut config = TileConfig::default();
+        config.palette_id = 1;
+        for i in 0..3 {
+            config.colsb[i] = 64;
+            config.rows[i] = 16;
+        }
+        _tile_loadconfig(&config as *const TileConfig as *const u8);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadconfig() {
+        init();
+        _tile_zero::<0>();
+        let mut out = [[1_u8; 64]; 16];
+        _tile_stored::<0>(&mut out as *mut [u8; 64] as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[0; 64]; 16]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_storeconfig() {
+        let mut config = TileConfig::default();
+        config.palette_id = 1;
+        config.colsb[0] = 64;
+        config.rows[0] = 16;
+        config.colsb[7] = 8;
+        config.rows[7] = 2;
+        _tile_loadconfig(&config as *const TileConfig as *const u8);
+        let mut stored = TileConfig::default();
+        _tile_storeconfig(&mut stored as *mut TileConfig as *mut u8);
+        _tile_release();
+        assert_eq!(stored, config);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadd() {
+        init();
+        let mut mat = [[0_u8; 64]; 16];
+        for (i, row) in mat.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (i * 64 + j) as u8;
+            }
+        }
+        _tile_loadd::<0>(&mat as *const [u8; 64] as *const u8, 64);
+        let mut out = [[0_u8; 64]; 16];
+        _tile_stored::<0>(&mut out as *mut [u8; 64] as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, mat);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_stored() {
+        init();
+        // Every other row of `mat` is loaded, and stored with a stride of
+        // 128 bytes the tile leaves the odd rows of `out` untouched.
+        let mut mat = [[0_u8; 64]; 32];
+        for (i, row) in mat.iter_mut().enumerate() {
+            *row = [i as u8; 64];
+        }
+        _tile_loadd::<1>(&mat as *const [u8; 64] as *const u8, 128);
+        let mut out = [[0xff_u8; 64]; 32];
+        _tile_stored::<1>(&mut out as *mut [u8; 64] as *mut u8, 128);
+        _tile_release();
+        for (i, row) in out.iter().enumerate() {
+            let e = if i % 2 == 0 { i as u8 } else { 0xff };
+            assert_eq!(*row, [e; 64]);
+        }
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_zero() {
+        init();
+        let ones = [[1_u8; 64]; 16];
+        _tile_loadd::<2>(&ones as *const [u8; 64] as *const u8, 64);
+        _tile_zero::<2>();
+        let mut out = [[1_u8; 64]; 16];
+        _tile_stored::<2>(&mut out as *mut [u8; 64] as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[0; 64]; 16]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_release() {
+        init();
+        _tile_release();
+        let mut stored = TileConfig::default();
+        stored.palette_id = 1;
+        _tile_storeconfig(&mut stored as *mut TileConfig as *mut u8);
+        assert_eq!(stored, TileConfig::default());
+    }
+
+    /// Configures tiles 0 to 2, zeroes tile 0 and loads the 16 rows of 64
+    /// bytes at `a` and `b` into tiles 1 and 2.
+    unsafe fn init_dp(a: *const u8, b: *const u8) {
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a, 64);
+        _tile_loadd::<2>(b, 64);
+    }
+
+    /// Stores the 32-bit elements of tile 0 and releases the tiles.
+    unsafe fn release_dp() -> [[u32; 16]; 16] {
+        let mut out = [[0_u32; 16]; 16];
+        _tile_stored::<0>(&mut out as *mut [u32; 16] as *mut u8, 64);
+        _tile_release();
+        out
+    }
+
+    // Each 32-bit element of tile 0 sums the 64 products of a row of bytes of
+    // tile 1 with a column of bytes of tile 2. Every byte is `0xff`, which
+    // is -1 when signed and 255 when unsigned.
+    static MAT: [[u8; 64]; 16] = [[0xff; 64]; 16];
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbssd() {
+        init_dp(MAT.as_ptr() as *const u8, MAT.as_ptr() as *const u8);
+        _tile_dpbssd::<0, 1, 2>();
+        assert_eq!(release_dp(), [[64; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbsud() {
+        init_dp(MAT.as_ptr() as *const u8, MAT.as_ptr() as *const u8);
+        _tile_dpbsud::<0, 1, 2>();
+        assert_eq!(release_dp(), [[(-64 * 255_i32) as u32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbusd() {
+        init_dp(MAT.as_ptr() as *const u8, MAT.as_ptr() as *const u8);
+        _tile_dpbusd::<0, 1, 2>();
+        assert_eq!(release_dp(), [[(-64 * 255_i32) as u32; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbuud() {
+        init_dp(MAT.as_ptr() as *const u8, MAT.as_ptr() as *const u8);
+        _tile_dpbuud::<0, 1, 2>();
+        assert_eq!(release_dp(), [[64 * 255 * 255; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-bf16")]
+    unsafe fn test_tile_dpbf16ps() {
+        // Each element sums the 32 products of 1.0 and 2.0.
+        let a = [[0x3f80_u16; 32]; 16];
+        let b = [[0x4000_u16; 32]; 16];
+        init_dp(a.as_ptr() as *const u8, b.as_ptr() as *const u8);
+        _tile_dpbf16ps::<0, 1, 2>();
+        assert_eq!(release_dp(), [[64.0_f32.to_bits(); 16]; 16]);
+    }
+}
@@ -0,0 +1,81 @@
+//! `x86_64`'s MMX intrinsics
+
+use crate::{core_arch::x86::*, mem::transmute};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Copies 64-bit integer `a` to the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsi64_m64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(windows)), assert_instr(movq))]
+pub unsafe fn _mm_cvtsi64_m64(a: i64) -> __m64 {
+    transmute(a)
+}
+
+/// Copies 64-bit integer `a` to the result.
+///
+/// This is an alias for [`_mm_cvtsi64_m64`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_from_int64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(windows)), assert_instr(movq))]
+pub unsafe fn _m_from_int64(a: i64) -> __m64 {
+    _mm_cvtsi64_m64(a)
+}
+
+/// Copies the 64 bits of `a` to a 64-bit integer.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtm64_si64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(windows)), assert_instr(movq))]
+pub unsafe fn _mm_cvtm64_si64(a: __m64) -> i64 {
+    transmute(a)
+}
+
+/// Copies the 64 bits of `a` to a 64-bit integer.
+///
+/// This is an alias for [`_mm_cvtm64_si64`].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_m_to_int64)
+#[inline]
+#[target_feature(enable = "mmx,sse2")]
+#[cfg_attr(all(test, not(windows)), assert_instr(movq))]
+pub unsafe fn _m_to_int64(a: __m64) -> i64 {
+    _mm_cvtm64_si64(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdarch_test::simd_test;
+
+    use crate::core_arch::{x86::*, x86_64::*};
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtsi64_m64() {
+        let r = _mm_cvtsi64_m64(0x0000_0002_FFFF_FFFF);
+        assert_eq_m64(r, _mm_setr_pi32(-1, 2));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_from_int64() {
+        let r = _m_from_int64(-2);
+        assert_eq_m64(r, _mm_setr_pi32(-2, -1));
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_mm_cvtm64_si64() {
+        let a = _mm_setr_pi32(-1, 2);
+        assert_eq!(_mm_cvtm64_si64(a), 0x0000_0002_FFFF_FFFF);
+    }
+
+    #[simd_test(enable = "mmx,sse2")]
+    unsafe fn test_m_to_int64() {
+        let a = _mm_setr_pi16(1, 2, 3, 4);
+        assert_eq!(_m_to_int64(a), 0x0004_0003_0002_0001);
+    }
+}
@@ -12,6 +12,9 @@ pub use self::sse::*;
 mod sse2;
 pub use self::sse2::*;
 
+mod mmx;
+pub use self::mmx::*;
+
 mod sse41;
 pub use self::sse41::*;
 
@@ -53,3 +56,6 @@ pub use self::adx::*;
 
 mod bt;
 pub use self::bt::*;
+
+mod amx;
+pub use self::amx::*;
@@ -145,4 +145,24 @@ features! {
     /// FEAT_SHA512 & FEAT_SHA3 (SHA2-512 & SHA3 instructions)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm4: "sm4";
     /// FEAT_SM3 & FEAT_SM4 (SM3 & SM4 instructions)
+    @IMPLIES: asimd => fp;
+    @IMPLIES: fp16 => fp;
+    @IMPLIES: fhm => fp16;
+    @IMPLIES: jsconv => fp;
+    @IMPLIES: rcpc2 => rcpc;
+    @IMPLIES: rdm => asimd;
+    @IMPLIES: dotprod => asimd;
+    @IMPLIES: fcma => asimd;
+    @IMPLIES: aes => asimd;
+    @IMPLIES: sha2 => asimd;
+    @IMPLIES: sha3 => sha2;
+    @IMPLIES: sm4 => asimd;
+    @IMPLIES: sve => asimd;
+    @IMPLIES: f32mm => sve;
+    @IMPLIES: f64mm => sve;
+    @IMPLIES: sve2 => sve;
+    @IMPLIES: sve2_aes => sve2, aes;
+    @IMPLIES: sve2_sm4 => sve2, sm4;
+    @IMPLIES: sve2_sha3 => sve2, sha3;
+    @IMPLIES: sve2_bitperm => sve2;
 }
@@ -22,4 +22,7 @@ features! {
     /// FEAT_AES (AES instructions)
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha2: "sha2";
     /// FEAT_SHA1 & FEAT_SHA256 (SHA1 & SHA2-256 instructions)
+    @IMPLIES: crypto => aes, sha2, pmull;
+    @IMPLIES: aes => neon;
+    @IMPLIES: sha2 => neon;
 }
@@ -12,4 +12,17 @@ features! {
     /// VSX
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8: "power8";
     /// Power8
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_vector: "power8-vector";
+    /// Power8 vector
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_crypto: "power8-crypto";
+    /// Power8 crypto
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9_altivec: "power9-altivec";
+    /// Power9 altivec
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9_vector: "power9-vector";
+    /// Power9 vector
+    @IMPLIES: vsx => altivec;
+    @IMPLIES: power8_vector => vsx;
+    @IMPLIES: power8_crypto => altivec;
+    @IMPLIES: power9_altivec => altivec;
+    @IMPLIES: power9_vector => power9_altivec, power8_vector;
 }
@@ -42,6 +42,9 @@ features! {
     ///
     /// * `"aes"`
     /// * `"pclmulqdq"`
+    /// * `"gfni"`
+    /// * `"vaes"`
+    /// * `"vpclmulqdq"`
     /// * `"rdrand"`
     /// * `"rdseed"`
     /// * `"tsc"`
@@ -74,6 +77,11 @@ features! {
     /// * `"avx512bitalg"`
     /// * `"avx512bf16"`
     /// * `"avx512vp2intersect"`
+    /// * `"avx512fp16"`
+    /// * `"avxvnni"`
+    /// * `"amx-tile"`
+    /// * `"amx-int8"`
+    /// * `"amx-bf16"`
     /// * `"f16c"`
     /// * `"fma"`
     /// * `"bmi1"`
@@ -90,6 +98,25 @@ features! {
     /// * `"cmpxchg16b"`
     /// * `"adx"`
     /// * `"rtm"`
+    /// * `"serialize"`
+    /// * `"tsxldtrk"`
+    /// * `"hreset"`
+    /// * `"movbe"`
+    /// * `"movdiri"`
+    /// * `"movdir64b"`
+    /// * `"cldemote"`
+    /// * `"waitpkg"`
+    /// * `"clflushopt"`
+    /// * `"clwb"`
+    /// * `"rdpid"`
+    /// * `"rdtscp"`
+    /// * `"sgx"`
+    ///
+    /// On Linux, the tile data of `"amx-tile"`, `"amx-int8"` and `"amx-bf16"`
+    /// can only be used once the kernel granted the process the permission to.
+    /// Detecting these features requests it with
+    /// `arch_prctl(ARCH_REQ_XCOMP_PERM)`, and reports them only if it is
+    /// granted. The permission applies to the whole process.
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
@@ -98,6 +125,12 @@ features! {
     /// AES (Advanced Encryption Standard New Instructions AES-NI)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] pclmulqdq: "pclmulqdq";
     /// CLMUL (Carry-less Multiplication)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] gfni: "gfni";
+    /// GFNI (Galois Field New Instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vaes: "vaes";
+    /// VAES (Vector AES instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vpclmulqdq: "vpclmulqdq";
+    /// VPCLMULQDQ (Vector carry-less multiplication of quadwords)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rdrand: "rdrand";
     /// RDRAND
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rdseed: "rdseed";
@@ -163,6 +196,18 @@ features! {
     /// AVX-512 BF16 (BFLOAT16 instructions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vp2intersect: "avx512vp2intersect";
     /// AVX-512 P2INTERSECT
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512fp16: "avx512fp16";
+    /// AVX-512 FP16 (FLOAT16 instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avxvnni: "avxvnni";
+    /// AVX-VNNI (VEX-encoded Vector Neural Network Instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] amx_tile: "amx-tile";
+    /// AMX-TILE (Advanced Matrix Extensions tile architecture)
+    ///
+    /// On Linux, only detected once the process is allowed to use the tile data.
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] amx_int8: "amx-int8";
+    /// AMX-INT8 (Advanced Matrix Extensions 8-bit integer operations)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] amx_bf16: "amx-bf16";
+    /// AMX-BF16 (Advanced Matrix Extensions BFLOAT16 operations)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] f16c: "f16c";
     /// F16C (Conversions between IEEE-754 `binary16` and `binary32` formats)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] fma: "fma";
@@ -193,4 +238,71 @@ features! {
     /// ADX, Intel ADX (Multi-Precision Add-Carry Instruction Extensions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rtm: "rtm";
     /// RTM, Intel (Restricted Transactional Memory)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] serialize: "serialize";
+    /// SERIALIZE (Serialize instruction execution)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] tsxldtrk: "tsxldtrk";
+    /// TSXLDTRK (TSX suspend load address tracking)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] hreset: "hreset";
+    /// HRESET (History reset)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] movbe: "movbe";
+    /// MOVBE (Move data after swapping bytes)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] movdiri: "movdiri";
+    /// MOVDIRI (Move doubleword as direct store)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] movdir64b: "movdir64b";
+    /// MOVDIR64B (Move 64 bytes as direct store)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] cldemote: "cldemote";
+    /// CLDEMOTE (Cache line demote)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] waitpkg: "waitpkg";
+    /// WAITPKG (UMONITOR, UMWAIT and TPAUSE)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] clflushopt: "clflushopt";
+    /// CLFLUSHOPT (Optimized cache line flush)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] clwb: "clwb";
+    /// CLWB (Cache line write back)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rdpid: "rdpid";
+    /// RDPID (Read processor ID)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rdtscp: "rdtscp";
+    /// RDTSCP (Read time-stamp counter and processor ID)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sgx: "sgx";
+    /// SGX (Software Guard Extensions)
+    @IMPLIES: sse2 => sse;
+    @IMPLIES: sse3 => sse2;
+    @IMPLIES: ssse3 => sse3;
+    @IMPLIES: sse4_1 => ssse3;
+    @IMPLIES: sse4_2 => sse4_1;
+    @IMPLIES: sse4a => sse3;
+    @IMPLIES: aes => sse2;
+    @IMPLIES: pclmulqdq => sse2;
+    @IMPLIES: sha => sse2;
+    @IMPLIES: gfni => sse2;
+    @IMPLIES: avx => sse4_2;
+    @IMPLIES: avx2 => avx;
+    @IMPLIES: fma => avx;
+    @IMPLIES: f16c => avx;
+    @IMPLIES: vaes => avx2, aes;
+    @IMPLIES: vpclmulqdq => avx, pclmulqdq;
+    @IMPLIES: avxvnni => avx2;
+    @IMPLIES: avx512f => avx2, fma, f16c;
+    @IMPLIES: avx512cd => avx512f;
+    @IMPLIES: avx512er => avx512f;
+    @IMPLIES: avx512pf => avx512f;
+    @IMPLIES: avx512bw => avx512f;
+    @IMPLIES: avx512dq => avx512f;
+    @IMPLIES: avx512vl => avx512f;
+    @IMPLIES: avx512ifma => avx512f;
+    @IMPLIES: avx512vbmi => avx512bw;
+    @IMPLIES: avx512vpopcntdq => avx512f;
+    @IMPLIES: avx512vbmi2 => avx512bw;
+    @IMPLIES: avx512gfni => avx512f, gfni;
+    @IMPLIES: avx512vaes => avx512f, vaes;
+    @IMPLIES: avx512vpclmulqdq => avx512f, vpclmulqdq;
+    @IMPLIES: avx512vnni => avx512f;
+    @IMPLIES: avx512bitalg => avx512bw;
+    @IMPLIES: avx512bf16 => avx512bw;
+    @IMPLIES: avx512vp2intersect => avx512f;
+    @IMPLIES: avx512fp16 => avx512bw, avx512dq, avx512vl;
+    @IMPLIES: amx_int8 => amx_tile;
+    @IMPLIES: amx_bf16 => amx_tile;
+    @IMPLIES: xsaveopt => xsave;
+    @IMPLIES: xsavec => xsave;
+    @IMPLIES: xsaves => xsave;
 }
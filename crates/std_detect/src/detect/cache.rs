@@ -7,35 +7,56 @@ use core::sync::atomic::Ordering;
 
 use core::sync::atomic::AtomicUsize;
 
+use super::Feature;
+
 /// Sets the `bit` of `x`.
 #[inline]
-const fn set_bit(x: u64, bit: u32) -> u64 {
+const fn set_bit(x: usize, bit: u32) -> usize {
     x | 1 << bit
 }
 
 /// Tests the `bit` of `x`.
 #[inline]
-const fn test_bit(x: u64, bit: u32) -> bool {
+const fn test_bit(x: usize, bit: u32) -> bool {
     x & (1 << bit) != 0
 }
 
 /// Unset the `bit of `x`.
 #[inline]
-const fn unset_bit(x: u64, bit: u32) -> u64 {
+const fn unset_bit(x: usize, bit: u32) -> usize {
     x & !(1 << bit)
 }
 
+/// Number of features that have to be cached.
+const FEATURE_COUNT: u32 = Feature::_last as u32;
+
+/// Number of `Cache` words needed to hold `FEATURE_COUNT` features.
+const CACHE_WORDS: usize = ((FEATURE_COUNT + Cache::CAPACITY - 1) / Cache::CAPACITY) as usize;
+
 /// Maximum number of features that can be cached.
-const CACHE_CAPACITY: u32 = 62;
+const CACHE_CAPACITY: u32 = CACHE_WORDS as u32 * Cache::CAPACITY;
+
+// Fails to compile if the `Feature` enum ever outgrows the cache.
+const _: () = assert!(
+    FEATURE_COUNT <= CACHE_CAPACITY,
+    "too many features, time to increase the cache size!"
+);
+
+/// Returns the index of the `Cache` word holding `bit`, and the position of
+/// `bit` inside that word.
+#[inline]
+const fn word_and_bit(bit: u32) -> (usize, u32) {
+    ((bit / Cache::CAPACITY) as usize, bit % Cache::CAPACITY)
+}
 
 /// This type is used to initialize the cache
-#[derive(Copy, Clone)]
-pub(crate) struct Initializer(u64);
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Initializer([usize; CACHE_WORDS]);
 
 #[allow(clippy::use_self)]
 impl Default for Initializer {
     fn default() -> Self {
-        Initializer(0)
+        Initializer([0; CACHE_WORDS])
     }
 }
 
@@ -49,7 +70,8 @@ impl Initializer {
             bit < CACHE_CAPACITY,
             "too many features, time to increase the cache size!"
         );
-        test_bit(self.0, bit)
+        let (idx, bit) = word_and_bit(bit);
+        test_bit(self.0[idx], bit)
     }
 
     /// Sets the `bit` of the cache.
@@ -59,8 +81,9 @@ impl Initializer {
             bit < CACHE_CAPACITY,
             "too many features, time to increase the cache size!"
         );
-        let v = self.0;
-        self.0 = set_bit(v, bit);
+        let (idx, bit) = word_and_bit(bit);
+        let v = self.0[idx];
+        self.0[idx] = set_bit(v, bit);
     }
 
     /// Unsets the `bit` of the cache.
@@ -70,14 +93,101 @@ impl Initializer {
             bit < CACHE_CAPACITY,
             "too many features, time to increase the cache size!"
         );
-        let v = self.0;
-        self.0 = unset_bit(v, bit);
+        let (idx, bit) = word_and_bit(bit);
+        let v = self.0[idx];
+        self.0[idx] = unset_bit(v, bit);
+    }
+
+    /// Returns the features that are set in both `self` and `other`.
+    #[inline]
+    pub(crate) fn intersection(mut self, other: Initializer) -> Initializer {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a &= *b;
+        }
+        self
+    }
+
+    /// Returns the features that are set in `self`, `other`, or both.
+    #[inline]
+    pub(crate) fn union(mut self, other: Initializer) -> Initializer {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a |= *b;
+        }
+        self
+    }
+
+    /// Is every feature set in `self` also set in `other`?
+    #[inline]
+    pub(crate) fn is_subset(self, other: Initializer) -> bool {
+        self.intersection(other) == self
+    }
+
+    /// Is no feature set?
+    #[inline]
+    pub(crate) fn is_empty(self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    /// Returns the features that are set in `self` but not in `other`.
+    #[inline]
+    pub(crate) fn difference(mut self, other: Initializer) -> Initializer {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a &= !*b;
+        }
+        self
+    }
+
+    /// Removes every feature that implies a feature missing from the set,
+    /// according to `Feature::IMPLICATIONS`.
+    ///
+    /// The resulting set is closed under implication: e.g. on x86 it never
+    /// contains `avx2` without `avx`, even if a hypervisor only masked the
+    /// CPUID bit of `avx`.
+    pub(crate) fn without_missing_implied(mut self) -> Initializer {
+        loop {
+            let mut changed = false;
+            for &(implying, implied) in Feature::IMPLICATIONS {
+                if self.test(implying as u32) && !self.test(implied as u32) {
+                    self.unset(implying as u32);
+                    changed = true;
+                }
+            }
+            if !changed {
+                return self;
+            }
+        }
+    }
+
+    /// Adds every feature that implies a feature of the set, according to
+    /// `Feature::IMPLICATIONS`.
+    ///
+    /// Disabling the resulting set never leaves a feature enabled while a
+    /// feature it implies is disabled.
+    pub(crate) fn with_implying(mut self) -> Initializer {
+        loop {
+            let mut changed = false;
+            for &(implying, implied) in Feature::IMPLICATIONS {
+                if self.test(implied as u32) && !self.test(implying as u32) {
+                    self.set(implying as u32);
+                    changed = true;
+                }
+            }
+            if !changed {
+                return self;
+            }
+        }
     }
 }
 
 /// This global variable is a cache of the features supported by the CPU.
-// Note: on x64, we only use the first slot
-static CACHE: [Cache; 2] = [Cache::uninitialized(), Cache::uninitialized()];
+///
+/// It holds as many words as needed to store `Feature::_last` features.
+static CACHE: [Cache; CACHE_WORDS] = [Cache::UNINITIALIZED; CACHE_WORDS];
+
+/// The features disabled with `disable`, closed under `with_implying`.
+///
+/// An uninitialized word does not disable any feature.
+static DISABLED: [Cache; CACHE_WORDS] = [Cache::UNINITIALIZED; CACHE_WORDS];
 
 /// Feature cache with capacity for `size_of::<usize::MAX>() * 8 - 1` features.
 ///
@@ -94,11 +204,9 @@ impl Cache {
     const MASK: usize = (1 << Cache::CAPACITY) - 1;
     const INITIALIZED_BIT: usize = 1usize << Cache::CAPACITY;
 
-    /// Creates an uninitialized cache.
+    /// An uninitialized cache, used to initialize the `CACHE` array.
     #[allow(clippy::declare_interior_mutable_const)]
-    const fn uninitialized() -> Self {
-        Cache(AtomicUsize::new(0))
-    }
+    const UNINITIALIZED: Cache = Cache(AtomicUsize::new(0));
 
     /// Is the `bit` in the cache set? Returns `None` if the cache has not been initialized.
     #[inline]
@@ -107,7 +215,19 @@ impl Cache {
         if cached == 0 {
             None
         } else {
-            Some(test_bit(cached as u64, bit))
+            Some(test_bit(cached, bit))
+        }
+    }
+
+    /// Returns the cached features, or `None` if the cache has not been
+    /// initialized.
+    #[inline]
+    fn load(&self) -> Option<usize> {
+        let cached = self.0.load(Ordering::Relaxed);
+        if cached == 0 {
+            None
+        } else {
+            Some(cached & Cache::MASK)
         }
     }
 
@@ -137,22 +257,31 @@ cfg_if::cfg_if! {
                     }
                 }
             }
-            do_initialize(value);
-            value
+            do_initialize(value)
         }
     } else {
         #[inline]
         fn initialize(value: Initializer) -> Initializer {
-            do_initialize(value);
-            value
+            do_initialize(value)
         }
     }
 }
 
+/// Stores `value`, minus the features disabled with `disable` and the
+/// features whose implied features are missing, in the cache, and returns the
+/// stored features.
+///
+/// All the features reported by `test`, `detected` and thus `features()` go
+/// through here, which keeps them closed under implication no matter whether
+/// a feature was missing from `os::detect_features()`, disabled by the
+/// environment override, or disabled with `disable`.
 #[inline]
-fn do_initialize(value: Initializer) {
-    CACHE[0].initialize((value.0) as usize & Cache::MASK);
-    CACHE[1].initialize((value.0 >> Cache::CAPACITY) as usize & Cache::MASK);
+fn do_initialize(value: Initializer) -> Initializer {
+    let value = value.difference(disabled()).without_missing_implied();
+    for (cache, &word) in CACHE.iter().zip(value.0.iter()) {
+        cache.initialize(word);
+    }
+    value
 }
 
 // We only have to detect features once, and it's fairly costly, so hint to LLVM
@@ -169,11 +298,50 @@ fn detect_and_initialize() -> Initializer {
     initialize(super::os::detect_features())
 }
 
+/// Returns all the features in the storage. If the storage has not been
+/// initialized, initializes it with the result of `os::detect_features()`.
+#[inline]
+pub(crate) fn detected() -> Initializer {
+    let mut value = Initializer::default();
+    for (word, cache) in value.0.iter_mut().zip(CACHE.iter()) {
+        match cache.load() {
+            Some(cached) => *word = cached,
+            None => return detect_and_initialize(),
+        }
+    }
+    value
+}
+
+/// Returns the features disabled with `disable`.
+#[inline]
+pub(crate) fn disabled() -> Initializer {
+    let mut value = Initializer::default();
+    for (word, disabled) in value.0.iter_mut().zip(DISABLED.iter()) {
+        *word = disabled.load().unwrap_or(0);
+    }
+    value
+}
+
+/// Disables `features`, and the features that imply them, replacing the
+/// features disabled by previous calls.
+///
+/// If the storage has already been initialized, the features are detected
+/// again and the storage is re-initialized without the disabled features.
+pub(crate) fn disable(features: Initializer) {
+    let features = features.with_implying();
+    for (disabled, &word) in DISABLED.iter().zip(features.0.iter()) {
+        disabled.initialize(word);
+    }
+    if CACHE.iter().any(|cache| cache.load().is_some()) {
+        detect_and_initialize();
+    }
+}
+
 /// Tests the `bit` of the storage. If the storage has not been initialized,
 /// initializes it with the result of `os::detect_features()`.
 ///
 /// On its first invocation, it detects the CPU features and caches them in the
-/// `CACHE` global variable as an `AtomicU64`.
+/// `CACHE` global variable as an array of `AtomicUsize`.
 ///
 /// It uses the `Feature` variant to index into this variable as a bitset. If
 /// the bit is set, the feature is enabled, and otherwise it is disabled.
@@ -181,14 +349,94 @@ fn detect_and_initialize() -> Initializer {
 /// If the feature `std_detect_env_override` is enabled looks for the env
 /// variable `RUST_STD_DETECT_UNSTABLE` and uses its its content to disable
 /// Features that would had been otherwise detected.
+///
+/// Features disabled with `disable` are never reported as enabled.
 #[inline]
 pub(crate) fn test(bit: u32) -> bool {
-    let (relative_bit, idx) = if bit < Cache::CAPACITY {
-        (bit, 0)
-    } else {
-        (bit - Cache::CAPACITY, 1)
-    };
+    let (idx, relative_bit) = word_and_bit(bit);
     CACHE[idx]
         .test(relative_bit)
         .unwrap_or_else(|| detect_and_initialize().test(bit))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_fits_all_features() {
+        assert!(CACHE_WORDS >= 1);
+        assert!(FEATURE_COUNT <= CACHE_CAPACITY);
+        assert!(CACHE_CAPACITY - FEATURE_COUNT < Cache::CAPACITY);
+    }
+
+    #[test]
+    fn initializer_words() {
+        let mut value = Initializer::default();
+        for bit in 0..CACHE_CAPACITY {
+            assert!(!value.test(bit));
+            value.set(bit);
+            assert!(value.test(bit));
+        }
+        for word in value.0.iter() {
+            assert_eq!(*word, Cache::MASK);
+        }
+        value.unset(Cache::CAPACITY - 1);
+        assert!(!value.test(Cache::CAPACITY - 1));
+        assert!(value.test(0));
+        assert_eq!(value.0[0], Cache::MASK >> 1);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn with_implying() {
+        let mut value = Initializer::default();
+        value.set(Feature::avx as u32);
+        let value = value.with_implying();
+        assert!(value.test(Feature::avx as u32));
+        assert!(value.test(Feature::avx2 as u32));
+        assert!(value.test(Feature::fma as u32));
+        assert!(value.test(Feature::avx512vbmi as u32));
+        assert!(value.test(Feature::avx512vaes as u32));
+        assert!(!value.test(Feature::sse4_2 as u32));
+        assert!(!value.test(Feature::aes as u32));
+        assert!(Initializer::default().with_implying().is_empty());
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn without_missing_implied() {
+        let mut value = Initializer::default();
+        for f in &[Feature::sse, Feature::sse2, Feature::sse3, Feature::ssse3] {
+            value.set(*f as u32);
+        }
+        // `sse4.2` without `sse4.1`, and `avx2` without `avx`:
+        value.set(Feature::sse4_2 as u32);
+        value.set(Feature::avx2 as u32);
+        value.set(Feature::avx512f as u32);
+        value.set(Feature::aes as u32);
+        let value = value.without_missing_implied();
+        assert!(value.test(Feature::ssse3 as u32));
+        assert!(value.test(Feature::aes as u32));
+        assert!(!value.test(Feature::sse4_2 as u32));
+        assert!(!value.test(Feature::avx2 as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+    }
+
+    #[test]
+    fn implications() {
+        let mut all = Initializer::default();
+        for bit in 0..FEATURE_COUNT {
+            all.set(bit);
+        }
+        assert!(all.without_missing_implied() == all);
+        for &(implying, implied) in Feature::IMPLICATIONS {
+            let mut value = Initializer::default();
+            value.set(implying as u32);
+            assert!(value.without_missing_implied().is_empty());
+            let mut value = Initializer::default();
+            value.set(implied as u32);
+            assert!(value.with_implying().test(implying as u32));
+        }
+    }
+}
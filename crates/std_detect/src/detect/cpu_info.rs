@@ -0,0 +1,160 @@
+//! Identification of the host CPU.
+
+use core::{fmt, str};
+
+/// Identifies the host CPU, as returned by [`cpu_info`](super::cpu_info).
+///
+/// Which parts of the identification are available depends on the
+/// architecture and the operating system:
+///
+/// - on x86, the vendor, family, model, stepping and brand string are read
+///   with the CPUID instruction,
+/// - on aarch64 Linux, the implementer, variant, part and revision are read
+///   from the `MIDR_EL1` register exposed by the kernel in
+///   `/sys/devices/system/cpu/cpu0/regs/identification/midr_el1`, or from
+///   `/proc/cpuinfo`.
+///
+/// The parts that are not available are `None`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuInfo {
+    pub(crate) vendor: Option<[u8; 12]>,
+    pub(crate) family: Option<u32>,
+    pub(crate) model: Option<u32>,
+    pub(crate) stepping: Option<u32>,
+    pub(crate) brand: Option<[u8; 48]>,
+    pub(crate) implementer: Option<u8>,
+    pub(crate) variant: Option<u8>,
+    pub(crate) part: Option<u16>,
+    pub(crate) revision: Option<u8>,
+}
+
+impl CpuInfo {
+    /// Decodes the content of the aarch64 `MIDR_EL1` register.
+    #[allow(dead_code)]
+    pub(crate) fn from_midr(midr: u64) -> Self {
+        CpuInfo {
+            implementer: Some((midr >> 24) as u8),
+            variant: Some((midr >> 20) as u8 & 0xf),
+            part: Some((midr >> 4) as u16 & 0xfff),
+            revision: Some(midr as u8 & 0xf),
+            ..CpuInfo::default()
+        }
+    }
+
+    /// Returns the x86 vendor ID, e.g. `"GenuineIntel"` or `"AuthenticAMD"`.
+    #[inline]
+    pub fn vendor(&self) -> Option<&str> {
+        self.vendor.as_ref().and_then(|v| str::from_utf8(v).ok())
+    }
+
+    /// Returns the x86 family, including the extended family.
+    #[inline]
+    pub fn family(&self) -> Option<u32> {
+        self.family
+    }
+
+    /// Returns the x86 model, including the extended model.
+    #[inline]
+    pub fn model(&self) -> Option<u32> {
+        self.model
+    }
+
+    /// Returns the x86 stepping.
+    #[inline]
+    pub fn stepping(&self) -> Option<u32> {
+        self.stepping
+    }
+
+    /// Returns the x86 brand string, e.g.
+    /// `"AMD Ryzen 9 5950X 16-Core Processor"`.
+    ///
+    /// Leading spaces and trailing NUL padding are removed. Returns `None` if
+    /// the CPU does not report a brand string.
+    #[inline]
+    pub fn brand(&self) -> Option<&str> {
+        let brand = self.brand.as_ref()?;
+        let len = brand.iter().position(|&b| b == 0).unwrap_or(brand.len());
+        str::from_utf8(&brand[..len])
+            .ok()
+            .map(str::trim)
+            .filter(|brand| !brand.is_empty())
+    }
+
+    /// Returns the aarch64 implementer code, e.g. `0x41` for Arm.
+    #[inline]
+    pub fn implementer(&self) -> Option<u8> {
+        self.implementer
+    }
+
+    /// Returns the aarch64 variant, that is, the major revision of the part.
+    #[inline]
+    pub fn variant(&self) -> Option<u8> {
+        self.variant
+    }
+
+    /// Returns the aarch64 part number, e.g. `0xd0c` for an Arm Neoverse N1.
+    #[inline]
+    pub fn part(&self) -> Option<u16> {
+        self.part
+    }
+
+    /// Returns the aarch64 revision, that is, the minor revision of the part.
+    #[inline]
+    pub fn revision(&self) -> Option<u8> {
+        self.revision
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for CpuInfo {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CpuInfo")
+            .field("vendor", &self.vendor())
+            .field("family", &self.family)
+            .field("model", &self.model)
+            .field("stepping", &self.stepping)
+            .field("brand", &self.brand())
+            .field("implementer", &self.implementer)
+            .field("variant", &self.variant)
+            .field("part", &self.part)
+            .field("revision", &self.revision)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_midr() {
+        // Arm Neoverse N1 r3p1:
+        let info = CpuInfo::from_midr(0x413f_d0c1);
+        assert_eq!(info.implementer(), Some(0x41));
+        assert_eq!(info.variant(), Some(3));
+        assert_eq!(info.part(), Some(0xd0c));
+        assert_eq!(info.revision(), Some(1));
+        assert_eq!(info.vendor(), None);
+        assert_eq!(info.family(), None);
+        assert_eq!(info.brand(), None);
+    }
+
+    #[test]
+    fn brand() {
+        let mut brand = [0; 48];
+        brand[..22].copy_from_slice(b"  Intel(R) Xeon(R) CPU");
+        let info = CpuInfo {
+            brand: Some(brand),
+            ..CpuInfo::default()
+        };
+        assert_eq!(info.brand(), Some("Intel(R) Xeon(R) CPU"));
+        assert_eq!(CpuInfo::default().brand(), None);
+        let info = CpuInfo {
+            brand: Some([0; 48]),
+            ..CpuInfo::default()
+        };
+        assert_eq!(info.brand(), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! A value type holding a set of run-time detectable features.
+
+use super::{cache, Feature};
+use core::{fmt, str};
+
+/// Number of bits in the [`FeatureSet::to_bitmask`] representation.
+const BITMASK_BITS: u32 = 4 * 64;
+
+// Fails to compile if the `Feature` enum ever outgrows the bitmask.
+const _: () = assert!(
+    Feature::_last as u32 <= BITMASK_BITS,
+    "too many features, time to increase the bitmask size!"
+);
+
+/// A set of the target features of the current architecture.
+///
+/// [`FeatureSet::detected`] returns a snapshot of the features detected on
+/// the host at run-time, that is, of the features for which the
+/// `is_{arch}_feature_detected!` macros return `true`.
+///
+/// Sets can be compared, hashed, intersected, and queried by feature name.
+/// They are serialized to, and parsed from, a comma-separated list of
+/// feature names with `Display` and `FromStr`, e.g. `"avx,avx2,fma"`. This
+/// representation is portable across versions of this crate. The
+/// [`to_bitmask`](FeatureSet::to_bitmask) representation is more compact,
+/// but bit positions may change between versions of this crate.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct FeatureSet(pub(crate) cache::Initializer);
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Default for FeatureSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeatureSet {
+    /// Returns an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        FeatureSet(cache::Initializer::default())
+    }
+
+    /// Returns the set of features detected on the host at run-time.
+    ///
+    /// The features are only detected once and cached, just as for the
+    /// `is_{arch}_feature_detected!` macros.
+    #[inline]
+    pub fn detected() -> Self {
+        FeatureSet(cache::detected())
+    }
+
+    /// Is the feature called `name` in the set?
+    ///
+    /// Returns `false` if `name` is not a feature of the current
+    /// architecture.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        Feature::from_str(name).map_or(false, |f| self.0.test(f as u32))
+    }
+
+    /// Adds the feature called `name` to the set.
+    ///
+    /// Returns an error if `name` is not a feature of the current
+    /// architecture.
+    #[inline]
+    pub fn insert(&mut self, name: &str) -> Result<(), ParseFeatureSetError> {
+        let f = Feature::from_str(name).map_err(|()| ParseFeatureSetError(()))?;
+        self.0.set(f as u32);
+        Ok(())
+    }
+
+    /// Removes the feature called `name` from the set.
+    ///
+    /// Returns `false` if `name` is not a feature of the current
+    /// architecture.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> bool {
+        match Feature::from_str(name) {
+            Ok(f) => {
+                self.0.unset(f as u32);
+                true
+            }
+            Err(()) => false,
+        }
+    }
+
+    /// Returns the features that are in both `self` and `other`.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        FeatureSet(self.0.intersection(other.0))
+    }
+
+    /// Returns the features that are in `self`, `other`, or both.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        FeatureSet(self.0.union(other.0))
+    }
+
+    /// Is every feature of `self` also in `other`?
+    ///
+    /// For example, code compiled for the features in `self` can run on a
+    /// host if `self.is_subset(&FeatureSet::detected())`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(other.0)
+    }
+
+    /// Is the set empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the names of the features in the set.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        Feature::ALL
+            .iter()
+            .filter(move |&&f| self.0.test(f as u32))
+            .map(|&f| f.to_str())
+    }
+
+    /// Returns the set as a bitmask, in which bit `i` of word `i / 64` is
+    /// set if the `i`-th feature of the current architecture is in the set.
+    ///
+    /// The bit positions are only meaningful for the version of this crate
+    /// that produced them; use the `Display` representation to exchange sets
+    /// with other programs.
+    #[inline]
+    pub fn to_bitmask(&self) -> [u64; 4] {
+        let mut bitmask = [0_u64; 4];
+        for bit in 0..Feature::_last as u32 {
+            if self.0.test(bit) {
+                bitmask[(bit / 64) as usize] |= 1 << (bit % 64);
+            }
+        }
+        bitmask
+    }
+
+    /// Creates a set from a bitmask returned by
+    /// [`to_bitmask`](FeatureSet::to_bitmask).
+    ///
+    /// Bits that do not correspond to a feature of the current architecture
+    /// are ignored.
+    #[inline]
+    pub fn from_bitmask(bitmask: [u64; 4]) -> Self {
+        let mut value = cache::Initializer::default();
+        for bit in 0..Feature::_last as u32 {
+            if bitmask[(bit / 64) as usize] & (1 << (bit % 64)) != 0 {
+                value.set(bit);
+            }
+        }
+        FeatureSet(value)
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for FeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for FeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl str::FromStr for FeatureSet {
+    type Err = ParseFeatureSetError;
+
+    /// Parses a comma-separated list of feature names, as produced by the
+    /// `Display` implementation. Whitespace around the names is ignored.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = FeatureSet::new();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            set.insert(name)?;
+        }
+        Ok(set)
+    }
+}
+
+/// The error returned when a feature name is not a feature of the current
+/// architecture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct ParseFeatureSetError(());
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for ParseFeatureSetError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown target feature")
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detected_matches_macros() {
+        let detected = FeatureSet::detected();
+        for (name, enabled) in super::super::features() {
+            assert_eq!(detected.contains(name), enabled, "{}", name);
+        }
+    }
+
+    #[test]
+    fn display_from_str_round_trip() {
+        let set: FeatureSet = "avx2, sse4.1,,avx".parse().unwrap();
+        assert!(set.contains("avx"));
+        assert!(set.contains("avx2"));
+        assert!(set.contains("sse4.1"));
+        assert!(!set.contains("sse4.2"));
+        assert_eq!(format!("{}", set), "sse4.1,avx,avx2");
+        assert_eq!(format!("{}", set).parse::<FeatureSet>(), Ok(set));
+        assert_eq!(format!("{:?}", set), r#"{"sse4.1", "avx", "avx2"}"#);
+        assert_eq!("".parse::<FeatureSet>(), Ok(FeatureSet::new()));
+        assert!("avx,neon".parse::<FeatureSet>().is_err());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: FeatureSet = "sse2,avx,avx2".parse().unwrap();
+        let b: FeatureSet = "avx,fma".parse().unwrap();
+        assert_eq!(a.intersection(&b), "avx".parse().unwrap());
+        assert_eq!(a.union(&b), "sse2,avx,avx2,fma".parse().unwrap());
+        assert!(a.intersection(&b).is_subset(&a));
+        assert!(!a.is_subset(&b));
+        assert!(FeatureSet::new().is_empty());
+        assert!(!a.is_empty());
+
+        let mut c = a;
+        assert!(c.remove("avx2"));
+        assert!(!c.remove("neon"));
+        assert_eq!(c, "sse2,avx".parse().unwrap());
+        assert_eq!(c.insert("neon"), Err(ParseFeatureSetError(())));
+    }
+
+    #[test]
+    fn all_features_in_order() {
+        assert_eq!(Feature::ALL.len(), Feature::_last as usize);
+        for (i, &f) in Feature::ALL.iter().enumerate() {
+            assert_eq!(f as usize, i);
+        }
+    }
+
+    #[test]
+    fn bitmask_round_trip() {
+        let set: FeatureSet = "sse2,avx512f,rdtscp".parse().unwrap();
+        assert_eq!(FeatureSet::from_bitmask(set.to_bitmask()), set);
+        assert_eq!(FeatureSet::new().to_bitmask(), [0; 4]);
+        assert_eq!(
+            FeatureSet::from_bitmask([!0; 4]).iter().count(),
+            Feature::_last as usize
+        );
+        let detected = FeatureSet::detected();
+        assert_eq!(FeatureSet::from_bitmask(detected.to_bitmask()), detected);
+    }
+}
@@ -7,6 +7,7 @@ macro_rules! features {
       $(@BIND_FEATURE_NAME: $bind_feature:tt; $feature_impl:tt; )*
       $(@NO_RUNTIME_DETECTION: $nort_feature:tt; )*
       $(@FEATURE: #[$stability_attr:meta] $feature:ident: $feature_lit:tt; $(#[$feature_comment:meta])*)*
+      $(@IMPLIES: $implying:ident => $($implied:ident),+; )*
     ) => {
         #[macro_export]
         $(#[$macro_attrs])*
@@ -70,13 +71,21 @@ macro_rules! features {
                     Feature::_last => unreachable!(),
                 }
             }
-            #[cfg(feature = "std_detect_env_override")]
             pub(crate) fn from_str(s: &str) -> Result<Feature, ()> {
                 match s {
                     $($feature_lit => Ok(Feature::$feature),)*
                     _ => Err(())
                 }
             }
+
+            /// All the features, in the order of their discriminants.
+            pub(crate) const ALL: &'static [Feature] = &[$(Feature::$feature,)*];
+
+            /// Pairs `(a, b)` of features such that `a` can only be available
+            /// if `b` is available too, e.g. `(avx2, avx)`.
+            pub(crate) const IMPLICATIONS: &'static [(Feature, Feature)] = &[
+                $($((Feature::$implying, Feature::$implied),)+)*
+            ];
         }
 
         /// Each function performs run-time feature detection for a single
@@ -60,7 +60,9 @@ cfg_if! {
         mod arch {
             #[doc(hidden)]
             pub(crate) enum Feature {
-                Null
+                Null,
+                // Do not add variants after last:
+                _last
             }
             #[doc(hidden)]
             pub mod __is_feature_detected {}
@@ -70,6 +72,8 @@ cfg_if! {
                 pub(crate) fn from_str(_s: &str) -> Result<Feature, ()> { Err(()) }
                 #[doc(hidden)]
                 pub(crate) fn to_str(self) -> &'static str { "" }
+                #[doc(hidden)]
+                pub(crate) const IMPLICATIONS: &'static [(Feature, Feature)] = &[];
             }
         }
     }
@@ -84,6 +88,13 @@ pub(crate) use self::arch::Feature;
 
 mod bit;
 mod cache;
+mod cpu_info;
+mod feature_set;
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::cpu_info::CpuInfo;
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::feature_set::{FeatureSet, ParseFeatureSetError};
 
 cfg_if! {
     if #[cfg(miri)] {
@@ -123,6 +134,42 @@ fn check_for(x: Feature) -> bool {
     cache::test(x as u32)
 }
 
+/// Disables the run-time detection of `features`, and of every feature that
+/// implies one of them.
+///
+/// Afterwards, the `is_{arch}_feature_detected!` macros, [`features`] and
+/// [`FeatureSet::detected`] report these features as not available, even if
+/// the host supports them. For example, disabling `avx` on x86 also disables
+/// `avx2`, `fma` and the `avx512*` features. This is useful to exercise
+/// fallback code paths in tests.
+///
+/// This can be called before or after the features are detected for the
+/// first time. Each call replaces the features disabled by the previous one,
+/// so `disable_features(&FeatureSet::new())` enables all the detected
+/// features again.
+///
+/// The disabled features are global to the process. Threads that query
+/// features while this function runs observe either the previous or the new
+/// set of disabled features for each feature.
+///
+/// Features that are enabled at compile-time, e.g. with
+/// `-C target-feature=+avx2`, are still reported as available: the
+/// `is_{arch}_feature_detected!` macros do not perform any run-time detection
+/// for them.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn disable_features(features: &FeatureSet) {
+    cache::disable(features.0)
+}
+
+/// Returns the features disabled with [`disable_features`], including the
+/// features that imply them.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn disabled_features() -> FeatureSet {
+    FeatureSet(cache::disabled())
+}
+
 /// Returns an `Iterator<Item=(&'static str, bool)>` where
 /// `Item.0` is the feature name, and `Item.1` is a `bool` which
 /// is `true` if the feature is supported by the host and `false` otherwise.
@@ -150,3 +197,27 @@ pub fn features() -> impl Iterator<Item = (&'static str, bool)> {
         }
     }
 }
+
+/// Returns the identification of the host CPU.
+///
+/// Unlike feature detection, this is not cached: each call queries the CPU,
+/// or reads the files exposed by the operating system, again.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn cpu_info() -> CpuInfo {
+    cfg_if! {
+        if #[cfg(miri)] {
+            CpuInfo::default()
+        } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+            os::cpu_info()
+        } else if #[cfg(all(
+            target_os = "linux",
+            target_arch = "aarch64",
+            feature = "libc",
+        ))] {
+            os::cpu_info()
+        } else {
+            CpuInfo::default()
+        }
+    }
+}
@@ -12,9 +12,22 @@ pub(crate) fn detect_features() -> cache::Initializer {
     };
 
     if let Ok(auxv) = auxvec::auxv() {
-        enable_feature(&mut value, Feature::altivec, auxv.hwcap & 0x10000000 != 0);
-        enable_feature(&mut value, Feature::vsx, auxv.hwcap & 0x00000080 != 0);
-        enable_feature(&mut value, Feature::power8, auxv.hwcap2 & 0x80000000 != 0);
+        let altivec = auxv.hwcap & 0x10000000 != 0;
+        let vsx = auxv.hwcap & 0x00000080 != 0;
+        let arch_2_07 = auxv.hwcap2 & 0x80000000 != 0;
+        let arch_3_00 = auxv.hwcap2 & 0x00800000 != 0;
+        let vec_crypto = auxv.hwcap2 & 0x02000000 != 0;
+
+        enable_feature(&mut value, Feature::altivec, altivec);
+        enable_feature(&mut value, Feature::vsx, vsx);
+        enable_feature(&mut value, Feature::power8, arch_2_07);
+
+        // The vector extensions of ISA 2.07 (POWER8) and 3.00 (POWER9) are
+        // only usable if the base vector unit is available as well.
+        enable_feature(&mut value, Feature::power8_vector, arch_2_07 && vsx);
+        enable_feature(&mut value, Feature::power8_crypto, vec_crypto && altivec);
+        enable_feature(&mut value, Feature::power9_altivec, arch_3_00 && altivec);
+        enable_feature(&mut value, Feature::power9_vector, arch_3_00 && vsx);
         return value;
     }
     value
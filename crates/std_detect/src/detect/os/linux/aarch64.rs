@@ -1,7 +1,7 @@
 //! Run-time feature detection for Aarch64 on Linux.
 
 use super::auxvec;
-use crate::detect::{bit, cache, Feature};
+use crate::detect::{bit, cache, CpuInfo, Feature};
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
 /// to read them from /proc/cpuinfo.
@@ -18,6 +18,34 @@ pub(crate) fn detect_features() -> cache::Initializer {
     cache::Initializer::default()
 }
 
+/// Try to read the identification of the CPU from the `MIDR_EL1` register
+/// exposed in sysfs, and if that fails, from /proc/cpuinfo.
+pub(crate) fn cpu_info() -> CpuInfo {
+    #[cfg(feature = "std_detect_file_io")]
+    {
+        if let Ok(midr) =
+            super::read_file("/sys/devices/system/cpu/cpu0/regs/identification/midr_el1")
+        {
+            let midr = core::str::from_utf8(&midr)
+                .ok()
+                .and_then(|midr| super::cpuinfo::CpuInfoField::new(Some(midr)).parse_int());
+            if let Some(midr) = midr {
+                return CpuInfo::from_midr(midr);
+            }
+        }
+        if let Ok(c) = super::cpuinfo::CpuInfo::new() {
+            return CpuInfo {
+                implementer: c.field("CPU implementer").parse_int().map(|v| v as u8),
+                variant: c.field("CPU variant").parse_int().map(|v| v as u8),
+                part: c.field("CPU part").parse_int().map(|v| v as u16),
+                revision: c.field("CPU revision").parse_int().map(|v| v as u8),
+                ..CpuInfo::default()
+            };
+        }
+    }
+    CpuInfo::default()
+}
+
 /// These values are part of the platform-specific [asm/hwcap.h][hwcap] .
 ///
 /// The names match those used for cpuinfo.
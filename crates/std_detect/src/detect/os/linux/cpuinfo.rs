@@ -65,6 +65,15 @@ impl<'a> CpuInfoField<'a> {
     pub(crate) fn exists(&self) -> bool {
         self.0.is_some()
     }
+    /// Parses the field as an integer, which is hexadecimal if it starts
+    /// with `0x`.
+    pub(crate) fn parse_int(&self) -> Option<u64> {
+        let f = self.0?;
+        match f.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => f.parse().ok(),
+        }
+    }
     /// Does the field contain `other`?
     pub(crate) fn has(&self, other: &str) -> bool {
         match self.0 {
@@ -182,6 +191,11 @@ power management:
         assert!(cpuinfo.field("Features").has("pmull"));
         assert!(!cpuinfo.field("Features").has("neon"));
         assert!(cpuinfo.field("Features").has("asimd"));
+        assert_eq!(cpuinfo.field("CPU implementer").parse_int(), Some(0x41));
+        assert_eq!(cpuinfo.field("CPU part").parse_int(), Some(0xd03));
+        assert_eq!(cpuinfo.field("CPU revision").parse_int(), Some(3));
+        assert_eq!(cpuinfo.field("CPU architecture").parse_int(), None);
+        assert_eq!(cpuinfo.field("Hardware2").parse_int(), None);
     }
 
     const ARM_CORTEX_A57: &str = r"Processor	: Cortex A57 Processor rev 1 (aarch64)
@@ -41,7 +41,7 @@ fn read_file(path: &str) -> Result<Vec<u8>, ()> {
 cfg_if::cfg_if! {
     if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
-        pub(crate) use self::aarch64::detect_features;
+        pub(crate) use self::aarch64::{cpu_info, detect_features};
     } else if #[cfg(target_arch = "arm")] {
         mod arm;
         pub(crate) use self::arm::detect_features;
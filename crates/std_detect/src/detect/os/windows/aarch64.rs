@@ -19,6 +19,18 @@ pub(crate) fn detect_features() -> cache::Initializer {
         pub fn IsProcessorFeaturePresent(ProcessorFeature: DWORD) -> BOOL;
     }
 
+    // We require an unsafe block for the extern "system" calls.
+    unsafe {
+        features(
+            IsProcessorFeaturePresent(PF_ARM_NEON_INSTRUCTIONS_AVAILABLE) != FALSE,
+            IsProcessorFeaturePresent(PF_ARM_V8_CRC32_INSTRUCTIONS_AVAILABLE) != FALSE,
+            IsProcessorFeaturePresent(PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE) != FALSE,
+        )
+    }
+}
+
+/// Initializes the cache from the processor features reported by Windows.
+fn features(neon: bool, crc32: bool, crypto: bool) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     {
         let mut enable_feature = |f, enable| {
@@ -27,33 +39,42 @@ pub(crate) fn detect_features() -> cache::Initializer {
             }
         };
 
-        // Some features such Feature::fp may be supported on current CPU,
-        // but no way to detect it by OS API.
-        // Also, we require unsafe block for the extern "system" calls.
-        unsafe {
-            enable_feature(
-                Feature::asimd,
-                IsProcessorFeaturePresent(PF_ARM_NEON_INSTRUCTIONS_AVAILABLE) != FALSE,
-            );
-            enable_feature(
-                Feature::crc,
-                IsProcessorFeaturePresent(PF_ARM_V8_CRC32_INSTRUCTIONS_AVAILABLE) != FALSE,
-            );
-            // PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE means aes, sha1, sha2 and
-            // pmull support
-            enable_feature(
-                Feature::aes,
-                IsProcessorFeaturePresent(PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE) != FALSE,
-            );
-            enable_feature(
-                Feature::pmull,
-                IsProcessorFeaturePresent(PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE) != FALSE,
-            );
-            enable_feature(
-                Feature::sha2,
-                IsProcessorFeaturePresent(PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE) != FALSE,
-            );
-        }
+        // There is no processor feature for floating-point support, but the
+        // Armv8-A Advanced SIMD extension requires it.
+        enable_feature(Feature::fp, neon);
+        enable_feature(Feature::asimd, neon);
+        enable_feature(Feature::crc, crc32);
+        // PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE means aes, sha1, sha2 and
+        // pmull support
+        enable_feature(Feature::aes, crypto);
+        enable_feature(Feature::pmull, crypto);
+        enable_feature(Feature::sha2, crypto);
     }
     value
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_under_implication() {
+        for &neon in &[false, true] {
+            for &crc32 in &[false, true] {
+                let value = features(neon, crc32, neon);
+                assert!(value.without_missing_implied() == value);
+            }
+        }
+        let value = features(true, true, true).without_missing_implied();
+        for &f in &[
+            Feature::fp,
+            Feature::asimd,
+            Feature::crc,
+            Feature::aes,
+            Feature::pmull,
+            Feature::sha2,
+        ] {
+            assert!(value.test(f as u32), "{}", f.to_str());
+        }
+    }
+}
@@ -7,7 +7,7 @@ use core::arch::x86_64::*;
 
 use core::mem;
 
-use crate::detect::{bit, cache, Feature};
+use crate::detect::{bit, cache, CpuInfo, Feature};
 
 /// Run-time feature detection on x86 works by using the CPUID instruction.
 ///
@@ -24,40 +24,178 @@ use crate::detect::{bit, cache, Feature};
 /// [wiki_cpuid]: https://en.wikipedia.org/wiki/CPUID
 /// [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
 /// [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
-#[allow(clippy::similar_names)]
 pub(crate) fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-
     // If the x86 CPU does not support the CPUID instruction then it is too
     // old to support any of the currently-detectable features.
     if !has_cpuid() {
-        return value;
+        return cache::Initializer::default();
     }
 
-    // Calling `__cpuid`/`__cpuid_count` from here on is safe because the CPU
-    // has `cpuid` support.
+    // Safe because the CPU has `cpuid` support.
+    decode_features(unsafe { &Cpu::new() })
+}
 
-    // 0. EAX = 0: Basic Information:
+/// Returns the highest basic leaf and the vendor ID of `cpu`.
+fn vendor_id(cpu: &impl CpuidProvider) -> (u32, [u8; 12]) {
+    // EAX = 0: Basic Information:
     // - EAX returns the "Highest Function Parameter", that is, the maximum
     // leaf value for subsequent calls of `cpuinfo` in range [0,
     // 0x8000_0000]. - The vendor ID is stored in 12 u8 ascii chars,
     // returned in EBX, EDX, and   ECX (in that order):
-    let (max_basic_leaf, vendor_id) = unsafe {
-        let CpuidResult {
-            eax: max_basic_leaf,
-            ebx,
-            ecx,
-            edx,
-        } = __cpuid(0);
-        let vendor_id: [[u8; 4]; 3] = [
-            mem::transmute(ebx),
-            mem::transmute(edx),
-            mem::transmute(ecx),
-        ];
-        let vendor_id: [u8; 12] = mem::transmute(vendor_id);
-        (max_basic_leaf, vendor_id)
+    let CpuidResult {
+        eax: max_basic_leaf,
+        ebx,
+        ecx,
+        edx,
+    } = cpu.cpuid(0, 0);
+    let vendor_id: [[u8; 4]; 3] = [ebx.to_ne_bytes(), edx.to_ne_bytes(), ecx.to_ne_bytes()];
+    let vendor_id: [u8; 12] = unsafe { mem::transmute(vendor_id) };
+    (max_basic_leaf, vendor_id)
+}
+
+/// Returns the identification of the host CPU.
+pub(crate) fn cpu_info() -> CpuInfo {
+    if !has_cpuid() {
+        return CpuInfo::default();
+    }
+    // Safe because the CPU has `cpuid` support.
+    decode_cpu_info(unsafe { &Cpu::new() })
+}
+
+/// Decodes the identification of the CPU from the results of the CPUID
+/// instruction returned by `cpu`.
+fn decode_cpu_info(cpu: &impl CpuidProvider) -> CpuInfo {
+    let (max_basic_leaf, vendor_id) = vendor_id(cpu);
+    let mut info = CpuInfo {
+        vendor: Some(vendor_id),
+        ..CpuInfo::default()
     };
 
+    // EAX = 1: EAX contains the stepping in bits [3:0], the model in bits
+    // [7:4], the family in bits [11:8], the extended model in bits [19:16],
+    // and the extended family in bits [27:20]. The extended family is only
+    // used by family 0xF, and the extended model by families 0x6 and 0xF.
+    if max_basic_leaf >= 1 {
+        let eax = cpu.cpuid(0x0000_0001_u32, 0).eax;
+        let base_family = (eax >> 8) & 0xf;
+        let mut family = base_family;
+        let mut model = (eax >> 4) & 0xf;
+        if base_family == 0xf {
+            family += (eax >> 20) & 0xff;
+        }
+        if base_family == 0x6 || base_family == 0xf {
+            model |= (eax >> 12) & 0xf0;
+        }
+        info.family = Some(family);
+        info.model = Some(model);
+        info.stepping = Some(eax & 0xf);
+    }
+
+    // EAX = 0x8000_0002..=0x8000_0004: each leaf contains 16 bytes of the
+    // 48 bytes of the brand string in EAX, EBX, ECX and EDX (in that order).
+    if cpu.cpuid(0x8000_0000_u32, 0).eax >= 0x8000_0004_u32 {
+        let mut brand = [0_u8; 48];
+        for (i, chunk) in brand.chunks_exact_mut(16).enumerate() {
+            let CpuidResult { eax, ebx, ecx, edx } = cpu.cpuid(0x8000_0002_u32 + i as u32, 0);
+            for (j, reg) in [eax, ebx, ecx, edx].iter().enumerate() {
+                chunk[j * 4..j * 4 + 4].copy_from_slice(&reg.to_ne_bytes());
+            }
+        }
+        info.brand = Some(brand);
+    }
+
+    info
+}
+
+/// Provides the results of the CPUID and XGETBV instructions.
+///
+/// Decoding the features from a provider, instead of executing the
+/// instructions directly, allows testing the decoding against recorded
+/// CPUID dumps.
+trait CpuidProvider {
+    /// Returns the result of executing CPUID with `EAX = leaf` and
+    /// `ECX = sub_leaf`.
+    fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult;
+
+    /// Returns the content of the extended control register `xcr`.
+    ///
+    /// # Safety
+    ///
+    /// The `OSXSAVE` bit must have been detected.
+    unsafe fn xgetbv(&self, xcr: u32) -> u64;
+
+    /// Requests permission to use the AMX tile data state, and returns
+    /// whether it is granted.
+    ///
+    /// This is only called once `XCR0` reports that the OS manages the AMX
+    /// state.
+    fn request_amx_permission(&self) -> bool;
+}
+
+/// The CPU that this program is running on.
+struct Cpu(());
+
+impl Cpu {
+    /// # Safety
+    ///
+    /// The CPU must support the CPUID instruction, that is `has_cpuid()` must
+    /// have returned `true`.
+    unsafe fn new() -> Self {
+        Cpu(())
+    }
+}
+
+impl CpuidProvider for Cpu {
+    #[inline]
+    fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+        // Safe because a `Cpu` is only created once `cpuid` support has been
+        // detected.
+        unsafe { __cpuid_count(leaf, sub_leaf) }
+    }
+
+    #[inline]
+    unsafe fn xgetbv(&self, xcr: u32) -> u64 {
+        _xgetbv(xcr)
+    }
+
+    fn request_amx_permission(&self) -> bool {
+        cfg_if::cfg_if! {
+            if #[cfg(all(target_os = "linux", target_arch = "x86_64", feature = "libc"))] {
+                // Linux enables the AMX state in `XCR0`, but the tile data is
+                // too large to be saved for every process, so the first use
+                // of it raises `SIGILL` unless the process asked for it with
+                // `arch_prctl(ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)`. The
+                // permission is process-wide and requesting it again once it
+                // has been granted succeeds.
+                //
+                // See https://docs.kernel.org/arch/x86/xstate.html
+                const ARCH_REQ_XCOMP_PERM: libc::c_ulong = 0x1023;
+                const XFEATURE_XTILEDATA: libc::c_ulong = 18;
+                let ret = unsafe {
+                    libc::syscall(libc::SYS_arch_prctl, ARCH_REQ_XCOMP_PERM, XFEATURE_XTILEDATA)
+                };
+                ret == 0
+            } else if #[cfg(target_os = "linux")] {
+                // The permission cannot be requested without `libc`, and
+                // the tiles are not usable outside of 64-bit mode anyway.
+                false
+            } else {
+                // Other operating systems grant the permission to every
+                // process that `XCR0` allows to use the AMX state.
+                true
+            }
+        }
+    }
+}
+
+/// Decodes the features from the results of the CPUID and XGETBV
+/// instructions returned by `cpu`.
+#[allow(clippy::similar_names)]
+fn decode_features(cpu: &impl CpuidProvider) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+
+    let (max_basic_leaf, vendor_id) = vendor_id(cpu);
+
     if max_basic_leaf < 1 {
         // Earlier Intel 486, CPUID not implemented
         return value;
@@ -69,15 +207,28 @@ pub(crate) fn detect_features() -> cache::Initializer {
         ecx: proc_info_ecx,
         edx: proc_info_edx,
         ..
-    } = unsafe { __cpuid(0x0000_0001_u32) };
+    } = cpu.cpuid(0x0000_0001_u32, 0);
 
     // EAX = 7, ECX = 0: Queries "Extended Features";
     // Contains information about bmi,bmi2, and avx2 support.
-    let (extended_features_ebx, extended_features_ecx) = if max_basic_leaf >= 7 {
-        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid(0x0000_0007_u32) };
-        (ebx, ecx)
+    // EAX = 7, ECX = 1: Queries "Extended Features" sub-leaf 1, which is only
+    // available if EAX of sub-leaf 0 (the highest sub-leaf) is at least 1;
+    // contains information about avxvnni and avx512bf16 support.
+    let (
+        extended_features_ebx,
+        extended_features_ecx,
+        extended_features_edx,
+        extended_features_eax_leaf_1,
+    ) = if max_basic_leaf >= 7 {
+        let CpuidResult { eax, ebx, ecx, edx } = cpu.cpuid(0x0000_0007_u32, 0);
+        let eax_leaf_1 = if eax >= 1 {
+            cpu.cpuid(0x0000_0007_u32, 1).eax
+        } else {
+            0
+        };
+        (ebx, ecx, edx, eax_leaf_1)
     } else {
-        (0, 0) // CPUID does not support "Extended Features"
+        (0, 0, 0, 0) // CPUID does not support "Extended Features"
     };
 
     // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
@@ -86,15 +237,15 @@ pub(crate) fn detect_features() -> cache::Initializer {
     let CpuidResult {
         eax: extended_max_basic_leaf,
         ..
-    } = unsafe { __cpuid(0x8000_0000_u32) };
+    } = cpu.cpuid(0x8000_0000_u32, 0);
 
     // EAX = 0x8000_0001, ECX=0: Queries "Extended Processor Info and Feature
     // Bits"
-    let extended_proc_info_ecx = if extended_max_basic_leaf >= 1 {
-        let CpuidResult { ecx, .. } = unsafe { __cpuid(0x8000_0001_u32) };
-        ecx
+    let (extended_proc_info_ecx, extended_proc_info_edx) = if extended_max_basic_leaf >= 1 {
+        let CpuidResult { ecx, edx, .. } = cpu.cpuid(0x8000_0001_u32, 0);
+        (ecx, edx)
     } else {
-        0
+        (0, 0)
     };
 
     {
@@ -113,7 +264,11 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable(proc_info_ecx, 20, Feature::sse4_2);
         enable(proc_info_ecx, 23, Feature::popcnt);
         enable(proc_info_ecx, 25, Feature::aes);
+        // GFNI also has a legacy SSE encoding, which only needs the SSE
+        // state that every x86 OS saves:
+        enable(extended_features_ecx, 8, Feature::gfni);
         enable(proc_info_ecx, 29, Feature::f16c);
+        enable(proc_info_ecx, 22, Feature::movbe);
         enable(proc_info_ecx, 30, Feature::rdrand);
         enable(extended_features_ebx, 18, Feature::rdseed);
         enable(extended_features_ebx, 19, Feature::adx);
@@ -128,6 +283,19 @@ pub(crate) fn detect_features() -> cache::Initializer {
         enable(extended_features_ebx, 3, Feature::bmi1);
         enable(extended_features_ebx, 8, Feature::bmi2);
 
+        enable(extended_features_ebx, 2, Feature::sgx);
+        enable(extended_features_ebx, 23, Feature::clflushopt);
+        enable(extended_features_ebx, 24, Feature::clwb);
+        enable(extended_features_ecx, 5, Feature::waitpkg);
+        enable(extended_features_ecx, 22, Feature::rdpid);
+        enable(extended_features_ecx, 25, Feature::cldemote);
+        enable(extended_features_ecx, 27, Feature::movdiri);
+        enable(extended_features_ecx, 28, Feature::movdir64b);
+        enable(extended_features_edx, 14, Feature::serialize);
+        enable(extended_features_edx, 16, Feature::tsxldtrk);
+        enable(extended_features_eax_leaf_1, 22, Feature::hreset);
+        enable(extended_proc_info_edx, 27, Feature::rdtscp);
+
         // `XSAVE` and `AVX` support:
         let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
         if cpu_xsave {
@@ -151,16 +319,20 @@ pub(crate) fn detect_features() -> cache::Initializer {
                 // * SSE -> `XCR0.SSE[1]`
                 // * AVX -> `XCR0.AVX[2]`
                 // * AVX-512 -> `XCR0.AVX-512[7:5]`.
+                // * AMX -> `XCR0.AMX[18:17]`.
                 //
                 // by setting the corresponding bits of `XCR0` to `1`.
                 //
                 // This is safe because the CPU supports `xsave`
                 // and the OS has set `osxsave`.
-                let xcr0 = unsafe { _xgetbv(0) };
+                let xcr0 = unsafe { cpu.xgetbv(0) };
                 // Test `XCR0.SSE[1]` and `XCR0.AVX[2]` with the mask `0b110 == 6`:
                 let os_avx_support = xcr0 & 6 == 6;
                 // Test `XCR0.AVX-512[7:5]` with the mask `0b1110_0000 == 224`:
                 let os_avx512_support = xcr0 & 224 == 224;
+                // Test `XCR0.XTILECFG[17]` and `XCR0.XTILEDATA[18]` with the
+                // mask `0b110 << 16 == 0x6_0000`:
+                let os_amx_support = xcr0 & 0x6_0000 == 0x6_0000;
 
                 // Only if the OS and the CPU support saving/restoring the AVX
                 // registers we enable `xsave` support:
@@ -184,7 +356,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         let CpuidResult {
                             eax: proc_extended_state1_eax,
                             ..
-                        } = unsafe { __cpuid_count(0xd_u32, 1) };
+                        } = cpu.cpuid(0xd_u32, 1);
                         enable(proc_extended_state1_eax, 0, Feature::xsaveopt);
                         enable(proc_extended_state1_eax, 1, Feature::xsavec);
                         enable(proc_extended_state1_eax, 3, Feature::xsaves);
@@ -196,6 +368,12 @@ pub(crate) fn detect_features() -> cache::Initializer {
                     // And AVX/AVX2:
                     enable(proc_info_ecx, 28, Feature::avx);
                     enable(extended_features_ebx, 5, Feature::avx2);
+                    enable(extended_features_eax_leaf_1, 4, Feature::avxvnni);
+
+                    // VAES and VPCLMULQDQ have VEX-encoded 256-bit forms that
+                    // do not need AVX-512:
+                    enable(extended_features_ecx, 9, Feature::vaes);
+                    enable(extended_features_ecx, 10, Feature::vpclmulqdq);
 
                     // For AVX-512 the OS also needs to support saving/restoring
                     // the extended state, only then we enable AVX-512 support:
@@ -209,17 +387,27 @@ pub(crate) fn detect_features() -> cache::Initializer {
                         enable(extended_features_ebx, 30, Feature::avx512bw);
                         enable(extended_features_ebx, 31, Feature::avx512vl);
                         enable(extended_features_ecx, 1, Feature::avx512vbmi);
-                        enable(extended_features_ecx, 5, Feature::avx512bf16);
                         enable(extended_features_ecx, 6, Feature::avx512vbmi2);
                         enable(extended_features_ecx, 8, Feature::avx512gfni);
-                        enable(extended_features_ecx, 8, Feature::avx512vp2intersect);
                         enable(extended_features_ecx, 9, Feature::avx512vaes);
                         enable(extended_features_ecx, 10, Feature::avx512vpclmulqdq);
                         enable(extended_features_ecx, 11, Feature::avx512vnni);
                         enable(extended_features_ecx, 12, Feature::avx512bitalg);
                         enable(extended_features_ecx, 14, Feature::avx512vpopcntdq);
+                        enable(extended_features_edx, 8, Feature::avx512vp2intersect);
+                        enable(extended_features_edx, 23, Feature::avx512fp16);
+                        enable(extended_features_eax_leaf_1, 5, Feature::avx512bf16);
                     }
                 }
+
+                // AMX tiles are only usable if the OS saves and restores the
+                // tile configuration and the tile data, and allows this
+                // process to use them:
+                if os_amx_support && cpu.request_amx_permission() {
+                    enable(extended_features_edx, 24, Feature::amx_tile);
+                    enable(extended_features_edx, 25, Feature::amx_int8);
+                    enable(extended_features_edx, 22, Feature::amx_bf16);
+                }
             }
         }
 
@@ -251,3 +439,294 @@ pub(crate) fn detect_features() -> cache::Initializer {
 
     value
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::detect::FeatureSet;
+    use core::cell::RefCell;
+    use std::{fs, vec::Vec};
+
+    /// A recorded CPUID dump from `test_data/`: the `[eax, ebx, ecx, edx]`
+    /// results of the `(leaf, sub_leaf)` queries, the content of `XCR0`, and
+    /// the features that must be detected from the dump. Leaves that are not
+    /// part of the dump return zeros.
+    ///
+    /// Each file contains one `leaf sub-leaf eax ebx ecx edx` line per
+    /// recorded query, an `xcr0` line, and a `features` line with the
+    /// comma-separated features that must be detected.
+    ///
+    /// Every query is recorded in `queried`, so that tests can check that a
+    /// leaf is not queried at all.
+    struct Dump {
+        leaves: Vec<((u32, u32), [u32; 4])>,
+        xcr0: u64,
+        amx_permission: bool,
+        features: FeatureSet,
+        queried: RefCell<Vec<(u32, u32)>>,
+    }
+
+    impl CpuidProvider for Dump {
+        fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+            self.queried.borrow_mut().push((leaf, sub_leaf));
+            let [eax, ebx, ecx, edx] = self
+                .leaves
+                .iter()
+                .find(|(l, _)| *l == (leaf, sub_leaf))
+                .map_or([0; 4], |(_, r)| *r);
+            CpuidResult { eax, ebx, ecx, edx }
+        }
+
+        unsafe fn xgetbv(&self, xcr: u32) -> u64 {
+            assert_eq!(xcr, 0);
+            self.xcr0
+        }
+
+        fn request_amx_permission(&self) -> bool {
+            self.amx_permission
+        }
+    }
+
+    fn parse_hex(s: &str) -> u64 {
+        u64::from_str_radix(s.trim_start_matches("0x"), 16).unwrap()
+    }
+
+    impl Dump {
+        fn load(name: &str) -> Self {
+            let file = format!(
+                "{}/src/detect/test_data/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                name
+            );
+            let contents = fs::read_to_string(&file).unwrap();
+            let mut leaves = Vec::new();
+            let mut xcr0 = None;
+            let mut features = None;
+            for line in contents.lines().map(str::trim) {
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let mut words = line.splitn(2, ' ');
+                match (words.next().unwrap(), words.next().unwrap_or("")) {
+                    ("xcr0", v) => xcr0 = Some(parse_hex(v)),
+                    ("features", v) => features = Some(v.parse::<FeatureSet>().unwrap()),
+                    (leaf, rest) => {
+                        let r: Vec<u32> = rest
+                            .split_whitespace()
+                            .map(|w| parse_hex(w) as u32)
+                            .collect();
+                        assert_eq!(r.len(), 5, "{}: {}", name, line);
+                        leaves.push(((parse_hex(leaf) as u32, r[0]), [r[1], r[2], r[3], r[4]]));
+                    }
+                }
+            }
+            Dump {
+                leaves,
+                xcr0: xcr0.unwrap(),
+                amx_permission: true,
+                features: features.unwrap(),
+                queried: RefCell::new(Vec::new()),
+            }
+        }
+
+        /// Replaces the recorded `XCR0`, to test an OS that does not enable
+        /// all the state components the CPU supports.
+        fn with_xcr0(mut self, xcr0: u64) -> Self {
+            self.xcr0 = xcr0;
+            self
+        }
+
+        /// Makes the OS refuse the permission to use the AMX tile data.
+        fn without_amx_permission(mut self) -> Self {
+            self.amx_permission = false;
+            self
+        }
+
+        fn decode(&self) -> cache::Initializer {
+            decode_features(self)
+        }
+
+        fn was_queried(&self, leaf: u32, sub_leaf: u32) -> bool {
+            self.queried.borrow().contains(&(leaf, sub_leaf))
+        }
+    }
+
+    /// The CPUID dumps in `test_data/`.
+    const CORPUS: &[&str] = &[
+        "x86-skylake-x.cpuid",
+        "x86-ice-lake.cpuid",
+        "x86-tiger-lake.cpuid",
+        "x86-sapphire-rapids.cpuid",
+        "x86-zen2.cpuid",
+        "x86-zen3.cpuid",
+        "x86-zen4.cpuid",
+        "x86-hygon-dhyana.cpuid",
+        "x86-vm-avx-masked.cpuid",
+    ];
+
+    #[test]
+    fn corpus() {
+        for name in CORPUS {
+            let dump = Dump::load(name);
+            let detected = FeatureSet(dump.decode().without_missing_implied());
+            assert_eq!(detected, dump.features, "{}: detected `{}`", name, detected);
+        }
+    }
+
+    #[test]
+    fn sapphire_rapids() {
+        let value = Dump::load("x86-sapphire-rapids.cpuid").decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx512f));
+        assert!(has(Feature::avx512fp16));
+        assert!(has(Feature::avx512bf16));
+        assert!(has(Feature::avx512gfni));
+        assert!(has(Feature::gfni));
+        assert!(has(Feature::vaes));
+        assert!(has(Feature::vpclmulqdq));
+        assert!(has(Feature::avxvnni));
+        assert!(has(Feature::amx_tile));
+        assert!(has(Feature::amx_int8));
+        assert!(has(Feature::amx_bf16));
+        assert!(has(Feature::serialize));
+        assert!(has(Feature::tsxldtrk));
+        assert!(has(Feature::movbe));
+        assert!(has(Feature::movdiri));
+        assert!(has(Feature::movdir64b));
+        assert!(has(Feature::cldemote));
+        assert!(has(Feature::waitpkg));
+        assert!(has(Feature::clflushopt));
+        assert!(has(Feature::clwb));
+        assert!(has(Feature::rdpid));
+        assert!(has(Feature::rdtscp));
+        assert!(has(Feature::sgx));
+        // GFNI (leaf 7 ECX bit 8) must not be mistaken for VP2INTERSECT
+        // (leaf 7 EDX bit 8):
+        assert!(!has(Feature::avx512vp2intersect));
+        assert!(!has(Feature::hreset));
+        assert!(!has(Feature::sse4a));
+    }
+
+    #[test]
+    fn sapphire_rapids_without_os_amx_support() {
+        let value = Dump::load("x86-sapphire-rapids.cpuid")
+            .with_xcr0(0x2e7)
+            .decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx512fp16));
+        assert!(has(Feature::avxvnni));
+        assert!(!has(Feature::amx_tile));
+        assert!(!has(Feature::amx_int8));
+        assert!(!has(Feature::amx_bf16));
+    }
+
+    #[test]
+    fn sapphire_rapids_without_amx_permission() {
+        let value = Dump::load("x86-sapphire-rapids.cpuid")
+            .without_amx_permission()
+            .decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx512fp16));
+        assert!(has(Feature::avx512bf16));
+        assert!(!has(Feature::amx_tile));
+        assert!(!has(Feature::amx_int8));
+        assert!(!has(Feature::amx_bf16));
+    }
+
+    #[test]
+    fn sapphire_rapids_without_os_avx512_support() {
+        let value = Dump::load("x86-sapphire-rapids.cpuid")
+            .with_xcr0(0x7)
+            .decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx2));
+        assert!(has(Feature::avxvnni));
+        assert!(has(Feature::gfni));
+        assert!(has(Feature::vaes));
+        assert!(has(Feature::vpclmulqdq));
+        assert!(!has(Feature::avx512f));
+        assert!(!has(Feature::avx512gfni));
+        assert!(!has(Feature::avx512vaes));
+        assert!(!has(Feature::avx512fp16));
+        assert!(!has(Feature::avx512bf16));
+        assert!(!has(Feature::amx_tile));
+    }
+
+    #[test]
+    fn sapphire_rapids_without_os_avx_support() {
+        let value = Dump::load("x86-sapphire-rapids.cpuid")
+            .with_xcr0(0x3)
+            .decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::sse4_2));
+        assert!(has(Feature::gfni));
+        assert!(!has(Feature::avx));
+        assert!(!has(Feature::vaes));
+        assert!(!has(Feature::vpclmulqdq));
+    }
+
+    #[test]
+    fn tiger_lake() {
+        let dump = Dump::load("x86-tiger-lake.cpuid");
+        let value = dump.decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx512f));
+        assert!(has(Feature::avx512vp2intersect));
+        assert!(has(Feature::avx512gfni));
+        assert!(has(Feature::movdiri));
+        assert!(has(Feature::movdir64b));
+        assert!(has(Feature::rdpid));
+        // Sub-leaf 1 is not available, so it must not be queried:
+        assert!(dump.was_queried(7, 0));
+        assert!(!dump.was_queried(7, 1));
+        assert!(!has(Feature::avx512bf16));
+        assert!(!has(Feature::avxvnni));
+        assert!(!has(Feature::avx512fp16));
+        assert!(!has(Feature::amx_tile));
+        assert!(!has(Feature::serialize));
+    }
+
+    #[test]
+    fn zen_3() {
+        let value = Dump::load("x86-zen3.cpuid").decode();
+        let has = |f: Feature| value.test(f as u32);
+        assert!(has(Feature::avx2));
+        assert!(has(Feature::sse4a));
+        assert!(has(Feature::lzcnt));
+        assert!(has(Feature::vaes));
+        assert!(has(Feature::vpclmulqdq));
+        assert!(has(Feature::movbe));
+        assert!(has(Feature::clwb));
+        assert!(has(Feature::rdpid));
+        assert!(has(Feature::rdtscp));
+        assert!(!has(Feature::tbm));
+        assert!(!has(Feature::avx512f));
+        assert!(!has(Feature::avx512vp2intersect));
+        assert!(!has(Feature::avx512vaes));
+        assert!(!has(Feature::gfni));
+        assert!(!has(Feature::sgx));
+        assert!(!has(Feature::waitpkg));
+    }
+
+    #[test]
+    fn cpu_info() {
+        let zen_3 = Dump::load("x86-zen3.cpuid");
+        let info = decode_cpu_info(&zen_3);
+        assert_eq!(info.vendor(), Some("AuthenticAMD"));
+        assert_eq!(info.family(), Some(0x19));
+        assert_eq!(info.model(), Some(0x21));
+        assert_eq!(info.stepping(), Some(0));
+        assert_eq!(info.brand(), Some("AMD Ryzen 9 5950X 16-Core Processor"));
+        assert_eq!(info.part(), None);
+
+        // The brand string leaves are not part of the dump, so they are zero:
+        let sapphire_rapids = Dump::load("x86-sapphire-rapids.cpuid");
+        let info = decode_cpu_info(&sapphire_rapids);
+        assert_eq!(info.vendor(), Some("GenuineIntel"));
+        assert_eq!(info.family(), Some(6));
+        assert_eq!(info.model(), Some(0x8f));
+        assert_eq!(info.stepping(), Some(8));
+        assert_eq!(info.brand(), None);
+    }
+}
@@ -19,6 +19,11 @@ fn all() {
     }
 }
 
+#[test]
+fn cpu_info() {
+    println!("{:?}", std_detect::detect::cpu_info());
+}
+
 #[test]
 #[cfg(all(target_arch = "arm", any(target_os = "linux", target_os = "android")))]
 fn arm_linux() {
@@ -88,6 +93,22 @@ fn powerpc_linux() {
     println!("altivec: {}", is_powerpc_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc_feature_detected!("power8"));
+    println!(
+        "power8-vector: {}",
+        is_powerpc_feature_detected!("power8-vector")
+    );
+    println!(
+        "power8-crypto: {}",
+        is_powerpc_feature_detected!("power8-crypto")
+    );
+    println!(
+        "power9-altivec: {}",
+        is_powerpc_feature_detected!("power9-altivec")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc_feature_detected!("power9-vector")
+    );
 }
 
 #[test]
@@ -96,6 +117,22 @@ fn powerpc64_linux() {
     println!("altivec: {}", is_powerpc64_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc64_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc64_feature_detected!("power8"));
+    println!(
+        "power8-vector: {}",
+        is_powerpc64_feature_detected!("power8-vector")
+    );
+    println!(
+        "power8-crypto: {}",
+        is_powerpc64_feature_detected!("power8-crypto")
+    );
+    println!(
+        "power9-altivec: {}",
+        is_powerpc64_feature_detected!("power9-altivec")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc64_feature_detected!("power9-vector")
+    );
 }
 
 #[test]
@@ -103,6 +140,9 @@ fn powerpc64_linux() {
 fn x86_all() {
     println!("aes: {:?}", is_x86_feature_detected!("aes"));
     println!("pcmulqdq: {:?}", is_x86_feature_detected!("pclmulqdq"));
+    println!("gfni: {:?}", is_x86_feature_detected!("gfni"));
+    println!("vaes: {:?}", is_x86_feature_detected!("vaes"));
+    println!("vpclmulqdq: {:?}", is_x86_feature_detected!("vpclmulqdq"));
     println!("rdrand: {:?}", is_x86_feature_detected!("rdrand"));
     println!("rdseed: {:?}", is_x86_feature_detected!("rdseed"));
     println!("tsc: {:?}", is_x86_feature_detected!("tsc"));
@@ -147,6 +187,11 @@ fn x86_all() {
         "avx512vp2intersect {:?}",
         is_x86_feature_detected!("avx512vp2intersect")
     );
+    println!("avx512fp16 {:?}", is_x86_feature_detected!("avx512fp16"));
+    println!("avxvnni {:?}", is_x86_feature_detected!("avxvnni"));
+    println!("amx-tile {:?}", is_x86_feature_detected!("amx-tile"));
+    println!("amx-int8 {:?}", is_x86_feature_detected!("amx-int8"));
+    println!("amx-bf16 {:?}", is_x86_feature_detected!("amx-bf16"));
     println!("f16c: {:?}", is_x86_feature_detected!("f16c"));
     println!("fma: {:?}", is_x86_feature_detected!("fma"));
     println!("bmi1: {:?}", is_x86_feature_detected!("bmi1"));
@@ -160,4 +205,17 @@ fn x86_all() {
     println!("xsaveopt: {:?}", is_x86_feature_detected!("xsaveopt"));
     println!("xsaves: {:?}", is_x86_feature_detected!("xsaves"));
     println!("xsavec: {:?}", is_x86_feature_detected!("xsavec"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("tsxldtrk: {:?}", is_x86_feature_detected!("tsxldtrk"));
+    println!("hreset: {:?}", is_x86_feature_detected!("hreset"));
+    println!("movbe: {:?}", is_x86_feature_detected!("movbe"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("movdir64b: {:?}", is_x86_feature_detected!("movdir64b"));
+    println!("cldemote: {:?}", is_x86_feature_detected!("cldemote"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("rdtscp: {:?}", is_x86_feature_detected!("rdtscp"));
+    println!("sgx: {:?}", is_x86_feature_detected!("sgx"));
 }
@@ -0,0 +1,57 @@
+#![feature(stdsimd)]
+#![allow(clippy::unwrap_used)]
+#![cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+
+#[macro_use]
+extern crate std_detect;
+
+use std_detect::detect::{disable_features, disabled_features, features, FeatureSet};
+
+// Disabling features changes the process-wide state, so everything is tested
+// sequentially from a single test.
+#[test]
+fn disable_features_before_and_after_detection() {
+    // Before the features are detected for the first time:
+    disable_features(&"sse4.2".parse().unwrap());
+    let disabled = disabled_features();
+    for name in &[
+        "sse4.2", "avx", "avx2", "fma", "avx512f", "avx512bw", "vaes",
+    ] {
+        assert!(disabled.contains(name), "{}", name);
+    }
+    assert!(!disabled.contains("sse4.1"));
+    assert!(!disabled.contains("aes"));
+
+    let detected = FeatureSet::detected();
+    assert!(detected.intersection(&disabled).is_empty());
+    assert!(!is_x86_feature_detected!("avx") || cfg!(target_feature = "avx"));
+    for (name, enabled) in features() {
+        assert_eq!(detected.contains(name), enabled, "{}", name);
+    }
+
+    // Re-enabling all features after detection:
+    disable_features(&FeatureSet::new());
+    assert!(disabled_features().is_empty());
+    let all = FeatureSet::detected();
+    assert!(detected.is_subset(&all));
+
+    // Disabling a feature after detection:
+    disable_features(&"avx".parse().unwrap());
+    let masked = FeatureSet::detected();
+    assert!(!masked.contains("avx"));
+    assert!(!masked.contains("avx2"));
+    assert!(!masked.contains("fma"));
+    assert!(!masked.contains("avx512f"));
+    assert_eq!(masked.contains("sse4.2"), all.contains("sse4.2"));
+    assert_eq!(masked.union(&disabled_features()).intersection(&all), all);
+    if !cfg!(target_feature = "avx2") {
+        assert!(!is_x86_feature_detected!("avx2"));
+    }
+
+    disable_features(&FeatureSet::new());
+    assert_eq!(FeatureSet::detected(), all);
+    assert_eq!(
+        is_x86_feature_detected!("avx2"),
+        all.contains("avx2") || cfg!(target_feature = "avx2")
+    );
+}
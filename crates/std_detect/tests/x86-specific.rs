@@ -9,6 +9,9 @@ extern crate std_detect;
 fn dump() {
     println!("aes: {:?}", is_x86_feature_detected!("aes"));
     println!("pclmulqdq: {:?}", is_x86_feature_detected!("pclmulqdq"));
+    println!("gfni: {:?}", is_x86_feature_detected!("gfni"));
+    println!("vaes: {:?}", is_x86_feature_detected!("vaes"));
+    println!("vpclmulqdq: {:?}", is_x86_feature_detected!("vpclmulqdq"));
     println!("rdrand: {:?}", is_x86_feature_detected!("rdrand"));
     println!("rdseed: {:?}", is_x86_feature_detected!("rdseed"));
     println!("tsc: {:?}", is_x86_feature_detected!("tsc"));
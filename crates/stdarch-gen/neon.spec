@@ -47,6 +47,24 @@
 // b       - Second input for tests, it gets scaled to the size of
 //           the type.
 //
+// load_fn - The intrinsic loads a tuple type (such as `int8x8x2_t`)
+//           from memory, generated as `*const i8:int8x8x2_t` or
+//           `*const i8:int8x8x2_t:int8x8x2_t` for the lane variants.
+//           `a` is the memory loaded from, starting at its second
+//           element. `validate` is the expected tuple, for the `dup`
+//           and `lane` variants it only lists the loaded elements
+//           which are then broadcast or inserted into lane `n` of `b`.
+//
+// store_fn - The intrinsic stores a tuple type to memory, generated
+//           as `*mut i8:int8x8x2_t:void`. The tuple is loaded from `a`
+//           with the matching full load and `validate` is the memory
+//           expected after the store, padded with zeros.
+//
+// link-arm/link-aarch64 of loads and stores take `_EXTpi8_`
+// (`v8i8.p0i8`), `_EXTpi8r_` (`p0i8.v8i8`), `_EXTv2_` (`v8i8.p0v8i8`)
+// and `_EXTpe_` (`v8i8.p0i8` with the element pointer type)
+// placeholders, derived from the vector type of the tuple.
+//
 // # special values
 //
 // TRUE - 'true' all bits are set to 1
@@ -926,6 +944,48 @@ validate -1.2, 1.2, -2.3f64 as f32, 3.4f64 as f32
 aarch64 = fcvtn
 generate float32x2_t:float64x2_t:float32x4_t
 
+/// Floating-point convert to higher precision long
+name = vcvt
+noq-double-suffixes
+a = 0x3C00, 0x4000, 0xC200, 0x2E66
+validate 1., 2., -3., 0.0999755859375
+
+aarch64 = fcvtl
+link-aarch64 = vcvthf2fp
+generate float16x4_t:float32x4_t
+
+/// Floating-point convert to higher precision long
+name = vcvt_high
+noq-double-suffixes
+multi_fn = simd_shuffle4!, b:float16x4_t, a, a, [4, 5, 6, 7]
+multi_fn = vcvt_f32_f16, b
+a = 0x3C00, 0x4000, 0xC200, 0x2E66, 0x4400, 0x4500, 0x4600, 0x4700
+validate 4., 5., 6., 7.
+
+aarch64 = fcvtl
+generate float16x8_t:float32x4_t
+
+/// Floating-point convert to lower precision narrow
+name = vcvt
+noq-double-suffixes
+a = 1., 2., -3., 0.1
+validate 0x3C00, 0x4000, 0xC200, 0x2E66
+
+aarch64 = fcvtn
+link-aarch64 = vcvtfp2hf
+generate float32x4_t:float16x4_t
+
+/// Floating-point convert to lower precision narrow
+name = vcvt_high
+noq-double-suffixes
+multi_fn = simd_shuffle8!, a, {vcvt_f16_f32, b}, [0, 1, 2, 3, 4, 5, 6, 7]
+a = 0x3C00, 0x4000, 0xC200, 0x2E66
+b = 4., 5., 6., 7.
+validate 0x3C00, 0x4000, 0xC200, 0x2E66, 0x4400, 0x4500, 0x4600, 0x4700
+
+aarch64 = fcvtn
+generate float16x4_t:float32x4_t:float16x8_t
+
 /// Floating-point convert to lower precision narrow, rounding to odd
 name = vcvtx
 double-suffixes
@@ -1345,6 +1405,1207 @@ generate float64x2_t
 arm = "vext.8"
 generate float*_t
 
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-nox
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+validate 1, 2, 2, 3, 2, 3, 4, 5, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17
+load_fn
+
+aarch64 = ld2
+link-aarch64 = ld2._EXTv2_
+generate *const i64:int64x2x2_t
+
+arm = vld2
+link-arm = vld2._EXTpi8_
+generate *const i8:int8x8x2_t, *const i16:int16x4x2_t, *const i32:int32x2x2_t
+generate *const i8:int8x16x2_t, *const i16:int16x8x2_t, *const i32:int32x4x2_t
+arm = nop
+aarch64 = nop
+generate *const i64:int64x1x2_t
+
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-nox
+multi_fn = transmute, {vld2-outsignednox-noext, transmute(a)}
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+validate 1, 2, 2, 3, 2, 3, 4, 5, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17
+load_fn
+
+aarch64 = ld2
+generate *const u64:uint64x2x2_t
+target = crypto
+generate *const p64:poly64x2x2_t
+
+target = default
+arm = vld2
+generate *const u8:uint8x8x2_t, *const u16:uint16x4x2_t, *const u32:uint32x2x2_t
+generate *const u8:uint8x16x2_t, *const u16:uint16x8x2_t, *const u32:uint32x4x2_t
+generate *const p8:poly8x8x2_t, *const p16:poly16x4x2_t, *const p8:poly8x16x2_t, *const p16:poly16x8x2_t
+arm = nop
+aarch64 = nop
+generate *const u64:uint64x1x2_t
+target = crypto
+generate *const p64:poly64x1x2_t
+
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-nox
+a = 0., 1., 2., 2., 3., 2., 4., 3., 5., 2., 6., 3., 7., 4., 8., 5., 9., 2., 10., 3., 11., 4., 12., 5., 13., 6., 14., 7., 15., 8., 16., 9., 17.
+validate 1., 2., 2., 3., 2., 3., 4., 5., 2., 3., 4., 5., 6., 7., 8., 9., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16., 17.
+load_fn
+
+aarch64 = nop
+link-aarch64 = ld2._EXTv2_
+generate *const f64:float64x1x2_t
+aarch64 = ld2
+generate *const f64:float64x2x2_t
+
+arm = vld2
+link-arm = vld2._EXTpi8_
+generate *const f32:float32x2x2_t, *const f32:float32x4x2_t
+
+/// Load single 2-element structure and replicate to all lanes of two registers
+name = vld2
+out-dup-nox
+a = 0, 1, 2
+validate 1, 2
+load_fn
+
+aarch64 = ld2r
+link-aarch64 = ld2r._EXTpe_
+generate *const i64:int64x2x2_t
+
+arm = vld2
+link-arm = vld2dup._EXTpi8_
+generate *const i8:int8x8x2_t, *const i16:int16x4x2_t, *const i32:int32x2x2_t
+generate *const i8:int8x16x2_t, *const i16:int16x8x2_t, *const i32:int32x4x2_t
+arm = nop
+generate *const i64:int64x1x2_t
+
+/// Load single 2-element structure and replicate to all lanes of two registers
+name = vld2
+out-dup-nox
+multi_fn = transmute, {vld2-outsigneddupnox-noext, transmute(a)}
+a = 0, 1, 2
+validate 1, 2
+load_fn
+
+aarch64 = ld2r
+generate *const u64:uint64x2x2_t
+target = crypto
+generate *const p64:poly64x2x2_t
+
+target = default
+arm = vld2
+generate *const u8:uint8x8x2_t, *const u16:uint16x4x2_t, *const u32:uint32x2x2_t
+generate *const u8:uint8x16x2_t, *const u16:uint16x8x2_t, *const u32:uint32x4x2_t
+generate *const p8:poly8x8x2_t, *const p16:poly16x4x2_t, *const p8:poly8x16x2_t, *const p16:poly16x8x2_t
+arm = nop
+generate *const u64:uint64x1x2_t
+target = crypto
+generate *const p64:poly64x1x2_t
+
+/// Load single 2-element structure and replicate to all lanes of two registers
+name = vld2
+out-dup-nox
+a = 0., 1., 2.
+validate 1., 2.
+load_fn
+
+aarch64 = ld2r
+link-aarch64 = ld2r._EXTpe_
+generate *const f64:float64x1x2_t, *const f64:float64x2x2_t
+
+arm = vld2
+link-arm = vld2dup._EXTpi8_
+generate *const f32:float32x2x2_t, *const f32:float32x4x2_t
+
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41
+n = HFLEN
+validate 1, 2
+load_fn
+
+aarch64 = ld2
+link-aarch64 = ld2lane._EXTpi8_
+generate *const i8:int8x16x2_t:int8x16x2_t, *const i64:int64x1x2_t:int64x1x2_t, *const i64:int64x2x2_t:int64x2x2_t
+
+arm = vld2
+link-arm = vld2lane._EXTpi8_
+generate *const i8:int8x8x2_t:int8x8x2_t, *const i16:int16x4x2_t:int16x4x2_t, *const i32:int32x2x2_t:int32x2x2_t
+generate *const i16:int16x8x2_t:int16x8x2_t, *const i32:int32x4x2_t:int32x4x2_t
+
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = transmute, {vld2-outsignedlanenox-::<LANE>, transmute(a), transmute(b)}
+a = 0, 1, 2
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41
+n = HFLEN
+validate 1, 2
+load_fn
+
+aarch64 = ld2
+generate *const u8:uint8x16x2_t:uint8x16x2_t, *const u64:uint64x1x2_t:uint64x1x2_t, *const u64:uint64x2x2_t:uint64x2x2_t, *const p8:poly8x16x2_t:poly8x16x2_t
+target = crypto
+generate *const p64:poly64x1x2_t:poly64x1x2_t, *const p64:poly64x2x2_t:poly64x2x2_t
+
+target = default
+arm = vld2
+generate *const u8:uint8x8x2_t:uint8x8x2_t, *const u16:uint16x4x2_t:uint16x4x2_t, *const u32:uint32x2x2_t:uint32x2x2_t
+generate *const u16:uint16x8x2_t:uint16x8x2_t, *const u32:uint32x4x2_t:uint32x4x2_t
+generate *const p8:poly8x8x2_t:poly8x8x2_t, *const p16:poly16x4x2_t:poly16x4x2_t, *const p16:poly16x8x2_t:poly16x8x2_t
+
+/// Load multiple 2-element structures to two registers
+name = vld2
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2.
+b = 10., 11., 12., 13., 14., 15., 16., 17., 18., 19., 20., 21., 22., 23., 24., 25., 26., 27., 28., 29., 30., 31., 32., 33., 34., 35., 36., 37., 38., 39., 40., 41.
+n = HFLEN
+validate 1., 2.
+load_fn
+
+aarch64 = ld2
+link-aarch64 = ld2lane._EXTpi8_
+generate *const f64:float64x1x2_t:float64x1x2_t, *const f64:float64x2x2_t:float64x2x2_t
+
+arm = vld2
+link-arm = vld2lane._EXTpi8_
+generate *const f32:float32x2x2_t:float32x2x2_t, *const f32:float32x4x2_t:float32x4x2_t
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-nox
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+validate 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+store_fn
+
+aarch64 = st2
+link-aarch64 = st2._EXTpi8_
+generate *mut i64:int64x2x2_t:void
+
+arm = vst2
+link-arm = vst2._EXTpi8r_
+generate *mut i8:int8x8x2_t:void, *mut i16:int16x4x2_t:void, *mut i32:int32x2x2_t:void
+generate *mut i8:int8x16x2_t:void, *mut i16:int16x8x2_t:void, *mut i32:int32x4x2_t:void
+arm = nop
+aarch64 = nop
+generate *mut i64:int64x1x2_t:void
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-nox
+multi_fn = vst2-in1signednox-noext, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+validate 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+store_fn
+
+aarch64 = st2
+generate *mut u64:uint64x2x2_t:void
+target = crypto
+generate *mut p64:poly64x2x2_t:void
+
+target = default
+arm = vst2
+generate *mut u8:uint8x8x2_t:void, *mut u16:uint16x4x2_t:void, *mut u32:uint32x2x2_t:void
+generate *mut u8:uint8x16x2_t:void, *mut u16:uint16x8x2_t:void, *mut u32:uint32x4x2_t:void
+generate *mut p8:poly8x8x2_t:void, *mut p16:poly16x4x2_t:void, *mut p8:poly8x16x2_t:void, *mut p16:poly16x8x2_t:void
+arm = nop
+aarch64 = nop
+generate *mut u64:uint64x1x2_t:void
+target = crypto
+generate *mut p64:poly64x1x2_t:void
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-nox
+a = 0., 1., 2., 2., 3., 2., 4., 3., 5., 2., 6., 3., 7., 4., 8., 5., 9., 2., 10., 3., 11., 4., 12., 5., 13., 6., 14., 7., 15., 8., 16., 9., 17.
+validate 1., 2., 2., 3., 2., 4., 3., 5., 2., 6., 3., 7., 4., 8., 5., 9., 2., 10., 3., 11., 4., 12., 5., 13., 6., 14., 7., 15., 8., 16., 9., 17.
+store_fn
+
+aarch64 = nop
+link-aarch64 = st2._EXTpi8_
+generate *mut f64:float64x1x2_t:void
+aarch64 = st2
+generate *mut f64:float64x2x2_t:void
+
+arm = vst2
+link-arm = vst2._EXTpi8r_
+generate *mut f32:float32x2x2_t:void, *mut f32:float32x4x2_t:void
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+n = 0
+validate 1, 2
+store_fn
+
+aarch64 = st2
+link-aarch64 = st2lane._EXTpi8_
+generate *mut i8:int8x16x2_t:void, *mut i64:int64x1x2_t:void, *mut i64:int64x2x2_t:void
+
+arm = vst2
+link-arm = vst2lane._EXTpi8r_
+generate *mut i8:int8x8x2_t:void, *mut i16:int16x4x2_t:void, *mut i32:int32x2x2_t:void
+generate *mut i16:int16x8x2_t:void, *mut i32:int32x4x2_t:void
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = vst2-in1signedlanenox-::<LANE>, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 3, 2, 4, 3, 5, 2, 6, 3, 7, 4, 8, 5, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15, 8, 16, 9, 17
+n = 0
+validate 1, 2
+store_fn
+
+aarch64 = st2
+generate *mut u8:uint8x16x2_t:void, *mut u64:uint64x1x2_t:void, *mut u64:uint64x2x2_t:void, *mut p8:poly8x16x2_t:void
+target = crypto
+generate *mut p64:poly64x1x2_t:void, *mut p64:poly64x2x2_t:void
+
+target = default
+arm = vst2
+generate *mut u8:uint8x8x2_t:void, *mut u16:uint16x4x2_t:void, *mut u32:uint32x2x2_t:void
+generate *mut u16:uint16x8x2_t:void, *mut u32:uint32x4x2_t:void
+generate *mut p8:poly8x8x2_t:void, *mut p16:poly16x4x2_t:void, *mut p16:poly16x8x2_t:void
+
+/// Store multiple 2-element structures from two registers
+name = vst2
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2., 2., 3., 2., 4., 3., 5., 2., 6., 3., 7., 4., 8., 5., 9., 2., 10., 3., 11., 4., 12., 5., 13., 6., 14., 7., 15., 8., 16., 9., 17.
+n = 0
+validate 1., 2.
+store_fn
+
+aarch64 = st2
+link-aarch64 = st2lane._EXTpi8_
+generate *mut f64:float64x1x2_t:void, *mut f64:float64x2x2_t:void
+
+arm = vst2
+link-arm = vst2lane._EXTpi8r_
+generate *mut f32:float32x2x2_t:void, *mut f32:float32x4x2_t:void
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-nox
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+validate 1, 2, 2, 3, 2, 3, 4, 5, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 2, 3, 4, 5, 6, 7, 8, 9, 18, 19, 20, 21, 22, 23, 24, 25
+load_fn
+
+aarch64 = ld3
+link-aarch64 = ld3._EXTv2_
+generate *const i64:int64x2x3_t
+
+arm = vld3
+link-arm = vld3._EXTpi8_
+generate *const i8:int8x8x3_t, *const i16:int16x4x3_t, *const i32:int32x2x3_t
+generate *const i8:int8x16x3_t, *const i16:int16x8x3_t, *const i32:int32x4x3_t
+arm = nop
+aarch64 = nop
+generate *const i64:int64x1x3_t
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-nox
+multi_fn = transmute, {vld3-outsignednox-noext, transmute(a)}
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+validate 1, 2, 2, 3, 2, 3, 4, 5, 2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 2, 3, 4, 5, 6, 7, 8, 9, 18, 19, 20, 21, 22, 23, 24, 25
+load_fn
+
+aarch64 = ld3
+generate *const u64:uint64x2x3_t
+target = crypto
+generate *const p64:poly64x2x3_t
+
+target = default
+arm = vld3
+generate *const u8:uint8x8x3_t, *const u16:uint16x4x3_t, *const u32:uint32x2x3_t
+generate *const u8:uint8x16x3_t, *const u16:uint16x8x3_t, *const u32:uint32x4x3_t
+generate *const p8:poly8x8x3_t, *const p16:poly16x4x3_t, *const p8:poly8x16x3_t, *const p16:poly16x8x3_t
+arm = nop
+aarch64 = nop
+generate *const u64:uint64x1x3_t
+target = crypto
+generate *const p64:poly64x1x3_t
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-nox
+a = 0., 1., 2., 2., 2., 3., 3., 2., 4., 4., 3., 5., 5., 2., 6., 6., 3., 7., 7., 4., 8., 8., 5., 9., 9., 2., 10., 18., 3., 11., 19., 4., 12., 20., 5., 13., 21., 6., 14., 22., 7., 15., 23., 8., 16., 24., 9., 17., 25.
+validate 1., 2., 2., 3., 2., 3., 4., 5., 2., 3., 4., 5., 6., 7., 8., 9., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16., 17., 2., 3., 4., 5., 6., 7., 8., 9., 18., 19., 20., 21., 22., 23., 24., 25.
+load_fn
+
+aarch64 = nop
+link-aarch64 = ld3._EXTv2_
+generate *const f64:float64x1x3_t
+aarch64 = ld3
+generate *const f64:float64x2x3_t
+
+arm = vld3
+link-arm = vld3._EXTpi8_
+generate *const f32:float32x2x3_t, *const f32:float32x4x3_t
+
+/// Load single 3-element structure and replicate to all lanes of three registers
+name = vld3
+out-dup-nox
+a = 0, 1, 2, 3
+validate 1, 2, 3
+load_fn
+
+aarch64 = ld3r
+link-aarch64 = ld3r._EXTpe_
+generate *const i64:int64x2x3_t
+
+arm = vld3
+link-arm = vld3dup._EXTpi8_
+generate *const i8:int8x8x3_t, *const i16:int16x4x3_t, *const i32:int32x2x3_t
+generate *const i8:int8x16x3_t, *const i16:int16x8x3_t, *const i32:int32x4x3_t
+arm = nop
+generate *const i64:int64x1x3_t
+
+/// Load single 3-element structure and replicate to all lanes of three registers
+name = vld3
+out-dup-nox
+multi_fn = transmute, {vld3-outsigneddupnox-noext, transmute(a)}
+a = 0, 1, 2, 3
+validate 1, 2, 3
+load_fn
+
+aarch64 = ld3r
+generate *const u64:uint64x2x3_t
+target = crypto
+generate *const p64:poly64x2x3_t
+
+target = default
+arm = vld3
+generate *const u8:uint8x8x3_t, *const u16:uint16x4x3_t, *const u32:uint32x2x3_t
+generate *const u8:uint8x16x3_t, *const u16:uint16x8x3_t, *const u32:uint32x4x3_t
+generate *const p8:poly8x8x3_t, *const p16:poly16x4x3_t, *const p8:poly8x16x3_t, *const p16:poly16x8x3_t
+arm = nop
+generate *const u64:uint64x1x3_t
+target = crypto
+generate *const p64:poly64x1x3_t
+
+/// Load single 3-element structure and replicate to all lanes of three registers
+name = vld3
+out-dup-nox
+a = 0., 1., 2., 3.
+validate 1., 2., 3.
+load_fn
+
+aarch64 = ld3r
+link-aarch64 = ld3r._EXTpe_
+generate *const f64:float64x1x3_t, *const f64:float64x2x3_t
+
+arm = vld3
+link-arm = vld3dup._EXTpi8_
+generate *const f32:float32x2x3_t, *const f32:float32x4x3_t
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2, 3
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57
+n = HFLEN
+validate 1, 2, 3
+load_fn
+
+aarch64 = ld3
+link-aarch64 = ld3lane._EXTpi8_
+generate *const i8:int8x16x3_t:int8x16x3_t, *const i64:int64x1x3_t:int64x1x3_t, *const i64:int64x2x3_t:int64x2x3_t
+
+arm = vld3
+link-arm = vld3lane._EXTpi8_
+generate *const i8:int8x8x3_t:int8x8x3_t, *const i16:int16x4x3_t:int16x4x3_t, *const i32:int32x2x3_t:int32x2x3_t
+generate *const i16:int16x8x3_t:int16x8x3_t, *const i32:int32x4x3_t:int32x4x3_t
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = transmute, {vld3-outsignedlanenox-::<LANE>, transmute(a), transmute(b)}
+a = 0, 1, 2, 3
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57
+n = HFLEN
+validate 1, 2, 3
+load_fn
+
+aarch64 = ld3
+generate *const u8:uint8x16x3_t:uint8x16x3_t, *const u64:uint64x1x3_t:uint64x1x3_t, *const u64:uint64x2x3_t:uint64x2x3_t, *const p8:poly8x16x3_t:poly8x16x3_t
+target = crypto
+generate *const p64:poly64x1x3_t:poly64x1x3_t, *const p64:poly64x2x3_t:poly64x2x3_t
+
+target = default
+arm = vld3
+generate *const u8:uint8x8x3_t:uint8x8x3_t, *const u16:uint16x4x3_t:uint16x4x3_t, *const u32:uint32x2x3_t:uint32x2x3_t
+generate *const u16:uint16x8x3_t:uint16x8x3_t, *const u32:uint32x4x3_t:uint32x4x3_t
+generate *const p8:poly8x8x3_t:poly8x8x3_t, *const p16:poly16x4x3_t:poly16x4x3_t, *const p16:poly16x8x3_t:poly16x8x3_t
+
+/// Load multiple 3-element structures to three registers
+name = vld3
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2., 3.
+b = 10., 11., 12., 13., 14., 15., 16., 17., 18., 19., 20., 21., 22., 23., 24., 25., 26., 27., 28., 29., 30., 31., 32., 33., 34., 35., 36., 37., 38., 39., 40., 41., 42., 43., 44., 45., 46., 47., 48., 49., 50., 51., 52., 53., 54., 55., 56., 57.
+n = HFLEN
+validate 1., 2., 3.
+load_fn
+
+aarch64 = ld3
+link-aarch64 = ld3lane._EXTpi8_
+generate *const f64:float64x1x3_t:float64x1x3_t, *const f64:float64x2x3_t:float64x2x3_t
+
+arm = vld3
+link-arm = vld3lane._EXTpi8_
+generate *const f32:float32x2x3_t:float32x2x3_t, *const f32:float32x4x3_t:float32x4x3_t
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-nox
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+validate 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+store_fn
+
+aarch64 = st3
+link-aarch64 = st3._EXTpi8_
+generate *mut i64:int64x2x3_t:void
+
+arm = vst3
+link-arm = vst3._EXTpi8r_
+generate *mut i8:int8x8x3_t:void, *mut i16:int16x4x3_t:void, *mut i32:int32x2x3_t:void
+generate *mut i8:int8x16x3_t:void, *mut i16:int16x8x3_t:void, *mut i32:int32x4x3_t:void
+arm = nop
+aarch64 = nop
+generate *mut i64:int64x1x3_t:void
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-nox
+multi_fn = vst3-in1signednox-noext, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+validate 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+store_fn
+
+aarch64 = st3
+generate *mut u64:uint64x2x3_t:void
+target = crypto
+generate *mut p64:poly64x2x3_t:void
+
+target = default
+arm = vst3
+generate *mut u8:uint8x8x3_t:void, *mut u16:uint16x4x3_t:void, *mut u32:uint32x2x3_t:void
+generate *mut u8:uint8x16x3_t:void, *mut u16:uint16x8x3_t:void, *mut u32:uint32x4x3_t:void
+generate *mut p8:poly8x8x3_t:void, *mut p16:poly16x4x3_t:void, *mut p8:poly8x16x3_t:void, *mut p16:poly16x8x3_t:void
+arm = nop
+aarch64 = nop
+generate *mut u64:uint64x1x3_t:void
+target = crypto
+generate *mut p64:poly64x1x3_t:void
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-nox
+a = 0., 1., 2., 2., 2., 3., 3., 2., 4., 4., 3., 5., 5., 2., 6., 6., 3., 7., 7., 4., 8., 8., 5., 9., 9., 2., 10., 18., 3., 11., 19., 4., 12., 20., 5., 13., 21., 6., 14., 22., 7., 15., 23., 8., 16., 24., 9., 17., 25.
+validate 1., 2., 2., 2., 3., 3., 2., 4., 4., 3., 5., 5., 2., 6., 6., 3., 7., 7., 4., 8., 8., 5., 9., 9., 2., 10., 18., 3., 11., 19., 4., 12., 20., 5., 13., 21., 6., 14., 22., 7., 15., 23., 8., 16., 24., 9., 17., 25.
+store_fn
+
+aarch64 = nop
+link-aarch64 = st3._EXTpi8_
+generate *mut f64:float64x1x3_t:void
+aarch64 = st3
+generate *mut f64:float64x2x3_t:void
+
+arm = vst3
+link-arm = vst3._EXTpi8r_
+generate *mut f32:float32x2x3_t:void, *mut f32:float32x4x3_t:void
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+n = 0
+validate 1, 2, 2
+store_fn
+
+aarch64 = st3
+link-aarch64 = st3lane._EXTpi8_
+generate *mut i8:int8x16x3_t:void, *mut i64:int64x1x3_t:void, *mut i64:int64x2x3_t:void
+
+arm = vst3
+link-arm = vst3lane._EXTpi8r_
+generate *mut i8:int8x8x3_t:void, *mut i16:int16x4x3_t:void, *mut i32:int32x2x3_t:void
+generate *mut i16:int16x8x3_t:void, *mut i32:int32x4x3_t:void
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = vst3-in1signedlanenox-::<LANE>, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 2, 3, 3, 2, 4, 4, 3, 5, 5, 2, 6, 6, 3, 7, 7, 4, 8, 8, 5, 9, 9, 2, 10, 18, 3, 11, 19, 4, 12, 20, 5, 13, 21, 6, 14, 22, 7, 15, 23, 8, 16, 24, 9, 17, 25
+n = 0
+validate 1, 2, 2
+store_fn
+
+aarch64 = st3
+generate *mut u8:uint8x16x3_t:void, *mut u64:uint64x1x3_t:void, *mut u64:uint64x2x3_t:void, *mut p8:poly8x16x3_t:void
+target = crypto
+generate *mut p64:poly64x1x3_t:void, *mut p64:poly64x2x3_t:void
+
+target = default
+arm = vst3
+generate *mut u8:uint8x8x3_t:void, *mut u16:uint16x4x3_t:void, *mut u32:uint32x2x3_t:void
+generate *mut u16:uint16x8x3_t:void, *mut u32:uint32x4x3_t:void
+generate *mut p8:poly8x8x3_t:void, *mut p16:poly16x4x3_t:void, *mut p16:poly16x8x3_t:void
+
+/// Store multiple 3-element structures from three registers
+name = vst3
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2., 2., 2., 3., 3., 2., 4., 4., 3., 5., 5., 2., 6., 6., 3., 7., 7., 4., 8., 8., 5., 9., 9., 2., 10., 18., 3., 11., 19., 4., 12., 20., 5., 13., 21., 6., 14., 22., 7., 15., 23., 8., 16., 24., 9., 17., 25.
+n = 0
+validate 1., 2., 2.
+store_fn
+
+aarch64 = st3
+link-aarch64 = st3lane._EXTpi8_
+generate *mut f64:float64x1x3_t:void, *mut f64:float64x2x3_t:void
+
+arm = vst3
+link-arm = vst3lane._EXTpi8r_
+generate *mut f32:float32x2x3_t:void, *mut f32:float32x4x3_t:void
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-nox
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+validate 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 5, 6, 2, 3, 3, 4, 3, 4, 5, 6, 3, 4, 5, 6, 7, 8, 9, 10, 2, 3, 3, 4, 3, 4, 5, 6, 11, 12, 13, 14, 15, 16, 17, 18, 3, 4, 5, 6, 7, 8, 9, 10, 19, 20, 21, 22, 23, 24, 25, 26
+load_fn
+
+aarch64 = ld4
+link-aarch64 = ld4._EXTv2_
+generate *const i64:int64x2x4_t
+
+arm = vld4
+link-arm = vld4._EXTpi8_
+generate *const i8:int8x8x4_t, *const i16:int16x4x4_t, *const i32:int32x2x4_t
+generate *const i8:int8x16x4_t, *const i16:int16x8x4_t, *const i32:int32x4x4_t
+arm = nop
+aarch64 = nop
+generate *const i64:int64x1x4_t
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-nox
+multi_fn = transmute, {vld4-outsignednox-noext, transmute(a)}
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+validate 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 4, 3, 4, 5, 6, 2, 3, 3, 4, 3, 4, 5, 6, 3, 4, 5, 6, 7, 8, 9, 10, 2, 3, 3, 4, 3, 4, 5, 6, 11, 12, 13, 14, 15, 16, 17, 18, 3, 4, 5, 6, 7, 8, 9, 10, 19, 20, 21, 22, 23, 24, 25, 26
+load_fn
+
+aarch64 = ld4
+generate *const u64:uint64x2x4_t
+target = crypto
+generate *const p64:poly64x2x4_t
+
+target = default
+arm = vld4
+generate *const u8:uint8x8x4_t, *const u16:uint16x4x4_t, *const u32:uint32x2x4_t
+generate *const u8:uint8x16x4_t, *const u16:uint16x8x4_t, *const u32:uint32x4x4_t
+generate *const p8:poly8x8x4_t, *const p16:poly16x4x4_t, *const p8:poly8x16x4_t, *const p16:poly16x8x4_t
+arm = nop
+aarch64 = nop
+generate *const u64:uint64x1x4_t
+target = crypto
+generate *const p64:poly64x1x4_t
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-nox
+a = 0., 1., 2., 2., 3., 2., 3., 3., 4., 2., 3., 3., 5., 3., 4., 4., 6., 2., 3., 3., 7., 3., 4., 4., 8., 3., 5., 5., 9., 4., 6., 6., 10., 2., 3., 11., 19., 3., 4., 12., 20., 3., 5., 13., 21., 4., 6., 14., 22., 3., 7., 15., 23., 4., 8., 16., 24., 5., 9., 17., 25., 6., 10., 18., 26.
+validate 1., 2., 2., 3., 2., 3., 3., 4., 2., 3., 3., 4., 3., 4., 5., 6., 2., 3., 3., 4., 3., 4., 5., 6., 3., 4., 5., 6., 7., 8., 9., 10., 2., 3., 3., 4., 3., 4., 5., 6., 11., 12., 13., 14., 15., 16., 17., 18., 3., 4., 5., 6., 7., 8., 9., 10., 19., 20., 21., 22., 23., 24., 25., 26.
+load_fn
+
+aarch64 = nop
+link-aarch64 = ld4._EXTv2_
+generate *const f64:float64x1x4_t
+aarch64 = ld4
+generate *const f64:float64x2x4_t
+
+arm = vld4
+link-arm = vld4._EXTpi8_
+generate *const f32:float32x2x4_t, *const f32:float32x4x4_t
+
+/// Load single 4-element structure and replicate to all lanes of four registers
+name = vld4
+out-dup-nox
+a = 0, 1, 2, 3, 4
+validate 1, 2, 3, 4
+load_fn
+
+aarch64 = ld4r
+link-aarch64 = ld4r._EXTpe_
+generate *const i64:int64x2x4_t
+
+arm = vld4
+link-arm = vld4dup._EXTpi8_
+generate *const i8:int8x8x4_t, *const i16:int16x4x4_t, *const i32:int32x2x4_t
+generate *const i8:int8x16x4_t, *const i16:int16x8x4_t, *const i32:int32x4x4_t
+arm = nop
+generate *const i64:int64x1x4_t
+
+/// Load single 4-element structure and replicate to all lanes of four registers
+name = vld4
+out-dup-nox
+multi_fn = transmute, {vld4-outsigneddupnox-noext, transmute(a)}
+a = 0, 1, 2, 3, 4
+validate 1, 2, 3, 4
+load_fn
+
+aarch64 = ld4r
+generate *const u64:uint64x2x4_t
+target = crypto
+generate *const p64:poly64x2x4_t
+
+target = default
+arm = vld4
+generate *const u8:uint8x8x4_t, *const u16:uint16x4x4_t, *const u32:uint32x2x4_t
+generate *const u8:uint8x16x4_t, *const u16:uint16x8x4_t, *const u32:uint32x4x4_t
+generate *const p8:poly8x8x4_t, *const p16:poly16x4x4_t, *const p8:poly8x16x4_t, *const p16:poly16x8x4_t
+arm = nop
+generate *const u64:uint64x1x4_t
+target = crypto
+generate *const p64:poly64x1x4_t
+
+/// Load single 4-element structure and replicate to all lanes of four registers
+name = vld4
+out-dup-nox
+a = 0., 1., 2., 3., 4.
+validate 1., 2., 3., 4.
+load_fn
+
+aarch64 = ld4r
+link-aarch64 = ld4r._EXTpe_
+generate *const f64:float64x1x4_t, *const f64:float64x2x4_t
+
+arm = vld4
+link-arm = vld4dup._EXTpi8_
+generate *const f32:float32x2x4_t, *const f32:float32x4x4_t
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2, 3, 4
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73
+n = HFLEN
+validate 1, 2, 3, 4
+load_fn
+
+aarch64 = ld4
+link-aarch64 = ld4lane._EXTpi8_
+generate *const i8:int8x16x4_t:int8x16x4_t, *const i64:int64x1x4_t:int64x1x4_t, *const i64:int64x2x4_t:int64x2x4_t
+
+arm = vld4
+link-arm = vld4lane._EXTpi8_
+generate *const i8:int8x8x4_t:int8x8x4_t, *const i16:int16x4x4_t:int16x4x4_t, *const i32:int32x2x4_t:int32x2x4_t
+generate *const i16:int16x8x4_t:int16x8x4_t, *const i32:int32x4x4_t:int32x4x4_t
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = transmute, {vld4-outsignedlanenox-::<LANE>, transmute(a), transmute(b)}
+a = 0, 1, 2, 3, 4
+b = 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73
+n = HFLEN
+validate 1, 2, 3, 4
+load_fn
+
+aarch64 = ld4
+generate *const u8:uint8x16x4_t:uint8x16x4_t, *const u64:uint64x1x4_t:uint64x1x4_t, *const u64:uint64x2x4_t:uint64x2x4_t, *const p8:poly8x16x4_t:poly8x16x4_t
+target = crypto
+generate *const p64:poly64x1x4_t:poly64x1x4_t, *const p64:poly64x2x4_t:poly64x2x4_t
+
+target = default
+arm = vld4
+generate *const u8:uint8x8x4_t:uint8x8x4_t, *const u16:uint16x4x4_t:uint16x4x4_t, *const u32:uint32x2x4_t:uint32x2x4_t
+generate *const u16:uint16x8x4_t:uint16x8x4_t, *const u32:uint32x4x4_t:uint32x4x4_t
+generate *const p8:poly8x8x4_t:poly8x8x4_t, *const p16:poly16x4x4_t:poly16x4x4_t, *const p16:poly16x8x4_t:poly16x8x4_t
+
+/// Load multiple 4-element structures to four registers
+name = vld4
+out-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2., 3., 4.
+b = 10., 11., 12., 13., 14., 15., 16., 17., 18., 19., 20., 21., 22., 23., 24., 25., 26., 27., 28., 29., 30., 31., 32., 33., 34., 35., 36., 37., 38., 39., 40., 41., 42., 43., 44., 45., 46., 47., 48., 49., 50., 51., 52., 53., 54., 55., 56., 57., 58., 59., 60., 61., 62., 63., 64., 65., 66., 67., 68., 69., 70., 71., 72., 73.
+n = HFLEN
+validate 1., 2., 3., 4.
+load_fn
+
+aarch64 = ld4
+link-aarch64 = ld4lane._EXTpi8_
+generate *const f64:float64x1x4_t:float64x1x4_t, *const f64:float64x2x4_t:float64x2x4_t
+
+arm = vld4
+link-arm = vld4lane._EXTpi8_
+generate *const f32:float32x2x4_t:float32x2x4_t, *const f32:float32x4x4_t:float32x4x4_t
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-nox
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+validate 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+store_fn
+
+aarch64 = st4
+link-aarch64 = st4._EXTpi8_
+generate *mut i64:int64x2x4_t:void
+
+arm = vst4
+link-arm = vst4._EXTpi8r_
+generate *mut i8:int8x8x4_t:void, *mut i16:int16x4x4_t:void, *mut i32:int32x2x4_t:void
+generate *mut i8:int8x16x4_t:void, *mut i16:int16x8x4_t:void, *mut i32:int32x4x4_t:void
+arm = nop
+aarch64 = nop
+generate *mut i64:int64x1x4_t:void
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-nox
+multi_fn = vst4-in1signednox-noext, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+validate 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+store_fn
+
+aarch64 = st4
+generate *mut u64:uint64x2x4_t:void
+target = crypto
+generate *mut p64:poly64x2x4_t:void
+
+target = default
+arm = vst4
+generate *mut u8:uint8x8x4_t:void, *mut u16:uint16x4x4_t:void, *mut u32:uint32x2x4_t:void
+generate *mut u8:uint8x16x4_t:void, *mut u16:uint16x8x4_t:void, *mut u32:uint32x4x4_t:void
+generate *mut p8:poly8x8x4_t:void, *mut p16:poly16x4x4_t:void, *mut p8:poly8x16x4_t:void, *mut p16:poly16x8x4_t:void
+arm = nop
+aarch64 = nop
+generate *mut u64:uint64x1x4_t:void
+target = crypto
+generate *mut p64:poly64x1x4_t:void
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-nox
+a = 0., 1., 2., 2., 3., 2., 3., 3., 4., 2., 3., 3., 5., 3., 4., 4., 6., 2., 3., 3., 7., 3., 4., 4., 8., 3., 5., 5., 9., 4., 6., 6., 10., 2., 3., 11., 19., 3., 4., 12., 20., 3., 5., 13., 21., 4., 6., 14., 22., 3., 7., 15., 23., 4., 8., 16., 24., 5., 9., 17., 25., 6., 10., 18., 26.
+validate 1., 2., 2., 3., 2., 3., 3., 4., 2., 3., 3., 5., 3., 4., 4., 6., 2., 3., 3., 7., 3., 4., 4., 8., 3., 5., 5., 9., 4., 6., 6., 10., 2., 3., 11., 19., 3., 4., 12., 20., 3., 5., 13., 21., 4., 6., 14., 22., 3., 7., 15., 23., 4., 8., 16., 24., 5., 9., 17., 25., 6., 10., 18., 26.
+store_fn
+
+aarch64 = nop
+link-aarch64 = st4._EXTpi8_
+generate *mut f64:float64x1x4_t:void
+aarch64 = st4
+generate *mut f64:float64x2x4_t:void
+
+arm = vst4
+link-arm = vst4._EXTpi8r_
+generate *mut f32:float32x2x4_t:void, *mut f32:float32x4x4_t:void
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+n = 0
+validate 1, 2, 2, 3
+store_fn
+
+aarch64 = st4
+link-aarch64 = st4lane._EXTpi8_
+generate *mut i8:int8x16x4_t:void, *mut i64:int64x1x4_t:void, *mut i64:int64x2x4_t:void
+
+arm = vst4
+link-arm = vst4lane._EXTpi8r_
+generate *mut i8:int8x8x4_t:void, *mut i16:int16x4x4_t:void, *mut i32:int32x2x4_t:void
+generate *mut i16:int16x8x4_t:void, *mut i32:int32x4x4_t:void
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+multi_fn = vst4-in1signedlanenox-::<LANE>, transmute(a), transmute(b)
+a = 0, 1, 2, 2, 3, 2, 3, 3, 4, 2, 3, 3, 5, 3, 4, 4, 6, 2, 3, 3, 7, 3, 4, 4, 8, 3, 5, 5, 9, 4, 6, 6, 10, 2, 3, 11, 19, 3, 4, 12, 20, 3, 5, 13, 21, 4, 6, 14, 22, 3, 7, 15, 23, 4, 8, 16, 24, 5, 9, 17, 25, 6, 10, 18, 26
+n = 0
+validate 1, 2, 2, 3
+store_fn
+
+aarch64 = st4
+generate *mut u8:uint8x16x4_t:void, *mut u64:uint64x1x4_t:void, *mut u64:uint64x2x4_t:void, *mut p8:poly8x16x4_t:void
+target = crypto
+generate *mut p64:poly64x1x4_t:void, *mut p64:poly64x2x4_t:void
+
+target = default
+arm = vst4
+generate *mut u8:uint8x8x4_t:void, *mut u16:uint16x4x4_t:void, *mut u32:uint32x2x4_t:void
+generate *mut u16:uint16x8x4_t:void, *mut u32:uint32x4x4_t:void
+generate *mut p8:poly8x8x4_t:void, *mut p16:poly16x4x4_t:void, *mut p16:poly16x8x4_t:void
+
+/// Store multiple 4-element structures from four registers
+name = vst4
+in1-lane-nox
+constn = LANE
+multi_fn = static_assert_imm-in_exp_len-LANE
+a = 0., 1., 2., 2., 3., 2., 3., 3., 4., 2., 3., 3., 5., 3., 4., 4., 6., 2., 3., 3., 7., 3., 4., 4., 8., 3., 5., 5., 9., 4., 6., 6., 10., 2., 3., 11., 19., 3., 4., 12., 20., 3., 5., 13., 21., 4., 6., 14., 22., 3., 7., 15., 23., 4., 8., 16., 24., 5., 9., 17., 25., 6., 10., 18., 26.
+n = 0
+validate 1., 2., 2., 3.
+store_fn
+
+aarch64 = st4
+link-aarch64 = st4lane._EXTpi8_
+generate *mut f64:float64x1x4_t:void, *mut f64:float64x2x4_t:void
+
+arm = vst4
+link-arm = vst4lane._EXTpi8r_
+generate *mut f32:float32x2x4_t:void, *mut f32:float32x4x4_t:void
+
+/// Floating-point complex add
+name = vcadd_rot270
+rot-suffix
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+validate 2., 0., 2., 0.
+target = fcma
+
+aarch64 = fcadd
+link-aarch64 = vcadd.rot270._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex add
+name = vcadd_rot90
+rot-suffix
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+validate 0., -2., 0., -2.
+target = fcma
+
+aarch64 = fcadd
+link-aarch64 = vcadd.rot90._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+c = 1., 1., -1., -1.
+validate 0., -2., 2., 0.
+target = fcma
+
+aarch64 = fcmla
+link-aarch64 = vcmla.rot0._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot90
+rot-suffix
+a = 1., 1., 1., 1.
+b = 1., -1., 1., -1.
+c = 1., 1., 1., 1.
+validate 2., 0., 2., 0.
+target = fcma
+
+aarch64 = fcmla
+link-aarch64 = vcmla.rot90._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot180
+rot-suffix
+a = 1., 1., 1., 1.
+b = 1., -1., 1., -1.
+c = 1., 1., 1., 1.
+validate 0., 0., 0., 0.
+target = fcma
+
+aarch64 = fcmla
+link-aarch64 = vcmla.rot180._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot270
+rot-suffix
+a = 1., 1., 1., 1.
+b = 1., -1., 1., -1.
+c = 1., 1., 1., 1.
+validate 0., 2., 0., 2.
+target = fcma
+
+aarch64 = fcmla
+link-aarch64 = vcmla.rot270._EXT_
+generate float32x2_t, float32x4_t, float64x2_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla
+in2-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-!, c:out_t, c, c, {base-2-LANE}
+multi_fn = vcmla-self-noext, a, b, c
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+c = 1., 1., -1., -1.
+n = 0
+validate 0., -2., 0., -2.
+target = fcma
+
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot90
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-!, c:out_t, c, c, {base-2-LANE}
+multi_fn = vcmla_rot90-rot-noext, a, b, c
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+c = 1., 1., -1., -1.
+n = 0
+validate 0., 0., 0., 0.
+target = fcma
+
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot180
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-!, c:out_t, c, c, {base-2-LANE}
+multi_fn = vcmla_rot180-rot-noext, a, b, c
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+c = 1., 1., -1., -1.
+n = 0
+validate 2., 0., 2., 0.
+target = fcma
+
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Floating-point complex multiply accumulate
+name = vcmla_rot270
+rot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_rot_exp_len-LANE
+multi_fn = simd_shuffle-out_len-!, c:out_t, c, c, {base-2-LANE}
+multi_fn = vcmla_rot270-rot-noext, a, b, c
+a = 1., -1., 1., -1.
+b = -1., 1., -1., 1.
+c = 1., 1., -1., -1.
+n = 0
+validate 2., -2., 2., -2.
+target = fcma
+
+aarch64 = fcmla
+generate float32x2_t, float32x2_t:float32x2_t:float32x4_t:float32x2_t
+generate float32x4_t:float32x4_t:float32x2_t:float32x4_t, float32x4_t
+
+/// Dot product arithmetic
+name = vdot
+out-suffix
+a = 1, 2, 1, 2
+b = 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8
+c = 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8
+validate 31, 176, 31, 176
+target = dotprod
+
+aarch64 = sdot
+link-aarch64 = sdot._EXT_._EXT3_
+generate int32x2_t:int8x8_t:int8x8_t:int32x2_t, int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+aarch64 = udot
+link-aarch64 = udot._EXT_._EXT3_
+generate uint32x2_t:uint8x8_t:uint8x8_t:uint32x2_t, uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
+/// Dot product arithmetic
+name = vdot
+in2-dot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = transmute, c:in2_dot, c
+multi_fn = simd_shuffle-out_len-!, c:out_signed, c, c, {dup-out_len-LANE as u32}
+multi_fn = vdot-out-noext, a, b, {transmute, c}
+a = 1, 2, 1, 2
+b = 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8
+c = 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8
+n = 1
+validate 71, 176, 71, 176
+target = dotprod
+
+aarch64 = sdot
+generate int32x2_t:int8x8_t:int8x8_t:int32x2_t, int32x2_t:int8x8_t:int8x16_t:int32x2_t
+generate int32x4_t:int8x16_t:int8x8_t:int32x4_t, int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+aarch64 = udot
+generate uint32x2_t:uint8x8_t:uint8x8_t:uint32x2_t, uint32x2_t:uint8x8_t:uint8x16_t:uint32x2_t
+generate uint32x4_t:uint8x16_t:uint8x8_t:uint32x4_t, uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
+/// Dot product vector form with unsigned and signed integers
+name = vusdot
+out-suffix
+a = 1000, -4200, -1000, 2000
+b = 100, 205, 110, 195, 120, 185, 130, 175, 100, 205, 110, 195, 120, 185, 130, 175
+c = 0, 1, 2, 3, -1, -2, -3, -4, 0, 1, 2, 3, -1, -2, -3, -4
+validate 2010, -5780, 10, 420
+target = i8mm
+
+aarch64 = usdot
+link-aarch64 = usdot._EXT_._EXT3_
+generate int32x2_t:uint8x8_t:int8x8_t:int32x2_t, int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// Dot product index form with unsigned and signed integers
+name = vusdot
+in2-dot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = transmute, c:in2_dot, c
+multi_fn = simd_shuffle-out_len-!, c:out_signed, c, c, {dup-out_len-LANE as u32}
+multi_fn = vusdot-out-noext, a, b, {transmute, c}
+a = 1000, -4200, -1000, 2000
+b = 100, 205, 110, 195, 120, 185, 130, 175, 100, 205, 110, 195, 120, 185, 130, 175
+c = 0, 1, 2, 3, -1, -2, -3, -4, 0, 1, 2, 3, -1, -2, -3, -4
+n = 1
+validate -620, -5780, -2620, 420
+target = i8mm
+
+aarch64 = usdot
+generate int32x2_t:uint8x8_t:int8x8_t:int32x2_t, int32x2_t:uint8x8_t:int8x16_t:int32x2_t
+generate int32x4_t:uint8x16_t:int8x8_t:int32x4_t, int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// Dot product index form with signed and unsigned integers
+name = vsudot
+in2-dot-lane-suffixes
+constn = LANE
+multi_fn = static_assert_imm-in2_dot_exp_len-LANE
+multi_fn = transmute, c:in2_dot, c
+multi_fn = simd_shuffle-out_len-!, c:out_signed, c, c, {dup-out_len-LANE as u32}
+multi_fn = vusdot-out-noext, a, {transmute, c}, b
+a = -2000, 4200, -1000, 2000
+b = 4, 3, 2, 1, 0, -1, -2, -3, 4, 3, 2, 1, 0, -1, -2, -3
+c = 100, 205, 110, 195, 120, 185, 130, 175, 100, 205, 110, 195, 120, 185, 130, 175
+n = 1
+validate -530, 3230, 470, 1030
+target = i8mm
+
+aarch64 = sudot
+generate int32x2_t:int8x8_t:uint8x8_t:int32x2_t, int32x2_t:int8x8_t:uint8x16_t:int32x2_t
+generate int32x4_t:int8x16_t:uint8x8_t:int32x4_t, int32x4_t:int8x16_t:uint8x16_t:int32x4_t
+
+/// Matrix multiply-accumulate
+name = vmmla
+out-suffix
+a = 1, 2, 3, 4
+b = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+c = 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
+validate 205, 494, 495, 1296
+target = i8mm
+
+aarch64 = smmla
+link-aarch64 = smmla._EXT_._EXT3_
+generate int32x4_t:int8x16_t:int8x16_t:int32x4_t
+
+aarch64 = ummla
+link-aarch64 = ummla._EXT_._EXT3_
+generate uint32x4_t:uint8x16_t:uint8x16_t:uint32x4_t
+
+/// Unsigned and signed integer matrix multiply-accumulate
+name = vusmmla
+out-suffix
+a = 1, 2, 3, 4
+b = 100, 205, 110, 195, 120, 185, 130, 175, 1, 2, 3, 4, 5, 6, 7, 8
+c = 0, 1, 2, 3, -1, -2, -3, -4, 1, 2, 3, 4, 5, 6, 7, 8
+validate -569, 5642, -47, 208
+target = i8mm
+
+aarch64 = usmmla
+link-aarch64 = usmmla._EXT_._EXT3_
+generate int32x4_t:uint8x16_t:int8x16_t:int32x4_t
+
+/// BFloat16 floating-point dot product
+name = vbfdot
+out-suffix
+multi_fn = vbfdot-out-ext, a, {transmute, b}, {transmute, c}
+a = 1., 2., 3., 4.
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+validate 6., 27., 64., 117.
+target = bf16
+
+aarch64 = bfdot
+link-aarch64 = bfdot.v2f32.v8i8:float32x2_t:int8x8_t:int8x8_t:float32x2_t
+generate float32x2_t:bfloat16x4_t:bfloat16x4_t:float32x2_t
+
+/// BFloat16 floating-point dot product
+name = vbfdot
+out-suffix
+multi_fn = vbfdot-out-ext, a, {transmute, b}, {transmute, c}
+a = 1., 2., 3., 4.
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+validate 6., 27., 64., 117.
+target = bf16
+
+aarch64 = bfdot
+link-aarch64 = bfdot.v4f32.v16i8:float32x4_t:int8x16_t:int8x16_t:float32x4_t
+generate float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
+/// BFloat16 floating-point matrix multiply-accumulate
+name = vbfmmla
+out-suffix
+multi_fn = vbfmmla-out-ext, a, {transmute, b}, {transmute, c}
+a = 1., 2., 3., 4.
+b = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+c = 0x3F80, 0x4000, 0x4040, 0x4080, 0x40A0, 0x40C0, 0x40E0, 0x4100
+validate 31., 72., 73., 178.
+target = bf16
+
+aarch64 = bfmmla
+link-aarch64 = bfmmla:float32x4_t:int8x16_t:int8x16_t:float32x4_t
+generate float32x4_t:bfloat16x8_t:bfloat16x8_t:float32x4_t
+
 /// Multiply-add to accumulator
 name = vmla
 multi_fn = simd_add, a, {simd_mul, b, c}
@@ -1985,6 +3246,46 @@ aarch64 = frinti
 link-aarch64 = llvm.nearbyint._EXT_
 generate float*_t, float64x*_t
 
+/// Floating-point round to 32-bit integer, using current rounding mode
+name = vrnd32x
+a = -1.5, 2.9, 1.5, -2.5
+validate -2.0, 3.0, 2.0, -2.0
+target = frintts
+
+aarch64 = frint32x
+link-aarch64 = frint32x._EXT_
+generate float32x2_t, float32x4_t
+
+/// Floating-point round to 32-bit integer toward zero
+name = vrnd32z
+a = -1.5, 2.9, 1.5, -2.5
+validate -1.0, 2.0, 1.0, -2.0
+target = frintts
+
+aarch64 = frint32z
+link-aarch64 = frint32z._EXT_
+generate float32x2_t, float32x4_t
+
+/// Floating-point round to 64-bit integer, using current rounding mode
+name = vrnd64x
+a = -1.5, 2.9, 1.5, -2.5
+validate -2.0, 3.0, 2.0, -2.0
+target = frintts
+
+aarch64 = frint64x
+link-aarch64 = frint64x._EXT_
+generate float32x2_t, float32x4_t
+
+/// Floating-point round to 64-bit integer toward zero
+name = vrnd64z
+a = -1.5, 2.9, 1.5, -2.5
+validate -1.0, 2.0, 1.0, -2.0
+target = frintts
+
+aarch64 = frint64z
+link-aarch64 = frint64z._EXT_
+generate float32x2_t, float32x4_t
+
 /// Saturating add
 name = vqadd
 a = 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42
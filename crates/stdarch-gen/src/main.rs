@@ -80,12 +80,61 @@ fn type_len(t: &str) -> usize {
         "poly16x8_t" => 8,
         "poly64x1_t" => 1,
         "poly64x2_t" => 2,
+        "bfloat16x4_t" => 4,
+        "bfloat16x8_t" => 8,
         "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" | "p8"
         | "p16" | "p64" | "p128" => 1,
         _ => panic!("unknown type: {}", t),
     }
 }
 
+fn type_sub_len(t: &str) -> usize {
+    let s: Vec<_> = t.split('x').collect();
+    if s.len() != 3 {
+        1
+    } else {
+        match s[2] {
+            "2_t" => 2,
+            "3_t" => 3,
+            "4_t" => 4,
+            _ => panic!("unknown type: {}", t),
+        }
+    }
+}
+
+// Returns the vector type of a tuple type, non-tuple types are returned unchanged.
+fn type_to_sub_type(t: &str) -> &str {
+    match t {
+        "int8x8x2_t" | "int8x8x3_t" | "int8x8x4_t" => "int8x8_t",
+        "int8x16x2_t" | "int8x16x3_t" | "int8x16x4_t" => "int8x16_t",
+        "int16x4x2_t" | "int16x4x3_t" | "int16x4x4_t" => "int16x4_t",
+        "int16x8x2_t" | "int16x8x3_t" | "int16x8x4_t" => "int16x8_t",
+        "int32x2x2_t" | "int32x2x3_t" | "int32x2x4_t" => "int32x2_t",
+        "int32x4x2_t" | "int32x4x3_t" | "int32x4x4_t" => "int32x4_t",
+        "int64x1x2_t" | "int64x1x3_t" | "int64x1x4_t" => "int64x1_t",
+        "int64x2x2_t" | "int64x2x3_t" | "int64x2x4_t" => "int64x2_t",
+        "uint8x8x2_t" | "uint8x8x3_t" | "uint8x8x4_t" => "uint8x8_t",
+        "uint8x16x2_t" | "uint8x16x3_t" | "uint8x16x4_t" => "uint8x16_t",
+        "uint16x4x2_t" | "uint16x4x3_t" | "uint16x4x4_t" => "uint16x4_t",
+        "uint16x8x2_t" | "uint16x8x3_t" | "uint16x8x4_t" => "uint16x8_t",
+        "uint32x2x2_t" | "uint32x2x3_t" | "uint32x2x4_t" => "uint32x2_t",
+        "uint32x4x2_t" | "uint32x4x3_t" | "uint32x4x4_t" => "uint32x4_t",
+        "uint64x1x2_t" | "uint64x1x3_t" | "uint64x1x4_t" => "uint64x1_t",
+        "uint64x2x2_t" | "uint64x2x3_t" | "uint64x2x4_t" => "uint64x2_t",
+        "float32x2x2_t" | "float32x2x3_t" | "float32x2x4_t" => "float32x2_t",
+        "float32x4x2_t" | "float32x4x3_t" | "float32x4x4_t" => "float32x4_t",
+        "float64x1x2_t" | "float64x1x3_t" | "float64x1x4_t" => "float64x1_t",
+        "float64x2x2_t" | "float64x2x3_t" | "float64x2x4_t" => "float64x2_t",
+        "poly8x8x2_t" | "poly8x8x3_t" | "poly8x8x4_t" => "poly8x8_t",
+        "poly8x16x2_t" | "poly8x16x3_t" | "poly8x16x4_t" => "poly8x16_t",
+        "poly16x4x2_t" | "poly16x4x3_t" | "poly16x4x4_t" => "poly16x4_t",
+        "poly16x8x2_t" | "poly16x8x3_t" | "poly16x8x4_t" => "poly16x8_t",
+        "poly64x1x2_t" | "poly64x1x3_t" | "poly64x1x4_t" => "poly64x1_t",
+        "poly64x2x2_t" | "poly64x2x3_t" | "poly64x2x4_t" => "poly64x2_t",
+        _ => t,
+    }
+}
+
 fn type_bits(t: &str) -> usize {
     match t {
         "int8x8_t" | "int8x16_t" | "uint8x8_t" | "uint8x16_t" | "poly8x8_t" | "poly8x16_t"
@@ -177,6 +226,8 @@ fn type_to_suffix(t: &str) -> &str {
         "poly16x8_t" => "q_p16",
         "poly64x1_t" => "_p64",
         "poly64x2_t" => "q_p64",
+        "bfloat16x4_t" => "_bf16",
+        "bfloat16x8_t" => "q_bf16",
         "i8" => "b_s8",
         "i16" => "h_s16",
         "i32" => "s_s32",
@@ -274,6 +325,54 @@ fn type_to_lane_suffixes<'a>(out_t: &'a str, in_t: &'a str) -> String {
     str
 }
 
+// Lane suffixes of the indexed dot products, the lane selects a group of four elements of
+// `in_t` while the element type comes from `out_t`, e.g. `q_laneq_s32`.
+fn type_to_dot_lane_suffixes<'a>(out_t: &'a str, in_t: &'a str) -> String {
+    let mut str = String::new();
+    let suf = type_to_suffix(out_t);
+    if !suf.starts_with("_") {
+        str.push_str(&suf[0..1]);
+    }
+    str.push_str("_lane");
+    if type_to_suffix(in_t).starts_with('q') {
+        str.push('q');
+    }
+    str.push_str(type_to_noq_suffix(out_t));
+    str
+}
+
+// Moves the `q` of a suffix in front of the rotation of a complex intrinsic name,
+// e.g. `vcmla_rot90` with `q_f32` gives `vcmlaq_rot90_f32`.
+fn type_to_rot_suffix(c_name: &str, suf: &str) -> String {
+    let ns: Vec<_> = c_name.split('_').collect();
+    assert_eq!(ns.len(), 2);
+    if let Some(suf) = suf.strip_prefix('q') {
+        format!("{}q_{}{}", ns[0], ns[1], suf)
+    } else {
+        format!("{}{}", c_name, suf)
+    }
+}
+
+// Returns the 32-bit vector type covering the same bits as an 8-bit vector, each of its
+// elements is one group of four bytes of a dot product.
+fn type_to_dot_lane_type(t: &str) -> &str {
+    match t {
+        "int8x8_t" | "uint8x8_t" => "int32x2_t",
+        "int8x16_t" | "uint8x16_t" => "int32x4_t",
+        _ => panic!("unknown dot product type: {}", t),
+    }
+}
+
+// Inserts `infix` after the `q` of a vector suffix, e.g. `q_s8` => `q_dup_s8`.
+fn type_to_infix_suffix(t: &str, infix: &str) -> String {
+    let suf = type_to_suffix(t);
+    if let Some(suf) = suf.strip_prefix('q') {
+        format!("q{}{}", infix, suf)
+    } else {
+        format!("{}{}", infix, suf)
+    }
+}
+
 fn type_to_signed(t: &str) -> &str {
     match t {
         "int8x8_t" | "uint8x8_t" | "poly8x8_t" => "int8x8_t",
@@ -372,6 +471,21 @@ enum Suffix {
     Lane,
     In2,
     In2Lane,
+    OutNox,
+    In1Nox,
+    OutDupNox,
+    OutLaneNox,
+    In1LaneNox,
+    In2DotLane,
+    Rot,
+    RotLane,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Fntype {
+    Normal,
+    Load,
+    Store,
 }
 
 #[derive(Clone, Copy)]
@@ -380,6 +494,11 @@ enum TargetFeature {
     ArmV7,
     FPArmV8,
     Crypto,
+    Dotprod,
+    I8MM,
+    BF16,
+    Fcma,
+    FrintTS,
 }
 
 fn type_to_global_type(t: &str) -> &str {
@@ -400,8 +519,8 @@ fn type_to_global_type(t: &str) -> &str {
         "uint32x4_t" => "u32x4",
         "uint64x1_t" => "u64x1",
         "uint64x2_t" => "u64x2",
-        "float16x4_t" => "f16x4",
-        "float16x8_t" => "f16x8",
+        "float16x4_t" => "u16x4",
+        "float16x8_t" => "u16x8",
         "float32x2_t" => "f32x2",
         "float32x4_t" => "f32x4",
         "float64x1_t" => "f64",
@@ -412,6 +531,8 @@ fn type_to_global_type(t: &str) -> &str {
         "poly16x8_t" => "i16x8",
         "poly64x1_t" => "i64x1",
         "poly64x2_t" => "i64x2",
+        "bfloat16x4_t" => "u16x4",
+        "bfloat16x8_t" => "u16x8",
         "i8" => "i8",
         "i16" => "i16",
         "i32" => "i32",
@@ -443,6 +564,8 @@ fn type_to_native_type(t: &str) -> &str {
         "float16x4_t" | "float16x8_t" => "f16",
         "float32x2_t" | "float32x4_t" => "f32",
         "float64x1_t" | "float64x2_t" => "f64",
+        "poly8x8_t" | "poly8x16_t" => "u8",
+        "poly16x4_t" | "poly16x8_t" => "u16",
         "poly64x1_t" | "poly64x2_t" => "u64",
         _ => panic!("unknown type: {}", t),
     }
@@ -510,6 +633,8 @@ fn type_to_ext(t: &str) -> &str {
         "poly8x16_t" => "v16i8",
         "poly16x4_t" => "v4i16",
         "poly16x8_t" => "v8i16",
+        "bfloat16x4_t" => "v4bf16",
+        "bfloat16x8_t" => "v8bf16",
         "i8" => "i8",
         "i16" => "i16",
         "i32" => "i32",
@@ -858,9 +983,8 @@ fn gen_aarch64(
     target: TargetFeature,
     fixed: &Vec<String>,
     multi_fn: &Vec<String>,
+    fn_type: Fntype,
 ) -> (String, String) {
-    let _global_t = type_to_global_type(in_t[0]);
-    let _global_ret_t = type_to_global_type(out_t);
     let name = match suffix {
         Normal => format!("{}{}", current_name, type_to_suffix(in_t[1])),
         NoQ => format!("{}{}", current_name, type_to_noq_suffix(in_t[1])),
@@ -886,12 +1010,49 @@ fn gen_aarch64(
         Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[1])),
         In2 => format!("{}{}", current_name, type_to_suffix(in_t[2])),
         In2Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[2])),
+        OutNox => format!(
+            "{}{}",
+            current_name,
+            type_to_suffix(type_to_sub_type(out_t))
+        ),
+        In1Nox => format!(
+            "{}{}",
+            current_name,
+            type_to_suffix(type_to_sub_type(in_t[1]))
+        ),
+        OutDupNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(out_t), "_dup")
+        ),
+        OutLaneNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(out_t), "_lane")
+        ),
+        In1LaneNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(in_t[1]), "_lane")
+        ),
+        In2DotLane => format!(
+            "{}{}",
+            current_name,
+            type_to_dot_lane_suffixes(out_t, in_t[2])
+        ),
+        Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
+        RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2])),
     };
     let current_target = match target {
         Default => "neon",
         ArmV7 => "v7",
         FPArmV8 => "fp-armv8,v8",
         Crypto => "neon,crypto",
+        Dotprod => "neon,dotprod",
+        I8MM => "neon,i8mm",
+        BF16 => "neon,bf16",
+        Fcma => "neon,fcma",
+        FrintTS => "neon,frintts",
     };
     let current_fn = if let Some(current_fn) = current_fn.clone() {
         if link_aarch64.is_some() {
@@ -918,7 +1079,7 @@ fn gen_aarch64(
         in_t[2].to_string(),
         out_t.to_string(),
     ];
-    if let Some(mut link_aarch64) = link_aarch64.clone() {
+    if let (Some(mut link_aarch64), Fntype::Normal) = (link_aarch64.clone(), fn_type) {
         if link_aarch64.contains(":") {
             let links: Vec<_> = link_aarch64.split(':').map(|v| v.to_string()).collect();
             assert_eq!(links.len(), 5);
@@ -932,12 +1093,18 @@ fn gen_aarch64(
         }
         let ext = type_to_ext(in_t[0]);
         let ext2 = type_to_ext(out_t);
+        let ext3 = type_to_ext(in_t[1]);
         let link_aarch64 = if link_aarch64.starts_with("llvm") {
-            link_aarch64.replace("_EXT_", ext).replace("_EXT2_", ext2)
+            link_aarch64
+                .replace("_EXT_", ext)
+                .replace("_EXT2_", ext2)
+                .replace("_EXT3_", ext3)
         } else {
             let mut link = String::from("llvm.aarch64.neon.");
             link.push_str(&link_aarch64);
-            link.replace("_EXT_", ext).replace("_EXT2_", ext2)
+            link.replace("_EXT_", ext)
+                .replace("_EXT2_", ext2)
+                .replace("_EXT3_", ext3)
         };
         ext_c = format!(
             r#"#[allow(improper_ctypes)]
@@ -1037,7 +1204,10 @@ fn gen_aarch64(
             format!(
                 r#", {} = {}"#,
                 constn,
-                map_val(in_t[1], current_tests[0].3.as_ref().unwrap())
+                map_val(
+                    type_to_sub_type(in_t[1]),
+                    current_tests[0].3.as_ref().unwrap()
+                )
             )
         }
     } else {
@@ -1061,7 +1231,32 @@ fn gen_aarch64(
     } else {
         ["", ""]
     };
-    let call = if let Some(const_aarch64) = const_aarch64 {
+    let call = if fn_type != Fntype::Normal {
+        let ext = if let Some(link_aarch64) = link_aarch64 {
+            load_store_ext(
+                "aarch64",
+                &load_store_link(link_aarch64, "aarch64", in_t, out_t, fn_type),
+                &current_fn,
+                in_t,
+                out_t,
+                fn_type,
+                constn,
+                matches!(suffix, OutDupNox),
+            )
+        } else {
+            String::new()
+        };
+        gen_load_store_fn(
+            &name,
+            &const_declare,
+            in_t,
+            out_t,
+            fn_type,
+            para_num,
+            &multi_calls,
+            &ext,
+        )
+    } else if let Some(const_aarch64) = const_aarch64 {
         match para_num {
             1 => format!(
                 r#"pub unsafe fn {}{}(a: {}) -> {} {{
@@ -1162,18 +1357,24 @@ fn gen_aarch64(
         current_comment, current_target, current_aarch64, const_assert, const_legacy, call
     );
 
-    let test = gen_test(
-        &name,
-        in_t,
-        &out_t,
-        current_tests,
-        [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
-        type_len(out_t),
-        para_num,
-    );
+    let test = match fn_type {
+        Fntype::Normal => gen_test(
+            &name,
+            in_t,
+            &out_t,
+            current_tests,
+            [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
+            type_len(out_t),
+            para_num,
+            target,
+        ),
+        Fntype::Load => gen_load_test(&name, out_t, current_tests, matches!(suffix, OutDupNox)),
+        Fntype::Store => gen_store_test(&name, in_t, current_tests),
+    };
     (function, test)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_test(
     name: &str,
     in_t: &[&str; 3],
@@ -1188,12 +1389,21 @@ fn gen_test(
     len_in: [usize; 3],
     len_out: usize,
     para_num: i32,
+    target: TargetFeature,
 ) -> String {
+    let test_target = match target {
+        Dotprod => "neon,dotprod",
+        I8MM => "neon,i8mm",
+        BF16 => "neon,bf16",
+        Fcma => "neon,fcma",
+        FrintTS => "neon,frintts",
+        _ => "neon",
+    };
     let mut test = format!(
         r#"
-    #[simd_test(enable = "neon")]
+    #[simd_test(enable = "{}")]
     unsafe fn test_{}() {{"#,
-        name,
+        test_target, name,
     );
     for (a, b, c, n, e) in current_tests {
         let a: Vec<String> = a.iter().take(len_in[0]).cloned().collect();
@@ -1279,6 +1489,327 @@ fn gen_test(
     test
 }
 
+// Expands the link name of a structured load/store, the `_EXT*_` placeholders are
+// derived from the vector type of the loaded or stored tuple.
+fn load_store_link(
+    link: &str,
+    target_arch: &str,
+    in_t: &[&str; 3],
+    out_t: &str,
+    fn_type: Fntype,
+) -> String {
+    let tuple_t = if fn_type == Fntype::Load {
+        out_t
+    } else {
+        in_t[1]
+    };
+    let ext = type_to_ext(type_to_sub_type(tuple_t));
+    let elem = in_t[0]
+        .trim_start_matches("*const ")
+        .trim_start_matches("*mut ");
+    let link = link
+        .replace("_EXTpi8r_", &format!("p0i8.{}", ext))
+        .replace("_EXTpi8_", &format!("{}.p0i8", ext))
+        .replace("_EXTpe_", &format!("{}.p0{}", ext, type_to_ext(elem)))
+        .replace("_EXTv2_", &format!("{}.p0{}", ext, ext));
+    if link.starts_with("llvm") {
+        link
+    } else {
+        format!("llvm.{}.neon.{}", target_arch, link)
+    }
+}
+
+// Generates the extern declaration and the call of the LLVM intrinsic backing
+// a structured load/store. `arm` and `aarch64` disagree on the argument order
+// and arm additionally takes the alignment of the elements.
+#[allow(clippy::too_many_arguments)]
+fn load_store_ext(
+    target_arch: &str,
+    link: &str,
+    current_fn: &str,
+    in_t: &[&str; 3],
+    out_t: &str,
+    fn_type: Fntype,
+    constn: &Option<String>,
+    dup: bool,
+) -> String {
+    let tuple_t = if fn_type == Fntype::Load {
+        out_t
+    } else {
+        in_t[1]
+    };
+    let sub_t = type_to_sub_type(tuple_t);
+    let (ptr_t, ret) = if fn_type == Fntype::Load {
+        ("*const i8", format!(" -> {}", out_t))
+    } else {
+        ("*mut i8", String::new())
+    };
+    let mut vecs = Vec::new();
+    let mut args = Vec::new();
+    if fn_type == Fntype::Store || constn.is_some() {
+        for (i, v) in ["a", "b", "c", "d"]
+            .iter()
+            .take(type_sub_len(tuple_t))
+            .enumerate()
+        {
+            vecs.push(format!("{}: {}", v, sub_t));
+            args.push(format!("b.{}", i));
+        }
+    }
+    let (params, args) = if target_arch == "arm" {
+        let mut params = vec![format!("ptr: {}", ptr_t)];
+        params.append(&mut vecs);
+        let mut call_args = vec![format!("a as {}", ptr_t)];
+        call_args.append(&mut args);
+        if let Some(constn) = constn {
+            params.push(String::from("n: i32"));
+            call_args.push(constn.clone());
+        }
+        params.push(String::from("size: i32"));
+        call_args.push((type_bits(sub_t) / 8).to_string());
+        (params, call_args)
+    } else if fn_type == Fntype::Load && constn.is_none() {
+        let ptr_t = if dup {
+            in_t[0].to_string()
+        } else {
+            format!("*const {}", sub_t)
+        };
+        (
+            vec![format!("ptr: {}", ptr_t)],
+            vec![String::from("a.cast()")],
+        )
+    } else {
+        if let Some(constn) = constn {
+            vecs.push(String::from("n: i64"));
+            args.push(format!("{} as i64", constn));
+        }
+        vecs.push(format!("ptr: {}", ptr_t));
+        args.push(format!("a as {}", ptr_t));
+        (vecs, args)
+    };
+    format!(
+        r#"#[allow(improper_ctypes)]
+    extern "C" {{
+        #[cfg_attr(target_arch = "{}", link_name = "{}")]
+        fn {}({}){};
+    }}
+{}({})"#,
+        target_arch,
+        link,
+        current_fn,
+        params.join(", "),
+        ret,
+        current_fn,
+        args.join(", ")
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn gen_load_store_fn(
+    name: &str,
+    const_declare: &str,
+    in_t: &[&str; 3],
+    out_t: &str,
+    fn_type: Fntype,
+    para_num: i32,
+    multi_calls: &str,
+    ext: &str,
+) -> String {
+    let params = match para_num {
+        1 => format!("a: {}", in_t[0]),
+        2 => format!("a: {}, b: {}", in_t[0], in_t[1]),
+        _ => unimplemented!("unknown para_num"),
+    };
+    let ret = if fn_type == Fntype::Load {
+        format!(" -> {}", out_t)
+    } else {
+        String::new()
+    };
+    let body = if multi_calls.is_empty() || ext.is_empty() {
+        format!("{}{}", multi_calls, ext)
+    } else {
+        format!("{}\n    {}", multi_calls, ext)
+    };
+    format!(
+        r#"pub unsafe fn {}{}({}){} {{
+    {}
+}}"#,
+        name, const_declare, params, ret, body
+    )
+}
+
+fn vectors(t: &str, vs: &[String]) -> String {
+    let len = type_len(t);
+    let global_t = type_to_global_type(t);
+    let vs: Vec<String> = vs
+        .chunks(len)
+        .map(|v| {
+            if global_t == "f64" {
+                v[0].clone()
+            } else {
+                format!("{}::new({})", global_t, v.join(", "))
+            }
+        })
+        .collect();
+    format!("[{}]", vs.join(", "))
+}
+
+fn gen_load_test(
+    name: &str,
+    out_t: &str,
+    current_tests: &[(
+        Vec<String>,
+        Vec<String>,
+        Vec<String>,
+        Option<String>,
+        Vec<String>,
+    )],
+    dup: bool,
+) -> String {
+    let mut test = format!(
+        r#"
+    #[simd_test(enable = "neon")]
+    unsafe fn test_{}() {{"#,
+        name,
+    );
+    let sub_t = type_to_sub_type(out_t);
+    let cnt = type_sub_len(out_t);
+    let len = type_len(sub_t);
+    let global_t = type_to_global_type(sub_t);
+    for (a, b, _, n, e) in current_tests {
+        // `dup` and `lane` only read one structure, their `validate` lists the
+        // loaded elements which are then broadcast or inserted into `b`.
+        let a_len = if dup || n.is_some() {
+            cnt + 1
+        } else {
+            cnt * len + 1
+        };
+        let a: Vec<String> = a.iter().take(a_len).cloned().collect();
+        let e: Vec<String> = if dup {
+            e.iter()
+                .take(cnt)
+                .flat_map(|v| std::iter::repeat_n(v.clone(), len))
+                .collect()
+        } else if let Some(n) = n {
+            let lane: usize = map_val(sub_t, n).parse().unwrap();
+            let mut e_lane: Vec<String> = b.iter().take(cnt * len).cloned().collect();
+            for i in 0..cnt {
+                e_lane[i * len + lane] = e[i].clone();
+            }
+            e_lane
+        } else {
+            e.iter().take(cnt * len).cloned().collect()
+        };
+        let t = if let Some(n) = n {
+            let b: Vec<String> = b.iter().take(cnt * len).cloned().collect();
+            format!(
+                r#"
+        let a: [{}; {}] = [{}];
+        let b: [{}; {}] = {};
+        let e: [{}; {}] = {};
+        let r: [{}; {}] = transmute({}::<{}>(a[1..].as_ptr(), transmute(b)));
+        assert_eq!(r, e);
+"#,
+                type_to_native_type(sub_t),
+                a_len,
+                a.join(", "),
+                global_t,
+                cnt,
+                vectors(sub_t, &b),
+                global_t,
+                cnt,
+                vectors(sub_t, &e),
+                global_t,
+                cnt,
+                name,
+                map_val(sub_t, n),
+            )
+        } else {
+            format!(
+                r#"
+        let a: [{}; {}] = [{}];
+        let e: [{}; {}] = {};
+        let r: [{}; {}] = transmute({}(a[1..].as_ptr()));
+        assert_eq!(r, e);
+"#,
+                type_to_native_type(sub_t),
+                a_len,
+                a.join(", "),
+                global_t,
+                cnt,
+                vectors(sub_t, &e),
+                global_t,
+                cnt,
+                name,
+            )
+        };
+        test.push_str(&t);
+    }
+    test.push_str("    }\n");
+    test
+}
+
+fn gen_store_test(
+    name: &str,
+    in_t: &[&str; 3],
+    current_tests: &[(
+        Vec<String>,
+        Vec<String>,
+        Vec<String>,
+        Option<String>,
+        Vec<String>,
+    )],
+) -> String {
+    let mut test = format!(
+        r#"
+    #[simd_test(enable = "neon")]
+    unsafe fn test_{}() {{"#,
+        name,
+    );
+    let sub_t = type_to_sub_type(in_t[1]);
+    let cnt = type_sub_len(in_t[1]);
+    let len = cnt * type_len(sub_t);
+    let native_t = type_to_native_type(sub_t);
+    // The stored value is loaded back from `a` with the matching full load,
+    // the remainder of `e` is zero for stores of a single lane.
+    let load = format!("vld{}{}", cnt, type_to_suffix(sub_t));
+    for (a, _, _, n, e) in current_tests {
+        let a: Vec<String> = a.iter().take(len + 1).cloned().collect();
+        let mut e: Vec<String> = e.iter().take(len).cloned().collect();
+        let zero = if native_t.starts_with('f') { "0." } else { "0" };
+        e.resize(len, zero.to_string());
+        let const_value = if let Some(n) = n {
+            format!("::<{}>", map_val(sub_t, n))
+        } else {
+            String::new()
+        };
+        test.push_str(&format!(
+            r#"
+        let a: [{}; {}] = [{}];
+        let e: [{}; {}] = [{}];
+        let mut r: [{}; {}] = [0{}; {}];
+        {}{}(r.as_mut_ptr(), {}(a[1..].as_ptr()));
+        assert_eq!(r, e);
+"#,
+            native_t,
+            len + 1,
+            a.join(", "),
+            native_t,
+            len,
+            e.join(", "),
+            native_t,
+            len,
+            native_t,
+            len,
+            name,
+            const_value,
+            load,
+        ));
+    }
+    test.push_str("    }\n");
+    test
+}
+
 #[allow(clippy::too_many_arguments)]
 fn gen_arm(
     current_comment: &str,
@@ -1305,9 +1836,8 @@ fn gen_arm(
     target: TargetFeature,
     fixed: &Vec<String>,
     multi_fn: &Vec<String>,
+    fn_type: Fntype,
 ) -> (String, String) {
-    let _global_t = type_to_global_type(in_t[0]);
-    let _global_ret_t = type_to_global_type(out_t);
     let name = match suffix {
         Normal => format!("{}{}", current_name, type_to_suffix(in_t[1])),
         NoQ => format!("{}{}", current_name, type_to_noq_suffix(in_t[1])),
@@ -1333,6 +1863,38 @@ fn gen_arm(
         Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[1])),
         In2 => format!("{}{}", current_name, type_to_suffix(in_t[2])),
         In2Lane => format!("{}{}", current_name, type_to_lane_suffixes(out_t, in_t[2])),
+        OutNox => format!(
+            "{}{}",
+            current_name,
+            type_to_suffix(type_to_sub_type(out_t))
+        ),
+        In1Nox => format!(
+            "{}{}",
+            current_name,
+            type_to_suffix(type_to_sub_type(in_t[1]))
+        ),
+        OutDupNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(out_t), "_dup")
+        ),
+        OutLaneNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(out_t), "_lane")
+        ),
+        In1LaneNox => format!(
+            "{}{}",
+            current_name,
+            type_to_infix_suffix(type_to_sub_type(in_t[1]), "_lane")
+        ),
+        In2DotLane => format!(
+            "{}{}",
+            current_name,
+            type_to_dot_lane_suffixes(out_t, in_t[2])
+        ),
+        Rot => type_to_rot_suffix(current_name, type_to_suffix(out_t)),
+        RotLane => type_to_rot_suffix(current_name, &type_to_lane_suffixes(out_t, in_t[2])),
     };
     let current_aarch64 = current_aarch64
         .clone()
@@ -1342,12 +1904,22 @@ fn gen_arm(
         ArmV7 => "neon",
         FPArmV8 => "neon",
         Crypto => "neon,crypto",
+        Dotprod => "neon,dotprod",
+        I8MM => "neon,i8mm",
+        BF16 => "neon,bf16",
+        Fcma => "neon,fcma",
+        FrintTS => "neon,frintts",
     };
     let current_target_arm = match target {
         Default => "v7",
         ArmV7 => "v7",
         FPArmV8 => "fp-armv8,v8",
         Crypto => "crypto,v8",
+        Dotprod => "dotprod,v8",
+        I8MM => "i8mm,v8",
+        BF16 => "bf16,v8",
+        Fcma => "fcma,v8",
+        FrintTS => "frintts,v8",
     };
 
     let current_fn = if let Some(current_fn) = current_fn.clone() {
@@ -1398,7 +1970,9 @@ fn gen_arm(
         in_t[2].to_string(),
         out_t.to_string(),
     ];
-    if let (Some(mut link_arm), Some(mut link_aarch64)) = (link_arm.clone(), link_aarch64.clone()) {
+    if let (Some(mut link_arm), Some(mut link_aarch64), Fntype::Normal) =
+        (link_arm.clone(), link_aarch64.clone(), fn_type)
+    {
         if link_arm.contains(":") {
             let links: Vec<_> = link_arm.split(':').map(|v| v.to_string()).collect();
             assert_eq!(links.len(), 5);
@@ -1612,7 +2186,10 @@ fn gen_arm(
         format!(
             r#", {} = {}"#,
             constn,
-            map_val(in_t[1], current_tests[0].3.as_ref().unwrap())
+            map_val(
+                type_to_sub_type(in_t[1]),
+                current_tests[0].3.as_ref().unwrap()
+            )
         )
     } else {
         String::new()
@@ -1628,6 +2205,16 @@ fn gen_arm(
         ["transmute(", ")"]
     };
     let call = match (multi_calls.len(), para_num, fixed.len()) {
+        _ if fn_type != Fntype::Normal => gen_load_store_fn(
+            &name,
+            &const_declare,
+            in_t,
+            out_t,
+            fn_type,
+            para_num,
+            &multi_calls,
+            "",
+        ),
         (0, 1, 0) => format!(
             r#"pub unsafe fn {}{}(a: {}) -> {} {{
     {}{}(a)
@@ -1682,7 +2269,32 @@ fn gen_arm(
         ),
         (_, _, _) => String::new(),
     };
-    let call_arm = if let Some(const_arm) = const_arm {
+    let load_store_call = |target_arch: &str, link: &Option<String>| {
+        let link = link.as_ref().filter(|_| fn_type != Fntype::Normal)?;
+        let ext = load_store_ext(
+            target_arch,
+            &load_store_link(link, target_arch, in_t, out_t, fn_type),
+            &current_fn,
+            in_t,
+            out_t,
+            fn_type,
+            constn,
+            matches!(suffix, OutDupNox),
+        );
+        Some(gen_load_store_fn(
+            &name,
+            &const_declare,
+            in_t,
+            out_t,
+            fn_type,
+            para_num,
+            &multi_calls,
+            &ext,
+        ))
+    };
+    let call_arm = if let Some(call) = load_store_call("arm", link_arm) {
+        call
+    } else if let Some(const_arm) = const_arm {
         let cnt = if const_arm.contains(':') {
             let consts: Vec<_> = const_arm.split(':').map(|v| v.trim().to_string()).collect();
             consts[0].clone()
@@ -1758,7 +2370,9 @@ fn gen_arm(
     } else {
         String::new()
     };
-    let call_aarch64 = if let Some(const_aarch64) = const_aarch64 {
+    let call_aarch64 = if let Some(call) = load_store_call("aarch64", link_aarch64) {
+        call
+    } else if let Some(const_aarch64) = const_aarch64 {
         match para_num {
             1 => format!(
                 r#"pub unsafe fn {}{}(a: {}) -> {} {{
@@ -1828,6 +2442,7 @@ fn gen_arm(
     let function = if (const_arm.is_some() && const_aarch64.is_some())
         || out_t != link_arm_t[3]
         || out_t != link_aarch64_t[3]
+        || (fn_type != Fntype::Normal && link_arm.is_some())
     {
         format!(
             r#"
@@ -1880,15 +2495,20 @@ fn gen_arm(
             call,
         )
     };
-    let test = gen_test(
-        &name,
-        in_t,
-        &out_t,
-        current_tests,
-        [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
-        type_len(out_t),
-        para_num,
-    );
+    let test = match fn_type {
+        Fntype::Normal => gen_test(
+            &name,
+            in_t,
+            &out_t,
+            current_tests,
+            [type_len(in_t[0]), type_len(in_t[1]), type_len(in_t[2])],
+            type_len(out_t),
+            para_num,
+            target,
+        ),
+        Fntype::Load => gen_load_test(&name, out_t, current_tests, matches!(suffix, OutDupNox)),
+        Fntype::Store => gen_store_test(&name, in_t, current_tests),
+    };
 
     (function, test)
 }
@@ -2054,6 +2674,22 @@ fn get_call(
         s.push_str("]");
         return s;
     }
+    if fn_name.starts_with("base") {
+        let fn_format: Vec<_> = fn_name.split('-').map(|v| v.to_string()).collect();
+        let step = fn_format[1].parse::<usize>().unwrap();
+        let mut s = format!("{} [", const_declare);
+        for i in 0..type_len(out_t) {
+            if i != 0 {
+                s.push_str(", ");
+            }
+            s.push_str(&format!("{} * {} as u32", step, fn_format[2]));
+            if i % step != 0 {
+                s.push_str(&format!(" + {}", i % step));
+            }
+        }
+        s.push(']');
+        return s;
+    }
     if fn_name.starts_with("asc") {
         let fn_format: Vec<_> = fn_name.split('-').map(|v| v.to_string()).collect();
         let start = match &*fn_format[1] {
@@ -2106,11 +2742,13 @@ fn get_call(
         let len = match &*fn_format[1] {
             "out_exp_len" => type_exp_len(out_t),
             "out_bits_exp_len" => type_bits_exp_len(out_t),
-            "in_exp_len" => type_exp_len(in_t[1]),
+            "in_exp_len" => type_exp_len(type_to_sub_type(in_t[1])),
             "in_bits_exp_len" => type_bits_exp_len(in_t[1]),
             "in0_exp_len" => type_exp_len(in_t[0]),
             "in1_exp_len" => type_exp_len(in_t[1]),
             "in2_exp_len" => type_exp_len(in_t[2]),
+            "in2_dot_exp_len" => type_exp_len(type_to_dot_lane_type(in_t[2])),
+            "in2_rot_exp_len" => type_exp_len(in_t[2]) - 1,
             _ => 0,
         };
         if len == 0 {
@@ -2243,6 +2881,13 @@ fn get_call(
                 re = Some((re_params[0].clone(), in_t[1].to_string()));
             } else if re_params[1] == "out_t" {
                 re = Some((re_params[0].clone(), out_t.to_string()));
+            } else if re_params[1] == "out_signed" {
+                re = Some((re_params[0].clone(), type_to_signed(out_t).to_string()));
+            } else if re_params[1] == "in2_dot" {
+                re = Some((
+                    re_params[0].clone(),
+                    type_to_dot_lane_type(in_t[2]).to_string(),
+                ));
             } else if re_params[1] == "half" {
                 re = Some((re_params[0].clone(), type_to_half(in_t[1]).to_string()));
             } else if re_params[1] == "in_ntt" {
@@ -2302,12 +2947,33 @@ fn get_call(
             fn_name.push_str(type_to_suffix(in_t[0]));
         } else if fn_format[1] == "in2" {
             fn_name.push_str(type_to_suffix(in_t[2]));
+        } else if fn_format[1] == "rot" {
+            fn_name = type_to_rot_suffix(&fn_name, type_to_suffix(out_t));
         } else if fn_format[1] == "in2lane" {
             fn_name.push_str(&type_to_lane_suffixes(out_t, in_t[2]));
         } else if fn_format[1] == "signed" {
             fn_name.push_str(type_to_suffix(type_to_signed(in_t[1])));
         } else if fn_format[1] == "unsigned" {
             fn_name.push_str(type_to_suffix(type_to_unsigned(in_t[1])));
+        } else if fn_format[1] == "outsignednox" {
+            fn_name.push_str(type_to_suffix(type_to_signed(type_to_sub_type(out_t))));
+        } else if fn_format[1] == "in1signednox" {
+            fn_name.push_str(type_to_suffix(type_to_signed(type_to_sub_type(in_t[1]))));
+        } else if fn_format[1] == "outsigneddupnox" {
+            fn_name.push_str(&type_to_infix_suffix(
+                type_to_signed(type_to_sub_type(out_t)),
+                "_dup",
+            ));
+        } else if fn_format[1] == "outsignedlanenox" {
+            fn_name.push_str(&type_to_infix_suffix(
+                type_to_signed(type_to_sub_type(out_t)),
+                "_lane",
+            ));
+        } else if fn_format[1] == "in1signedlanenox" {
+            fn_name.push_str(&type_to_infix_suffix(
+                type_to_signed(type_to_sub_type(in_t[1])),
+                "_lane",
+            ));
         } else if fn_format[1] == "doubleself" {
             fn_name.push_str(&type_to_double_suffixes(out_t, in_t[1]));
         } else if fn_format[1] == "noq_doubleself" {
@@ -2410,6 +3076,7 @@ fn main() -> io::Result<()> {
     )> = Vec::new();
     let mut multi_fn: Vec<String> = Vec::new();
     let mut target: TargetFeature = Default;
+    let mut fn_type: Fntype = Fntype::Normal;
 
     //
     // THIS FILE IS GENERATED FORM neon.spec DO NOT CHANGE IT MANUALLY
@@ -2491,6 +3158,7 @@ mod test {
             n = None;
             multi_fn = Vec::new();
             target = Default;
+            fn_type = Fntype::Normal;
         } else if line.starts_with("//") {
         } else if line.starts_with("name = ") {
             current_name = Some(String::from(&line[7..]));
@@ -2526,6 +3194,26 @@ mod test {
             suffix = In2;
         } else if line.starts_with("in2-lane-suffixes") {
             suffix = In2Lane;
+        } else if line.starts_with("out-nox") {
+            suffix = OutNox;
+        } else if line.starts_with("in1-nox") {
+            suffix = In1Nox;
+        } else if line.starts_with("out-dup-nox") {
+            suffix = OutDupNox;
+        } else if line.starts_with("out-lane-nox") {
+            suffix = OutLaneNox;
+        } else if line.starts_with("in1-lane-nox") {
+            suffix = In1LaneNox;
+        } else if line.starts_with("in2-dot-lane-suffixes") {
+            suffix = In2DotLane;
+        } else if line.starts_with("rot-suffix") {
+            suffix = Rot;
+        } else if line.starts_with("rot-lane-suffixes") {
+            suffix = RotLane;
+        } else if line.starts_with("load_fn") {
+            fn_type = Fntype::Load;
+        } else if line.starts_with("store_fn") {
+            fn_type = Fntype::Store;
         } else if line.starts_with("a = ") {
             a = line[4..].split(',').map(|v| v.trim().to_string()).collect();
         } else if line.starts_with("b = ") {
@@ -2553,6 +3241,11 @@ mod test {
                     "v7" => ArmV7,
                     "fp-armv8" => FPArmV8,
                     "crypto" => Crypto,
+                    "dotprod" => Dotprod,
+                    "i8mm" => I8MM,
+                    "bf16" => BF16,
+                    "fcma" => Fcma,
+                    "frintts" => FrintTS,
                     _ => Default,
                 },
                 _ => Default,
@@ -2592,7 +3285,9 @@ mod test {
                 } else {
                     panic!("Bad spec: {}", line)
                 }
-                if b.len() == 0 {
+                if fn_type == Fntype::Store {
+                    para_num = 2;
+                } else if b.len() == 0 {
                     para_num = 1;
                 } else if c.len() != 0 {
                     para_num = 3;
@@ -2618,6 +3313,7 @@ mod test {
                         target,
                         &fixed,
                         &multi_fn,
+                        fn_type,
                     );
                     out_arm.push_str(&function);
                     tests_arm.push_str(&test);
@@ -2638,6 +3334,7 @@ mod test {
                         target,
                         &fixed,
                         &multi_fn,
+                        fn_type,
                     );
                     out_aarch64.push_str(&function);
                     tests_aarch64.push_str(&test);
@@ -41,6 +41,8 @@ static U16: Type = Type::PrimUnsigned(16);
 static U32: Type = Type::PrimUnsigned(32);
 static U64: Type = Type::PrimUnsigned(64);
 static U128: Type = Type::PrimUnsigned(128);
+// Only used by intrinsics that are exclusive to x86_64.
+static USIZE: Type = Type::PrimUnsigned(64);
 static ORDERING: Type = Type::Ordering;
 
 static M64: Type = Type::M64;
@@ -48,14 +50,17 @@ static M128: Type = Type::M128;
 static M128BH: Type = Type::M128BH;
 static M128I: Type = Type::M128I;
 static M128D: Type = Type::M128D;
+static M128H: Type = Type::M128H;
 static M256: Type = Type::M256;
 static M256BH: Type = Type::M256BH;
 static M256I: Type = Type::M256I;
 static M256D: Type = Type::M256D;
+static M256H: Type = Type::M256H;
 static M512: Type = Type::M512;
 static M512BH: Type = Type::M512BH;
 static M512I: Type = Type::M512I;
 static M512D: Type = Type::M512D;
+static M512H: Type = Type::M512H;
 static MMASK8: Type = Type::MMASK8;
 static MMASK16: Type = Type::MMASK16;
 static MMASK32: Type = Type::MMASK32;
@@ -80,14 +85,17 @@ enum Type {
     M128,
     M128BH,
     M128D,
+    M128H,
     M128I,
     M256,
     M256BH,
     M256D,
+    M256H,
     M256I,
     M512,
     M512BH,
     M512D,
+    M512H,
     M512I,
     MMASK8,
     MMASK16,
@@ -504,6 +512,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             "avx512_bf16" => String::from("avx512bf16"),
             // The XML file names VNNI as "avx512_bf16", while Rust calls
             // it "avx512bf16".
+            // The XML file names FP16 as "avx512_fp16", while Rust calls
+            // it "avx512fp16".
+            "avx512_fp16" => String::from("avx512fp16"),
             _ => cpuid,
         };
         let fixed_cpuid = fixup_cpuid(cpuid);
@@ -652,7 +663,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         | "_mm_cvttsd_i64"
         | "_mm_cvttsd_u64"
         | "_mm_cvtu64_ss"
-        | "_mm_cvtu64_sd" => true,
+        | "_mm_cvtu64_sd"
+        | "_cvtmask64_u64"
+        | "_cvtu64_mask64" => true,
 
         // These return a 64-bit argument but they're assembled from other
         // 32-bit registers, so these work on 32-bit just fine. See #308 for
@@ -689,7 +702,8 @@ fn equate(
         intel = intel.replace("const ", "");
         intel = intel.replace("*", " const*");
     }
-    if etype == "IMM" {
+    // The AMX intrinsics take the indices of their tiles as `constexpr int`.
+    if etype == "IMM" || intel == "constexpr int" {
         // The _bittest intrinsics claim to only accept immediates but actually
         // accept run-time values as well.
         if !is_const && !intrinsic.starts_with("_bittest") {
@@ -709,29 +723,35 @@ fn equate(
         (&Type::PrimSigned(16), "short") => {}
         (&Type::PrimSigned(32), "__int32") => {}
         (&Type::PrimSigned(32), "const int") => {}
+        (&Type::PrimSigned(32), "constexpr int") => {}
         (&Type::PrimSigned(32), "int") => {}
         (&Type::PrimSigned(64), "__int64") => {}
         (&Type::PrimSigned(64), "long long") => {}
         (&Type::PrimSigned(8), "__int8") => {}
         (&Type::PrimSigned(8), "char") => {}
         (&Type::PrimUnsigned(16), "unsigned short") => {}
+        (&Type::PrimUnsigned(16), "_Float16") => {}
         (&Type::PrimUnsigned(32), "unsigned int") => {}
         (&Type::PrimUnsigned(32), "const unsigned int") => {}
         (&Type::PrimUnsigned(64), "unsigned __int64") => {}
+        (&Type::PrimUnsigned(64), "size_t") => {}
         (&Type::PrimUnsigned(8), "unsigned char") => {}
         (&Type::M64, "__m64") => {}
         (&Type::M128, "__m128") => {}
         (&Type::M128BH, "__m128bh") => {}
         (&Type::M128I, "__m128i") => {}
         (&Type::M128D, "__m128d") => {}
+        (&Type::M128H, "__m128h") => {}
         (&Type::M256, "__m256") => {}
         (&Type::M256BH, "__m256bh") => {}
         (&Type::M256I, "__m256i") => {}
         (&Type::M256D, "__m256d") => {}
+        (&Type::M256H, "__m256h") => {}
         (&Type::M512, "__m512") => {}
         (&Type::M512BH, "__m512bh") => {}
         (&Type::M512I, "__m512i") => {}
         (&Type::M512D, "__m512d") => {}
+        (&Type::M512H, "__m512h") => {}
         (&Type::MMASK64, "__mmask64") => {}
         (&Type::MMASK32, "__mmask32") => {}
         (&Type::MMASK16, "__mmask16") => {}
@@ -753,6 +773,9 @@ fn equate(
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "unsigned int*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "unsigned __int64*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(8)), "void*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(8)), "unsigned char*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(8)), "__mmask8*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(16)), "__mmask16*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
         (&Type::MutPtr(&Type::M64), "__m64*") => {}
@@ -799,6 +822,8 @@ fn equate(
         (&Type::ConstPtr(&Type::M512BH), "__m512bh const*") => {}
         (&Type::ConstPtr(&Type::M512I), "__m512i const*") => {}
         (&Type::ConstPtr(&Type::M512D), "__m512d const*") => {}
+        (&Type::ConstPtr(&Type::PrimUnsigned(8)), "__mmask8*") => {}
+        (&Type::ConstPtr(&Type::PrimUnsigned(16)), "__mmask16*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
 